@@ -10,8 +10,17 @@
 
 //! CLI output formatters (`pretty`, `json`, and `markdown`).
 
-use design_data_core::diff::{ChangeType, DiffReport, PropertyChange};
-use design_data_core::report::ValidationReport;
+use std::path::Path;
+
+use design_data_core::diff::{
+    ChangeType, DiffReport, PropertyChange, ResolvedDiffReport, ResolvedValueChange,
+};
+use design_data_core::locate::{self, SourceCache};
+use design_data_core::release::{Bump, Classification, Subject};
+use design_data_core::report::{Diagnostic, Severity, ValidationReport};
+use design_data_core::validate::baseline::BaselineOutcome;
+use design_data_core::validate::fix::{FixOutcome, FixPlan, PlannedFix};
+use miette::{GraphicalReportHandler, LabeledSpan, MietteDiagnostic, NamedSource, Report};
 
 /// Human-readable stderr/stdout mix: errors on stderr, success line on stdout.
 pub fn print_report_pretty(report: &ValidationReport) {
@@ -19,6 +28,7 @@ pub fn print_report_pretty(report: &ValidationReport) {
         println!("No issues found.");
         return;
     }
+    let mut sources = SourceCache::default();
     for d in &report.errors {
         eprintln!(
             "error: {} [{}] {}",
@@ -32,15 +42,139 @@ pub fn print_report_pretty(report: &ValidationReport) {
         if let Some(p) = &d.instance_path {
             eprintln!("  at: {p}");
         }
+        print_fix_hint(d);
+        print_code_frame(&mut sources, d);
     }
     for d in &report.warnings {
+        let label = match d.severity {
+            Severity::Info => "info",
+            _ => "warning",
+        };
         eprintln!(
-            "warning: {} [{}] {}",
+            "{label}: {} [{}] {}",
             d.file.display(),
             d.rule_id.as_deref().unwrap_or("?"),
             d.message
         );
+        print_fix_hint(d);
+        print_code_frame(&mut sources, d);
+    }
+}
+
+/// Source excerpt under a diagnostic's header, rendered by miette with the
+/// span's first line highlighted. Prints nothing when the file is unreadable
+/// or no span can be found.
+fn print_code_frame(sources: &mut SourceCache, d: &Diagnostic) {
+    let Some(span) = sources.span(d) else {
+        return;
+    };
+    let Some(text) = sources.text(&d.file) else {
+        return;
+    };
+    // `sources.span` only returns spans inside the file, but stay defensive:
+    // a frame is optional, the header line above already carries the message.
+    let Some(covered) = locate::span_text(text, span) else {
+        return;
+    };
+    // A token span covers the whole object; underline only its first line.
+    let end = span.offset + covered.find('\n').unwrap_or(covered.len());
+    let label = d.rule_id.as_deref().unwrap_or("structural");
+    // Empty message: the header line above already carries it.
+    let diagnostic = MietteDiagnostic::new("").with_label(LabeledSpan::at(span.offset..end, label));
+    let report = Report::new(diagnostic).with_source_code(NamedSource::new(
+        d.file.display().to_string(),
+        text.to_string(),
+    ));
+    let mut out = String::new();
+    if GraphicalReportHandler::new()
+        .render_report(&mut out, report.as_ref())
+        .is_err()
+    {
+        return;
+    }
+    for line in out
+        .lines()
+        .skip_while(|l| l.trim().is_empty())
+        .skip(1) // the empty `×` message line
+        .filter(|l| !l.trim().is_empty())
+    {
+        eprintln!("{line}");
+    }
+}
+
+fn print_fix_hint(d: &Diagnostic) {
+    if let Some(fix) = &d.fix {
+        eprintln!("  fix: {} (validate --fix)", fix.description);
+    }
+}
+
+/// Summary of a `validate --baseline` comparison (stderr, like the fix summary).
+pub fn print_baseline_outcome(outcome: &BaselineOutcome, path: &Path) {
+    for e in &outcome.fixed {
+        let times = if e.count > 1 {
+            format!(" (x{})", e.count)
+        } else {
+            String::new()
+        };
+        eprintln!(
+            "baseline: fixed [{}] {}: {}{times}",
+            e.rule, e.subject, e.message
+        );
+    }
+    let fixed: usize = outcome.fixed.iter().map(|e| e.count).sum();
+    eprintln!(
+        "baseline: {} known diagnostic(s) suppressed, {fixed} fixed",
+        outcome.suppressed
+    );
+    if fixed > 0 {
+        eprintln!(
+            "baseline: run with --update-baseline to remove fixed entries from {}",
+            path.display()
+        );
+    }
+}
+
+/// Summary of a `validate --fix` run (stderr, so `--format json` stays parseable).
+pub fn print_fix_outcome(plan: &FixPlan, outcome: &FixOutcome, dry_run: bool) {
+    let verb = if dry_run { "would fix" } else { "fixed" };
+    for (file, p) in &outcome.applied {
+        eprintln!("{verb}: {}", describe_fix(file, p));
+    }
+    for (file, p) in &plan.conflicts {
+        eprintln!(
+            "skipped: {} (overlaps another fix; re-run --fix)",
+            describe_fix(file, p)
+        );
+    }
+    for (file, p, reason) in &outcome.failed {
+        eprintln!("skipped: {} ({reason})", describe_fix(file, p));
+    }
+    for (file, p) in &plan.held_back {
+        eprintln!(
+            "skipped: {} (renames a published key; pass --fix-renames)",
+            describe_fix(file, p)
+        );
     }
+    eprintln!(
+        "{} fix(es) {} in {} file(s)",
+        outcome.applied.len(),
+        if dry_run { "to apply" } else { "applied" },
+        outcome.files_written.len()
+    );
+}
+
+fn describe_fix(file: &std::path::Path, p: &PlannedFix) -> String {
+    let token = p
+        .token
+        .as_deref()
+        .map(|t| format!(" ({t})"))
+        .unwrap_or_default();
+    format!(
+        "{} [{}] {}{token}",
+        file.display(),
+        p.rule_id.as_deref().unwrap_or("structural"),
+        p.fix.description
+    )
 }
 
 /// Full report as JSON (stdout).
@@ -187,10 +321,11 @@ fn print_property_changes_pretty(changes: &[PropertyChange]) {
             }
             ChangeType::Updated => {
                 println!(
-                    "    {y}~{r} {}: {} → {}",
+                    "    {y}~{r} {}: {} → {}{}",
                     c.path,
                     fmt_value(&c.original_value),
                     fmt_value(&c.new_value),
+                    fmt_delta_e(c),
                     y = color(YELLOW),
                     r = color(RESET),
                 );
@@ -212,6 +347,15 @@ fn fmt_value(v: &Option<serde_json::Value>) -> String {
     }
 }
 
+/// ` (ΔE 0.42, visually identical)` suffix for color updates; empty otherwise.
+fn fmt_delta_e(c: &PropertyChange) -> String {
+    match c.delta_e {
+        Some(de) if c.visually_identical => format!(" (ΔE {de:.2}, visually identical)"),
+        Some(de) => format!(" (ΔE {de:.2})"),
+        None => String::new(),
+    }
+}
+
 /// Markdown-formatted diff report for changelogs.
 pub fn format_diff_markdown(report: &DiffReport) -> String {
     let mut out = String::new();
@@ -302,12 +446,194 @@ fn format_property_changes_md(changes: &[PropertyChange], out: &mut String) {
             }
             ChangeType::Updated => {
                 out.push_str(&format!(
-                    "  - **~** `{}`: {} → {}\n",
+                    "  - **~** `{}`: {} → {}{}\n",
                     c.path,
                     fmt_value(&c.original_value),
                     fmt_value(&c.new_value),
+                    fmt_delta_e(c),
                 ));
             }
         }
     }
 }
+
+// ── Resolved diff formatters ───────────────────────────────────────────────
+
+/// Human-readable `diff --resolved` report.
+pub fn print_resolved_diff_pretty(report: &ResolvedDiffReport) {
+    println!(
+        "Compared {} token(s) in {} mode combination(s)",
+        report.compared, report.combinations
+    );
+    if report.is_empty() {
+        println!("No resolved value changes.");
+        return;
+    }
+    println!("{} token(s) changed resolved value\n", report.changed.len());
+    for t in &report.changed {
+        println!("  {c}{}{r}", t.name, c = color(YELLOW), r = color(RESET));
+        for c in &t.changes {
+            println!(
+                "    {}: {} → {}{}",
+                fmt_when(c),
+                fmt_value(&c.original_value),
+                fmt_value(&c.new_value),
+                fmt_resolved_delta_e(c),
+            );
+            println!("      {}", fmt_chains(c, " → "));
+        }
+    }
+}
+
+/// Markdown `diff --resolved` report.
+pub fn format_resolved_diff_markdown(report: &ResolvedDiffReport) -> String {
+    let mut out = String::new();
+    if report.is_empty() {
+        out.push_str("No resolved value changes.\n");
+        return out;
+    }
+    out.push_str(&format!(
+        "**{} token(s) changed resolved value across {} mode combination(s).**\n\n",
+        report.changed.len(),
+        report.combinations
+    ));
+    for t in &report.changed {
+        out.push_str(&format!("- `{}`\n", t.name));
+        for c in &t.changes {
+            out.push_str(&format!(
+                "  - {}: {} → {}{} — {}\n",
+                fmt_when(c),
+                fmt_value(&c.original_value),
+                fmt_value(&c.new_value),
+                fmt_resolved_delta_e(c),
+                fmt_chains(c, " → "),
+            ));
+        }
+    }
+    out
+}
+
+/// `colorScheme=dark, scale=mobile | …`, or `all modes` for an empty pattern.
+fn fmt_when(c: &ResolvedValueChange) -> String {
+    let patterns: Vec<String> = c
+        .when
+        .iter()
+        .map(|p| {
+            if p.is_empty() {
+                "all modes".to_string()
+            } else {
+                p.iter()
+                    .map(|(set, mode)| format!("{set}={mode}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        })
+        .collect();
+    patterns.join(" | ")
+}
+
+/// The new alias chain and the cause, plus the old chain when it differs.
+fn fmt_chains(c: &ResolvedValueChange, sep: &str) -> String {
+    let mut out = format!("via {} (changed at {})", c.new_chain.join(sep), c.cause);
+    if c.original_chain != c.new_chain && !c.original_chain.is_empty() {
+        out.push_str(&format!(", was {}", c.original_chain.join(sep)));
+    }
+    out
+}
+
+fn fmt_resolved_delta_e(c: &ResolvedValueChange) -> String {
+    match c.delta_e {
+        Some(de) if c.visually_identical => format!(" (ΔE {de:.2}, visually identical)"),
+        Some(de) => format!(" (ΔE {de:.2})"),
+        None => String::new(),
+    }
+}
+
+// ── Release classification formatters ──────────────────────────────────────
+
+fn bump_summary(c: &Classification) -> String {
+    let counts: Vec<String> = [Bump::Major, Bump::Minor, Bump::Patch]
+        .into_iter()
+        .filter(|b| c.count(*b) > 0)
+        .map(|b| format!("{} {b}", c.count(b)))
+        .collect();
+    if counts.is_empty() {
+        "no changes".to_string()
+    } else {
+        counts.join(", ")
+    }
+}
+
+fn declared_verdict(c: &Classification, declared: Bump) -> &'static str {
+    if c.satisfied_by(declared) {
+        "ok"
+    } else {
+        "lower than required"
+    }
+}
+
+fn subject_label(s: Subject) -> &'static str {
+    match s {
+        Subject::Token => "token",
+        Subject::Component => "component",
+    }
+}
+
+/// Required bump, declared-bump verdict, and per-change justifications on stdout.
+pub fn print_classification_pretty(c: &Classification, declared: Option<Bump>) {
+    println!("Required bump: {} ({})", c.bump, bump_summary(c));
+    if let Some(d) = declared {
+        let code = if c.satisfied_by(d) { GREEN } else { RED };
+        println!(
+            "Declared bump: {d} ({k}{}{r})",
+            declared_verdict(c, d),
+            k = color(code),
+            r = color(RESET),
+        );
+    }
+    if c.changes.is_empty() {
+        return;
+    }
+    println!();
+    for j in &c.changes {
+        let code = match j.bump {
+            Bump::Major => RED,
+            Bump::Minor => YELLOW,
+            _ => CYAN,
+        };
+        println!(
+            "  {k}{:<5}{r}  {:<9}  {}  {}",
+            j.bump.as_str(),
+            subject_label(j.subject),
+            j.name,
+            j.reason,
+            k = color(code),
+            r = color(RESET),
+        );
+    }
+}
+
+/// Markdown table of the classification for PR comments.
+pub fn format_classification_markdown(c: &Classification, declared: Option<Bump>) -> String {
+    let mut out = format!("**Required bump: {}** ({})\n", c.bump, bump_summary(c));
+    if let Some(d) = declared {
+        out.push_str(&format!(
+            "\nDeclared bump: {d} ({})\n",
+            declared_verdict(c, d)
+        ));
+    }
+    if c.changes.is_empty() {
+        return out;
+    }
+    out.push_str("\n| Bump | Subject | Name | Reason |\n| --- | --- | --- | --- |\n");
+    for j in &c.changes {
+        out.push_str(&format!(
+            "| {} | {} | `{}` | {} |\n",
+            j.bump,
+            subject_label(j.subject),
+            j.name,
+            j.reason.replace('|', "\\|"),
+        ));
+    }
+    out
+}
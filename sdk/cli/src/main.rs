@@ -22,15 +22,20 @@ use std::collections::{HashMap, HashSet};
 
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 use design_data_core::cache;
-use design_data_core::cascade::{resolve_property, ResolutionContext};
+use design_data_core::cascade::{self, resolve_property, ResolutionContext};
+use design_data_core::codemod;
 use design_data_core::compat::{
     load_snapshot, snapshot_matches, write_snapshot, ValidationSnapshot,
 };
+use design_data_core::component;
 use design_data_core::data_source::{self, CliPathOverrides};
 use design_data_core::diff;
 use design_data_core::diff::display_name;
+use design_data_core::dtcg;
 use design_data_core::figma;
-use design_data_core::graph::TokenGraph;
+use design_data_core::generate;
+use design_data_core::graph::{TokenGraph, TokenRecord};
+use design_data_core::graph_export;
 use design_data_core::legacy;
 use design_data_core::manifest;
 use design_data_core::migrate;
@@ -38,9 +43,13 @@ use design_data_core::naming;
 use design_data_core::naming::NamingExceptionsFile;
 use design_data_core::primer;
 use design_data_core::query;
+use design_data_core::release::{self, Bump};
+use design_data_core::report::ValidationReport;
 use design_data_core::schema::SchemaRegistry;
 use design_data_core::suggest;
+use design_data_core::unused::{self, UnusedExceptionsFile};
 use design_data_core::validate;
+use design_data_core::validate::baseline::Baseline;
 use design_data_core::write::{write_token, WriteTokenInput};
 use design_data_tui::{LaunchOptions, ThemeChoice};
 use miette::{IntoDiagnostic, WrapErr};
@@ -105,8 +114,8 @@ enum Commands {
         #[arg(value_name = "PATH")]
         path: Option<PathBuf>,
         /// Output format
-        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
-        format: OutputFormat,
+        #[arg(long, value_enum, default_value_t = ValidateFormat::Pretty)]
+        format: ValidateFormat,
         /// Root directory containing `token-types/` and `token-file.json`
         #[arg(long, value_name = "DIR")]
         schema_path: Option<PathBuf>,
@@ -133,6 +142,25 @@ enum Commands {
         /// Treat warnings as errors
         #[arg(long)]
         strict: bool,
+        /// Apply machine-applicable fixes (duplicate uuids, missing lifecycle.deprecatedIn,
+        /// lastModified before introduced, …) to the source files, then re-validate
+        #[arg(long)]
+        fix: bool,
+        /// With --fix: list the fixes that would be applied without writing any file
+        #[arg(long, requires = "fix")]
+        dry_run: bool,
+        /// With --fix: also rename legacy keys that fail the SPEC-007 name roundtrip,
+        /// rewriting `{old}` references in the dataset. Breaking for consumers of the
+        /// old key; review with --dry-run first
+        #[arg(long, requires = "fix")]
+        fix_renames: bool,
+        /// Accept the diagnostics recorded in this baseline file: fail only on new ones,
+        /// and list baselined diagnostics that are no longer reported
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+        /// With --baseline: rewrite the baseline file from the current diagnostics
+        #[arg(long, requires = "baseline")]
+        update_baseline: bool,
     },
     /// Validate a whole dataset directory: SPEC-044 structure pre-check, tokens,
     /// and the registered catalog directories (fields, components, mode-sets, registry)
@@ -141,8 +169,8 @@ enum Commands {
         #[arg(value_name = "PATH")]
         path: Option<PathBuf>,
         /// Output format
-        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
-        format: OutputFormat,
+        #[arg(long, value_enum, default_value_t = ValidateFormat::Pretty)]
+        format: ValidateFormat,
         /// Root directory containing legacy `token-types/` and `token-file.json`
         #[arg(long, value_name = "DIR")]
         schema_path: Option<PathBuf>,
@@ -176,10 +204,81 @@ enum Commands {
         /// Contrast mode (e.g. regular, high)
         #[arg(long, value_name = "MODE")]
         contrast: Option<String>,
+        /// Reduced-motion mode (e.g. no-preference, reduce)
+        #[arg(long, value_name = "MODE")]
+        reduced_motion: Option<String>,
+        /// Mode for any declared mode set (repeatable, e.g. --mode density=compact)
+        #[arg(long = "mode", value_name = "SET=MODE")]
+        modes: Vec<String>,
+        /// Resolve across every mode-set combination; given modes pin their axis
+        #[arg(long)]
+        matrix: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+        format: OutputFormat,
+    },
+    /// Report what depends on a token — transitive aliases and composites,
+    /// higher-layer overrides, component bindings and CTR relationships — and
+    /// how a proposed value would change their resolved values
+    Impact {
+        /// Token UUID, set UUID or legacy key (e.g. blue-900)
+        #[arg(value_name = "TOKEN")]
+        target: String,
+        /// Directory containing cascade-format .tokens.json files
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+        /// Proposed new value (JSON, or a bare string such as #ff0000)
+        #[arg(long, value_name = "VALUE")]
+        value: Option<String>,
+        /// product-context.json whose overrides and extensions join as Product-layer tokens
+        #[arg(long, value_name = "FILE")]
+        product_context: Option<PathBuf>,
+        /// Directory containing spec-format mode set declaration JSON files
+        #[arg(long, value_name = "DIR")]
+        mode_sets_path: Option<PathBuf>,
+        /// Directory containing component declaration JSON files
+        #[arg(long, value_name = "DIR")]
+        components_path: Option<PathBuf>,
+        /// Directory containing Component/Token Relationship (CTR) JSON files
+        #[arg(long, value_name = "DIR")]
+        relationships_path: Option<PathBuf>,
         /// Output format
         #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
         format: OutputFormat,
     },
+    /// Export the alias graph (token aliases, component bindings, CTR edges)
+    /// as Graphviz DOT, Mermaid or node-link JSON
+    Graph {
+        /// Directory containing cascade-format .tokens.json files
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+        /// Start from the nodes matching this query expression (e.g. "component=button")
+        #[arg(long, value_name = "EXPR")]
+        filter: Option<String>,
+        /// Follow at most this many edges from the filtered nodes
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// Write the graph to this file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// Directory containing spec-format mode set declaration JSON files
+        #[arg(long, value_name = "DIR")]
+        mode_sets_path: Option<PathBuf>,
+        /// Directory containing component declaration JSON files
+        #[arg(long, value_name = "DIR")]
+        components_path: Option<PathBuf>,
+        /// Directory containing Component/Token Relationship (CTR) JSON files
+        #[arg(long, value_name = "DIR")]
+        relationships_path: Option<PathBuf>,
+    },
+    /// Dataset-wide analyses beyond schema and catalog validation
+    Lint {
+        #[command(subcommand)]
+        sub: LintSub,
+    },
     /// Decompose a legacy kebab-case token slug into its structured name-object
     /// fields (property/component/variant/state), verifying the roundtrip.
     /// One-off exposure of `naming::parse_legacy_name`/`roundtrips` for external
@@ -203,6 +302,10 @@ enum Commands {
         /// Directory containing cascade-format .tokens.json files
         #[arg(value_name = "PATH")]
         path: Option<PathBuf>,
+        /// Platform manifest whose `extensions.formatting` names each token
+        /// (`formattedName`); defaults to the `.design-data.toml` manifest
+        #[arg(long, value_name = "FILE")]
+        manifest: Option<PathBuf>,
     },
     /// Compare two token datasets and report changes
     Diff {
@@ -218,15 +321,64 @@ enum Commands {
         /// Filter to scope diff to matching tokens (query notation)
         #[arg(long, value_name = "EXPR")]
         filter: Option<String>,
+        /// Report the semver bump the changes require (spec/evolution.md) instead of
+        /// the change listing. Exits 1 only when a declared bump is lower than required
+        #[arg(long)]
+        classify: bool,
+        /// Bump the release declares (major, minor, patch, or none); requires --classify
+        #[arg(
+            long,
+            value_name = "BUMP",
+            requires = "classify",
+            conflicts_with = "changeset"
+        )]
+        declared: Option<Bump>,
+        /// Changeset file, or directory of changesets, declaring the bump; requires --classify
+        #[arg(long, value_name = "PATH", requires = "classify")]
+        changeset: Option<PathBuf>,
+        /// Only count changeset entries for this package
+        #[arg(long, value_name = "NAME", requires = "changeset")]
+        package: Option<String>,
+        /// Old component catalog directory [default: OLD/../components when present]
+        #[arg(long, value_name = "DIR")]
+        old_components: Option<PathBuf>,
+        /// New component catalog directory [default: NEW/../components when present]
+        #[arg(long, value_name = "DIR")]
+        new_components: Option<PathBuf>,
+        /// Render release notes grouped by component and category (markdown, or json
+        /// with --format json) instead of the change listing
+        #[arg(long, conflicts_with = "classify")]
+        notes: bool,
+        /// Version to title the release notes with
+        #[arg(long, value_name = "VERSION", requires = "notes")]
+        release_version: Option<String>,
+        /// Markdown template for the release notes; {{version}}, {{summary}}, and
+        /// {{notes}} are replaced
+        #[arg(long, value_name = "FILE", requires = "notes")]
+        template: Option<PathBuf>,
+        /// Compare effective values: resolve every token through the cascade and
+        /// its alias chain in each mode combination, and report values that differ
+        #[arg(long, conflicts_with_all = ["classify", "notes"])]
+        resolved: bool,
+        /// Mode set declarations for both datasets with --resolved
+        /// [default: mode-sets/ beside each token directory when present]
+        #[arg(long, value_name = "DIR", requires = "resolved")]
+        mode_sets_path: Option<PathBuf>,
     },
     /// Filter and list tokens matching a query expression
     Query {
         /// Path to token dataset directory
         #[arg(value_name = "PATH")]
         path: Option<PathBuf>,
-        /// Filter expression (e.g. "component=button,state=hover")
+        /// Filter expression (e.g. "component=button,state in (hover,down),!exists(deprecated)"
+        /// or "property=*-size-*,value>=24px")
         #[arg(long, value_name = "EXPR")]
         filter: String,
+        /// Query the tokens that win cascade resolution in this context; `value`
+        /// conditions compare the values they resolve to. Repeatable or
+        /// comma-separated (e.g. "colorScheme=dark,scale=mobile")
+        #[arg(long, value_name = "SET=MODE")]
+        context: Vec<String>,
         /// Output format
         #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
         format: OutputFormat,
@@ -234,6 +386,11 @@ enum Commands {
         #[arg(long)]
         count: bool,
     },
+    /// Generate platform source (CSS, Swift, Kotlin) from the resolved dataset
+    Generate {
+        #[command(subcommand)]
+        sub: GenerateSub,
+    },
     /// Snapshot and backward-compat verification helpers
     Migrate {
         #[command(subcommand)]
@@ -379,6 +536,61 @@ enum Commands {
     Tui(TuiArgs),
 }
 
+#[derive(Subcommand)]
+enum GenerateSub {
+    /// Emit CSS custom properties, one selector per mode-set combination
+    Css {
+        #[command(flatten)]
+        common: GenerateArgs,
+        /// Prefix for custom property and mode class names
+        #[arg(long, value_name = "PREFIX", default_value = "spectrum")]
+        prefix: String,
+        /// Selector for default-mode values
+        #[arg(long, value_name = "SELECTOR", default_value = ":root")]
+        root_selector: String,
+    },
+    /// Emit a Swift source file of UIKit constants, one enum per mode-set combination
+    Swift {
+        #[command(flatten)]
+        common: GenerateArgs,
+        /// Name of the top-level namespace enum
+        #[arg(long, value_name = "NAME", default_value = "Spectrum")]
+        namespace: String,
+    },
+    /// Emit a Kotlin source file of Compose constants, one object per mode-set combination
+    Kotlin {
+        #[command(flatten)]
+        common: GenerateArgs,
+        /// Name of the top-level object
+        #[arg(long, value_name = "NAME", default_value = "Spectrum")]
+        namespace: String,
+        /// Kotlin package declaration
+        #[arg(
+            long,
+            value_name = "PACKAGE",
+            default_value = "com.adobe.spectrum.tokens"
+        )]
+        package: String,
+    },
+}
+
+/// Arguments shared by every `generate` backend.
+#[derive(Args)]
+struct GenerateArgs {
+    /// Directory containing cascade-format .tokens.json files
+    #[arg(value_name = "PATH")]
+    path: Option<PathBuf>,
+    /// Write the generated source to this file instead of stdout
+    #[arg(long, value_name = "FILE")]
+    output: Option<PathBuf>,
+    /// Directory containing spec-format mode set declaration JSON files
+    #[arg(long, value_name = "DIR")]
+    mode_sets_path: Option<PathBuf>,
+    /// Platform manifest.json to apply (overrides the `.design-data.toml` `manifest` key)
+    #[arg(long, value_name = "FILE")]
+    manifest: Option<PathBuf>,
+}
+
 #[derive(Subcommand)]
 enum MigrateSub {
     /// Run validation and compare to a golden snapshot JSON
@@ -432,9 +644,9 @@ enum MigrateSub {
     /// Convert a dataset, with its configured platform manifest cascade applied,
     /// to a single legacy-format JSON snapshot (e.g. for tokentool
     /// `generate-source-code --input`). Contrast-variant tokens are skipped —
-    /// `legacy::convert_array` groups sets by colorScheme/scale only, so a
-    /// contrast:high extension sharing a base token's colorScheme would
-    /// silently overwrite it (see bead spectrum-design-data-h890.17).
+    /// `legacy::convert_array` groups sets by colorScheme/scale/reducedMotion
+    /// only, so a contrast:high extension sharing a base token's colorScheme
+    /// would silently overwrite it (see bead spectrum-design-data-h890.17).
     LegacyOutputCascaded {
         /// Path to the token dataset directory (default: resolved canonical dataset)
         #[arg(value_name = "PATH")]
@@ -455,6 +667,45 @@ enum MigrateSub {
         #[arg(value_name = "PATH")]
         path: PathBuf,
     },
+    /// Export a cascade dataset as a single W3C DTCG (`$value`/`$type`) JSON file
+    ToDtcg {
+        /// Path to the token dataset directory (default: current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+        /// Destination DTCG JSON file
+        #[arg(long, value_name = "FILE")]
+        output: PathBuf,
+    },
+    /// Import a W3C DTCG JSON file as cascade-format .tokens.json files (one per top-level group)
+    FromDtcg {
+        /// DTCG JSON file to import
+        #[arg(value_name = "INPUT")]
+        input: PathBuf,
+        /// Destination directory for cascade .tokens.json output files
+        #[arg(long, value_name = "OUTPUT")]
+        output: PathBuf,
+    },
+    /// Verify that the cascade → DTCG → cascade roundtrip is lossless
+    DtcgVerify {
+        /// Path to the token dataset directory (default: current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+    },
+    /// Rewrite references to deprecated tokens in consumer source files
+    /// (CSS/SCSS/JS/TS/Swift/Kotlin) to their `replacedBy` replacement. References
+    /// to tokens with several replacements are reported, not rewritten, and make
+    /// the command exit 1
+    Consumers {
+        /// Consumer source directory (or single file) to migrate
+        #[arg(value_name = "DIR")]
+        dir: PathBuf,
+        /// Token dataset declaring the deprecations (default: resolved canonical dataset)
+        #[arg(long, value_name = "PATH")]
+        tokens: Option<PathBuf>,
+        /// Print a unified diff instead of writing files
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// Verify that regenerating legacy output from cascade matches a reference legacy directory
     LegacyVerify {
         /// Source directory containing cascade .tokens.json files
@@ -466,6 +717,32 @@ enum MigrateSub {
     },
 }
 
+#[derive(Subcommand)]
+enum LintSub {
+    /// Report tokens unreachable from component bindings, CTR relationships,
+    /// manifest `include` queries and non-component semantic tokens
+    Unused {
+        /// Directory containing cascade-format .tokens.json files
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+        /// Allowlist of tokens to keep (same shape as naming-exceptions.json)
+        #[arg(long, value_name = "FILE")]
+        allowlist: Option<PathBuf>,
+        /// Directory containing spec-format mode set declaration JSON files
+        #[arg(long, value_name = "DIR")]
+        mode_sets_path: Option<PathBuf>,
+        /// Directory containing component declaration JSON files
+        #[arg(long, value_name = "DIR")]
+        components_path: Option<PathBuf>,
+        /// Directory containing Component/Token Relationship (CTR) JSON files
+        #[arg(long, value_name = "DIR")]
+        relationships_path: Option<PathBuf>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+        format: OutputFormat,
+    },
+}
+
 #[derive(Subcommand)]
 enum FigmaSub {
     /// Read existing variables from a Figma file
@@ -520,6 +797,19 @@ enum OutputFormat {
     Json,
 }
 
+/// Output format for `validate` and `validate-dataset` (superset of `OutputFormat` —
+/// adds the CI report formats `sarif` and `junit`).
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum ValidateFormat {
+    #[default]
+    Pretty,
+    Json,
+    /// SARIF 2.1.0 (GitHub code scanning)
+    Sarif,
+    /// JUnit XML (one testcase per rule)
+    Junit,
+}
+
 /// Output format for the `diff` command (superset of `OutputFormat` — adds `markdown`).
 #[derive(Clone, Copy, Debug, Default, ValueEnum)]
 enum DiffFormat {
@@ -529,6 +819,18 @@ enum DiffFormat {
     Markdown,
 }
 
+/// Output format for the `graph` command.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum GraphFormat {
+    /// Graphviz DOT
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// Node-link JSON
+    Json,
+}
+
 fn load_exceptions(path: Option<&Path>) -> miette::Result<HashSet<String>> {
     let Some(p) = path else {
         // No path provided and not resolved — no exceptions file found.
@@ -555,8 +857,20 @@ fn run_decompose_legacy_name(slug: &str, component_hint: Option<&str>) -> miette
 
 /// `design-data dump-legacy-keys [PATH]` — prints a JSON array of
 /// `{legacyKey, uuid, colorScheme?, contrast?}`, one entry per token (not
-/// deduped by legacyKey, unlike `TokenGraph::legacy_name_index`).
-fn run_dump_legacy_keys(path: &Path) -> miette::Result<ExitCode> {
+/// deduped by legacyKey, unlike `TokenGraph::legacy_name_index`). When the
+/// platform manifest declares `extensions.formatting`, each entry also carries
+/// `formattedName`.
+fn run_dump_legacy_keys(path: &Path, manifest_path: Option<PathBuf>) -> miette::Result<ExitCode> {
+    let cwd = std::env::current_dir().into_diagnostic()?;
+    let mut resolved =
+        data_source::resolve(&cwd, &CliPathOverrides::default()).into_diagnostic()?;
+    if manifest_path.is_some() {
+        resolved.platform_manifest = manifest_path;
+    }
+    let formatting = manifest::configured_formatting(&resolved)
+        .into_diagnostic()
+        .wrap_err("failed to read platform manifest formatting")?;
+
     let graph = TokenGraph::open_cached(path)
         .into_diagnostic()
         .wrap_err_with(|| format!("failed to load tokens from {}", path.display()))?;
@@ -574,12 +888,16 @@ fn run_dump_legacy_keys(path: &Path) -> miette::Result<ExitCode> {
         };
         let color_scheme = name_val.get("colorScheme").and_then(|v| v.as_str());
         let contrast = name_val.get("contrast").and_then(|v| v.as_str());
-        out.push(serde_json::json!({
+        let mut entry = serde_json::json!({
             "legacyKey": legacy_key,
             "uuid": uuid,
             "colorScheme": color_scheme,
             "contrast": contrast,
-        }));
+        });
+        if let Some(formatted) = formatting.as_ref().and_then(|f| f.format(name_val)) {
+            entry["formattedName"] = formatted.into();
+        }
+        out.push(entry);
     }
     println!("{}", serde_json::to_string_pretty(&out).into_diagnostic()?);
     Ok(ExitCode::SUCCESS)
@@ -589,23 +907,10 @@ fn run_resolve(
     property: &str,
     path: &Path,
     mode_sets_path: Option<PathBuf>,
-    color_scheme: Option<String>,
-    scale: Option<String>,
-    contrast: Option<String>,
+    modes: &[String],
+    matrix: bool,
     format: OutputFormat,
 ) -> miette::Result<ExitCode> {
-    // Build resolution context from flags.
-    let mut resolve_ctx = ResolutionContext::new();
-    if let Some(m) = color_scheme {
-        resolve_ctx = resolve_ctx.with("colorScheme", m);
-    }
-    if let Some(m) = scale {
-        resolve_ctx = resolve_ctx.with("scale", m);
-    }
-    if let Some(m) = contrast {
-        resolve_ctx = resolve_ctx.with("contrast", m);
-    }
-
     // Resolve catalog paths first so the cache can hydrate them on hit.
     let cwd = std::env::current_dir().into_diagnostic()?;
     let resolved = data_source::resolve(
@@ -632,6 +937,9 @@ fn run_resolve(
         .into_diagnostic()
         .wrap_err("failed to apply platform manifest cascade")?;
 
+    // Build resolution context from the mode flags.
+    let mut resolve_ctx = parse_mode_pairs(&graph, "--mode", modes)?;
+
     for (mode_set, allowed) in &restrictions {
         resolve_ctx = resolve_ctx.with_restriction(mode_set.clone(), allowed.clone());
     }
@@ -643,6 +951,11 @@ fn run_resolve(
         return Ok(ExitCode::from(1));
     }
 
+    if matrix {
+        let m = cascade::resolve_matrix(&graph, property, &restrictions, &resolve_ctx.mode_sets);
+        return print_resolution_matrix(&m, format);
+    }
+
     match candidates.iter().find(|c| c.is_winner) {
         None => {
             eprintln!("No matching token for property '{property}' in given context");
@@ -676,8 +989,496 @@ fn run_resolve(
     }
 }
 
-struct ValidateOpts {
+/// Print `resolve --matrix` output: one row per distinct outcome. Exits 1 when
+/// no combination resolves to a value.
+fn print_resolution_matrix(
+    m: &cascade::ResolutionMatrix,
     format: OutputFormat,
+) -> miette::Result<ExitCode> {
+    let resolved_any = m.outcomes.iter().any(|o| o.value.is_some());
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(m).into_diagnostic()?);
+        }
+        OutputFormat::Pretty => {
+            let total: usize = m.outcomes.iter().map(|o| o.combinations).sum();
+            println!("Property:  {}", m.property);
+            let axes = m
+                .axes
+                .iter()
+                .map(|a| format!("{} ({})", a.name, a.modes.join(", ")))
+                .collect::<Vec<_>>();
+            if !axes.is_empty() {
+                println!("Axes:      {}", axes.join(" × "));
+            }
+            println!(
+                "Outcomes:  {} across {total} combination{}",
+                m.outcomes.len(),
+                if total == 1 { "" } else { "s" }
+            );
+            println!();
+            let rows: Vec<[String; 3]> = m
+                .outcomes
+                .iter()
+                .map(|o| {
+                    let when = o
+                        .when
+                        .iter()
+                        .map(|pattern| {
+                            if pattern.is_empty() {
+                                "(all)".to_string()
+                            } else {
+                                pattern
+                                    .iter()
+                                    .map(|(set, mode)| format!("{set}={mode}"))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" | ");
+                    let value = o
+                        .value
+                        .as_ref()
+                        .map(|v| v.to_string())
+                        .unwrap_or_else(|| "(no match)".to_string());
+                    let via = if o.chain.is_empty() {
+                        String::new()
+                    } else {
+                        format!("via {}", o.chain.join(" → "))
+                    };
+                    [when, value, via]
+                })
+                .collect();
+            let width = |i: usize| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0);
+            let (w0, w1) = (width(0), width(1));
+            for [when, value, via] in &rows {
+                let line = format!("{when:<w0$}  {value:<w1$}  {via}");
+                println!("{}", line.trim_end());
+            }
+        }
+    }
+    Ok(if resolved_any {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    })
+}
+
+fn run_graph(
+    path: &Path,
+    filter: Option<&str>,
+    depth: Option<usize>,
+    format: GraphFormat,
+    output: Option<&Path>,
+    overrides: CliPathOverrides,
+) -> miette::Result<ExitCode> {
+    let graph = load_graph_with_relationships(path, &overrides)?;
+    let filter = filter
+        .map(query::parse)
+        .transpose()
+        .into_diagnostic()
+        .wrap_err("failed to parse filter expression")?;
+
+    let exported = graph_export::export(&graph, filter.as_ref(), depth);
+    let text = match format {
+        GraphFormat::Dot => exported.to_dot(),
+        GraphFormat::Mermaid => exported.to_mermaid(),
+        GraphFormat::Json => {
+            serde_json::to_string_pretty(&exported.to_json()).into_diagnostic()? + "\n"
+        }
+    };
+    match output {
+        Some(file) => {
+            std::fs::write(file, text)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("failed to write {}", file.display()))?;
+            eprintln!(
+                "Wrote {} nodes and {} edges to {}",
+                exported.nodes.len(),
+                exported.edges.len(),
+                file.display()
+            );
+        }
+        None => print!("{text}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Load the token graph with its mode-set, component and relationship
+/// catalogs, with the configured platform manifest applied.
+fn load_graph_with_relationships(
+    path: &Path,
+    overrides: &CliPathOverrides,
+) -> miette::Result<TokenGraph> {
+    let (mut graph, resolved) = load_unfiltered_graph(path, overrides)?;
+    manifest::apply_configured(&mut graph, &resolved)
+        .into_diagnostic()
+        .wrap_err("failed to apply platform manifest cascade")?;
+    Ok(graph)
+}
+
+/// [`load_graph_with_relationships`] without the platform manifest, returning
+/// the resolved data paths alongside the graph.
+fn load_unfiltered_graph(
+    path: &Path,
+    overrides: &CliPathOverrides,
+) -> miette::Result<(TokenGraph, data_source::ResolvedData)> {
+    let cwd = std::env::current_dir().into_diagnostic()?;
+    let resolved = data_source::resolve(&cwd, overrides).into_diagnostic()?;
+    let mut graph = TokenGraph::open_cached_with_catalogs(
+        path,
+        resolved.mode_sets.as_deref(),
+        resolved.components.as_deref(),
+    )
+    .into_diagnostic()
+    .wrap_err_with(|| format!("failed to load tokens from {}", path.display()))?;
+    if let Some(dir) = resolved.relationships.as_deref() {
+        graph.relationships = TokenGraph::load_spec_relationships(dir)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to load relationships from {}", dir.display()))?;
+    }
+    Ok((graph, resolved))
+}
+
+fn run_lint_unused(
+    path: &Path,
+    allowlist: Option<&Path>,
+    overrides: CliPathOverrides,
+    format: OutputFormat,
+) -> miette::Result<ExitCode> {
+    // The manifest's `include` queries are roots here; applying the manifest
+    // would drop everything they do not match before the analysis sees it.
+    let (graph, resolved) = load_unfiltered_graph(path, &overrides)?;
+    let includes = manifest::configured_includes(&resolved)
+        .into_diagnostic()
+        .wrap_err("failed to read platform manifest")?
+        .iter()
+        .map(|expr| query::parse(expr))
+        .collect::<Result<Vec<_>, _>>()
+        .into_diagnostic()
+        .wrap_err("failed to parse platform manifest include query")?;
+    let allowlist = match allowlist {
+        Some(file) => UnusedExceptionsFile::load(file)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to load allowlist from {}", file.display()))?
+            .token_set(),
+        None => HashSet::new(),
+    };
+
+    let report = unused::analyze(&graph, &includes, &allowlist);
+    match format {
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&report).into_diagnostic()?
+            );
+        }
+        OutputFormat::Pretty => print_unused(&report),
+    }
+    Ok(if report.unused.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    })
+}
+
+fn print_unused(report: &unused::UnusedReport) {
+    let orphaned = report.orphaned().count();
+    println!(
+        "Unused:     {} of {} token{} ({} orphaned)",
+        report.unused.len(),
+        report.total,
+        if report.total == 1 { "" } else { "s" },
+        orphaned
+    );
+    println!(
+        "Roots:      {} token{}, {} allowlisted",
+        report.roots,
+        if report.roots == 1 { "" } else { "s" },
+        report.allowlisted
+    );
+
+    let mut current: Option<&Path> = None;
+    for t in &report.unused {
+        if current != Some(t.file.as_path()) {
+            println!();
+            println!("{}", t.file.display());
+            current = Some(t.file.as_path());
+        }
+        let mut line = format!("  {}", t.key);
+        if !t.modes.is_empty() {
+            let modes: Vec<String> = t.modes.iter().map(|(s, m)| format!("{s}={m}")).collect();
+            line.push_str(&format!(" [{}]", modes.join(", ")));
+        }
+        if let Some(uuid) = &t.uuid {
+            line.push_str(&format!("  {uuid}"));
+        }
+        if t.orphaned {
+            if let Some(component) = &t.component {
+                line.push_str(&format!("  (orphaned: no component \"{component}\")"));
+            }
+        }
+        println!("{line}");
+    }
+
+    if !report.stale_exceptions.is_empty() {
+        println!();
+        println!("Allowlist entries matching no token");
+        for entry in &report.stale_exceptions {
+            println!("  {entry}");
+        }
+    }
+}
+
+fn run_impact(
+    target: &str,
+    path: &Path,
+    value: Option<&str>,
+    product_context: Option<&Path>,
+    overrides: CliPathOverrides,
+    format: OutputFormat,
+) -> miette::Result<ExitCode> {
+    let mut graph = load_graph_with_relationships(path, &overrides)?;
+    if let Some(file) = product_context {
+        graph
+            .load_product_context(file)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to load product context {}", file.display()))?;
+    }
+
+    // Accept bare strings (`#ff0000`, `12px`) as well as JSON literals.
+    let proposed = value.map(|v| {
+        serde_json::from_str(v).unwrap_or_else(|_| serde_json::Value::String(v.to_string()))
+    });
+    let Some(report) = design_data_core::impact::analyze(&graph, target, proposed.as_ref()) else {
+        eprintln!("No token matches '{target}' (expected a UUID, set UUID or legacy key)");
+        return Ok(ExitCode::from(1));
+    };
+
+    match format {
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&report).into_diagnostic()?
+            );
+        }
+        OutputFormat::Pretty => print_impact(&report),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_impact(report: &design_data_core::impact::ImpactReport) {
+    use design_data_core::impact::ImpactedToken;
+
+    let describe = |t: &ImpactedToken| {
+        let mut line = t.key.clone();
+        if !t.modes.is_empty() {
+            let modes: Vec<String> = t.modes.iter().map(|(s, m)| format!("{s}={m}")).collect();
+            line.push_str(&format!(" [{}]", modes.join(", ")));
+        }
+        if let (Some(kind), Some(via)) = (t.kind, &t.via) {
+            let kind = serde_json::to_value(kind)
+                .ok()
+                .and_then(|k| k.as_str().map(str::to_string))
+                .unwrap_or_default();
+            line.push_str(&format!(" ← {via} ({kind})"));
+        }
+        match (&t.value, &t.proposed) {
+            (Some(before), Some(after)) if t.changes() => {
+                line.push_str(&format!("  {before} → {after}"));
+            }
+            (Some(before), Some(_)) => line.push_str(&format!("  {before} (unchanged)")),
+            (Some(before), None) => line.push_str(&format!("  {before}")),
+            (None, _) => {}
+        }
+        line
+    };
+
+    let targets: Vec<&ImpactedToken> = report.targets().collect();
+    let dependents = report.tokens.len() - targets.len();
+    println!(
+        "Target:     {} ({} token{})",
+        report.target,
+        targets.len(),
+        if targets.len() == 1 { "" } else { "s" }
+    );
+    println!(
+        "Impact:     {dependents} dependent token{}, {} component binding{}, {} relationship{}, {} component{}",
+        if dependents == 1 { "" } else { "s" },
+        report.bindings.len(),
+        if report.bindings.len() == 1 { "" } else { "s" },
+        report.relationships.len(),
+        if report.relationships.len() == 1 { "" } else { "s" },
+        report.components().len(),
+        if report.components().len() == 1 { "" } else { "s" },
+    );
+    if let Some(value) = &report.proposed {
+        let changed = report.tokens.iter().filter(|t| t.changes()).count();
+        println!(
+            "Proposed:   {value} changes {changed} resolved value{}",
+            if changed == 1 { "" } else { "s" }
+        );
+    }
+    println!();
+    for t in &targets {
+        println!("  {}", describe(t));
+    }
+
+    for (component, layers) in report.dependents_by_component() {
+        println!();
+        println!("{}", component.unwrap_or("(no component)"));
+        for (layer, tokens) in layers {
+            let layer = serde_json::to_value(layer)
+                .ok()
+                .and_then(|l| l.as_str().map(str::to_string))
+                .unwrap_or_default();
+            println!("  {layer}");
+            for t in tokens {
+                println!("    {}", describe(t));
+            }
+        }
+    }
+
+    if !report.bindings.is_empty() {
+        println!();
+        println!("Component bindings");
+        for b in &report.bindings {
+            match &b.context {
+                Some(context) => println!("  {}: {} ({context})", b.component, b.token),
+                None => println!("  {}: {}", b.component, b.token),
+            }
+        }
+    }
+    if !report.relationships.is_empty() {
+        println!();
+        println!("Relationships");
+        for r in &report.relationships {
+            let scope = [r.component.as_deref(), r.property.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            println!(
+                "  {} ← {}  {}[{}]",
+                if scope.is_empty() {
+                    "(unscoped)"
+                } else {
+                    &scope
+                },
+                r.via,
+                r.file.display(),
+                r.index
+            );
+        }
+    }
+}
+
+/// Load a dataset for code generation: resolve catalogs, apply the configured
+/// platform manifest, and resolve every variable across the mode-set matrix,
+/// named by the manifest's `extensions.formatting` when it declares one.
+fn load_generate_set(args: &GenerateArgs) -> miette::Result<generate::ResolvedTokenSet> {
+    let path = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let path = path.as_path();
+    let cwd = std::env::current_dir().into_diagnostic()?;
+    let mut resolved = data_source::resolve(
+        &cwd,
+        &CliPathOverrides {
+            mode_sets: args.mode_sets_path.clone(),
+            ..Default::default()
+        },
+    )
+    .into_diagnostic()?;
+    if let Some(manifest_path) = &args.manifest {
+        resolved.platform_manifest = Some(manifest_path.clone());
+    }
+
+    let mut graph = TokenGraph::open_cached_with_catalogs(
+        path,
+        resolved.mode_sets.as_deref(),
+        resolved.components.as_deref(),
+    )
+    .into_diagnostic()
+    .wrap_err_with(|| format!("failed to load tokens from {}", path.display()))?;
+
+    let platform = manifest::apply_configured_manifest(&mut graph, &resolved)
+        .into_diagnostic()
+        .wrap_err("failed to apply platform manifest cascade")?;
+
+    let mut set = generate::resolve_all(&graph, &platform.mode_set_restrictions);
+    if let Some(formatting) = &platform.formatting {
+        for (name, keys) in set.apply_formatting(formatting) {
+            eprintln!(
+                "design-data: warning: formatted name `{name}` is shared by {}; \
+                 keeping their legacy keys",
+                keys.join(", ")
+            );
+        }
+    }
+    Ok(set)
+}
+
+/// Write generated source to `output`, or stdout when `None`.
+fn write_generated(source: &str, output: Option<&Path>) -> miette::Result<ExitCode> {
+    match output {
+        Some(file) => {
+            if let Some(parent) = file.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)
+                    .into_diagnostic()
+                    .wrap_err_with(|| format!("failed to create {}", parent.display()))?;
+            }
+            std::fs::write(file, source)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("failed to write {}", file.display()))?;
+            eprintln!("Wrote {}", file.display());
+        }
+        None => print!("{source}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_generate(sub: GenerateSub) -> miette::Result<ExitCode> {
+    match sub {
+        GenerateSub::Css {
+            common,
+            prefix,
+            root_selector,
+        } => {
+            let set = load_generate_set(&common)?;
+            let opts = generate::css::CssOptions {
+                prefix,
+                root_selector,
+            };
+            write_generated(
+                &generate::css::render(&set, &opts),
+                common.output.as_deref(),
+            )
+        }
+        GenerateSub::Swift { common, namespace } => {
+            let set = load_generate_set(&common)?;
+            let opts = generate::swift::SwiftOptions { namespace };
+            write_generated(
+                &generate::swift::render(&set, &opts),
+                common.output.as_deref(),
+            )
+        }
+        GenerateSub::Kotlin {
+            common,
+            namespace,
+            package,
+        } => {
+            let set = load_generate_set(&common)?;
+            let opts = generate::kotlin::KotlinOptions { namespace, package };
+            write_generated(
+                &generate::kotlin::render(&set, &opts),
+                common.output.as_deref(),
+            )
+        }
+    }
+}
+
+struct ValidateOpts {
+    format: ValidateFormat,
     schema_path: Option<PathBuf>,
     exceptions_path: Option<PathBuf>,
     mode_sets_path: Option<PathBuf>,
@@ -686,6 +1487,11 @@ struct ValidateOpts {
     names_dir: Option<PathBuf>,
     components_report_only: bool,
     strict: bool,
+    fix: bool,
+    dry_run: bool,
+    fix_renames: bool,
+    baseline: Option<PathBuf>,
+    update_baseline: bool,
 }
 
 fn run_validate(path: &Path, opts: ValidateOpts) -> miette::Result<ExitCode> {
@@ -716,44 +1522,139 @@ fn run_validate(path: &Path, opts: ValidateOpts) -> miette::Result<ExitCode> {
     let comps_dir = resolved.components;
     let relationships_dir = resolved.relationships;
 
-    let mut report = validate::validate_all_with_full_options(
-        path,
-        &registry,
-        &exceptions,
-        dims_dir.as_deref(),
-        comps_dir.as_deref(),
-        opts.names_dir.as_deref(),
-        None,
-        relationships_dir.as_deref(),
-    )
-    .into_diagnostic()
-    .wrap_err("validation failed")?;
+    warn_unknown_rules(&resolved.rule_config);
 
-    if opts.components_report_only {
-        let ids: std::collections::HashSet<&str> = validate::rules::COMPONENT_RULE_IDS
-            .iter()
-            .copied()
-            .collect();
-        report.downgrade_rules(&ids);
-    }
+    let run = || -> miette::Result<ValidationReport> {
+        let mut report = validate::validate_all_with_rule_config(
+            path,
+            &registry,
+            &exceptions,
+            dims_dir.as_deref(),
+            comps_dir.as_deref(),
+            opts.names_dir.as_deref(),
+            None,
+            relationships_dir.as_deref(),
+            &resolved.rule_config,
+        )
+        .into_diagnostic()
+        .wrap_err("validation failed")?;
 
-    match opts.format {
-        OutputFormat::Json => {
-            println!("{}", format::format_report_json(&report).into_diagnostic()?);
+        if opts.components_report_only {
+            let ids: std::collections::HashSet<&str> = validate::rules::COMPONENT_RULE_IDS
+                .iter()
+                .copied()
+                .collect();
+            report.downgrade_rules(&ids);
         }
-        OutputFormat::Pretty => {
-            format::print_report_pretty(&report);
+        Ok(report)
+    };
+
+    let mut report = run()?;
+
+    if opts.fix {
+        let mut plan = validate::fix::plan(&report, opts.fix_renames);
+        // Never rewrite fetched or embedded data — only files under the
+        // validated path.
+        if matches!(
+            resolved.provenance,
+            data_source::Provenance::Cache { .. } | data_source::Provenance::Embedded { .. }
+        ) {
+            plan.files.retain(|file, fixes| {
+                fixes.retain(|p| {
+                    p.fix
+                        .edits
+                        .iter()
+                        .all(|e| e.file.as_deref().is_none_or(|f| f.starts_with(path)))
+                });
+                file.starts_with(path) && !fixes.is_empty()
+            });
+        }
+        let outcome = validate::fix::apply(&plan, opts.dry_run)
+            .into_diagnostic()
+            .wrap_err("failed to apply fixes")?;
+        format::print_fix_outcome(&plan, &outcome, opts.dry_run);
+        if !opts.dry_run && !outcome.applied.is_empty() {
+            report = run()?;
         }
     }
 
+    if let Some(baseline_path) = &opts.baseline {
+        let baseline = if opts.update_baseline {
+            let baseline = Baseline::from_report(&report);
+            baseline
+                .write(baseline_path)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("failed to write {}", baseline_path.display()))?;
+            eprintln!(
+                "baseline: wrote {} diagnostic(s) to {}",
+                baseline.len(),
+                baseline_path.display()
+            );
+            baseline
+        } else {
+            Baseline::load(baseline_path)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("failed to read {}", baseline_path.display()))?
+        };
+        let outcome = validate::baseline::compare(&report, &baseline);
+        format::print_baseline_outcome(&outcome, baseline_path);
+        report = outcome.report;
+    }
+
+    print_validation_report(&report, opts.format, "design-data validate", opts.strict)?;
+
     if report.failed(opts.strict) {
         return Ok(ExitCode::from(1));
     }
     Ok(ExitCode::SUCCESS)
 }
 
+/// Print a validation report in the requested format. SARIF and JUnit paths are
+/// relative to the working directory so they line up with repository paths.
+fn print_validation_report(
+    report: &ValidationReport,
+    format: ValidateFormat,
+    suite: &str,
+    strict: bool,
+) -> miette::Result<()> {
+    match format {
+        ValidateFormat::Json => {
+            println!("{}", format::format_report_json(report).into_diagnostic()?);
+        }
+        ValidateFormat::Pretty => {
+            format::print_report_pretty(report);
+        }
+        ValidateFormat::Sarif => {
+            let cwd = std::env::current_dir().into_diagnostic()?;
+            let log = validate::export::sarif(report, env!("CARGO_PKG_VERSION"), &cwd);
+            println!("{}", serde_json::to_string_pretty(&log).into_diagnostic()?);
+        }
+        ValidateFormat::Junit => {
+            let cwd = std::env::current_dir().into_diagnostic()?;
+            print!("{}", validate::export::junit(report, suite, strict, &cwd));
+        }
+    }
+    Ok(())
+}
+
+/// Warn about `[validate]` rule ids that match no catalog or custom rule (most likely typos).
+fn warn_unknown_rules(config: &validate::rule_config::RuleConfig) {
+    let rules = validate::rules::default_rules();
+    let known: Vec<&str> = rules
+        .iter()
+        .map(|r| r.id())
+        .chain(config.custom.iter().map(|r| r.spec().id.as_str()))
+        .chain(std::iter::once("SPEC-044"))
+        .collect();
+    for id in config.unknown_rules(&known) {
+        eprintln!(
+            "design-data: warning: .design-data.toml [validate] configures unknown rule {id}"
+        );
+    }
+}
+
 struct ValidateDatasetOpts {
-    format: OutputFormat,
+    format: ValidateFormat,
     schema_path: Option<PathBuf>,
     spec_schemas: Option<PathBuf>,
     exceptions_path: Option<PathBuf>,
@@ -827,10 +1728,18 @@ fn run_validate_dataset(path: &Path, opts: ValidateDatasetOpts) -> miette::Resul
     // flagging component-scoped gap endpoints (see spec047.rs). Only the
     // full `validate` subcommand — which does load components — enforces
     // that arm.
-    let mut report =
-        validate::validate_dataset(&dataset_root, &registry, &exceptions, None, None, None)
-            .into_diagnostic()
-            .wrap_err("validation failed")?;
+    warn_unknown_rules(&resolved.rule_config);
+    let mut report = validate::validate_dataset_with_config(
+        &dataset_root,
+        &registry,
+        &exceptions,
+        None,
+        None,
+        None,
+        &resolved.rule_config,
+    )
+    .into_diagnostic()
+    .wrap_err("validation failed")?;
 
     // Layer 1 schema-shape validation of the registered catalog directories.
     match resolve_spec_schemas(opts.spec_schemas, &dataset_root, &cwd) {
@@ -849,14 +1758,12 @@ fn run_validate_dataset(path: &Path, opts: ValidateDatasetOpts) -> miette::Resul
 
     report.recompute_valid();
 
-    match opts.format {
-        OutputFormat::Json => {
-            println!("{}", format::format_report_json(&report).into_diagnostic()?);
-        }
-        OutputFormat::Pretty => {
-            format::print_report_pretty(&report);
-        }
-    }
+    print_validation_report(
+        &report,
+        opts.format,
+        "design-data validate-dataset",
+        opts.strict,
+    )?;
 
     if report.failed(opts.strict) {
         return Ok(ExitCode::from(1));
@@ -940,8 +1847,8 @@ fn run_migrate_legacy_output_cascaded(path: &Path, output: &Path) -> miette::Res
 
     // ponytail: contrast-variant records (e.g. manifest extensions adding
     // contrast:"high") share a legacyKey+colorScheme with their regular-contrast
-    // sibling — legacy::convert_array groups sets by colorScheme/scale only, so
-    // keeping both would silently overwrite one. Skip non-default contrast until
+    // sibling — legacy::convert_array groups sets by colorScheme/scale/
+    // reducedMotion only, so keeping both would silently overwrite one. Skip non-default contrast until
     // legacy.rs learns a contrast set-dimension (spectrum-design-data-h890.17).
     let is_default_contrast = |v: &serde_json::Value| {
         v.get("name")
@@ -967,55 +1874,237 @@ fn run_migrate_legacy_output_cascaded(path: &Path, output: &Path) -> miette::Res
             .cloned(),
     );
 
-    let (legacy_map, summary) = legacy::convert_records(&records)
+    let (legacy_map, summary) = legacy::convert_records(&records)
+        .into_diagnostic()
+        .wrap_err("failed to convert cascaded dataset to legacy format")?;
+
+    if let Some(parent) = output.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).into_diagnostic()?;
+        }
+    }
+    let mut out_text =
+        serde_json::to_string_pretty(&serde_json::Value::Object(legacy_map)).into_diagnostic()?;
+    out_text.push('\n');
+    std::fs::write(output, out_text).into_diagnostic()?;
+
+    println!(
+        "Wrote {}: {} tokens produced ({} sets, {} flat)",
+        output.display(),
+        summary.tokens_produced,
+        summary.sets_reconstructed,
+        summary.flat_tokens,
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_migrate_add_uuids(dir: &Path) -> miette::Result<ExitCode> {
+    let summary = migrate::add_uuids(dir)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("add-uuids failed: {}", dir.display()))?;
+    println!(
+        "Scanned {} file(s): {} UUID(s) added across {} file(s)",
+        summary.files_scanned, summary.uuids_added, summary.files_modified,
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_migrate_roundtrip_verify(path: &Path) -> miette::Result<ExitCode> {
+    let diffs = legacy::roundtrip_verify(path)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("roundtrip-verify failed: {}", path.display()))?;
+    if diffs.is_empty() {
+        println!("Roundtrip OK: {}", path.display());
+        return Ok(ExitCode::SUCCESS);
+    }
+    for d in &diffs {
+        if d.token.is_empty() {
+            eprintln!("  {}: {}", d.file, d.detail);
+        } else {
+            eprintln!("  {}/{}: {}", d.file, d.token, d.detail);
+        }
+    }
+    eprintln!("{} difference(s) found", diffs.len());
+    Ok(ExitCode::from(1))
+}
+
+/// Load a dataset for DTCG export with the resolved mode-set catalog, so each
+/// token's base record and variant `modes` follow the declared defaults.
+fn load_dtcg_graph(path: &Path) -> miette::Result<TokenGraph> {
+    let cwd = std::env::current_dir().into_diagnostic()?;
+    let resolved = data_source::resolve(&cwd, &CliPathOverrides::default()).into_diagnostic()?;
+    TokenGraph::open_cached_with_catalogs(
+        path,
+        resolved.mode_sets.as_deref(),
+        resolved.components.as_deref(),
+    )
+    .into_diagnostic()
+    .wrap_err_with(|| format!("failed to load tokens from {}", path.display()))
+}
+
+fn run_migrate_to_dtcg(path: &Path, output: &Path) -> miette::Result<ExitCode> {
+    let graph = load_dtcg_graph(path)?;
+    let doc = dtcg::to_dtcg(&graph);
+    let tokens: usize = doc
+        .as_object()
+        .map(|groups| {
+            groups
+                .values()
+                .filter_map(|g| g.as_object())
+                .map(|g| g.len())
+                .sum()
+        })
+        .unwrap_or(0);
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to create {}", parent.display()))?;
+    }
+    let text = serde_json::to_string_pretty(&doc).into_diagnostic()?;
+    std::fs::write(output, text + "\n")
+        .into_diagnostic()
+        .wrap_err_with(|| format!("failed to write {}", output.display()))?;
+    println!(
+        "Exported {} record(s) as {tokens} DTCG token(s) to {}",
+        graph.tokens.len(),
+        output.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_migrate_consumers(
+    dir: &Path,
+    tokens: Option<&Path>,
+    dry_run: bool,
+) -> miette::Result<ExitCode> {
+    let cwd = std::env::current_dir().into_diagnostic()?;
+    let resolved = data_source::resolve(
+        &cwd,
+        &CliPathOverrides {
+            tokens_root: tokens.map(Path::to_path_buf),
+            ..Default::default()
+        },
+    )
+    .into_diagnostic()?;
+    let graph = TokenGraph::open_cached_with_catalogs(
+        &resolved.tokens_root,
+        resolved.mode_sets.as_deref(),
+        resolved.components.as_deref(),
+    )
+    .into_diagnostic()
+    .wrap_err_with(|| {
+        format!(
+            "failed to load tokens from {}",
+            resolved.tokens_root.display()
+        )
+    })?;
+
+    let renames = codemod::RenameMap::from_graph(&graph);
+    for (name, uuids) in renames.unresolved() {
+        for uuid in uuids {
+            eprintln!(
+                "design-data: warning: {name}: replacedBy {uuid} does not resolve to a token"
+            );
+        }
+    }
+    let files = codemod::migrate_dir(dir, &renames)
         .into_diagnostic()
-        .wrap_err("failed to convert cascaded dataset to legacy format")?;
+        .wrap_err_with(|| format!("failed to scan {}", dir.display()))?;
 
-    if let Some(parent) = output.parent() {
-        if !parent.as_os_str().is_empty() {
-            std::fs::create_dir_all(parent).into_diagnostic()?;
+    let mut rewritten = 0;
+    let mut ambiguous = 0;
+    for file in &files {
+        for r in &file.result.ambiguous {
+            eprintln!(
+                "design-data: warning: {}:{}:{}: {} has several replacements ({}); not rewritten",
+                file.path.display(),
+                r.line,
+                r.column,
+                r.text,
+                r.candidates.join(", ")
+            );
+        }
+        ambiguous += file.result.ambiguous.len();
+        if !file.changed() {
+            continue;
+        }
+        rewritten += file.result.edits.len();
+        if dry_run {
+            let name = file.path.display().to_string();
+            print!(
+                "{}",
+                similar::TextDiff::from_lines(
+                    file.original.as_str(),
+                    file.result.migrated.as_str()
+                )
+                .unified_diff()
+                .header(&format!("a/{name}"), &format!("b/{name}"))
+            );
+        } else {
+            file.write()
+                .into_diagnostic()
+                .wrap_err_with(|| format!("failed to write {}", file.path.display()))?;
         }
     }
-    let mut out_text =
-        serde_json::to_string_pretty(&serde_json::Value::Object(legacy_map)).into_diagnostic()?;
-    out_text.push('\n');
-    std::fs::write(output, out_text).into_diagnostic()?;
 
-    println!(
-        "Wrote {}: {} tokens produced ({} sets, {} flat)",
-        output.display(),
-        summary.tokens_produced,
-        summary.sets_reconstructed,
-        summary.flat_tokens,
+    let changed = files.iter().filter(|f| f.changed()).count();
+    let verb = if dry_run { "Would rewrite" } else { "Rewrote" };
+    let summary = format!(
+        "{verb} {rewritten} reference(s) in {changed} file(s) ({} deprecated token(s) known)",
+        renames.len()
     );
+    // Keep stdout a clean patch under --dry-run.
+    if dry_run {
+        eprintln!("{summary}");
+    } else {
+        println!("{summary}");
+    }
+    if ambiguous > 0 {
+        eprintln!("{ambiguous} ambiguous reference(s) need a manual choice");
+        return Ok(ExitCode::from(1));
+    }
     Ok(ExitCode::SUCCESS)
 }
 
-fn run_migrate_add_uuids(dir: &Path) -> miette::Result<ExitCode> {
-    let summary = migrate::add_uuids(dir)
+fn run_migrate_from_dtcg(input: &Path, output: &Path) -> miette::Result<ExitCode> {
+    let summary = dtcg::convert_file(input, output)
         .into_diagnostic()
-        .wrap_err_with(|| format!("add-uuids failed: {}", dir.display()))?;
+        .wrap_err_with(|| {
+            format!(
+                "from-dtcg failed: {} → {}",
+                input.display(),
+                output.display()
+            )
+        })?;
     println!(
-        "Scanned {} file(s): {} UUID(s) added across {} file(s)",
-        summary.files_scanned, summary.uuids_added, summary.files_modified,
+        "Imported {} DTCG token(s) into {} file(s): {} records ({} from extensions, {} new uuids)",
+        summary.tokens,
+        summary.files_written,
+        summary.records,
+        summary.from_extensions,
+        summary.generated_uuids,
     );
+    if summary.dangling_aliases > 0 {
+        eprintln!(
+            "design-data: warning: {} alias reference(s) did not resolve within {}",
+            summary.dangling_aliases,
+            input.display()
+        );
+    }
     Ok(ExitCode::SUCCESS)
 }
 
-fn run_migrate_roundtrip_verify(path: &Path) -> miette::Result<ExitCode> {
-    let diffs = legacy::roundtrip_verify(path)
+fn run_migrate_dtcg_verify(path: &Path) -> miette::Result<ExitCode> {
+    let graph = load_dtcg_graph(path)?;
+    let diffs = dtcg::roundtrip_verify(&graph)
         .into_diagnostic()
-        .wrap_err_with(|| format!("roundtrip-verify failed: {}", path.display()))?;
+        .wrap_err_with(|| format!("dtcg-verify failed: {}", path.display()))?;
     if diffs.is_empty() {
-        println!("Roundtrip OK: {}", path.display());
+        println!("DTCG roundtrip OK: {}", path.display());
         return Ok(ExitCode::SUCCESS);
     }
     for d in &diffs {
-        if d.token.is_empty() {
-            eprintln!("  {}: {}", d.file, d.detail);
-        } else {
-            eprintln!("  {}/{}: {}", d.file, d.token, d.detail);
-        }
+        eprintln!("  {}/{}: {}", d.file, d.token, d.detail);
     }
     eprintln!("{} difference(s) found", diffs.len());
     Ok(ExitCode::from(1))
@@ -1146,7 +2235,11 @@ fn run_diff(
     new_path: &Path,
     format: DiffFormat,
     filter_expr: Option<&str>,
+    mode: DiffMode,
 ) -> miette::Result<ExitCode> {
+    if let DiffMode::Resolved { mode_sets } = mode {
+        return run_resolved_diff(old_path, new_path, format, filter_expr, mode_sets);
+    }
     let old_graph = TokenGraph::open_cached(old_path)
         .into_diagnostic()
         .wrap_err_with(|| format!("failed to load old tokens from {}", old_path.display()))?;
@@ -1155,13 +2248,13 @@ fn run_diff(
         .wrap_err_with(|| format!("failed to load new tokens from {}", new_path.display()))?;
 
     // Optionally filter both graphs to matching tokens before diffing.
-    let (old_filtered, new_filtered) = if let Some(expr_str) = filter_expr {
+    let filtered = if let Some(expr_str) = filter_expr {
         let expr = query::parse(expr_str)
             .into_diagnostic()
             .wrap_err("failed to parse --filter expression")?;
         let old_matched = query::filter(&old_graph, &expr);
         let new_matched = query::filter(&new_graph, &expr);
-        (
+        Some((
             TokenGraph::from_pairs(
                 old_matched
                     .iter()
@@ -1174,12 +2267,33 @@ fn run_diff(
                     .map(|t| (t.name.clone(), t.file.clone(), t.raw.clone()))
                     .collect(),
             ),
-        )
+        ))
     } else {
-        (old_graph, new_graph)
+        None
+    };
+    let (old_scope, new_scope) = match &filtered {
+        Some((old_filtered, new_filtered)) => (old_filtered, new_filtered),
+        None => (&old_graph, &new_graph),
     };
 
-    let report = diff::semantic_diff(&old_filtered, &new_filtered);
+    let report = diff::semantic_diff(old_scope, new_scope);
+    match mode {
+        DiffMode::Classify(options) => {
+            return run_classify(&report, old_path, new_path, format, options);
+        }
+        DiffMode::Notes { version, template } => {
+            // Resolve successors against the full datasets, not just the filtered scope.
+            return run_notes(
+                &report,
+                &old_graph,
+                &new_graph,
+                format,
+                version.as_deref(),
+                template.as_deref(),
+            );
+        }
+        DiffMode::Changes | DiffMode::Resolved { .. } => {}
+    }
 
     match format {
         DiffFormat::Json => {
@@ -1203,9 +2317,223 @@ fn run_diff(
     }
 }
 
+fn run_resolved_diff(
+    old_path: &Path,
+    new_path: &Path,
+    format: DiffFormat,
+    filter_expr: Option<&str>,
+    mode_sets: Option<PathBuf>,
+) -> miette::Result<ExitCode> {
+    // Mode sets: one explicit directory for both, else `mode-sets/` beside each
+    // token dir. Without any, every token resolves once.
+    let load = |path: &Path, what: &str| {
+        let dir = mode_sets.clone().or_else(|| {
+            path.parent()
+                .map(|d| d.join("mode-sets"))
+                .filter(|d| d.is_dir())
+        });
+        TokenGraph::open_cached_with_catalogs(path, dir.as_deref(), None)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to load {what} tokens from {}", path.display()))
+    };
+    let old_graph = load(old_path, "old")?;
+    let new_graph = load(new_path, "new")?;
+    let filter = filter_expr
+        .map(query::parse)
+        .transpose()
+        .into_diagnostic()
+        .wrap_err("failed to parse --filter expression")?;
+
+    let report = diff::resolved_diff(&old_graph, &new_graph, filter.as_ref());
+    match format {
+        DiffFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&report).into_diagnostic()?
+            );
+        }
+        DiffFormat::Markdown => {
+            print!("{}", format::format_resolved_diff_markdown(&report));
+        }
+        DiffFormat::Pretty => {
+            format::print_resolved_diff_pretty(&report);
+        }
+    }
+
+    if report.is_empty() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::from(1))
+    }
+}
+
+/// What `diff` reports.
+enum DiffMode {
+    /// The categorized change listing.
+    Changes,
+    /// `--classify`: the required semver bump.
+    Classify(ClassifyOptions),
+    /// `--notes`: release notes.
+    Notes {
+        version: Option<String>,
+        template: Option<PathBuf>,
+    },
+    /// `--resolved`: effective value changes per mode combination.
+    Resolved { mode_sets: Option<PathBuf> },
+}
+
+/// `diff --classify` settings.
+struct ClassifyOptions {
+    declared: Option<Bump>,
+    changeset: Option<PathBuf>,
+    package: Option<String>,
+    old_components: Option<PathBuf>,
+    new_components: Option<PathBuf>,
+}
+
+fn run_classify(
+    report: &diff::DiffReport,
+    old_path: &Path,
+    new_path: &Path,
+    format: DiffFormat,
+    options: ClassifyOptions,
+) -> miette::Result<ExitCode> {
+    // Component catalogs: explicit directories, else `components/` beside both token dirs.
+    let sibling = |p: &Path| p.parent().map(|d| d.join("components"));
+    let catalogs = match (options.old_components, options.new_components) {
+        (Some(old), Some(new)) => Some((old, new)),
+        (None, None) => match (sibling(old_path), sibling(new_path)) {
+            (Some(old), Some(new)) if old.is_dir() && new.is_dir() => Some((old, new)),
+            _ => None,
+        },
+        _ => miette::bail!("--old-components and --new-components must be given together"),
+    };
+    let component_changes = match catalogs {
+        Some((old_dir, new_dir)) => {
+            let load = |dir: &Path| {
+                component::load_all(dir)
+                    .into_diagnostic()
+                    .wrap_err_with(|| format!("failed to load components from {}", dir.display()))
+            };
+            release::diff_components(&load(&old_dir)?, &load(&new_dir)?)
+        }
+        None => Vec::new(),
+    };
+    let classification = release::classify(report, &component_changes);
+
+    let declared = match (options.declared, options.changeset) {
+        (Some(bump), _) => Some(bump),
+        (None, Some(path)) => Some(read_changeset_bump(&path, options.package.as_deref())?),
+        (None, None) => None,
+    };
+
+    match format {
+        DiffFormat::Json => {
+            let out = serde_json::json!({
+                "bump": classification.bump,
+                "declared": declared,
+                "satisfied": declared.map(|d| classification.satisfied_by(d)),
+                "changes": classification.changes,
+            });
+            println!("{}", serde_json::to_string_pretty(&out).into_diagnostic()?);
+        }
+        DiffFormat::Markdown => {
+            print!(
+                "{}",
+                format::format_classification_markdown(&classification, declared)
+            );
+        }
+        DiffFormat::Pretty => {
+            format::print_classification_pretty(&classification, declared);
+        }
+    }
+
+    match declared {
+        Some(d) if !classification.satisfied_by(d) => Ok(ExitCode::from(1)),
+        _ => Ok(ExitCode::SUCCESS),
+    }
+}
+
+fn run_notes(
+    report: &diff::DiffReport,
+    old_graph: &TokenGraph,
+    new_graph: &TokenGraph,
+    format: DiffFormat,
+    version: Option<&str>,
+    template_path: Option<&Path>,
+) -> miette::Result<ExitCode> {
+    let notes = release::notes::release_notes(report, old_graph, new_graph, version);
+    match format {
+        DiffFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&notes).into_diagnostic()?
+            );
+        }
+        DiffFormat::Markdown | DiffFormat::Pretty => {
+            let template = template_path
+                .map(|p| {
+                    std::fs::read_to_string(p)
+                        .into_diagnostic()
+                        .wrap_err_with(|| format!("failed to read template {}", p.display()))
+                })
+                .transpose()?;
+            if let Some(t) = &template {
+                if !release::notes::PLACEHOLDERS.iter().any(|p| t.contains(p)) {
+                    eprintln!(
+                        "design-data: warning: template has none of the placeholders {}",
+                        release::notes::PLACEHOLDERS.join(", ")
+                    );
+                }
+            }
+            print!(
+                "{}",
+                release::notes::render_markdown(&notes, template.as_deref())
+            );
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Highest bump declared by a changeset file or a directory of changesets.
+///
+/// A directory with no declaring changeset yields [`Bump::None`], so CI fails a
+/// PR that changes tokens without adding a changeset.
+fn read_changeset_bump(path: &Path, package: Option<&str>) -> miette::Result<Bump> {
+    let files = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.extension().and_then(|x| x.to_str()) == Some("md")
+                    && p.file_name().and_then(|n| n.to_str()) != Some("README.md")
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut declared = Bump::None;
+    for file in files {
+        let text = std::fs::read_to_string(&file)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to read {}", file.display()))?;
+        let bump = release::declared_bump(&text, package)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("invalid changeset {}", file.display()))?;
+        declared = declared.max(bump.unwrap_or_default());
+    }
+    Ok(declared)
+}
+
 fn run_query(
     path: &Path,
     filter_expr: &str,
+    context: &[String],
     format: OutputFormat,
     count_only: bool,
 ) -> miette::Result<ExitCode> {
@@ -1221,7 +2549,7 @@ fn run_query(
     .wrap_err_with(|| format!("failed to load tokens from {}", path.display()))?;
 
     // Apply a configured platform manifest (filters/overrides/extensions) before querying.
-    manifest::apply_configured(&mut graph, &resolved)
+    let platform = manifest::apply_configured_manifest(&mut graph, &resolved)
         .into_diagnostic()
         .wrap_err("failed to apply platform manifest cascade")?;
     // Manifest overlays change the token set — rebuild the index when one is configured.
@@ -1233,7 +2561,24 @@ fn run_query(
         .into_diagnostic()
         .wrap_err("failed to parse filter expression")?;
 
-    let results = query::filter_with_index(&graph, &index, &expr);
+    let ctx = if context.is_empty() {
+        None
+    } else {
+        let ctx = parse_query_context(&graph, context)?;
+        Some(cascade::apply_restrictions(
+            ctx,
+            &platform.mode_set_restrictions,
+        ))
+    };
+    let results = match &ctx {
+        Some(ctx) => query::filter_in_context(&graph, &expr, ctx),
+        None => query::filter_with_index(&graph, &index, &expr),
+    };
+    let resolved_value = |t: &TokenRecord| {
+        ctx.as_ref()
+            .and_then(|ctx| query::resolved_value(&graph, t, ctx))
+            .cloned()
+    };
 
     if count_only {
         println!("{}", results.len());
@@ -1246,10 +2591,28 @@ fn run_query(
 
     match format {
         OutputFormat::Json => {
-            let raw_values: Vec<&serde_json::Value> = results.iter().map(|t| &t.raw).collect();
+            // With platform formatting, each token also carries its platform name.
+            let values: Vec<serde_json::Value> = results
+                .iter()
+                .map(|t| {
+                    let mut raw = t.raw.clone();
+                    let formatted = platform
+                        .formatting
+                        .as_ref()
+                        .zip(t.raw.get("name"))
+                        .and_then(|(fmt, name)| fmt.format(name));
+                    if let (Some(obj), Some(formatted)) = (raw.as_object_mut(), formatted) {
+                        obj.insert("formattedName".into(), formatted.into());
+                    }
+                    if let (Some(obj), Some(value)) = (raw.as_object_mut(), resolved_value(t)) {
+                        obj.insert("resolvedValue".into(), value);
+                    }
+                    raw
+                })
+                .collect();
             println!(
                 "{}",
-                serde_json::to_string_pretty(&raw_values).into_diagnostic()?
+                serde_json::to_string_pretty(&values).into_diagnostic()?
             );
         }
         OutputFormat::Pretty => {
@@ -1264,6 +2627,9 @@ fn run_query(
                     println!("  {name}");
                     println!("    UUID:    {uuid}");
                     println!("    Schema:  {schema}");
+                    if let Some(value) = resolved_value(t) {
+                        println!("    Value:   {value}");
+                    }
                     println!("    File:    {}", t.file.display());
                     println!();
                 }
@@ -1278,6 +2644,52 @@ fn run_query(
     }
 }
 
+/// Parse `--context` values (`set=mode`, repeatable or comma-separated) into a
+/// resolution context, checking each pair against the declared mode sets.
+fn parse_query_context(graph: &TokenGraph, pairs: &[String]) -> miette::Result<ResolutionContext> {
+    parse_mode_pairs(graph, "--context", pairs)
+}
+
+/// Parse `set=mode` pairs given to `flag` (repeatable or comma-separated) into
+/// a resolution context. Each pair is checked against the declared mode sets,
+/// and a mode set given two different modes is an error.
+fn parse_mode_pairs(
+    graph: &TokenGraph,
+    flag: &str,
+    pairs: &[String],
+) -> miette::Result<ResolutionContext> {
+    let mut ctx = ResolutionContext::new();
+    for pair in pairs.iter().flat_map(|p| p.split(',')) {
+        let Some((set, mode)) = pair.split_once('=').map(|(s, m)| (s.trim(), m.trim())) else {
+            miette::bail!("invalid {flag} {pair:?} (expected SET=MODE)");
+        };
+        if set.is_empty() || mode.is_empty() {
+            miette::bail!("invalid {flag} {pair:?} (expected SET=MODE)");
+        }
+        if let Some(prev) = ctx.mode_sets.get(set).filter(|prev| *prev != mode) {
+            miette::bail!("conflicting modes for mode set {set:?}: {prev:?} and {mode:?}");
+        }
+        match graph.mode_sets.iter().find(|ms| ms.name == set) {
+            Some(ms) if !ms.modes.iter().any(|m| m == mode) => miette::bail!(
+                "unknown mode {mode:?} for mode set {set:?} (expected one of: {})",
+                ms.modes.join(", ")
+            ),
+            None if !graph.mode_sets.is_empty() => miette::bail!(
+                "unknown mode set {set:?} in {flag} (expected one of: {})",
+                graph
+                    .mode_sets
+                    .iter()
+                    .map(|ms| ms.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            _ => {}
+        }
+        ctx = ctx.with(set, mode);
+    }
+    Ok(ctx)
+}
+
 /// Load a legacyKey → Figma-name override map from a `figma audit` artifact
 /// (which nests it under an `overrides` field) or a bare `{legacyKey: name}`
 /// JSON object. Empty-string values (the artifact's "needs a decision"
@@ -1372,6 +2784,13 @@ fn run_figma_export(
             summary.skipped_unparseable_value,
         );
     }
+    if !summary.skipped_no_collection.is_empty() {
+        eprintln!(
+            "  Skipped (collection not in file): {} — {:?}",
+            summary.skipped_no_collection.len(),
+            summary.skipped_no_collection,
+        );
+    }
 
     Ok(ExitCode::SUCCESS)
 }
@@ -1412,11 +2831,12 @@ fn run_figma_audit(
                 );
             }
             println!(
-                "\nSkipped: composite={} unresolved_alias={} unknown_schema={} unparseable={}",
+                "\nSkipped: composite={} unresolved_alias={} unknown_schema={} unparseable={} no_collection={}",
                 report.skipped_composite.len(),
                 report.skipped_alias_unresolved.len(),
                 report.skipped_unknown_schema.len(),
                 report.skipped_unparseable_value.len(),
+                report.skipped_no_collection.len(),
             );
             println!("Overrides needing a decision: {}", report.overrides.len());
         }
@@ -1896,6 +3316,11 @@ fn main() -> ExitCode {
             names_dir,
             components_report_only,
             strict,
+            fix,
+            dry_run,
+            fix_renames,
+            baseline,
+            update_baseline,
         } => {
             let target = path.unwrap_or_else(|| PathBuf::from("."));
             run_validate(
@@ -1910,6 +3335,11 @@ fn main() -> ExitCode {
                     names_dir,
                     components_report_only,
                     strict,
+                    fix,
+                    dry_run,
+                    fix_renames,
+                    baseline,
+                    update_baseline,
                 },
             )
         }
@@ -1940,41 +3370,155 @@ fn main() -> ExitCode {
             color_scheme,
             scale,
             contrast,
+            reduced_motion,
+            modes,
+            matrix,
             format,
         } => {
             let target = path.unwrap_or_else(|| PathBuf::from("."));
-            run_resolve(
-                &property,
+            // The named flags are shorthands for `--mode SET=MODE`.
+            let pairs = [
+                ("colorScheme", color_scheme),
+                ("scale", scale),
+                ("contrast", contrast),
+                ("reducedMotion", reduced_motion),
+            ]
+            .into_iter()
+            .filter_map(|(set, mode)| mode.map(|m| format!("{set}={m}")))
+            .chain(modes)
+            .collect::<Vec<_>>();
+            run_resolve(&property, &target, mode_sets_path, &pairs, matrix, format)
+        }
+        Commands::Impact {
+            target,
+            path,
+            value,
+            product_context,
+            mode_sets_path,
+            components_path,
+            relationships_path,
+            format,
+        } => {
+            let tokens = path.unwrap_or_else(|| PathBuf::from("."));
+            run_impact(
                 &target,
-                mode_sets_path,
-                color_scheme,
-                scale,
-                contrast,
+                &tokens,
+                value.as_deref(),
+                product_context.as_deref(),
+                CliPathOverrides {
+                    mode_sets: mode_sets_path,
+                    components: components_path,
+                    relationships: relationships_path,
+                    ..Default::default()
+                },
+                format,
+            )
+        }
+        Commands::Graph {
+            path,
+            filter,
+            depth,
+            format,
+            output,
+            mode_sets_path,
+            components_path,
+            relationships_path,
+        } => {
+            let tokens = path.unwrap_or_else(|| PathBuf::from("."));
+            run_graph(
+                &tokens,
+                filter.as_deref(),
+                depth,
                 format,
+                output.as_deref(),
+                CliPathOverrides {
+                    mode_sets: mode_sets_path,
+                    components: components_path,
+                    relationships: relationships_path,
+                    ..Default::default()
+                },
             )
         }
+        Commands::Lint { sub } => match sub {
+            LintSub::Unused {
+                path,
+                allowlist,
+                mode_sets_path,
+                components_path,
+                relationships_path,
+                format,
+            } => {
+                let tokens = path.unwrap_or_else(|| PathBuf::from("."));
+                run_lint_unused(
+                    &tokens,
+                    allowlist.as_deref(),
+                    CliPathOverrides {
+                        mode_sets: mode_sets_path,
+                        components: components_path,
+                        relationships: relationships_path,
+                        ..Default::default()
+                    },
+                    format,
+                )
+            }
+        },
         Commands::DecomposeLegacyName {
             slug,
             component_hint,
         } => run_decompose_legacy_name(&slug, component_hint.as_deref()),
-        Commands::DumpLegacyKeys { path } => {
-            run_dump_legacy_keys(&path.unwrap_or_else(|| PathBuf::from(".")))
+        Commands::DumpLegacyKeys { path, manifest } => {
+            run_dump_legacy_keys(&path.unwrap_or_else(|| PathBuf::from(".")), manifest)
         }
         Commands::Diff {
             old,
             new,
             format,
             filter,
-        } => run_diff(&old, &new, format, filter.as_deref()),
+            classify,
+            declared,
+            changeset,
+            package,
+            old_components,
+            new_components,
+            notes,
+            release_version,
+            template,
+            resolved,
+            mode_sets_path,
+        } => {
+            let mode = if resolved {
+                DiffMode::Resolved {
+                    mode_sets: mode_sets_path,
+                }
+            } else if classify {
+                DiffMode::Classify(ClassifyOptions {
+                    declared,
+                    changeset,
+                    package,
+                    old_components,
+                    new_components,
+                })
+            } else if notes {
+                DiffMode::Notes {
+                    version: release_version,
+                    template,
+                }
+            } else {
+                DiffMode::Changes
+            };
+            run_diff(&old, &new, format, filter.as_deref(), mode)
+        }
         Commands::Query {
             path,
             filter,
+            context,
             format,
             count,
         } => {
             let target = path.unwrap_or_else(|| PathBuf::from("."));
-            run_query(&target, &filter, format, count)
+            run_query(&target, &filter, &context, format, count)
         }
+        Commands::Generate { sub } => run_generate(sub),
         Commands::Migrate { sub } => match sub {
             MigrateSub::Verify {
                 path,
@@ -2003,6 +3547,18 @@ fn main() -> ExitCode {
             }
             MigrateSub::AddUuids { dir } => run_migrate_add_uuids(&dir),
             MigrateSub::RoundtripVerify { path } => run_migrate_roundtrip_verify(&path),
+            MigrateSub::ToDtcg { path, output } => {
+                run_migrate_to_dtcg(&path.unwrap_or_else(|| PathBuf::from(".")), &output)
+            }
+            MigrateSub::FromDtcg { input, output } => run_migrate_from_dtcg(&input, &output),
+            MigrateSub::DtcgVerify { path } => {
+                run_migrate_dtcg_verify(&path.unwrap_or_else(|| PathBuf::from(".")))
+            }
+            MigrateSub::Consumers {
+                dir,
+                tokens,
+                dry_run,
+            } => run_migrate_consumers(&dir, tokens.as_deref(), dry_run),
             MigrateSub::LegacyVerify {
                 cascade_dir,
                 reference,
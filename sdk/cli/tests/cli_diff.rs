@@ -0,0 +1,270 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data diff` color reporting, `--classify`,
+//! `--notes` and `--resolved`.
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use serde_json::{json, Value};
+
+/// Write a `<side>/tokens` + `<side>/components` dataset under `root`.
+fn write_side(root: &Path, side: &str, tokens: Value, components: &[Value]) {
+    let tokens_dir = root.join(side).join("tokens");
+    let components_dir = root.join(side).join("components");
+    fs::create_dir_all(&tokens_dir).expect("create tokens dir");
+    fs::create_dir_all(&components_dir).expect("create components dir");
+    fs::write(tokens_dir.join("tokens.tokens.json"), tokens.to_string()).expect("write tokens");
+    for c in components {
+        let name = c["name"].as_str().expect("component name");
+        fs::write(components_dir.join(format!("{name}.json")), c.to_string())
+            .expect("write component");
+    }
+}
+
+/// Old/new datasets where a token value changes and a component gains an option.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    write_side(
+        project.path(),
+        "old",
+        json!([{"name": {"property": "gap"}, "value": "4px", "uuid": "00000000-0000-4000-8000-000000000001"}]),
+        &[json!({"name": "badge", "options": {"size": {"type": "string"}}})],
+    );
+    write_side(
+        project.path(),
+        "new",
+        json!([{"name": {"property": "gap"}, "value": "3px", "uuid": "00000000-0000-4000-8000-000000000001"}]),
+        &[
+            json!({"name": "badge", "options": {"size": {"type": "string"}, "icon": {"type": "string"}}}),
+        ],
+    );
+    project
+}
+
+#[test]
+fn classify_reports_required_bump_with_justifications() {
+    let project = setup_project();
+
+    let output = Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "diff",
+            "old/tokens",
+            "new/tokens",
+            "--classify",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("run diff");
+    assert!(output.status.success(), "{output:?}");
+    let result: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(result["bump"], "minor");
+    assert_eq!(result["declared"], Value::Null);
+    let changes = result["changes"].as_array().expect("changes array");
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0]["subject"], "component");
+    assert_eq!(changes[0]["reason"], "option added: icon");
+    assert_eq!(changes[1]["bump"], "patch");
+}
+
+#[test]
+fn classify_fails_when_changeset_bump_is_too_low() {
+    let project = setup_project();
+    let changesets = project.path().join(".changeset");
+    fs::create_dir_all(&changesets).expect("create changeset dir");
+    fs::write(changesets.join("README.md"), "# Changesets\n").expect("write readme");
+    fs::write(
+        changesets.join("gap.md"),
+        "---\n\"@adobe/spectrum-tokens\": patch\n---\n\nTighter gap.\n",
+    )
+    .expect("write changeset");
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "diff",
+            "old/tokens",
+            "new/tokens",
+            "--classify",
+            "--changeset",
+            ".changeset",
+            "--package",
+            "@adobe/spectrum-tokens",
+        ])
+        .assert()
+        .code(1)
+        .stdout(contains("Required bump: minor (1 minor, 1 patch)"))
+        .stdout(contains("Declared bump: patch"))
+        .stdout(contains("lower than required"));
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "diff",
+            "old/tokens",
+            "new/tokens",
+            "--classify",
+            "--declared",
+            "major",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn notes_render_markdown_through_a_template() {
+    let project = setup_project();
+    fs::write(
+        project.path().join("notes.md"),
+        "# Spectrum tokens {{version}}\n\n{{notes}}",
+    )
+    .expect("write template");
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "diff",
+            "old/tokens",
+            "new/tokens",
+            "--notes",
+            "--release-version",
+            "3.2.0",
+            "--template",
+            "notes.md",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("# Spectrum tokens 3.2.0\n\n### Global tokens"))
+        .stdout(contains("- `gap`\n  - `value`: 4px → 3px"));
+
+    let output = Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "diff",
+            "old/tokens",
+            "new/tokens",
+            "--notes",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("run diff");
+    assert!(output.status.success(), "{output:?}");
+    let notes: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(notes["summary"]["updated"], 1);
+    assert_eq!(notes["groups"][0]["component"], Value::Null);
+    assert_eq!(notes["groups"][0]["sections"][0]["category"], "updated");
+}
+
+#[test]
+fn color_changes_report_delta_e() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = |accent: &str, border: &str| {
+        json!([
+            {"name": {"property": "accent-color"}, "value": accent, "uuid": "00000000-0000-4000-8000-000000000001"},
+            {"name": {"property": "border-color"}, "value": border, "uuid": "00000000-0000-4000-8000-000000000002"}
+        ])
+    };
+    write_side(
+        project.path(),
+        "old",
+        tokens("rgb(20, 115, 230)", "#6e6e6e"),
+        &[],
+    );
+    write_side(project.path(), "new", tokens("#1473e6", "#8f8f8f"), &[]);
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args(["diff", "old/tokens", "new/tokens", "--format", "markdown"])
+        .assert()
+        .code(1)
+        .stdout(contains(
+            "rgb(20, 115, 230) → #1473e6 (ΔE 0.00, visually identical)",
+        ))
+        .stdout(contains("#6e6e6e → #8f8f8f (ΔE 12."));
+
+    let output = Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args(["diff", "old/tokens", "new/tokens", "--format", "json"])
+        .output()
+        .expect("run diff");
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    let report: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    let updated = report["updated"].as_array().expect("updated array");
+    assert_eq!(
+        updated[0]["property_changes"][0]["visually_identical"],
+        true
+    );
+    assert!(updated[1]["property_changes"][0]
+        .get("visually_identical")
+        .is_none());
+}
+
+/// A dark-scheme palette edit reaches the semantic alias in that scheme only.
+#[test]
+fn resolved_reports_effective_value_changes_per_mode() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let mode_sets = project.path().join("mode-sets");
+    fs::create_dir_all(&mode_sets).expect("create mode-sets dir");
+    fs::write(
+        mode_sets.join("color-scheme.json"),
+        json!({"name": "colorScheme", "modes": ["light", "dark"], "default": "light"}).to_string(),
+    )
+    .expect("write mode set");
+    const PALETTE: &str = "00000000-0000-4000-8000-000000000010";
+    let tokens = |dark: &str| {
+        json!([
+            {"name": {"property": "blue-900", "colorScheme": "light"}, "value": "#3b63fb",
+             "uuid": "00000000-0000-4000-8000-000000000001", "set_uuid": PALETTE},
+            {"name": {"property": "blue-900", "colorScheme": "dark"}, "value": dark,
+             "uuid": "00000000-0000-4000-8000-000000000002", "set_uuid": PALETTE},
+            {"name": {"property": "accent-color-900"}, "$ref": PALETTE,
+             "uuid": "00000000-0000-4000-8000-000000000003"}
+        ])
+    };
+    write_side(project.path(), "old", tokens("#5681ff"), &[]);
+    write_side(project.path(), "new", tokens("#4b75ff"), &[]);
+
+    let diff = |new: &str| {
+        let mut cmd = Command::cargo_bin("design-data").expect("binary builds");
+        cmd.current_dir(project.path()).args([
+            "diff",
+            "old/tokens",
+            new,
+            "--resolved",
+            "--mode-sets-path",
+            "mode-sets",
+        ]);
+        cmd
+    };
+    diff("new/tokens")
+        .assert()
+        .code(1)
+        .stdout(contains("2 token(s) changed resolved value"))
+        .stdout(contains(
+            "colorScheme=dark: #5681ff → #4b75ff (ΔE 3.82)\n      via accent-color-900 → blue-900 (changed at blue-900)",
+        ));
+    diff("old/tokens")
+        .assert()
+        .success()
+        .stdout(contains("No resolved value changes."));
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data graph`: DOT, Mermaid and node-link JSON
+//! export with `--filter` and `--depth`.
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use serde_json::{json, Value};
+
+const PALETTE: &str = "00000000-0000-4000-8000-000000000001";
+const ACCENT: &str = "00000000-0000-4000-8000-000000000002";
+const BUTTON: &str = "00000000-0000-4000-8000-000000000003";
+
+/// `button-background-color` → `accent-color-900` → `blue-900`, bound by the
+/// button component, with a button-scoped relationship onto the accent token.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let root = project.path();
+    for dir in ["tokens", "components", "relationships", "empty"] {
+        fs::create_dir_all(root.join(dir)).expect("create dir");
+    }
+    fs::write(
+        root.join("tokens/color.tokens.json"),
+        json!([
+            {"name": {"property": "blue-900"}, "value": "#3b63fb", "uuid": PALETTE},
+            {"name": {"property": "accent-color-900"}, "$ref": PALETTE, "uuid": ACCENT},
+            {"name": {"property": "background-color", "component": "button"},
+             "$ref": ACCENT, "uuid": BUTTON}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+    fs::write(
+        root.join("components/button.json"),
+        json!({"name": "button", "tokenBindings": [{"token": "button-background-color"}]})
+            .to_string(),
+    )
+    .expect("write component");
+    fs::write(
+        root.join("relationships/button.json"),
+        json!([{"scope": {"component": "button", "property": "border-color"}, "$ref": ACCENT}])
+            .to_string(),
+    )
+    .expect("write relationships");
+    project
+}
+
+fn graph(project: &tempfile::TempDir, extra: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+    cmd.current_dir(project.path())
+        .args([
+            "graph",
+            "tokens",
+            "--mode-sets-path",
+            "empty",
+            "--components-path",
+            "components",
+            "--relationships-path",
+            "relationships",
+        ])
+        .args(extra);
+    cmd
+}
+
+#[test]
+fn dot_export_follows_filter_and_depth() {
+    let project = setup_project();
+    graph(&project, &["--filter", "component=button", "--depth", "1"])
+        .assert()
+        .success()
+        .stdout(contains("digraph tokens {"))
+        .stdout(contains(format!(
+            "\"component:button\" -> \"{BUTTON}\" [label=binding];"
+        )))
+        .stdout(contains(format!(
+            "\"relationship:button.json#0\" -> \"{ACCENT}\" [label=ref];"
+        )))
+        .stdout(contains(format!("\"{BUTTON}\" -> \"{ACCENT}\";")))
+        // blue-900 is two hops from every seed.
+        .stdout(contains(PALETTE).not());
+}
+
+#[test]
+fn mermaid_and_json_exports() {
+    let project = setup_project();
+    graph(
+        &project,
+        &[
+            "--filter",
+            "property=accent-color-900",
+            "--format",
+            "mermaid",
+        ],
+    )
+    .assert()
+    .success()
+    .stdout(contains(
+        "flowchart LR\n  n0[\"accent-color-900\"]\n  n1[\"blue-900<br/>#3b63fb\"]\n  n0 --> n1\n",
+    ));
+
+    let out_file = project.path().join("graph.json");
+    graph(&project, &["--format", "json", "--output", "graph.json"])
+        .assert()
+        .success()
+        .stderr(contains("Wrote 5 nodes and 5 edges to graph.json"));
+    let exported: Value =
+        serde_json::from_str(&fs::read_to_string(out_file).expect("read export")).unwrap();
+    assert_eq!(exported["directed"], true);
+    assert_eq!(exported["links"].as_array().map(Vec::len), Some(5));
+}
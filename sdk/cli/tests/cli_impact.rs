@@ -0,0 +1,135 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data impact`: transitive dependents,
+//! component bindings, CTR relationships, product-context overrides and the
+//! `--value` what-if.
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use serde_json::{json, Value};
+
+const PALETTE: &str = "00000000-0000-4000-8000-000000000001";
+const ACCENT: &str = "00000000-0000-4000-8000-000000000002";
+const BUTTON: &str = "00000000-0000-4000-8000-000000000003";
+
+/// A temp project where `button-background-color` aliases `accent-color-900`,
+/// which aliases the palette's `blue-900`; a component binds the button token,
+/// a relationship references the accent token, and a product context
+/// overrides the palette entry.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let root = project.path();
+    for dir in ["tokens", "components", "relationships", "empty"] {
+        fs::create_dir_all(root.join(dir)).expect("create dir");
+    }
+
+    fs::write(
+        root.join("tokens/color.tokens.json"),
+        json!([
+            {"name": {"property": "blue-900"}, "value": "#3b63fb", "uuid": PALETTE},
+            {"name": {"property": "accent-color-900"}, "$ref": PALETTE, "uuid": ACCENT},
+            {"name": {"property": "background-color", "component": "button"},
+             "$ref": ACCENT, "uuid": BUTTON},
+            {"name": {"property": "gray-100"}, "value": "#e9e9e9",
+             "uuid": "00000000-0000-4000-8000-000000000004"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+    fs::write(
+        root.join("components/button.json"),
+        json!({"name": "button", "tokenBindings": [
+            {"token": "button-background-color", "context": "Fill"}
+        ]})
+        .to_string(),
+    )
+    .expect("write component");
+    fs::write(
+        root.join("relationships/link.json"),
+        json!([{"scope": {"component": "link", "property": "color"}, "$ref": ACCENT}]).to_string(),
+    )
+    .expect("write relationships");
+    fs::write(
+        root.join("product-context.json"),
+        json!({"overrides": [{"uuid": PALETTE, "value": "#2f55e0"}]}).to_string(),
+    )
+    .expect("write product context");
+
+    project
+}
+
+fn impact(project: &tempfile::TempDir, extra: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+    cmd.current_dir(project.path())
+        .args([
+            "impact",
+            "blue-900",
+            "tokens",
+            "--mode-sets-path",
+            "empty",
+            "--components-path",
+            "components",
+            "--relationships-path",
+            "relationships",
+        ])
+        .args(extra);
+    cmd
+}
+
+#[test]
+fn reports_dependents_grouped_by_component() {
+    let project = setup_project();
+    impact(&project, &["--product-context", "product-context.json"])
+        .assert()
+        .success()
+        .stdout(contains(
+            "Impact:     3 dependent tokens, 1 component binding, 1 relationship, 2 components",
+        ))
+        .stdout(contains(
+            "button\n  foundation\n    button-background-color ← accent-color-900 (alias)",
+        ))
+        .stdout(contains("  product\n    blue-900 ← blue-900 (override)"))
+        .stdout(contains("  button: button-background-color (Fill)"))
+        .stdout(contains("  link color ← accent-color-900"));
+}
+
+#[test]
+fn value_what_if_and_json_output() {
+    let project = setup_project();
+    let out = impact(&project, &["--value", "#000000", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: Value = serde_json::from_slice(&out).expect("impact JSON");
+    let tokens = report["tokens"].as_array().expect("tokens");
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[2]["key"], "button-background-color");
+    assert_eq!(tokens[2]["depth"], 2);
+    assert_eq!(tokens[2]["value"], "#3b63fb");
+    assert_eq!(tokens[2]["proposed"], "#000000");
+
+    let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+    cmd.current_dir(project.path())
+        .args([
+            "impact",
+            "no-such-token",
+            "tokens",
+            "--mode-sets-path",
+            "empty",
+        ])
+        .assert()
+        .code(1)
+        .stderr(contains("No token matches 'no-such-token'"));
+}
@@ -0,0 +1,132 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data lint unused`: reachability from
+//! component bindings, CTR relationships and semantic tokens, orphaned
+//! component tokens, and the allowlist.
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use serde_json::{json, Value};
+
+/// A temp project with two private palette entries (one aliased by a
+/// semantic token), a bound button token, a relationship-referenced link
+/// token, and a token for a `tooltip` component the catalog does not declare.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let root = project.path();
+    for dir in ["tokens", "components", "relationships", "empty"] {
+        fs::create_dir_all(root.join(dir)).expect("create dir");
+    }
+    fs::write(
+        root.join("tokens/color.tokens.json"),
+        json!([
+            {"name": {"property": "blue-900"}, "value": "#3b63fb", "private": true,
+             "uuid": "00000000-0000-4000-8000-000000000001"},
+            {"name": {"property": "red-900"}, "value": "#d31510", "private": true,
+             "uuid": "00000000-0000-4000-8000-000000000002"},
+            {"name": {"property": "accent-color-900"},
+             "$ref": "00000000-0000-4000-8000-000000000001",
+             "uuid": "00000000-0000-4000-8000-000000000003"},
+            {"name": {"property": "background-color", "component": "button"},
+             "value": "#ffffff", "uuid": "00000000-0000-4000-8000-000000000004"},
+            {"name": {"property": "border-color", "component": "link"},
+             "value": "#000000", "uuid": "00000000-0000-4000-8000-000000000005"},
+            {"name": {"property": "background-color", "component": "tooltip"},
+             "value": "#222222", "uuid": "00000000-0000-4000-8000-000000000006"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+    for name in ["button", "link"] {
+        let mut comp = json!({"name": name});
+        if name == "button" {
+            comp["tokenBindings"] = json!([{"token": "button-background-color"}]);
+        }
+        fs::write(
+            root.join(format!("components/{name}.json")),
+            comp.to_string(),
+        )
+        .expect("write component");
+    }
+    fs::write(
+        root.join("relationships/link.json"),
+        json!([{"scope": {"component": "link"}, "$ref": "00000000-0000-4000-8000-000000000005"}])
+            .to_string(),
+    )
+    .expect("write relationships");
+    fs::write(
+        root.join("unused-exceptions.json"),
+        json!({"exceptions": [
+            {"token": "red-900", "reason": "Reserved for the next negative palette"},
+            {"token": "retired-token", "reason": "Removed in 2.0"}
+        ]})
+        .to_string(),
+    )
+    .expect("write allowlist");
+    project
+}
+
+fn lint_unused(project: &tempfile::TempDir, extra: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+    cmd.current_dir(project.path())
+        .args([
+            "lint",
+            "unused",
+            "tokens",
+            "--mode-sets-path",
+            "empty",
+            "--components-path",
+            "components",
+            "--relationships-path",
+            "relationships",
+        ])
+        .args(extra);
+    cmd
+}
+
+#[test]
+fn reports_unreachable_and_orphaned_tokens() {
+    let project = setup_project();
+    lint_unused(&project, &[])
+        .assert()
+        .code(1)
+        .stdout(contains("Unused:     2 of 6 tokens (1 orphaned)"))
+        .stdout(contains("Roots:      3 tokens, 0 allowlisted"))
+        .stdout(contains(
+            "  red-900  00000000-0000-4000-8000-000000000002\n",
+        ))
+        .stdout(contains(
+            "  tooltip-background-color  00000000-0000-4000-8000-000000000006  (orphaned: no component \"tooltip\")",
+        ));
+}
+
+#[test]
+fn allowlist_and_json_output() {
+    let project = setup_project();
+    let out = lint_unused(
+        &project,
+        &["--allowlist", "unused-exceptions.json", "--format", "json"],
+    )
+    .assert()
+    .code(1)
+    .get_output()
+    .stdout
+    .clone();
+    let report: Value = serde_json::from_slice(&out).expect("unused JSON");
+    assert_eq!(report["allowlisted"], 1);
+    assert_eq!(report["stale_exceptions"], json!(["retired-token"]));
+    let unused = report["unused"].as_array().expect("unused");
+    assert_eq!(unused.len(), 1);
+    assert_eq!(unused[0]["key"], "tooltip-background-color");
+    assert_eq!(unused[0]["orphaned"], true);
+}
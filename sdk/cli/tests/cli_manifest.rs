@@ -15,6 +15,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::contains;
 use serde_json::json;
 
@@ -158,3 +159,113 @@ fn resolve_applies_manifest_override_by_uuid() {
         .success()
         .stdout(contains("#ffffff"));
 }
+
+#[test]
+fn generate_css_applies_manifest_include_and_override() {
+    let project = setup_project(json!({
+        "specVersion": "1.0.0-draft",
+        "foundationVersion": "1.0.0",
+        "include": ["component=button"],
+        "overrides": [{"target": "u-btn-bg", "value": "#ffffff"}]
+    }));
+
+    Command::cargo_bin("design-data")
+        .expect("binary design-data")
+        .current_dir(project.path())
+        .args(["generate", "css", "tokens"])
+        .assert()
+        .success()
+        .stdout(contains("--spectrum-button-background-color: #ffffff;"))
+        .stdout(contains("--spectrum-button-color: #111;"))
+        .stdout(contains("checkbox").not());
+}
+
+#[test]
+fn generate_prefers_explicit_manifest_flag() {
+    let project = setup_project(json!({
+        "specVersion": "1.0.0-draft",
+        "foundationVersion": "1.0.0",
+        "include": ["component=button"]
+    }));
+    let explicit = project.path().join("android.manifest.json");
+    fs::write(
+        &explicit,
+        json!({
+            "specVersion": "1.0.0-draft",
+            "foundationVersion": "1.0.0",
+            "include": ["component=checkbox"]
+        })
+        .to_string(),
+    )
+    .expect("write explicit manifest");
+
+    Command::cargo_bin("design-data")
+        .expect("binary design-data")
+        .current_dir(project.path())
+        .args(["generate", "css", "tokens", "--manifest"])
+        .arg(&explicit)
+        .assert()
+        .success()
+        .stdout(contains("--spectrum-checkbox-background-color: #bbb;"))
+        .stdout(contains("button").not());
+}
+
+fn formatting_manifest() -> serde_json::Value {
+    json!({
+        "specVersion": "1.0.0-draft",
+        "foundationVersion": "1.0.0",
+        "extensions": {"formatting": {
+            "casing": "camelCase",
+            "delimiter": ".",
+            "abbreviations": {"background": "bg"}
+        }}
+    })
+}
+
+#[test]
+fn query_json_includes_formatted_name() {
+    let project = setup_project(formatting_manifest());
+
+    Command::cargo_bin("design-data")
+        .expect("binary design-data")
+        .current_dir(project.path())
+        .args([
+            "query",
+            "tokens",
+            "--filter",
+            "component=checkbox",
+            "--format",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(contains(r#""formattedName": "checkbox.bgColor""#));
+}
+
+#[test]
+fn dump_legacy_keys_includes_formatted_name() {
+    let project = setup_project(formatting_manifest());
+
+    Command::cargo_bin("design-data")
+        .expect("binary design-data")
+        .current_dir(project.path())
+        .args(["dump-legacy-keys", "tokens"])
+        .assert()
+        .success()
+        .stdout(contains(r#""legacyKey": "button-background-color""#))
+        .stdout(contains(r#""formattedName": "button.bgColor""#));
+}
+
+#[test]
+fn generate_css_uses_formatted_names() {
+    let project = setup_project(formatting_manifest());
+
+    Command::cargo_bin("design-data")
+        .expect("binary design-data")
+        .current_dir(project.path())
+        .args(["generate", "css", "tokens"])
+        .assert()
+        .success()
+        .stdout(contains("--spectrum-button-bgColor: #aaa;"))
+        .stdout(contains("--spectrum-button-background-color").not());
+}
@@ -0,0 +1,124 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data migrate consumers`.
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use serde_json::json;
+
+const SOURCE: &str = ".a {\n  padding: var(--spectrum-old-padding);\n  box-shadow: 0 0 var(--spectrum-old-shadow);\n}\n";
+
+/// A temp project with a tokens dir deprecating `old-padding` (one replacement)
+/// and `old-shadow` (two), plus a consumer stylesheet referencing both.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens_dir = project.path().join("tokens");
+    fs::create_dir_all(&tokens_dir).expect("create tokens dir");
+    fs::write(
+        tokens_dir.join("tokens.tokens.json"),
+        json!([
+            {"name": {"property": "old-padding"}, "value": "8px", "uuid": "00000000-0000-4000-8000-000000000001",
+             "lifecycle": {"deprecatedIn": "2.0.0", "replacedBy": "00000000-0000-4000-8000-000000000002"}},
+            {"name": {"property": "new-padding"}, "value": "8px", "uuid": "00000000-0000-4000-8000-000000000002"},
+            {"name": {"property": "old-shadow"}, "value": "#000", "uuid": "00000000-0000-4000-8000-000000000003",
+             "lifecycle": {"deprecatedIn": "2.0.0", "deprecatedComment": "Pick one.",
+                           "replacedBy": ["00000000-0000-4000-8000-000000000004", "00000000-0000-4000-8000-000000000005"]}},
+            {"name": {"property": "shadow-100"}, "value": "#000", "uuid": "00000000-0000-4000-8000-000000000004"},
+            {"name": {"property": "shadow-200"}, "value": "#000", "uuid": "00000000-0000-4000-8000-000000000005"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+    fs::create_dir_all(project.path().join("src")).expect("create src dir");
+    fs::write(project.path().join("src/app.css"), SOURCE).expect("write source");
+    project
+}
+
+#[test]
+fn consumers_dry_run_prints_diff_without_writing() {
+    let project = setup_project();
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "migrate",
+            "consumers",
+            "src",
+            "--tokens",
+            "tokens",
+            "--dry-run",
+        ])
+        .assert()
+        .code(1)
+        .stdout(contains("+  padding: var(--spectrum-new-padding);"))
+        .stdout(contains("+  box-shadow").not())
+        .stderr(contains(
+            "old-shadow has several replacements (shadow-100, shadow-200)",
+        ));
+
+    let after = fs::read_to_string(project.path().join("src/app.css")).expect("read source");
+    assert_eq!(after, SOURCE);
+}
+
+#[test]
+fn consumers_rewrites_files_in_place() {
+    let project = setup_project();
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args(["migrate", "consumers", "src", "--tokens", "tokens"])
+        .assert()
+        .code(1)
+        .stdout(contains("Rewrote 1 reference(s) in 1 file(s)"));
+
+    let after = fs::read_to_string(project.path().join("src/app.css")).expect("read source");
+    assert_eq!(after, SOURCE.replace("old-padding", "new-padding"));
+}
+
+#[test]
+fn consumers_warns_about_unresolved_replacements_and_keeps_them_ambiguous() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens_dir = project.path().join("tokens");
+    fs::create_dir_all(&tokens_dir).expect("create tokens dir");
+    fs::write(
+        tokens_dir.join("tokens.tokens.json"),
+        json!([
+            {"name": {"property": "old-padding"}, "value": "8px", "uuid": "00000000-0000-4000-8000-000000000001",
+             "lifecycle": {"deprecatedIn": "2.0.0",
+                           "replacedBy": ["00000000-0000-4000-8000-000000000002", "00000000-0000-4000-8000-00000000dead"]}},
+            {"name": {"property": "new-padding"}, "value": "8px", "uuid": "00000000-0000-4000-8000-000000000002"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+    fs::create_dir_all(project.path().join("src")).expect("create src dir");
+    fs::write(project.path().join("src/app.css"), SOURCE).expect("write source");
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args(["migrate", "consumers", "src", "--tokens", "tokens"])
+        .assert()
+        .code(1)
+        .stderr(contains(
+            "old-padding: replacedBy 00000000-0000-4000-8000-00000000dead does not resolve to a token",
+        ))
+        .stderr(contains("old-padding has several replacements (new-padding)"))
+        .stdout(contains("Rewrote 0 reference(s) in 0 file(s)"));
+
+    let after = fs::read_to_string(project.path().join("src/app.css")).expect("read source");
+    assert_eq!(after, SOURCE);
+}
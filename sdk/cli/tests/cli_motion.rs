@@ -0,0 +1,177 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for motion token types (duration, easing, motion) and the
+//! `reducedMotion` mode set across validate, resolve, legacy output and generate.
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use serde_json::json;
+
+const TOKEN_TYPES: &str = "https://opensource.adobe.com/spectrum-design-data/schemas/token-types";
+
+/// Absolute path to the repo root (so the resolver finds the mode-set catalog).
+fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .expect("repo root canonicalizes")
+}
+
+/// A temp project with a duration set keyed by `reducedMotion`, an easing and a
+/// motion composite, plus a `.design-data.toml` whose `[source]` points at the
+/// repo root.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens_dir = project.path().join("tokens");
+    fs::create_dir_all(&tokens_dir).expect("create tokens dir");
+    fs::create_dir_all(project.path().join("empty")).expect("create empty catalog dir");
+
+    fs::write(
+        tokens_dir.join("motion.tokens.json"),
+        json!([
+            {"name": {"property": "animation-duration-100", "reducedMotion": "no-preference"},
+             "$schema": format!("{TOKEN_TYPES}/duration.json"), "value": "130ms",
+             "uuid": "00000000-0000-4000-8000-000000000001"},
+            {"name": {"property": "animation-duration-100", "reducedMotion": "reduce"},
+             "$schema": format!("{TOKEN_TYPES}/duration.json"), "value": "0ms",
+             "uuid": "00000000-0000-4000-8000-000000000002"},
+            {"name": {"property": "animation-ease-in-out"},
+             "$schema": format!("{TOKEN_TYPES}/easing.json"), "value": "cubic-bezier(0.45, 0, 0.4, 1)",
+             "uuid": "00000000-0000-4000-8000-000000000003"},
+            {"name": {"property": "animation-enter"},
+             "$schema": format!("{TOKEN_TYPES}/motion.json"),
+             "value": {"duration": "{animation-duration-100}", "timingFunction": "{animation-ease-in-out}"},
+             "uuid": "00000000-0000-4000-8000-000000000004"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+
+    fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root().display()
+        ),
+    )
+    .expect("write config");
+
+    project
+}
+
+fn design_data(project: &tempfile::TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+    cmd.current_dir(project.path());
+    cmd
+}
+
+#[test]
+fn motion_tokens_validate() {
+    let project = setup_project();
+    design_data(&project)
+        .args([
+            "validate",
+            "tokens",
+            "--components-path",
+            "empty",
+            "--relationships-path",
+            "empty",
+        ])
+        .assert()
+        .success()
+        .stderr(contains("error").not());
+
+    // A malformed duration fails structural validation.
+    let path = project.path().join("tokens/motion.tokens.json");
+    let text = fs::read_to_string(&path).expect("read tokens");
+    fs::write(&path, text.replace("\"130ms\"", "\"fast\"")).expect("write tokens");
+    design_data(&project)
+        .args([
+            "validate",
+            "tokens",
+            "--components-path",
+            "empty",
+            "--relationships-path",
+            "empty",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("\"fast\" does not match"));
+}
+
+#[test]
+fn resolve_honors_reduced_motion() {
+    let project = setup_project();
+    design_data(&project)
+        .args([
+            "resolve",
+            "animation-duration-100",
+            "tokens",
+            "--reduced-motion",
+            "no-preference",
+            "--format",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("130ms"));
+    design_data(&project)
+        .args([
+            "resolve",
+            "animation-duration-100",
+            "tokens",
+            "--reduced-motion",
+            "reduce",
+            "--format",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("0ms"))
+        .stdout(contains("130ms").not());
+}
+
+#[test]
+fn legacy_output_rebuilds_motion_set() {
+    let project = setup_project();
+    let output = project.path().join("legacy.json");
+    design_data(&project)
+        .args(["migrate", "legacy-output-cascaded", "tokens", "--output"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    let legacy: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&output).expect("read output"))
+            .expect("parse output");
+    let set = &legacy["animation-duration-100"];
+    assert_eq!(set["$schema"], format!("{TOKEN_TYPES}/motion-set.json"));
+    assert_eq!(set["sets"]["no-preference"]["value"], "130ms");
+    assert_eq!(set["sets"]["reduce"]["value"], "0ms");
+}
+
+#[test]
+fn generate_css_emits_reduce_block() {
+    let project = setup_project();
+    design_data(&project)
+        .args(["generate", "css", "tokens"])
+        .assert()
+        .success()
+        .stdout(contains("--spectrum-animation-duration-100: 130ms;"))
+        .stdout(contains(
+            ".spectrum--reduce {\n  --spectrum-animation-duration-100: 0ms;\n  \
+             --spectrum-animation-enter-duration: var(--spectrum-animation-duration-100);\n",
+        ));
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data query --context` and `value` predicates.
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use serde_json::json;
+
+/// Absolute path to the repo root (so the resolver finds the mode-set catalog).
+fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .expect("repo root canonicalizes")
+}
+
+/// A temp project with light/dark cascade tokens and a `.design-data.toml`
+/// whose `[source]` points at the repo root.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens_dir = project.path().join("tokens");
+    fs::create_dir_all(&tokens_dir).expect("create tokens dir");
+
+    fs::write(
+        tokens_dir.join("colors.tokens.json"),
+        json!([
+            {"name": {"property": "background-color", "component": "card", "colorScheme": "light"}, "value": "#ffffff", "uuid": "00000000-0000-4000-8000-000000000001"},
+            {"name": {"property": "background-color", "component": "card", "colorScheme": "dark"}, "value": "rgb(29, 29, 29)", "uuid": "00000000-0000-4000-8000-000000000002"},
+            {"name": {"property": "border-color", "component": "card", "colorScheme": "light"}, "value": "#000000", "uuid": "00000000-0000-4000-8000-000000000003"},
+            {"name": {"property": "border-color", "component": "card", "colorScheme": "dark"}, "value": "rgb(255, 255, 255)", "uuid": "00000000-0000-4000-8000-000000000004"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+
+    fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root().display()
+        ),
+    )
+    .expect("write config");
+
+    project
+}
+
+#[test]
+fn query_context_matches_resolved_values() {
+    let project = setup_project();
+
+    let output = Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "query",
+            "tokens",
+            "--context",
+            "colorScheme=dark",
+            "--filter",
+            "value=#FFFFFF",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("run query");
+    assert!(output.status.success(), "{output:?}");
+    let results: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    let results = results.as_array().expect("array output");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["uuid"], "00000000-0000-4000-8000-000000000004");
+    assert_eq!(results[0]["resolvedValue"], "rgb(255, 255, 255)");
+
+    // Light mode resolves the background instead.
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "query",
+            "tokens",
+            "--context",
+            "colorScheme=light",
+            "--filter",
+            "value=#FFFFFF",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("background-color"))
+        .stdout(contains("Value:   \"#ffffff\""));
+}
+
+#[test]
+fn query_rejects_unknown_context_mode() {
+    let project = setup_project();
+
+    Command::cargo_bin("design-data")
+        .expect("binary builds")
+        .current_dir(project.path())
+        .args([
+            "query",
+            "tokens",
+            "--context",
+            "colorScheme=dim",
+            "--filter",
+            "",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("unknown mode \"dim\""));
+}
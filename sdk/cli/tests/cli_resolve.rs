@@ -0,0 +1,133 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Integration tests for `design-data resolve` against a custom mode set:
+//! the generic `--mode SET=MODE` flag and `--matrix` resolution.
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use serde_json::{json, Value};
+
+const MODE_SET_SCHEMA: &str =
+    "https://opensource.adobe.com/spectrum-design-data/schemas/v0/mode-set.schema.json";
+
+/// A temp project with a `density` mode set alongside `colorScheme`, and a
+/// `row-height` property that varies by density only.
+fn setup_project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens_dir = project.path().join("tokens");
+    let mode_sets_dir = project.path().join("mode-sets");
+    fs::create_dir_all(&tokens_dir).expect("create tokens dir");
+    fs::create_dir_all(&mode_sets_dir).expect("create mode-sets dir");
+
+    for (file, name, modes, default) in [
+        (
+            "color-scheme.json",
+            "colorScheme",
+            json!(["light", "dark"]),
+            "light",
+        ),
+        (
+            "density.json",
+            "density",
+            json!(["comfortable", "compact"]),
+            "comfortable",
+        ),
+    ] {
+        fs::write(
+            mode_sets_dir.join(file),
+            json!({"$schema": MODE_SET_SCHEMA, "specVersion": "1.0.0-draft",
+                   "name": name, "modes": modes, "default": default})
+            .to_string(),
+        )
+        .expect("write mode set");
+    }
+
+    fs::write(
+        tokens_dir.join("layout.tokens.json"),
+        json!([
+            {"name": {"property": "row-height-base"}, "value": "40px",
+             "uuid": "00000000-0000-4000-8000-000000000001"},
+            {"name": {"property": "row-height"}, "$ref": "00000000-0000-4000-8000-000000000001",
+             "uuid": "00000000-0000-4000-8000-000000000002"},
+            {"name": {"property": "row-height", "density": "compact"}, "value": "32px",
+             "uuid": "00000000-0000-4000-8000-000000000003"}
+        ])
+        .to_string(),
+    )
+    .expect("write tokens");
+
+    project
+}
+
+fn resolve(project: &tempfile::TempDir, extra: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+    cmd.current_dir(project.path())
+        .args([
+            "resolve",
+            "row-height",
+            "tokens",
+            "--mode-sets-path",
+            "mode-sets",
+        ])
+        .args(extra);
+    cmd
+}
+
+#[test]
+fn mode_flag_targets_custom_mode_set() {
+    let project = setup_project();
+    resolve(&project, &["--mode", "density=compact"])
+        .assert()
+        .success()
+        .stdout(contains("\"32px\""));
+    resolve(&project, &["--mode", "density=cozy"])
+        .assert()
+        .failure()
+        .stderr(contains("unknown mode \"cozy\" for mode set \"density\""));
+    resolve(
+        &project,
+        &["--mode", "density=compact", "--mode", "density=comfortable"],
+    )
+    .assert()
+    .failure()
+    .stderr(contains("conflicting modes for mode set \"density\""));
+}
+
+#[test]
+fn matrix_collapses_combinations() {
+    let project = setup_project();
+    resolve(&project, &["--matrix"])
+        .assert()
+        .success()
+        .stdout(contains("Outcomes:  2 across 4 combinations"))
+        .stdout(contains(
+            "density=comfortable  \"40px\"  via {row-height-base}",
+        ))
+        .stdout(contains("density=compact      \"32px\""));
+
+    let out = resolve(
+        &project,
+        &["--matrix", "--color-scheme", "dark", "--format", "json"],
+    )
+    .assert()
+    .success()
+    .get_output()
+    .stdout
+    .clone();
+    let matrix: Value = serde_json::from_slice(&out).expect("matrix JSON");
+    let outcomes = matrix["outcomes"].as_array().expect("outcomes");
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[1]["when"], json!([{"density": "compact"}]));
+    assert_eq!(outcomes[1]["combinations"], 1);
+    assert_eq!(outcomes[1]["value"], "32px");
+}
@@ -13,6 +13,7 @@
 use std::path::PathBuf;
 
 use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::contains;
 use serde_json::json;
 
@@ -260,19 +261,17 @@ fn primer_emits_json_with_required_fields() {
         "tokenCount must be positive"
     );
     assert!(
-        doc["modeSets"].as_array().map_or(false, |a| !a.is_empty()),
+        doc["modeSets"].as_array().is_some_and(|a| !a.is_empty()),
         "modeSets must be non-empty"
     );
     assert!(
-        doc["components"]
-            .as_array()
-            .map_or(false, |a| !a.is_empty()),
+        doc["components"].as_array().is_some_and(|a| !a.is_empty()),
         "components must be non-empty"
     );
     assert!(
         doc["taxonomyFields"]
             .as_array()
-            .map_or(false, |a| !a.is_empty()),
+            .is_some_and(|a| !a.is_empty()),
         "taxonomyFields must be non-empty"
     );
 }
@@ -425,3 +424,528 @@ fn component_path_traversal_rejected() {
             .failure();
     }
 }
+
+#[test]
+fn validate_applies_rule_levels_from_config() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    let empty = project.path().join("empty");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(&empty).expect("create empty catalog dir");
+    std::fs::write(
+        tokens.join("alias.json"),
+        json!({
+            "button-gap": {
+                "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+                "value": "{missing-token}",
+                "uuid": "00000000-0000-4000-8000-000000000001"
+            }
+        })
+        .to_string(),
+    )
+    .expect("write token file");
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let config = format!(
+        "[source]\ntype = \"path\"\nroot = \"{}\"\n\n[validate.rules]\nSPEC-001 = \"warn\"\n",
+        repo_root.display()
+    );
+    std::fs::write(project.path().join(".design-data.toml"), &config).expect("write config");
+
+    let validate = || {
+        let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+        cmd.current_dir(project.path()).args([
+            "validate",
+            "tokens",
+            "--components-path",
+            "empty",
+            "--mode-sets-path",
+            "empty",
+            "--relationships-path",
+            "empty",
+        ]);
+        cmd
+    };
+
+    validate()
+        .assert()
+        .success()
+        .stderr(contains("warning: tokens/alias.json [SPEC-001]"));
+
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "{config}SPEC-999 = \"off\"\n\n[[validate.overrides]]\npaths = [\"tokens/*.json\"]\nrules = {{ SPEC-001 = \"off\" }}\n"
+        ),
+    )
+    .expect("rewrite config");
+
+    validate()
+        .assert()
+        .success()
+        .stdout(contains("No issues found."))
+        .stderr(contains("configures unknown rule SPEC-999"));
+}
+
+#[test]
+fn validate_fix_applies_mechanical_fixes_then_revalidates() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    let empty = project.path().join("empty");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(&empty).expect("create empty catalog dir");
+    let dimension =
+        "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/dimension.json";
+    let legacy = json!({
+        "spacing-100": {"$schema": dimension, "value": "8px", "uuid": "00000000-0000-4000-8000-000000000001"},
+        "spacing-200": {"$schema": dimension, "value": "16px", "uuid": "00000000-0000-4000-8000-000000000001"}
+    })
+    .to_string();
+    std::fs::write(tokens.join("spacing.json"), &legacy).expect("write legacy tokens");
+    std::fs::write(
+        tokens.join("gap.tokens.json"),
+        json!([{
+            "name": {"property": "gap"},
+            "value": "4px",
+            "uuid": "00000000-0000-4000-8000-000000000002",
+            "lastModified": "1.0.0",
+            "lifecycle": {
+                "introduced": "2.0.0",
+                "plannedRemoval": "3.0.0",
+                "replacedBy": "00000000-0000-4000-8000-000000000001"
+            }
+        }])
+        .to_string(),
+    )
+    .expect("write cascade tokens");
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root.display()
+        ),
+    )
+    .expect("write config");
+
+    let validate = |extra: &[&str]| {
+        let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+        cmd.current_dir(project.path())
+            .args([
+                "validate",
+                "tokens",
+                "--components-path",
+                "empty",
+                "--mode-sets-path",
+                "empty",
+                "--relationships-path",
+                "empty",
+            ])
+            .args(extra);
+        cmd
+    };
+
+    validate(&["--fix", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "would fix: tokens/spacing.json [SPEC-004] regenerate uuid (spacing-200)",
+        ))
+        .stderr(contains("3 fix(es) to apply in 2 file(s)"));
+    assert_eq!(
+        std::fs::read_to_string(tokens.join("spacing.json")).expect("read tokens"),
+        legacy
+    );
+
+    validate(&["--fix"])
+        .assert()
+        .success()
+        .stderr(contains("3 fix(es) applied in 2 file(s)"))
+        .stdout(contains("No issues found."));
+
+    let spacing: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(tokens.join("spacing.json")).expect("read tokens"),
+    )
+    .expect("valid json");
+    assert_eq!(
+        spacing["spacing-100"]["uuid"],
+        "00000000-0000-4000-8000-000000000001"
+    );
+    assert_ne!(
+        spacing["spacing-200"]["uuid"],
+        spacing["spacing-100"]["uuid"]
+    );
+    let gap: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(tokens.join("gap.tokens.json")).expect("read tokens"),
+    )
+    .expect("valid json");
+    assert_eq!(gap[0]["lastModified"], "2.0.0");
+    assert_eq!(gap[0]["lifecycle"]["deprecatedIn"], "2.0.0");
+}
+
+#[test]
+fn validate_fix_renames_only_when_asked() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(project.path().join("empty")).expect("create empty catalog dir");
+    let dimension =
+        "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/dimension.json";
+    std::fs::write(
+        tokens.join("swatch.json"),
+        json!({
+            "swatch-disabled-border-width": {
+                "$schema": dimension,
+                "component": "swatch",
+                "value": "1px",
+                "uuid": "00000000-0000-4000-8000-000000000001"
+            }
+        })
+        .to_string(),
+    )
+    .expect("write legacy tokens");
+    std::fs::write(
+        tokens.join("field.json"),
+        json!({
+            "field-border-width": {
+                "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+                "value": "{swatch-disabled-border-width}",
+                "uuid": "00000000-0000-4000-8000-000000000002"
+            }
+        })
+        .to_string(),
+    )
+    .expect("write referencing tokens");
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root.display()
+        ),
+    )
+    .expect("write config");
+
+    let validate = |extra: &[&str]| {
+        let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+        cmd.current_dir(project.path())
+            .args([
+                "validate",
+                "tokens",
+                "--components-path",
+                "empty",
+                "--mode-sets-path",
+                "empty",
+                "--relationships-path",
+                "empty",
+                "--fix",
+            ])
+            .args(extra);
+        cmd
+    };
+
+    validate(&[])
+        .assert()
+        .success()
+        .stderr(contains(
+            "skipped: tokens/swatch.json [SPEC-007] rename to swatch-border-width-disabled \
+             (swatch-disabled-border-width) (renames a published key; pass --fix-renames)",
+        ))
+        .stderr(contains("0 fix(es) applied in 0 file(s)"));
+
+    validate(&["--fix-renames"])
+        .assert()
+        .success()
+        .stderr(contains("1 fix(es) applied in 2 file(s)"))
+        .stdout(contains("No issues found."));
+    let swatch: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(tokens.join("swatch.json")).expect("read tokens"),
+    )
+    .expect("valid json");
+    assert!(swatch.get("swatch-disabled-border-width").is_none());
+    assert_eq!(swatch["swatch-border-width-disabled"]["value"], "1px");
+    let field: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(tokens.join("field.json")).expect("read tokens"),
+    )
+    .expect("valid json");
+    assert_eq!(
+        field["field-border-width"]["value"],
+        "{swatch-border-width-disabled}"
+    );
+}
+
+#[test]
+fn validate_emits_sarif_and_junit_reports() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    let empty = project.path().join("empty");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(&empty).expect("create empty catalog dir");
+    std::fs::write(
+        tokens.join("alias.json"),
+        serde_json::to_string_pretty(&json!({
+            "button-gap": {
+                "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+                "value": "{missing-token}",
+                "uuid": "00000000-0000-4000-8000-000000000001"
+            }
+        }))
+        .expect("serialize tokens"),
+    )
+    .expect("write token file");
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root.display()
+        ),
+    )
+    .expect("write config");
+
+    let validate = |format: &str| {
+        Command::cargo_bin("design-data")
+            .expect("binary design-data")
+            .current_dir(project.path())
+            .args([
+                "validate",
+                "tokens",
+                "--components-path",
+                "empty",
+                "--mode-sets-path",
+                "empty",
+                "--relationships-path",
+                "empty",
+                "--format",
+                format,
+            ])
+            .output()
+            .expect("run validate")
+    };
+
+    let output = validate("sarif");
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).expect("sarif json");
+    assert_eq!(log["version"], "2.1.0");
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "SPEC-001");
+    assert_eq!(
+        result["locations"][0]["physicalLocation"],
+        json!({
+            "artifactLocation": {"uri": "tokens/alias.json", "uriBaseId": "%SRCROOT%"},
+            "region": {"startLine": 2, "startColumn": 3}
+        })
+    );
+
+    let output = validate("junit");
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    let xml = String::from_utf8(output.stdout).expect("utf-8 xml");
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(xml.contains("<testcase name=\"SPEC-001 alias-target-exists\""));
+    assert!(xml.contains("tokens/alias.json:2:3 (button-gap)"));
+}
+
+#[test]
+fn validate_pretty_output_renders_code_frames() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    let empty = project.path().join("empty");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(&empty).expect("create empty catalog dir");
+    std::fs::write(
+        tokens.join("gap.json"),
+        serde_json::to_string_pretty(&json!({
+            "button-gap": {
+                "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/dimension.json",
+                "value": 5,
+                "uuid": "00000000-0000-4000-8000-000000000001"
+            }
+        }))
+        .expect("serialize tokens"),
+    )
+    .expect("write token file");
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root.display()
+        ),
+    )
+    .expect("write config");
+
+    Command::cargo_bin("design-data")
+        .expect("binary design-data")
+        .current_dir(project.path())
+        .env("NO_COLOR", "1")
+        .args([
+            "validate",
+            "tokens",
+            "--components-path",
+            "empty",
+            "--mode-sets-path",
+            "empty",
+            "--relationships-path",
+            "empty",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("error: tokens/gap.json [structural]"))
+        .stderr(contains("[tokens/gap.json:4:5]"))
+        .stderr(contains(" 4 |     \"value\": 5,"));
+}
+
+#[test]
+fn validate_baseline_fails_only_on_new_diagnostics() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    let empty = project.path().join("empty");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(&empty).expect("create empty catalog dir");
+    let alias = |target: &str, uuid: &str| {
+        json!({
+            "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+            "value": format!("{{{target}}}"),
+            "uuid": uuid
+        })
+    };
+    let write_tokens = |value: serde_json::Value| {
+        std::fs::write(
+            tokens.join("alias.json"),
+            serde_json::to_string_pretty(&value).expect("serialize tokens"),
+        )
+        .expect("write token file");
+    };
+    write_tokens(json!({
+        "button-gap": alias("missing-token", "00000000-0000-4000-8000-000000000001")
+    }));
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n",
+            repo_root.display()
+        ),
+    )
+    .expect("write config");
+
+    let validate = |extra: &[&str]| {
+        let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+        cmd.current_dir(project.path()).args([
+            "validate",
+            "tokens",
+            "--components-path",
+            "empty",
+            "--mode-sets-path",
+            "empty",
+            "--relationships-path",
+            "empty",
+            "--baseline",
+            "baseline.json",
+        ]);
+        cmd.args(extra);
+        cmd.assert()
+    };
+
+    validate(&["--update-baseline"])
+        .success()
+        .stderr(contains("baseline: wrote 1 diagnostic(s) to baseline.json"));
+    let baseline: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(project.path().join("baseline.json")).expect("read baseline"),
+    )
+    .expect("baseline json");
+    assert_eq!(
+        baseline["entries"][0]["subject"],
+        "00000000-0000-4000-8000-000000000001"
+    );
+
+    // Known diagnostic only: suppressed, exit 0.
+    validate(&[]).success().stderr(contains(
+        "baseline: 1 known diagnostic(s) suppressed, 0 fixed",
+    ));
+
+    // Renamed (uuid kept) and a new broken alias: only the new one fails.
+    write_tokens(json!({
+        "button-gap-renamed": alias("missing-token", "00000000-0000-4000-8000-000000000001"),
+        "button-pad": alias("also-missing", "00000000-0000-4000-8000-000000000002")
+    }));
+    validate(&[])
+        .failure()
+        .stderr(contains("also-missing"))
+        .stderr(contains("token: button-pad"))
+        .stderr(contains("token: button-gap-renamed").not());
+
+    // Both fixed: the baselined entry is reported so the file can shrink.
+    write_tokens(json!({}));
+    validate(&[])
+        .success()
+        .stderr(contains(
+            "baseline: fixed [SPEC-001] 00000000-0000-4000-8000-000000000001",
+        ))
+        .stderr(contains("--update-baseline"));
+}
+
+#[test]
+fn validate_runs_custom_rules_from_config() {
+    let project = tempfile::tempdir().expect("temp project dir");
+    let tokens = project.path().join("tokens");
+    let empty = project.path().join("empty");
+    std::fs::create_dir_all(&tokens).expect("create tokens dir");
+    std::fs::create_dir_all(&empty).expect("create empty catalog dir");
+    std::fs::write(
+        tokens.join("alias.json"),
+        json!({
+            "button-gap": {
+                "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+                "value": "{missing-token}",
+                "uuid": "00000000-0000-4000-8000-000000000001"
+            }
+        })
+        .to_string(),
+    )
+    .expect("write token file");
+    let repo_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::write(
+        project.path().join(".design-data.toml"),
+        format!(
+            "[source]\ntype = \"path\"\nroot = \"{}\"\n\n[validate]\ncustom-rules = [\"house-rules.yaml\"]\n\n[validate.rules]\nSPEC-001 = \"off\"\n",
+            repo_root.display()
+        ),
+    )
+    .expect("write config");
+    let write_rules = |text: &str| {
+        std::fs::write(project.path().join("house-rules.yaml"), text).expect("write rules");
+    };
+
+    let validate = || {
+        let mut cmd = Command::cargo_bin("design-data").expect("binary design-data");
+        cmd.current_dir(project.path()).args([
+            "validate",
+            "tokens",
+            "--components-path",
+            "empty",
+            "--mode-sets-path",
+            "empty",
+            "--relationships-path",
+            "empty",
+        ]);
+        cmd.assert()
+    };
+
+    write_rules(
+        "rules:\n  - id: ACME-001\n    assert: { kind: schema-in, schemas: [color.json, dimension.json] }\n",
+    );
+    validate()
+        .failure()
+        .stderr(contains("error: tokens/alias.json [ACME-001]"))
+        .stderr(contains("is not one of color.json, dimension.json"))
+        .stderr(contains("configures unknown rule").not());
+
+    write_rules(
+        "rules:\n  - id: ACME-001\n    selector: \"compnent=button\"\n    assert: { kind: layer-in, layers: [foundation] }\n",
+    );
+    validate()
+        .failure()
+        .stderr(contains("custom rules configured in `.design-data.toml`"))
+        .stderr(contains("ACME-001: invalid"))
+        .stderr(contains("unknown key \"compnent\""));
+}
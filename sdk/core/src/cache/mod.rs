@@ -115,7 +115,7 @@ pub struct CachedDataset {
 
 /// Bump when the on-disk schema or value encoding changes, to invalidate caches
 /// written by older binaries (in addition to the tokens-version namespace).
-const CACHE_SCHEMA_VERSION: u32 = 3;
+const CACHE_SCHEMA_VERSION: u32 = 4;
 
 const META: TableDefinition<&str, &[u8]> = TableDefinition::new("meta");
 const TOKENS: TableDefinition<&str, &[u8]> = TableDefinition::new("tokens");
@@ -1147,12 +1147,12 @@ mod tests {
             "both fields should survive round-trip"
         );
         assert_eq!(graph.fields[0].name, "alignment");
-        assert_eq!(graph.fields[0].required, false);
+        assert!(!graph.fields[0].required);
         assert_eq!(
             graph.fields[0].description.as_deref(),
             Some("Alignment axis")
         );
         assert_eq!(graph.fields[1].name, "component");
-        assert_eq!(graph.fields[1].required, true);
+        assert!(graph.fields[1].required);
     }
 }
@@ -17,7 +17,9 @@
 //! 4. Tie-break by document order (file path lexicographic, then array index).
 //! 5. Resolve alias chain on the winner.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde_json::Value;
 
 use crate::graph::{ModeSetRecord, TokenGraph, TokenRecord};
 
@@ -130,6 +132,15 @@ pub fn matches_context(
 ///
 /// Returns `None` when no candidate matches the context.
 pub fn resolve<'a>(graph: &'a TokenGraph, context: &ResolutionContext) -> Option<&'a TokenRecord> {
+    select(graph.tokens.values(), &graph.mode_sets, context)
+}
+
+/// The cascade winner among `tokens` in `context` (see [`resolve`]).
+fn select<'a>(
+    tokens: impl IntoIterator<Item = &'a TokenRecord>,
+    mode_sets: &[ModeSetRecord],
+    context: &ResolutionContext,
+) -> Option<&'a TokenRecord> {
     // 0. Collect all candidates, then apply platform mode-set restrictions (spec cascade.md step 0).
     //    Candidates that set a mode set field to a disallowed value are filtered out before
     //    context matching. Candidates that omit the mode set field (wildcard) are not affected —
@@ -153,9 +164,8 @@ pub fn resolve<'a>(graph: &'a TokenGraph, context: &ResolutionContext) -> Option
     };
 
     // 1. Collect candidates with a `name` object matching the context.
-    let mut candidates: Vec<&TokenRecord> = graph
-        .tokens
-        .values()
+    let mut candidates: Vec<&TokenRecord> = tokens
+        .into_iter()
         .filter(restriction_filter)
         .filter(|t| {
             t.raw
@@ -175,13 +185,13 @@ pub fn resolve<'a>(graph: &'a TokenGraph, context: &ResolutionContext) -> Option
             .raw
             .get("name")
             .and_then(|v| v.as_object())
-            .map(|n| specificity(n, &graph.mode_sets))
+            .map(|n| specificity(n, mode_sets))
             .unwrap_or(0);
         let spec_b = b
             .raw
             .get("name")
             .and_then(|v| v.as_object())
-            .map(|n| specificity(n, &graph.mode_sets))
+            .map(|n| specificity(n, mode_sets))
             .unwrap_or(0);
         b.layer
             .cmp(&a.layer) // descending layer: Product > Platform > Foundation
@@ -321,6 +331,421 @@ pub fn apply_restrictions(
     })
 }
 
+// ── Mode matrix ───────────────────────────────────────────────────────────────
+
+/// One mode set as seen by matrix resolution and the generators: its allowed
+/// modes (after manifest restrictions) and the mode treated as the base.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ModeAxis {
+    pub name: String,
+    /// Allowed modes in declaration order.
+    pub modes: Vec<String>,
+    /// The declared default, or the first allowed mode when a manifest
+    /// restriction excludes the default.
+    pub base: String,
+}
+
+/// One point in the mode-set matrix: a mode chosen for every [`ModeAxis`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeCombination {
+    /// `(mode set, mode)` pairs in axis order.
+    pub modes: Vec<(String, String)>,
+}
+
+impl ModeCombination {
+    /// The `(mode set, mode)` pairs whose mode differs from the axis base.
+    pub fn non_base<'a>(&'a self, axes: &[ModeAxis]) -> Vec<(&'a str, &'a str)> {
+        self.modes
+            .iter()
+            .zip(axes)
+            .filter(|((_, mode), axis)| *mode != axis.base)
+            .map(|((set, mode), _)| (set.as_str(), mode.as_str()))
+            .collect()
+    }
+
+    /// Mode set → mode map, as consumed by context-aware alias resolution.
+    pub fn as_map(&self) -> HashMap<String, String> {
+        self.modes.iter().cloned().collect()
+    }
+
+    /// The resolution context that requests this combination's modes.
+    pub fn context(&self) -> ResolutionContext {
+        ResolutionContext {
+            mode_sets: self.as_map(),
+            ..ResolutionContext::default()
+        }
+    }
+}
+
+/// Build the mode axes for `graph`, honouring manifest `restrictions`.
+///
+/// Axes are sorted by mode set name so output is deterministic regardless of
+/// catalog load order. Mode sets whose restriction leaves no allowed mode are
+/// dropped.
+pub fn mode_axes(graph: &TokenGraph, restrictions: &HashMap<String, Vec<String>>) -> Vec<ModeAxis> {
+    let mut axes: Vec<ModeAxis> = graph
+        .mode_sets
+        .iter()
+        .filter_map(|ms| {
+            let modes: Vec<String> = match restrictions.get(&ms.name) {
+                Some(allowed) => ms
+                    .modes
+                    .iter()
+                    .filter(|m| allowed.contains(m))
+                    .cloned()
+                    .collect(),
+                None => ms.modes.clone(),
+            };
+            let base = if modes.contains(&ms.default_mode) {
+                ms.default_mode.clone()
+            } else {
+                modes.first()?.clone()
+            };
+            Some(ModeAxis {
+                name: ms.name.clone(),
+                modes,
+                base,
+            })
+        })
+        .collect();
+    axes.sort_by(|a, b| a.name.cmp(&b.name));
+    axes.dedup_by(|a, b| a.name == b.name);
+    axes
+}
+
+/// Enumerate every combination of `axes`.
+///
+/// The all-base combination is always first; the rest are ordered by the number
+/// of non-base modes, then by axis and mode declaration order.
+pub fn mode_combinations(axes: &[ModeAxis]) -> Vec<ModeCombination> {
+    let mut combos: Vec<Vec<usize>> = vec![Vec::new()];
+    for axis in axes {
+        let mut next = Vec::with_capacity(combos.len() * axis.modes.len());
+        for prefix in &combos {
+            for i in 0..axis.modes.len() {
+                let mut c = prefix.clone();
+                c.push(i);
+                next.push(c);
+            }
+        }
+        combos = next;
+    }
+
+    let non_base = |c: &[usize]| {
+        c.iter()
+            .zip(axes)
+            .filter(|(i, axis)| axis.modes[**i] != axis.base)
+            .count()
+    };
+    // Rank each mode with the base first so the sort key matches the documented order.
+    let rank = |c: &[usize]| -> Vec<usize> {
+        c.iter()
+            .zip(axes)
+            .map(|(i, axis)| {
+                if axis.modes[*i] == axis.base {
+                    0
+                } else {
+                    i + 1
+                }
+            })
+            .collect()
+    };
+    combos.sort_by(|a, b| {
+        non_base(a)
+            .cmp(&non_base(b))
+            .then_with(|| rank(a).cmp(&rank(b)))
+    });
+
+    combos
+        .into_iter()
+        .map(|c| ModeCombination {
+            modes: c
+                .iter()
+                .zip(axes)
+                .map(|(i, axis)| (axis.name.clone(), axis.modes[*i].clone()))
+                .collect(),
+        })
+        .collect()
+}
+
+// ── Variables ─────────────────────────────────────────────────────────────────
+
+/// Variable identity for a token: its legacy key, or the graph key when the
+/// token has no name object. Variants of one variable share it.
+pub(crate) fn variable_key(rec: &TokenRecord) -> String {
+    rec.raw
+        .get("name")
+        .and_then(crate::naming::extract_legacy_key)
+        .unwrap_or_else(|| rec.name.clone())
+}
+
+/// Resolve every variable of `graph` in each of `contexts`.
+///
+/// Tokens are grouped into variables by legacy key and the cascade runs over
+/// each group on its own, so only variants of the same variable compete.
+/// Entry `i` of a variable's list is its winner in `contexts[i]`, or `None`
+/// when none of its tokens match.
+pub fn resolve_variables<'a>(
+    graph: &'a TokenGraph,
+    contexts: &[ResolutionContext],
+) -> HashMap<String, Vec<Option<&'a TokenRecord>>> {
+    let mut groups: HashMap<String, Vec<&TokenRecord>> = HashMap::new();
+    for rec in graph.tokens.values() {
+        groups.entry(variable_key(rec)).or_default().push(rec);
+    }
+    groups
+        .into_iter()
+        .map(|(key, records)| {
+            let winners = contexts
+                .iter()
+                .map(|ctx| select(records.iter().copied(), &graph.mode_sets, ctx))
+                .collect();
+            (key, winners)
+        })
+        .collect()
+}
+
+// ── Matrix resolution ─────────────────────────────────────────────────────────
+
+/// One distinct result of [`resolve_matrix`] and the mode combinations that
+/// produce it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct MatrixOutcome {
+    /// Mode patterns covered, each a mode set → mode map. A mode set absent
+    /// from a pattern applies in every one of its modes; an empty pattern
+    /// covers the whole matrix.
+    pub when: Vec<BTreeMap<String, String>>,
+    /// Number of matrix combinations covered.
+    pub combinations: usize,
+    /// UUID (else graph key) of the winning token; `None` when no token
+    /// matches these combinations.
+    pub token: Option<String>,
+    /// Alias hops from the winner to the terminal token, as `{legacy-key}`
+    /// labels. Empty when the winner carries a literal value.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub chain: Vec<String>,
+    /// Terminal value; `None` when nothing matches or the chain dangles.
+    pub value: Option<serde_json::Value>,
+}
+
+/// A property resolved across every mode-set combination at once.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolutionMatrix {
+    pub property: String,
+    /// The axes enumerated, after restrictions and pinned modes.
+    pub axes: Vec<ModeAxis>,
+    /// Distinct outcomes in matrix order (the all-base combination's first).
+    pub outcomes: Vec<MatrixOutcome>,
+}
+
+/// Resolve `property` in every combination of the graph's mode sets.
+///
+/// Axes come from [`mode_axes`], so manifest `restrictions` prune the matrix
+/// and filter candidates exactly as in [`resolve_property`]. `pinned` fixes a
+/// mode set to one mode; pins naming a mode set the graph does not declare
+/// are still applied to every context. Each combination's winner is followed
+/// through its alias chain with context-aware set resolution, then
+/// combinations with an identical outcome (winner, chain and value) are
+/// collapsed and their mode patterns merged wherever an outcome holds for
+/// every mode of an axis.
+pub fn resolve_matrix(
+    graph: &TokenGraph,
+    property: &str,
+    restrictions: &HashMap<String, Vec<String>>,
+    pinned: &HashMap<String, String>,
+) -> ResolutionMatrix {
+    let mut axes = mode_axes(graph, restrictions);
+    for axis in &mut axes {
+        if let Some(mode) = pinned.get(&axis.name) {
+            axis.modes = vec![mode.clone()];
+            axis.base = mode.clone();
+        }
+    }
+    let extra: Vec<(&String, &String)> = pinned
+        .iter()
+        .filter(|(set, _)| !axes.iter().any(|a| &a.name == *set))
+        .collect();
+
+    let mut outcomes: Vec<(MatrixOutcome, Vec<Vec<Option<String>>>)> = Vec::new();
+    for combo in mode_combinations(&axes) {
+        let mut modes = combo.as_map();
+        modes.extend(extra.iter().map(|(s, m)| ((*s).clone(), (*m).clone())));
+        let ctx = apply_restrictions(
+            ResolutionContext {
+                mode_sets: modes.clone(),
+                ..ResolutionContext::default()
+            },
+            restrictions,
+        );
+        let winner = resolve_property(graph, property, &ctx)
+            .into_iter()
+            .find(|c| c.is_winner)
+            .map(|c| c.record);
+
+        let (token, chain, value) = match &winner {
+            None => (None, Vec::new(), None),
+            Some(rec) => {
+                let hops = alias_hops(graph, rec, &modes);
+                let terminal = hops.last().copied().unwrap_or(rec);
+                (
+                    Some(rec.uuid.clone().unwrap_or_else(|| rec.name.clone())),
+                    hops.iter().map(|h| hop_label(h)).collect(),
+                    terminal.raw.get("value").cloned(),
+                )
+            }
+        };
+        let cell: Vec<Option<String>> = combo.modes.into_iter().map(|(_, m)| Some(m)).collect();
+        match outcomes
+            .iter_mut()
+            .find(|(o, _)| o.token == token && o.chain == chain && o.value == value)
+        {
+            Some((outcome, cells)) => {
+                outcome.combinations += 1;
+                cells.push(cell);
+            }
+            None => outcomes.push((
+                MatrixOutcome {
+                    when: Vec::new(),
+                    combinations: 1,
+                    token,
+                    chain,
+                    value,
+                },
+                vec![cell],
+            )),
+        }
+    }
+
+    let outcomes = outcomes
+        .into_iter()
+        .map(|(mut outcome, cells)| {
+            outcome.when = collapse_when(&axes, cells);
+            outcome
+        })
+        .collect();
+
+    ResolutionMatrix {
+        property: property.to_string(),
+        axes,
+        outcomes,
+    }
+}
+
+/// [`collapse_patterns`] over full matrix cells (one mode per axis), as
+/// mode set → mode maps with wildcarded axes left out.
+pub(crate) fn collapse_when(
+    axes: &[ModeAxis],
+    cells: Vec<Vec<Option<String>>>,
+) -> Vec<BTreeMap<String, String>> {
+    collapse_patterns(axes, cells)
+        .into_iter()
+        .map(|pattern| {
+            pattern
+                .into_iter()
+                .zip(axes)
+                .filter_map(|(mode, axis)| Some((axis.name.clone(), mode?)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Merge mode patterns that differ only on one axis and together cover all of
+/// its modes into a single pattern with that axis wildcarded (`None`), until
+/// no merge applies. First-seen order is preserved.
+fn collapse_patterns(
+    axes: &[ModeAxis],
+    mut patterns: Vec<Vec<Option<String>>>,
+) -> Vec<Vec<Option<String>>> {
+    'merge: loop {
+        for (i, axis) in axes.iter().enumerate() {
+            // Patterns keyed by everything but axis `i`.
+            let mut groups: Vec<(Vec<Option<String>>, Vec<usize>)> = Vec::new();
+            for (idx, pattern) in patterns.iter().enumerate() {
+                if pattern[i].is_none() {
+                    continue;
+                }
+                let mut key = pattern.clone();
+                key[i] = None;
+                match groups.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, members)) => members.push(idx),
+                    None => groups.push((key, vec![idx])),
+                }
+            }
+            for (key, members) in groups {
+                let covered: HashSet<&str> = members
+                    .iter()
+                    .filter_map(|m| patterns[*m][i].as_deref())
+                    .collect();
+                if !axis.modes.iter().all(|m| covered.contains(m.as_str())) {
+                    continue;
+                }
+                let mut next = Vec::with_capacity(patterns.len());
+                for (idx, pattern) in patterns.into_iter().enumerate() {
+                    if idx == members[0] {
+                        next.push(key.clone());
+                    } else if !members.contains(&idx) {
+                        next.push(pattern);
+                    }
+                }
+                patterns = next;
+                continue 'merge;
+            }
+        }
+        return patterns;
+    }
+}
+
+/// Records reached by following `start`'s alias chain in `ctx`, in order,
+/// stopping at a dangling target or a cycle. Empty for a literal token.
+pub(crate) fn alias_hops<'a>(
+    graph: &'a TokenGraph,
+    start: &TokenRecord,
+    ctx: &HashMap<String, String>,
+) -> Vec<&'a TokenRecord> {
+    let mut hops = Vec::new();
+    let mut seen = HashSet::from([start.name.clone()]);
+    let mut target = start.alias_target.as_deref();
+    while let Some(t) = target {
+        let Some(next) = graph.resolve_alias_in_context(t, ctx) else {
+            break;
+        };
+        if !seen.insert(next.name.clone()) {
+            break;
+        }
+        hops.push(next);
+        target = next.alias_target.as_deref();
+    }
+    hops
+}
+
+/// Follow the alias chain from `rec` in context `ctx` to the terminal token.
+pub(crate) fn terminal_record<'a>(
+    graph: &'a TokenGraph,
+    rec: &'a TokenRecord,
+    ctx: &HashMap<String, String>,
+) -> Option<&'a TokenRecord> {
+    let mut current = rec;
+    let mut seen = HashSet::new();
+    seen.insert(current.name.clone());
+    while let Some(alias) = current.alias_target.as_deref() {
+        let next = graph.resolve_alias_in_context(alias, ctx)?;
+        if !seen.insert(next.name.clone()) {
+            return None;
+        }
+        current = next;
+    }
+    Some(current)
+}
+
+/// `{legacy-key}` for an alias hop when derivable, else `(uuid:…)`.
+fn hop_label(rec: &TokenRecord) -> String {
+    rec.raw
+        .get("name")
+        .and_then(crate::naming::extract_legacy_key)
+        .map(|k| format!("{{{k}}}"))
+        .unwrap_or_else(|| format!("(uuid:{})", rec.uuid.as_deref().unwrap_or("?")))
+}
+
 // ── Legacy-slug reference resolution ─────────────────────────────────────────
 
 /// The resolved value and alias chain from [`resolve_reference`].
@@ -402,28 +827,10 @@ pub fn resolve_reference(
         .expect("candidates is non-empty"); // safe
 
     // Build the chain by walking alias edges, using context-aware set resolution.
+    let hops = alias_hops(graph, best, ctx);
     let mut chain: Vec<String> = vec![format!("{{{name}}}")];
-    let mut current = best;
-    let mut seen = std::collections::HashSet::new();
-    seen.insert(current.name.clone());
-
-    while let Some(alias_target) = current.alias_target.as_deref() {
-        let Some(next) = graph.resolve_alias_in_context(alias_target, ctx) else {
-            break; // dangling ref — degrade gracefully
-        };
-        if !seen.insert(next.name.clone()) {
-            break; // cycle guard
-        }
-        // Represent the hop as `{legacy-key}` when derivable, else as UUID.
-        let hop_label = next
-            .raw
-            .get("name")
-            .and_then(extract_legacy_key)
-            .map(|k| format!("{{{k}}}"))
-            .unwrap_or_else(|| format!("(uuid:{})", next.uuid.as_deref().unwrap_or("?")));
-        chain.push(hop_label);
-        current = next;
-    }
+    chain.extend(hops.iter().map(|h| hop_label(h)));
+    let current = hops.last().copied().unwrap_or(best);
 
     // Append the terminal value as the last chain entry.
     let value = current.raw.get("value").cloned();
@@ -437,6 +844,37 @@ pub fn resolve_reference(
     Some(ReferenceChainResult { value, chain })
 }
 
+// ── Resolved values ───────────────────────────────────────────────────────────
+
+/// Split a dimension literal into its number and unit (`"14px"` → `(14.0, "px")`).
+pub fn parse_dimension(s: &str) -> Option<(f64, &str)> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .unwrap_or(s.len());
+    Some((s[..split].parse().ok()?, &s[split..]))
+}
+
+/// Parse a duration literal (`"150ms"`, `"0.2s"`) into milliseconds.
+pub fn parse_duration(s: &str) -> Option<f64> {
+    let (n, unit) = parse_dimension(s)?;
+    match unit {
+        "ms" => Some(n),
+        "s" => Some(n * 1000.0),
+        _ => None,
+    }
+}
+
+/// A literal as a number, accepting JSON numbers and numeric strings (opacity
+/// values are stored as strings).
+pub fn numeric_value(v: &Value) -> Option<f64> {
+    match v {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -702,6 +1140,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "bg"}, "uuid": "uuid-bg", "value": "#foundation"}),
             layer: Layer::Foundation,
+            span: None,
         };
         // Product override: same name-object, overrides value.
         let product = TokenRecord {
@@ -713,6 +1152,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "bg"}, "uuid": "uuid-bg", "value": "#product"}),
             layer: Layer::Product,
+            span: None,
         };
         let g = TokenGraph::from_records(vec![foundation, product]);
         let ctx = ResolutionContext::new();
@@ -789,6 +1229,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "bg"}, "value": "#foundation"}),
             layer: Layer::Foundation,
+            span: None,
         };
         let platform = TokenRecord {
             name: "platform-bg".into(),
@@ -799,6 +1240,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "bg"}, "value": "#platform"}),
             layer: Layer::Platform,
+            span: None,
         };
         let g = TokenGraph::from_records(vec![foundation, platform]);
         let ctx = ResolutionContext::new();
@@ -856,6 +1298,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "bg", "colorScheme": "dark"}, "value": "#first"}),
             layer: Layer::Foundation,
+            span: None,
         };
         let second = TokenRecord {
             name: "t-second".into(),
@@ -866,6 +1309,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "bg", "colorScheme": "dark"}, "value": "#second"}),
             layer: Layer::Foundation,
+            span: None,
         };
         let g = TokenGraph::from_records(vec![first, second])
             .with_mode_sets(vec![color_scheme_mode_set()]);
@@ -909,4 +1353,137 @@ mod tests {
             "double-specific token (specificity 2) should beat single-specific (specificity 1)"
         );
     }
+
+    // ── resolve_matrix ───────────────────────────────────────────────────────
+
+    /// `bg` aliases the light or dark `gray-100`, with a mobile-only literal
+    /// override in wireframe.
+    fn matrix_graph() -> TokenGraph {
+        TokenGraph::from_pairs(vec![
+            (
+                "gray-light".into(),
+                PathBuf::from("a.tokens.json"),
+                json!({"name": {"property": "gray-100", "colorScheme": "light"}, "value": "#eee", "uuid": "g-light"}),
+            ),
+            (
+                "gray-dark".into(),
+                PathBuf::from("a.tokens.json"),
+                json!({"name": {"property": "gray-100", "colorScheme": "dark"}, "value": "#222", "uuid": "g-dark"}),
+            ),
+            (
+                "bg".into(),
+                PathBuf::from("b.tokens.json"),
+                json!({"name": {"property": "bg"}, "$ref": "g-light", "uuid": "bg"}),
+            ),
+            (
+                "bg-dark".into(),
+                PathBuf::from("b.tokens.json"),
+                json!({"name": {"property": "bg", "colorScheme": "dark"}, "$ref": "g-dark", "uuid": "bg-dark"}),
+            ),
+            (
+                "bg-wire".into(),
+                PathBuf::from("b.tokens.json"),
+                json!({"name": {"property": "bg", "colorScheme": "wireframe", "scale": "mobile"}, "value": "#fff", "uuid": "bg-wire"}),
+            ),
+        ])
+        .with_mode_sets(vec![color_scheme_mode_set(), scale_mode_set()])
+    }
+
+    #[test]
+    fn matrix_collapses_identical_outcomes() {
+        let m = resolve_matrix(&matrix_graph(), "bg", &HashMap::new(), &HashMap::new());
+        assert_eq!(m.axes.len(), 2);
+        let summary: Vec<_> = m
+            .outcomes
+            .iter()
+            .map(|o| (o.when.clone(), o.combinations, o.value.clone()))
+            .collect();
+        let pattern = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            summary,
+            vec![
+                // Wireframe desktop falls back to the mode-agnostic base alias.
+                (
+                    vec![
+                        pattern(&[("colorScheme", "light")]),
+                        pattern(&[("colorScheme", "wireframe"), ("scale", "desktop")]),
+                    ],
+                    3,
+                    Some(json!("#eee"))
+                ),
+                (
+                    vec![pattern(&[("colorScheme", "dark")])],
+                    2,
+                    Some(json!("#222"))
+                ),
+                (
+                    vec![pattern(&[
+                        ("colorScheme", "wireframe"),
+                        ("scale", "mobile")
+                    ])],
+                    1,
+                    Some(json!("#fff"))
+                ),
+            ]
+        );
+        assert_eq!(m.outcomes[0].chain, vec!["{gray-100}"]);
+        assert_eq!(m.outcomes[0].token.as_deref(), Some("bg"));
+        assert!(m.outcomes[2].chain.is_empty());
+    }
+
+    #[test]
+    fn matrix_honors_pins_and_restrictions() {
+        let restrictions = HashMap::from([("scale".to_string(), vec!["mobile".to_string()])]);
+        let pinned = HashMap::from([("colorScheme".to_string(), "wireframe".to_string())]);
+        let m = resolve_matrix(&matrix_graph(), "bg", &restrictions, &pinned);
+        assert_eq!(m.outcomes.len(), 1);
+        assert_eq!(m.outcomes[0].combinations, 1);
+        assert_eq!(m.outcomes[0].when, vec![BTreeMap::new()]);
+        assert_eq!(m.outcomes[0].value, Some(json!("#fff")));
+
+        let none = resolve_matrix(&matrix_graph(), "missing", &HashMap::new(), &HashMap::new());
+        assert_eq!(none.outcomes.len(), 1);
+        assert_eq!(none.outcomes[0].combinations, 6);
+        assert_eq!(none.outcomes[0].token, None);
+    }
+
+    #[test]
+    fn variables_resolve_per_context_without_competing() {
+        let graph = matrix_graph();
+        let contexts = [
+            ResolutionContext::new()
+                .with("colorScheme", "light")
+                .with("scale", "desktop"),
+            ResolutionContext::new()
+                .with("colorScheme", "wireframe")
+                .with("scale", "mobile"),
+        ];
+        let variables = resolve_variables(&graph, &contexts);
+        let winners = |key: &str| -> Vec<Option<&str>> {
+            variables[key]
+                .iter()
+                .map(|w| w.map(|r| r.name.as_str()))
+                .collect()
+        };
+        assert_eq!(variables.len(), 2);
+        assert_eq!(winners("bg"), vec![Some("bg"), Some("bg-wire")]);
+        assert_eq!(winners("gray-100"), vec![Some("gray-light"), None]);
+    }
+
+    // ── resolved values ──────────────────────────────────────────────────────
+
+    #[test]
+    fn parses_dimensions_durations_and_numbers() {
+        assert_eq!(parse_dimension("14px"), Some((14.0, "px")));
+        assert_eq!(parse_dimension("-0.5em"), Some((-0.5, "em")));
+        assert_eq!(parse_duration("150ms"), Some(150.0));
+        assert_eq!(parse_duration("0.2s"), Some(200.0));
+        assert_eq!(parse_duration("150px"), None);
+        assert_eq!(numeric_value(&json!("0.1")), Some(0.1));
+    }
 }
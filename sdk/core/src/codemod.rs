@@ -0,0 +1,709 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Consumer codemod: rewrite references to deprecated tokens in product source.
+//!
+//! [`RenameMap::from_graph`] collects every token carrying a replacement —
+//! `lifecycle.replacedBy` (UUIDs, resolved through the graph) or a legacy
+//! `renamed` — keyed by legacy name. [`migrate_source`] then rewrites each
+//! reference in a CSS/SCSS/JS/TS/Swift/Kotlin file:
+//!
+//! - the legacy name itself (`accent-background-color-default`),
+//! - the custom-property form (`--spectrum-accent-background-color-default`),
+//! - in Swift and Kotlin, the camelCase identifier the native generators emit
+//!   (`accentBackgroundColorDefault`).
+//!
+//! A `replacedBy` array naming more than one distinct token (see SPEC-011) has
+//! no single replacement; references to it are reported, never rewritten.
+//! `replacedBy` UUIDs that name no token are collected in
+//! [`RenameMap::unresolved`] and still count towards that judgement, so a
+//! two-entry array with one dangling UUID is not mistaken for a plain rename.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use walkdir::WalkDir;
+
+use crate::cascade::variable_key;
+use crate::graph::TokenGraph;
+use crate::naming::camel_identifier;
+use crate::CoreError;
+
+/// Prefix of the CSS custom property emitted for every token.
+const CUSTOM_PROPERTY_PREFIX: &str = "--spectrum-";
+
+/// Upper bound on `replacedBy` hops followed from one deprecated token, so a
+/// cyclic chain still terminates.
+const MAX_CHAIN: usize = 32;
+
+// ── Rename map ───────────────────────────────────────────────────────────────
+
+/// What a deprecated token name should become.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Replacement {
+    /// Exactly one replacement token, by legacy name.
+    Token(String),
+    /// `replacedBy` names several distinct tokens; a human has to choose.
+    /// Lists the ones that resolve (see [`RenameMap::unresolved`]).
+    Ambiguous(Vec<String>),
+}
+
+/// Deprecated legacy token names and their replacements.
+#[derive(Debug, Clone, Default)]
+pub struct RenameMap {
+    renames: BTreeMap<String, Replacement>,
+    /// camelCase identifier → legacy name, for native sources. Single-word
+    /// names are left out: their identifier is an ordinary word.
+    camel: HashMap<String, String>,
+    /// Deprecated legacy name → `replacedBy` UUIDs that resolve to no token.
+    unresolved: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl RenameMap {
+    /// Collect the replacement of every token that declares one.
+    ///
+    /// Per-mode records of one token are merged. Chains (`a` → `b` where `b` is
+    /// itself replaced by `c`) are followed to their end, so consumers migrate
+    /// in one pass. A token with unresolved `replacedBy` UUIDs is ambiguous
+    /// unless it names exactly one replacement and no unresolved UUID.
+    pub fn from_graph(graph: &TokenGraph) -> Self {
+        let mut targets: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut unresolved: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for rec in graph.tokens.values() {
+            let (names, missing) = replacement_names(graph, &rec.raw);
+            if names.is_empty() && missing.is_empty() {
+                continue;
+            }
+            let source = variable_key(rec);
+            let entry = targets.entry(source.clone()).or_default();
+            entry.extend(names.into_iter().filter(|n| *n != source));
+            if !missing.is_empty() {
+                unresolved.entry(source).or_default().extend(missing);
+            }
+        }
+        targets.retain(|_, t| !t.is_empty());
+
+        let renames: BTreeMap<String, Replacement> = targets
+            .keys()
+            .map(|source| {
+                let mut finals: BTreeSet<String> = BTreeSet::new();
+                for target in &targets[source] {
+                    finals.insert(follow_chain(&targets, source, target));
+                }
+                let dangling = unresolved.get(source).map_or(0, BTreeSet::len);
+                let replacement = if finals.len() + dangling == 1 {
+                    Replacement::Token(finals.into_iter().next().expect("one element"))
+                } else {
+                    Replacement::Ambiguous(finals.into_iter().collect())
+                };
+                (source.clone(), replacement)
+            })
+            .collect();
+        Self {
+            unresolved,
+            ..Self::from_renames(renames)
+        }
+    }
+
+    /// Build a map from explicit `deprecated name → replacement` pairs.
+    pub fn from_renames(renames: BTreeMap<String, Replacement>) -> Self {
+        let camel = renames
+            .keys()
+            .filter_map(|name| {
+                let ident = camel_identifier(name);
+                ident
+                    .contains(|c: char| c.is_ascii_uppercase())
+                    .then(|| (ident, name.clone()))
+            })
+            .collect();
+        RenameMap {
+            renames,
+            camel,
+            unresolved: BTreeMap::new(),
+        }
+    }
+
+    /// The replacement for a deprecated legacy name.
+    pub fn get(&self, name: &str) -> Option<&Replacement> {
+        self.renames.get(name)
+    }
+
+    /// All `(deprecated name, replacement)` pairs, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Replacement)> {
+        self.renames.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Deprecated legacy names whose `replacedBy` lists UUIDs that resolve to
+    /// no token, with those UUIDs, sorted by name.
+    pub fn unresolved(&self) -> impl Iterator<Item = (&str, &BTreeSet<String>)> {
+        self.unresolved.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.renames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.renames.is_empty()
+    }
+}
+
+/// Legacy names of the tokens a raw token says replace it, and the
+/// `replacedBy` UUIDs that resolve to no token.
+fn replacement_names(graph: &TokenGraph, raw: &Value) -> (Vec<String>, Vec<String>) {
+    let uuids: Vec<&Value> = match raw.pointer("/lifecycle/replacedBy") {
+        Some(Value::Array(uuids)) => uuids.iter().collect(),
+        Some(uuid) => vec![uuid],
+        None => {
+            let renamed = raw.get("renamed").and_then(Value::as_str);
+            return (
+                renamed.map(str::to_string).into_iter().collect(),
+                Vec::new(),
+            );
+        }
+    };
+    let mut names = Vec::new();
+    let mut missing = Vec::new();
+    for uuid in uuids {
+        match graph.resolve_alias_key(uuid.as_str().unwrap_or_default()) {
+            Some(rec) => names.push(variable_key(rec)),
+            None => missing.push(match uuid {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            }),
+        }
+    }
+    (names, missing)
+}
+
+/// Follow single-target replacements from `target` to the last token in the chain.
+fn follow_chain(
+    targets: &BTreeMap<String, BTreeSet<String>>,
+    source: &str,
+    target: &str,
+) -> String {
+    let mut current = target;
+    for _ in 0..MAX_CHAIN {
+        match targets.get(current) {
+            Some(next) if next.len() == 1 => {
+                let next = next.iter().next().expect("one element");
+                if next == source {
+                    break;
+                }
+                current = next;
+            }
+            _ => break,
+        }
+    }
+    current.to_string()
+}
+
+// ── Source rewriting ─────────────────────────────────────────────────────────
+
+/// Source languages the codemod understands, by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceLanguage {
+    /// `.css`, `.scss`, `.sass`, `.less`
+    Css,
+    /// `.js`, `.jsx`, `.mjs`, `.cjs`, `.ts`, `.tsx`, `.mts`, `.cts`
+    Script,
+    /// `.swift`
+    Swift,
+    /// `.kt`, `.kts`
+    Kotlin,
+}
+
+impl SourceLanguage {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "css" | "scss" | "sass" | "less" => Some(Self::Css),
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => Some(Self::Script),
+            "swift" => Some(Self::Swift),
+            "kt" | "kts" => Some(Self::Kotlin),
+            _ => None,
+        }
+    }
+
+    /// Whether the native generators' camelCase identifiers appear in this language.
+    fn uses_identifiers(self) -> bool {
+        matches!(self, Self::Swift | Self::Kotlin)
+    }
+}
+
+/// One rewritten reference. `line` and `column` are 1-based, in the original text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub line: usize,
+    pub column: usize,
+    pub from: String,
+    pub to: String,
+}
+
+/// A reference to a token whose `replacedBy` names several tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbiguousReference {
+    pub line: usize,
+    pub column: usize,
+    /// The reference as written.
+    pub text: String,
+    /// Legacy names of the candidate replacements.
+    pub candidates: Vec<String>,
+}
+
+/// The result of migrating one source text.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMigration {
+    pub migrated: String,
+    pub edits: Vec<Edit>,
+    pub ambiguous: Vec<AmbiguousReference>,
+}
+
+/// Rewrite every reference to a deprecated token in `text`.
+///
+/// References are matched as whole words over `[A-Za-z0-9_-]`, so
+/// `blue-100` never matches inside `blue-1000`. Bare legacy names must contain
+/// a `-`; single-word names are only matched in `--spectrum-` form.
+pub fn migrate_source(
+    text: &str,
+    language: SourceLanguage,
+    renames: &RenameMap,
+) -> SourceMigration {
+    let mut out = SourceMigration {
+        migrated: String::with_capacity(text.len()),
+        ..Default::default()
+    };
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    let mut copied = 0;
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c == '\n' {
+            line += 1;
+            line_start = start + 1;
+            continue;
+        }
+        if !is_word(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, next)) = chars.peek() {
+            if !is_word(next) {
+                break;
+            }
+            end = i + next.len_utf8();
+            chars.next();
+        }
+        let word = &text[start..end];
+        let Some((name, form)) = lookup(word, language, renames) else {
+            continue;
+        };
+        let column = text[line_start..start].chars().count() + 1;
+        match renames.get(name) {
+            Some(Replacement::Token(target)) => {
+                let to = form.spell(target);
+                out.migrated.push_str(&text[copied..start]);
+                out.migrated.push_str(&to);
+                copied = end;
+                out.edits.push(Edit {
+                    line,
+                    column,
+                    from: word.to_string(),
+                    to,
+                });
+            }
+            Some(Replacement::Ambiguous(candidates)) => {
+                out.ambiguous.push(AmbiguousReference {
+                    line,
+                    column,
+                    text: word.to_string(),
+                    candidates: candidates.clone(),
+                });
+            }
+            None => {}
+        }
+    }
+    out.migrated.push_str(&text[copied..]);
+    out
+}
+
+/// How a reference spells a token name.
+#[derive(Debug, Clone, Copy)]
+enum Form {
+    /// The legacy name as-is.
+    Name,
+    /// `--spectrum-<name>`.
+    CustomProperty,
+    /// The native generators' camelCase identifier.
+    Identifier,
+}
+
+impl Form {
+    /// Spell `name` in this form.
+    fn spell(self, name: &str) -> String {
+        match self {
+            Form::Name => name.to_string(),
+            Form::CustomProperty => format!("{CUSTOM_PROPERTY_PREFIX}{name}"),
+            Form::Identifier => camel_identifier(name),
+        }
+    }
+}
+
+/// Match a word against the deprecated names, returning the legacy name it
+/// refers to and the form it is written in.
+fn lookup<'r>(
+    word: &str,
+    language: SourceLanguage,
+    renames: &'r RenameMap,
+) -> Option<(&'r str, Form)> {
+    if let Some(rest) = word.strip_prefix(CUSTOM_PROPERTY_PREFIX) {
+        let (name, _) = renames.renames.get_key_value(rest)?;
+        return Some((name, Form::CustomProperty));
+    }
+    if word.contains('-') {
+        let (name, _) = renames.renames.get_key_value(word)?;
+        return Some((name, Form::Name));
+    }
+    if language.uses_identifiers() {
+        let name = renames.camel.get(word)?;
+        return Some((name, Form::Identifier));
+    }
+    None
+}
+
+// ── Directory walk ───────────────────────────────────────────────────────────
+
+/// The migration of one file that references at least one deprecated token.
+#[derive(Debug, Clone)]
+pub struct FileMigration {
+    pub path: PathBuf,
+    pub original: String,
+    pub result: SourceMigration,
+}
+
+impl FileMigration {
+    /// Whether any reference was rewritten.
+    pub fn changed(&self) -> bool {
+        !self.result.edits.is_empty()
+    }
+
+    /// Write the migrated text back to [`Self::path`].
+    pub fn write(&self) -> Result<(), CoreError> {
+        std::fs::write(&self.path, &self.result.migrated)?;
+        Ok(())
+    }
+}
+
+/// Migrate every supported source file under `root` (or `root` itself, when it
+/// is a file), without writing anything.
+///
+/// Hidden directories and `node_modules` are skipped, as are files that are
+/// not UTF-8. Only files with at least one rewritten or ambiguous reference are
+/// returned, sorted by path.
+pub fn migrate_dir(root: &Path, renames: &RenameMap) -> Result<Vec<FileMigration>, CoreError> {
+    if !root.exists() {
+        return Err(CoreError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("path does not exist: {}", root.display()),
+        )));
+    }
+    let mut out = Vec::new();
+    let walker = WalkDir::new(root)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !e.file_type().is_dir()
+                || !(e.file_name().to_string_lossy().starts_with('.')
+                    || e.file_name() == "node_modules")
+        });
+    for entry in walker.filter_map(Result::ok) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Some(language) = SourceLanguage::from_path(entry.path()) else {
+            continue;
+        };
+        let Ok(original) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        let result = migrate_source(&original, language, renames);
+        if result.edits.is_empty() && result.ambiguous.is_empty() {
+            continue;
+        }
+        out.push(FileMigration {
+            path: entry.path().to_path_buf(),
+            original,
+            result,
+        });
+    }
+    Ok(out)
+}
+
+// ── Tests ────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn graph(tokens: Vec<(&str, Value)>) -> TokenGraph {
+        TokenGraph::from_pairs(
+            tokens
+                .into_iter()
+                .map(|(name, raw)| (name.to_string(), PathBuf::from("test.json"), raw))
+                .collect(),
+        )
+    }
+
+    fn renames(pairs: &[(&str, &str)]) -> RenameMap {
+        RenameMap::from_renames(
+            pairs
+                .iter()
+                .map(|(from, to)| (from.to_string(), Replacement::Token(to.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn rename_map_resolves_replaced_by_uuids_to_legacy_names() {
+        let g = graph(vec![
+            (
+                "old-desktop",
+                json!({
+                    "name": {"property": "component-bottom-to-text-100", "scale": "desktop"},
+                    "value": "9px",
+                    "lifecycle": {"deprecatedIn": "14.3.0", "replacedBy": "u-new"}
+                }),
+            ),
+            (
+                "old-mobile",
+                json!({
+                    "name": {"property": "component-bottom-to-text-100", "scale": "mobile"},
+                    "value": "11px",
+                    "lifecycle": {"deprecatedIn": "14.3.0", "replacedBy": "u-new"}
+                }),
+            ),
+            (
+                "new",
+                json!({"name": {"property": "base-padding-vertical-medium"}, "uuid": "u-new", "value": "9px"}),
+            ),
+        ]);
+        let map = RenameMap::from_graph(&g);
+        assert_eq!(map.len(), 1);
+        assert_eq!(
+            map.get("component-bottom-to-text-100"),
+            Some(&Replacement::Token("base-padding-vertical-medium".into()))
+        );
+    }
+
+    #[test]
+    fn rename_map_reports_multi_target_arrays_as_ambiguous() {
+        let g = graph(vec![
+            (
+                "shadow",
+                json!({
+                    "name": {"property": "drop-shadow-color"},
+                    "value": "#000",
+                    "lifecycle": {
+                        "deprecatedIn": "13.0.0",
+                        "deprecatedComment": "Pick an elevation.",
+                        "replacedBy": ["u-100", "u-200", "u-100-dark"]
+                    }
+                }),
+            ),
+            (
+                "s100",
+                json!({"name": {"property": "drop-shadow-color-100", "colorScheme": "light"}, "uuid": "u-100", "value": "#000"}),
+            ),
+            (
+                "s100-dark",
+                json!({"name": {"property": "drop-shadow-color-100", "colorScheme": "dark"}, "uuid": "u-100-dark", "value": "#000"}),
+            ),
+            (
+                "s200",
+                json!({"name": {"property": "drop-shadow-color-200"}, "uuid": "u-200", "value": "#000"}),
+            ),
+        ]);
+        let map = RenameMap::from_graph(&g);
+        assert_eq!(
+            map.get("drop-shadow-color"),
+            Some(&Replacement::Ambiguous(vec![
+                "drop-shadow-color-100".into(),
+                "drop-shadow-color-200".into()
+            ]))
+        );
+    }
+
+    #[test]
+    fn rename_map_reports_unresolved_uuids_and_keeps_them_ambiguous() {
+        let g = graph(vec![
+            (
+                "partial",
+                json!({
+                    "name": {"property": "old-gap"},
+                    "value": "4px",
+                    "lifecycle": {"deprecatedIn": "2.0.0", "replacedBy": ["u-new", "u-missing"]}
+                }),
+            ),
+            (
+                "dangling",
+                json!({
+                    "name": {"property": "old-margin"},
+                    "value": "4px",
+                    "lifecycle": {"deprecatedIn": "2.0.0", "replacedBy": "u-gone"}
+                }),
+            ),
+            (
+                "new",
+                json!({"name": {"property": "new-gap"}, "uuid": "u-new", "value": "4px"}),
+            ),
+        ]);
+        let map = RenameMap::from_graph(&g);
+        assert_eq!(
+            map.get("old-gap"),
+            Some(&Replacement::Ambiguous(vec!["new-gap".into()]))
+        );
+        assert_eq!(map.get("old-margin"), None);
+        let unresolved: Vec<(&str, Vec<&str>)> = map
+            .unresolved()
+            .map(|(name, uuids)| (name, uuids.iter().map(String::as_str).collect()))
+            .collect();
+        assert_eq!(
+            unresolved,
+            vec![
+                ("old-gap", vec!["u-missing"]),
+                ("old-margin", vec!["u-gone"])
+            ]
+        );
+    }
+
+    #[test]
+    fn rename_map_follows_chains_and_legacy_renamed() {
+        let g = graph(vec![
+            (
+                "a-old",
+                json!({"value": "1px", "deprecated": true, "renamed": "b-mid"}),
+            ),
+            (
+                "b-mid",
+                json!({"value": "1px", "deprecated": true, "renamed": "c-new"}),
+            ),
+            ("c-new", json!({"value": "1px"})),
+            ("loop-a", json!({"value": "1px", "renamed": "loop-b"})),
+            ("loop-b", json!({"value": "1px", "renamed": "loop-a"})),
+        ]);
+        let map = RenameMap::from_graph(&g);
+        assert_eq!(map.get("a-old"), Some(&Replacement::Token("c-new".into())));
+        assert_eq!(map.get("b-mid"), Some(&Replacement::Token("c-new".into())));
+        assert_eq!(
+            map.get("loop-a"),
+            Some(&Replacement::Token("loop-b".into()))
+        );
+    }
+
+    #[test]
+    fn migrate_source_rewrites_names_and_custom_properties() {
+        let map = renames(&[("blue-100", "accent-color-100")]);
+        let css =
+            ".a {\n  color: var(--spectrum-blue-100);\n  --x: var(--spectrum-blue-1000);\n}\n";
+        let result = migrate_source(css, SourceLanguage::Css, &map);
+        assert_eq!(
+            result.migrated,
+            ".a {\n  color: var(--spectrum-accent-color-100);\n  --x: var(--spectrum-blue-1000);\n}\n"
+        );
+        assert_eq!(
+            result.edits,
+            vec![Edit {
+                line: 2,
+                column: 14,
+                from: "--spectrum-blue-100".into(),
+                to: "--spectrum-accent-color-100".into(),
+            }]
+        );
+
+        let js = "const c = tokens['blue-100'] ?? tokens[\"my-blue-100\"];";
+        let result = migrate_source(js, SourceLanguage::Script, &map);
+        assert_eq!(
+            result.migrated,
+            "const c = tokens['accent-color-100'] ?? tokens[\"my-blue-100\"];"
+        );
+    }
+
+    #[test]
+    fn migrate_source_rewrites_native_identifiers() {
+        let map = renames(&[
+            ("component-height-100", "base-height-100"),
+            ("white", "gray-25"),
+        ]);
+        let swift = "let h = Tokens.componentHeight100 + Tokens.componentHeight1000\nlet w = white";
+        let result = migrate_source(swift, SourceLanguage::Swift, &map);
+        assert_eq!(
+            result.migrated,
+            "let h = Tokens.baseHeight100 + Tokens.componentHeight1000\nlet w = white"
+        );
+        // camelCase identifiers are only meaningful in native sources.
+        let js = "tokens.componentHeight100";
+        assert!(migrate_source(js, SourceLanguage::Script, &map)
+            .edits
+            .is_empty());
+    }
+
+    #[test]
+    fn migrate_source_reports_ambiguous_references_without_rewriting() {
+        let map = RenameMap::from_renames(BTreeMap::from([(
+            "drop-shadow-color".to_string(),
+            Replacement::Ambiguous(vec![
+                "drop-shadow-color-100".into(),
+                "drop-shadow-color-200".into(),
+            ]),
+        )]));
+        let css = "a { box-shadow: 0 0 2px var(--spectrum-drop-shadow-color); }";
+        let result = migrate_source(css, SourceLanguage::Css, &map);
+        assert_eq!(result.migrated, css);
+        assert!(result.edits.is_empty());
+        assert_eq!(result.ambiguous.len(), 1);
+        assert_eq!(result.ambiguous[0].column, 29);
+        assert_eq!(result.ambiguous[0].candidates.len(), 2);
+    }
+
+    #[test]
+    fn migrate_dir_skips_unsupported_and_vendored_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(
+            root.join("src/app.css"),
+            "a { color: var(--spectrum-blue-100); }",
+        )
+        .unwrap();
+        fs::write(root.join("src/notes.md"), "blue-100").unwrap();
+        fs::write(root.join("src/clean.ts"), "export {};").unwrap();
+        fs::write(root.join("node_modules/pkg/index.js"), "'blue-100'").unwrap();
+
+        let map = renames(&[("blue-100", "accent-color-100")]);
+        let files = migrate_dir(root, &map).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, root.join("src/app.css"));
+        assert!(files[0].changed());
+
+        files[0].write().unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("src/app.css")).unwrap(),
+            "a { color: var(--spectrum-accent-color-100); }"
+        );
+    }
+}
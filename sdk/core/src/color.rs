@@ -0,0 +1,789 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Color value model — parse CSS Color 4 literals, normalise them to one
+//! canonical representation, and compare them perceptually.
+//!
+//! Every supported form parses into a [`Color`]: gamma-encoded sRGB channels
+//! plus alpha. Channels are *not* clamped, so wide-gamut inputs (`oklch()`,
+//! `color(display-p3 …)`) keep their out-of-gamut components and survive a
+//! round trip; callers that need displayable sRGB use [`Color::clamped`].
+//!
+//! | Form                          | Example                                  |
+//! | ----------------------------- | ---------------------------------------- |
+//! | hex                           | `#fff`, `#ffff`, `#1d1d1d`, `#1d1d1d80`  |
+//! | `rgb()` / `rgba()`            | `rgb(0, 0, 0)`, `rgb(0 0 0 / 50%)`       |
+//! | `hsl()` / `hsla()`            | `hsl(210, 50%, 40%)`, `hsl(210deg 50% 40%)` |
+//! | `oklab()` / `oklch()`         | `oklch(62.8% 0.2577 29.23)`              |
+//! | `color()`                     | `color(display-p3 1 0 0)`, `color(srgb-linear 0.5 0.5 0.5)` |
+//!
+//! Perceptual comparison is available as CIEDE2000 ([`Color::delta_e`]) and
+//! Euclidean OKLab distance ([`Color::oklab_distance`]). Both ignore alpha;
+//! [`Color::is_visually_identical`] also requires matching opacity. Text
+//! contrast is measured as a WCAG 2.x ratio ([`Color::wcag_contrast`]) or an
+//! APCA lightness contrast ([`Color::apca_contrast`]).
+
+use std::fmt;
+
+/// CIEDE2000 difference below which two colors are treated as visually
+/// identical (the conventional just-noticeable difference).
+pub const JND_DELTA_E: f64 = 1.0;
+
+/// Alpha difference below which two opacities are treated as identical (one
+/// 8-bit step).
+const ALPHA_EPSILON: f64 = 1.0 / 255.0;
+
+/// Channel tolerance for the sRGB gamut check, absorbing round-trip error
+/// from wide-gamut conversions.
+const GAMUT_EPSILON: f64 = 1e-4;
+
+/// A color in gamma-encoded sRGB with straight alpha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl Color {
+    /// An opaque color from gamma-encoded sRGB channels in `0..=1`.
+    pub fn srgb(r: f64, g: f64, b: f64) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            alpha: 1.0,
+        }
+    }
+
+    /// Parse any supported CSS color literal. Named colors, `currentColor`
+    /// and relative color syntax are not dataset values and yield `None`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        let open = s.find('(')?;
+        let func = s[..open].trim().to_ascii_lowercase();
+        let args = s[open + 1..].strip_suffix(')')?;
+        match func.as_str() {
+            "rgb" | "rgba" => parse_rgb(args),
+            "hsl" | "hsla" => parse_hsl(args),
+            "oklab" => parse_oklab(args, false),
+            "oklch" => parse_oklab(args, true),
+            "color" => parse_color_fn(args),
+            _ => None,
+        }
+    }
+
+    /// Build a color from a named color space and its three components, as
+    /// found in `color()` or a DTCG `{colorSpace, components}` value.
+    pub fn from_components(space: &str, [c0, c1, c2]: [f64; 3], alpha: f64) -> Option<Self> {
+        let [r, g, b] = match space.to_ascii_lowercase().as_str() {
+            "srgb" => [c0, c1, c2],
+            "srgb-linear" => map3([c0, c1, c2], gamma_encode),
+            "display-p3" => {
+                let linear = mat3(&P3_TO_XYZ, map3([c0, c1, c2], gamma_decode));
+                map3(mat3(&XYZ_TO_SRGB, linear), gamma_encode)
+            }
+            "oklab" => map3(oklab_to_linear([c0, c1, c2]), gamma_encode),
+            "oklch" => {
+                let h = c2.to_radians();
+                map3(
+                    oklab_to_linear([c0, c1 * h.cos(), c1 * h.sin()]),
+                    gamma_encode,
+                )
+            }
+            "hsl" => hsl_to_srgb(c0, c1, c2),
+            _ => return None,
+        };
+        Some(Self {
+            r,
+            g,
+            b,
+            alpha: alpha.clamp(0.0, 1.0),
+        })
+    }
+
+    /// Channels as `[r, g, b, alpha]`.
+    pub fn to_rgba(self) -> [f64; 4] {
+        [self.r, self.g, self.b, self.alpha]
+    }
+
+    /// Whether every channel lies within the sRGB gamut.
+    pub fn in_srgb_gamut(self) -> bool {
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+    }
+
+    /// The color with each channel clipped into the sRGB gamut.
+    pub fn clamped(self) -> Self {
+        Self {
+            r: self.r.clamp(0.0, 1.0),
+            g: self.g.clamp(0.0, 1.0),
+            b: self.b.clamp(0.0, 1.0),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Canonical CSS serialisation: lowercase `#rrggbb` (or `#rrggbbaa` when
+    /// translucent) for in-gamut colors, `color(srgb …)` otherwise so
+    /// wide-gamut values are not silently clipped.
+    pub fn to_css(self) -> String {
+        if self.in_srgb_gamut() {
+            let c = self.clamped();
+            let byte = |v: f64| (v * 255.0).round() as u8;
+            let mut hex = format!("#{:02x}{:02x}{:02x}", byte(c.r), byte(c.g), byte(c.b));
+            if byte(c.alpha) < 255 {
+                hex.push_str(&format!("{:02x}", byte(c.alpha)));
+            }
+            return hex;
+        }
+        let mut css = format!(
+            "color(srgb {} {} {}",
+            fmt_num(self.r),
+            fmt_num(self.g),
+            fmt_num(self.b)
+        );
+        if self.alpha < 1.0 {
+            css.push_str(&format!(" / {}", fmt_num(self.alpha)));
+        }
+        css.push(')');
+        css
+    }
+
+    /// Linear-light sRGB channels.
+    pub fn to_linear_srgb(self) -> [f64; 3] {
+        map3([self.r, self.g, self.b], gamma_decode)
+    }
+
+    /// OKLab `[L, a, b]` coordinates.
+    pub fn to_oklab(self) -> [f64; 3] {
+        let [r, g, b] = self.to_linear_srgb();
+        let lms = map3(
+            [
+                0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b,
+                0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b,
+                0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b,
+            ],
+            f64::cbrt,
+        );
+        let [l, m, s] = lms;
+        [
+            0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+            1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+            0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+        ]
+    }
+
+    /// CIELAB `[L, a, b]` coordinates relative to the D65 white point.
+    pub fn to_lab(self) -> [f64; 3] {
+        const EPSILON: f64 = 216.0 / 24389.0;
+        const KAPPA: f64 = 24389.0 / 27.0;
+        let [x, y, z] = mat3(&SRGB_TO_XYZ, self.to_linear_srgb());
+        let f = |t: f64| {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / D65[0]), f(y / D65[1]), f(z / D65[2]));
+        [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+    }
+
+    /// CIEDE2000 color difference. Alpha is ignored.
+    pub fn delta_e(self, other: Self) -> f64 {
+        ciede2000(self.to_lab(), other.to_lab())
+    }
+
+    /// Euclidean distance in OKLab (ΔEOK, roughly `0.02` per JND). Alpha is
+    /// ignored.
+    pub fn oklab_distance(self, other: Self) -> f64 {
+        let ([l1, a1, b1], [l2, a2, b2]) = (self.to_oklab(), other.to_oklab());
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Whether `other` is indistinguishable from `self`: a CIEDE2000
+    /// difference below [`JND_DELTA_E`] at the same opacity.
+    pub fn is_visually_identical(self, other: Self) -> bool {
+        (self.alpha - other.alpha).abs() <= ALPHA_EPSILON && self.delta_e(other) < JND_DELTA_E
+    }
+
+    /// `self` alpha-composited over `backdrop`, whose own alpha is ignored.
+    /// The result is opaque.
+    pub fn over(self, backdrop: Self) -> Self {
+        let a = self.alpha.clamp(0.0, 1.0);
+        let mix = |fg: f64, bg: f64| fg * a + bg * (1.0 - a);
+        Self::srgb(
+            mix(self.r, backdrop.r),
+            mix(self.g, backdrop.g),
+            mix(self.b, backdrop.b),
+        )
+    }
+
+    /// WCAG 2.x relative luminance. Alpha is ignored.
+    pub fn relative_luminance(self) -> f64 {
+        let [r, g, b] = self.to_linear_srgb();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// WCAG 2.x contrast ratio, `1.0..=21.0`, independent of order. Alpha is
+    /// ignored.
+    pub fn wcag_contrast(self, other: Self) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// APCA (0.0.98G) lightness contrast `Lc` for text `self` on `background`.
+    /// Positive for dark-on-light, negative for light-on-dark. Alpha is
+    /// ignored.
+    pub fn apca_contrast(self, background: Self) -> f64 {
+        const BLK_THRS: f64 = 0.022;
+        const BLK_CLMP: f64 = 1.414;
+        const DELTA_Y_MIN: f64 = 0.0005;
+        const SCALE: f64 = 1.14;
+        const LO_CLIP: f64 = 0.1;
+        const LO_OFFSET: f64 = 0.027;
+
+        // APCA linearizes with a plain 2.4 exponent, not the sRGB curve.
+        let y = |c: Self| {
+            let y = 0.212_672_9 * c.r.powf(2.4)
+                + 0.715_152_2 * c.g.powf(2.4)
+                + 0.072_175 * c.b.powf(2.4);
+            if y > BLK_THRS {
+                y
+            } else {
+                y + (BLK_THRS - y).powf(BLK_CLMP)
+            }
+        };
+        let (txt, bg) = (y(self), y(background));
+        if (bg - txt).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+        let lc = if bg > txt {
+            let sapc = (bg.powf(0.56) - txt.powf(0.57)) * SCALE;
+            if sapc < LO_CLIP {
+                0.0
+            } else {
+                sapc - LO_OFFSET
+            }
+        } else {
+            let sapc = (bg.powf(0.65) - txt.powf(0.62)) * SCALE;
+            if sapc > -LO_CLIP {
+                0.0
+            } else {
+                sapc + LO_OFFSET
+            }
+        };
+        lc * 100.0
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_css())
+    }
+}
+
+/// CIEDE2000 difference between two CIELAB colors (Sharma, Wu & Dalal 2005).
+pub fn ciede2000([l1, a1, b1]: [f64; 3], [l2, a2, b2]: [f64; 3]) -> f64 {
+    const POW25_7: f64 = 6_103_515_625.0;
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt());
+    let (a1p, a2p) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1p, c2p) = (a1p.hypot(b1), a2p.hypot(b2));
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1p, h2p) = (hue(b1, a1p), hue(b2, a2p));
+    let chroma_zero = c1p * c2p == 0.0;
+
+    let dl = l2 - l1;
+    let dc = c2p - c1p;
+    let dh = if chroma_zero {
+        0.0
+    } else {
+        match h2p - h1p {
+            d if d > 180.0 => d - 360.0,
+            d if d < -180.0 => d + 360.0,
+            d => d,
+        }
+    };
+    let dh_big = 2.0 * (c1p * c2p).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar_p = (c1p + c2p) / 2.0;
+    let h_bar = if chroma_zero {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+
+    let cos = |deg: f64| deg.to_radians().cos();
+    let t =
+        1.0 - 0.17 * cos(h_bar - 30.0) + 0.24 * cos(2.0 * h_bar) + 0.32 * cos(3.0 * h_bar + 6.0)
+            - 0.20 * cos(4.0 * h_bar - 63.0);
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar_p.powi(7) / (c_bar_p.powi(7) + POW25_7)).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_p;
+    let s_h = 1.0 + 0.015 * c_bar_p * t;
+    let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+    let (l, c, h) = (dl / s_l, dc / s_c, dh_big / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+// ── Parsing ──────────────────────────────────────────────────────────────────
+
+/// Parse a CSS color literal (any form [`Color::parse`] accepts) into sRGB
+/// `[r, g, b, a]` components, each clipped to `0.0..=1.0`.
+pub fn parse_rgba(s: &str) -> Option<[f64; 4]> {
+    Color::parse(s).map(|c| c.clamped().to_rgba())
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let expanded: String = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+    let byte = |i: usize| {
+        u8::from_str_radix(&expanded[i..i + 2], 16)
+            .ok()
+            .map(|b| f64::from(b) / 255.0)
+    };
+    let alpha = if expanded.len() == 8 { byte(6)? } else { 1.0 };
+    Some(Color {
+        r: byte(0)?,
+        g: byte(2)?,
+        b: byte(4)?,
+        alpha,
+    })
+}
+
+/// Split function arguments into three channel tokens and an optional alpha
+/// token. Accepts both the legacy comma syntax (`a, b, c[, alpha]`) and the
+/// modern space syntax (`a b c[ / alpha]`).
+fn split_args(args: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    if args.contains(',') {
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let alpha = match parts.len() {
+            3 => None,
+            4 => parts.pop(),
+            _ => return None,
+        };
+        return Some((parts, alpha));
+    }
+    let (channels, alpha) = match args.split_once('/') {
+        Some((c, a)) => (c, Some(a.trim())),
+        None => (args, None),
+    };
+    Some((channels.split_whitespace().collect(), alpha))
+}
+
+/// A number or percentage; `percent_scale` is the value `100%` maps to.
+/// `none` is zero, per CSS Color 4 missing-component rules.
+fn component(token: &str, percent_scale: f64) -> Option<f64> {
+    let token = token.trim();
+    if token.eq_ignore_ascii_case("none") {
+        return Some(0.0);
+    }
+    match token.strip_suffix('%') {
+        Some(p) => Some(p.parse::<f64>().ok()? / 100.0 * percent_scale),
+        None => token.parse().ok(),
+    }
+}
+
+/// An angle in degrees (`120`, `120deg`, `0.5turn`, `3.14rad`, `200grad`).
+fn hue(token: &str) -> Option<f64> {
+    let token = token.trim().to_ascii_lowercase();
+    if token == "none" {
+        return Some(0.0);
+    }
+    let (n, factor) = if let Some(n) = token.strip_suffix("deg") {
+        (n, 1.0)
+    } else if let Some(n) = token.strip_suffix("grad") {
+        (n, 0.9)
+    } else if let Some(n) = token.strip_suffix("rad") {
+        (n, 180.0 / std::f64::consts::PI)
+    } else if let Some(n) = token.strip_suffix("turn") {
+        (n, 360.0)
+    } else {
+        (token.as_str(), 1.0)
+    };
+    Some(n.parse::<f64>().ok()? * factor)
+}
+
+fn alpha(token: Option<&str>) -> Option<f64> {
+    token.map_or(Some(1.0), |t| component(t, 1.0))
+}
+
+fn parse_rgb(args: &str) -> Option<Color> {
+    let (channels, a) = split_args(args)?;
+    let [r, g, b] = channels.as_slice() else {
+        return None;
+    };
+    let channel = |t: &str| component(t, 255.0).map(|v| v / 255.0);
+    Color::from_components("srgb", [channel(r)?, channel(g)?, channel(b)?], alpha(a)?)
+}
+
+fn parse_hsl(args: &str) -> Option<Color> {
+    let (channels, a) = split_args(args)?;
+    let [h, s, l] = channels.as_slice() else {
+        return None;
+    };
+    // Saturation and lightness are percentages; bare numbers (modern syntax)
+    // are on the same 0–100 scale.
+    let pct = |t: &str| component(t, 100.0).map(|v| v / 100.0);
+    Color::from_components("hsl", [hue(h)?, pct(s)?, pct(l)?], alpha(a)?)
+}
+
+fn parse_oklab(args: &str, polar: bool) -> Option<Color> {
+    let (channels, a) = split_args(args)?;
+    let [l, c1, c2] = channels.as_slice() else {
+        return None;
+    };
+    let l = component(l, 1.0)?;
+    let c1 = component(c1, 0.4)?;
+    if polar {
+        Color::from_components("oklch", [l, c1, hue(c2)?], alpha(a)?)
+    } else {
+        Color::from_components("oklab", [l, c1, component(c2, 0.4)?], alpha(a)?)
+    }
+}
+
+fn parse_color_fn(args: &str) -> Option<Color> {
+    let (channels, a) = split_args(args)?;
+    let [space, c0, c1, c2] = channels.as_slice() else {
+        return None;
+    };
+    if !matches!(
+        space.to_ascii_lowercase().as_str(),
+        "srgb" | "srgb-linear" | "display-p3"
+    ) {
+        return None;
+    }
+    Color::from_components(
+        space,
+        [
+            component(c0, 1.0)?,
+            component(c1, 1.0)?,
+            component(c2, 1.0)?,
+        ],
+        alpha(a)?,
+    )
+}
+
+// ── Conversions ──────────────────────────────────────────────────────────────
+
+/// D65 reference white in XYZ.
+const D65: [f64; 3] = [0.950_455_927_051_671_6, 1.0, 1.089_057_750_759_878_4];
+
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        0.412_390_799_265_959_34,
+        0.357_584_339_383_878,
+        0.180_480_788_401_834_3,
+    ],
+    [
+        0.212_639_005_871_510_27,
+        0.715_168_678_767_756,
+        0.072_192_315_360_733_71,
+    ],
+    [
+        0.019_330_818_715_591_82,
+        0.119_194_779_794_625_98,
+        0.950_532_152_249_660_7,
+    ],
+];
+
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [
+        3.240_969_941_904_522_6,
+        -1.537_383_177_570_094,
+        -0.498_610_760_293_003_4,
+    ],
+    [
+        -0.969_243_636_280_879_6,
+        1.875_967_501_507_720_2,
+        0.041_555_057_407_175_59,
+    ],
+    [
+        0.055_630_079_696_993_66,
+        -0.203_976_958_888_976_52,
+        1.056_971_514_242_878_6,
+    ],
+];
+
+const P3_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        0.486_570_948_648_216_2,
+        0.265_667_693_169_093_06,
+        0.198_217_285_234_362_5,
+    ],
+    [
+        0.228_974_564_069_748_8,
+        0.691_738_521_836_506_4,
+        0.079_286_914_093_745,
+    ],
+    [0.0, 0.045_113_381_858_902_64, 1.043_944_368_900_976],
+];
+
+fn mat3(m: &[[f64; 3]; 3], [x, y, z]: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * x + row[1] * y + row[2] * z)
+}
+
+fn map3(v: [f64; 3], f: impl Fn(f64) -> f64) -> [f64; 3] {
+    v.map(f)
+}
+
+/// sRGB (and Display P3) transfer function, extended sign-symmetrically so
+/// out-of-gamut channels round-trip.
+fn gamma_decode(c: f64) -> f64 {
+    let a = c.abs();
+    let v = if a <= 0.04045 {
+        a / 12.92
+    } else {
+        ((a + 0.055) / 1.055).powf(2.4)
+    };
+    v.copysign(c)
+}
+
+fn gamma_encode(c: f64) -> f64 {
+    let a = c.abs();
+    let v = if a <= 0.003_130_8 {
+        a * 12.92
+    } else {
+        1.055 * a.powf(1.0 / 2.4) - 0.055
+    };
+    v.copysign(c)
+}
+
+fn oklab_to_linear([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = l + 0.396_337_777_4 * a + 0.215_803_757_3 * b;
+    let m_ = l - 0.105_561_345_8 * a - 0.063_854_172_8 * b;
+    let s_ = l - 0.089_484_177_5 * a - 1.291_485_548_0 * b;
+    let (l, m, s) = (l_.powi(3), m_.powi(3), s_.powi(3));
+    [
+        4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+        -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+        -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+    ]
+}
+
+/// `h` in degrees, `s`/`l` in `0..=1`.
+fn hsl_to_srgb(h: f64, s: f64, l: f64) -> [f64; 3] {
+    let h = h.rem_euclid(360.0);
+    let s = s.clamp(0.0, 1.0);
+    let l = l.clamp(0.0, 1.0);
+    let channel = |n: f64| {
+        let k = (n + h / 30.0) % 12.0;
+        let a = s * l.min(1.0 - l);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [channel(0.0), channel(8.0), channel(4.0)]
+}
+
+/// Up to four decimals, trailing zeros trimmed.
+fn fmt_num(v: f64) -> String {
+    let s = format!("{v:.4}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rgba(c: Color, expected: [f64; 4]) {
+        let actual = c.to_rgba();
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| (a - e).abs() < 1e-3),
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn parses_rgba_components() {
+        assert_eq!(parse_rgba("#fff"), Some([1.0, 1.0, 1.0, 1.0]));
+        assert_eq!(parse_rgba("rgb(255, 0, 0)"), Some([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(parse_rgba("rgba(0, 0, 0, 0.5)"), Some([0.0, 0.0, 0.0, 0.5]));
+        assert_eq!(parse_rgba("blue"), None);
+    }
+
+    #[test]
+    fn parses_every_css_form() {
+        let red = [1.0, 0.0, 0.0, 1.0];
+        for input in [
+            "#f00",
+            "#ff0000",
+            "#FF0000FF",
+            "rgb(255, 0, 0)",
+            "rgba(255, 0, 0, 1)",
+            "rgb(255 0 0)",
+            "rgb(100% 0% 0% / 100%)",
+            "hsl(0, 100%, 50%)",
+            "hsla(360deg 100 50 / 1)",
+            "hsl(1turn 100% 50%)",
+            "oklch(62.796% 0.25768 29.234)",
+            "oklab(0.62796 0.22486 0.12585)",
+            "color(srgb 1 0 0)",
+            "color(srgb-linear 1 0 0)",
+        ] {
+            assert_rgba(
+                Color::parse(input).unwrap_or_else(|| panic!("{input}")),
+                red,
+            );
+        }
+        assert_rgba(
+            Color::parse("rgba(0, 0, 0, 0.5)").unwrap(),
+            [0.0, 0.0, 0.0, 0.5],
+        );
+        assert_rgba(
+            Color::parse("hsl(210 50% 40% / 25%)").unwrap(),
+            [0.2, 0.4, 0.6, 0.25],
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_forms() {
+        for input in [
+            "blue",
+            "currentColor",
+            "#gg0000",
+            "#12345",
+            "rgb(1, 2)",
+            "lab(50 20 30)",
+            "color(rec2020 1 0 0)",
+            "rgb(1 2 3",
+        ] {
+            assert!(Color::parse(input).is_none(), "{input} should not parse");
+        }
+    }
+
+    #[test]
+    fn display_p3_keeps_out_of_gamut_channels() {
+        let p3 = Color::parse("color(display-p3 1 0 0)").unwrap();
+        assert!(!p3.in_srgb_gamut());
+        assert!(p3.r > 1.0 && p3.g < 0.0);
+        assert!(p3.clamped().in_srgb_gamut());
+        assert!(p3.to_css().starts_with("color(srgb 1.0931 -0.2267"));
+        // In-gamut display-p3 values normalise to hex.
+        assert_eq!(
+            Color::parse("color(display-p3 1 1 1)").unwrap().to_css(),
+            "#ffffff"
+        );
+    }
+
+    #[test]
+    fn canonical_css_is_lowercase_hex() {
+        assert_eq!(Color::parse("rgb(29, 29, 29)").unwrap().to_css(), "#1d1d1d");
+        assert_eq!(Color::parse("#ABC").unwrap().to_css(), "#aabbcc");
+        assert_eq!(
+            Color::parse("rgba(0, 0, 0, 0.5)").unwrap().to_string(),
+            "#00000080"
+        );
+        assert_eq!(Color::parse("hsl(0 0% 100%)").unwrap().to_css(), "#ffffff");
+    }
+
+    #[test]
+    fn ciede2000_matches_reference_pairs() {
+        // Sharma, Wu & Dalal (2005) test data, pairs 1, 7, 17 and 25.
+        for (a, b, expected) in [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+            ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+            (
+                [60.2574, -34.0099, 36.2677],
+                [60.4626, -34.1751, 39.4387],
+                1.2644,
+            ),
+        ] {
+            let d = ciede2000(a, b);
+            assert!((d - expected).abs() < 1e-4, "{a:?} vs {b:?}: {d}");
+            assert!((ciede2000(b, a) - d).abs() < 1e-9, "symmetric");
+        }
+    }
+
+    #[test]
+    fn perceptual_distances() {
+        let white = Color::parse("#ffffff").unwrap();
+        let black = Color::parse("#000000").unwrap();
+        assert!((white.delta_e(black) - 100.0).abs() < 1e-3);
+        assert!((white.oklab_distance(black) - 1.0).abs() < 1e-3);
+
+        let a = Color::parse("#6e6e6e").unwrap();
+        let b = Color::parse("rgb(109, 110, 110)").unwrap();
+        assert!(a.is_visually_identical(b));
+        assert!(!a.is_visually_identical(Color::parse("#737373").unwrap()));
+        assert!(!a.is_visually_identical(Color::parse("#6e6e6e80").unwrap()));
+    }
+
+    #[test]
+    fn wcag_contrast_extremes() {
+        let (black, white, grey) = (
+            Color::srgb(0.0, 0.0, 0.0),
+            Color::srgb(1.0, 1.0, 1.0),
+            Color::srgb(0.5, 0.5, 0.5),
+        );
+        assert!((black.wcag_contrast(white) - 21.0).abs() < 1e-9);
+        assert!((white.wcag_contrast(black) - 21.0).abs() < 1e-9);
+        assert!((grey.wcag_contrast(grey) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn apca_polarity_and_magnitude() {
+        let (black, white, grey) = (
+            Color::srgb(0.0, 0.0, 0.0),
+            Color::srgb(1.0, 1.0, 1.0),
+            Color::srgb(0.5, 0.5, 0.5),
+        );
+        let dark_on_light = black.apca_contrast(white);
+        let light_on_dark = white.apca_contrast(black);
+        assert!((dark_on_light - 106.0).abs() < 0.1, "{dark_on_light}");
+        assert!((light_on_dark + 107.9).abs() < 0.1, "{light_on_dark}");
+        assert_eq!(grey.apca_contrast(grey), 0.0);
+    }
+
+    #[test]
+    fn translucent_colors_composite_over_the_backdrop() {
+        let half_black = Color::parse("rgba(0, 0, 0, 0.5)").unwrap();
+        let mixed = half_black.over(Color::parse("#ffffff80").unwrap());
+        assert_eq!(mixed.to_rgba(), [0.5, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn oklab_of_white_is_unit_lightness() {
+        let [l, a, b] = Color::srgb(1.0, 1.0, 1.0).to_oklab();
+        assert!((l - 1.0).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+    }
+}
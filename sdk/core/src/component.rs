@@ -15,6 +15,7 @@
 //! provides ID validation, single-component lookup, and directory listing so that
 //! each surface only needs to resolve the directory path and handle presentation.
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::CoreError;
@@ -82,6 +83,31 @@ pub fn list(dir: &Path) -> Vec<String> {
     names
 }
 
+/// Load every component declaration in `dir`, keyed by its `"name"` field.
+///
+/// Unlike [`list`], unreadable or malformed `*.json` files are errors: callers
+/// comparing two catalogs must not mistake a broken file for a removed component.
+/// Files without a string `"name"` field are skipped.  A missing `dir` yields an
+/// empty catalog.
+pub fn load_all(dir: &Path) -> Result<BTreeMap<String, serde_json::Value>, CoreError> {
+    let mut catalog = BTreeMap::new();
+    if !dir.is_dir() {
+        return Ok(catalog);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let p = entry?.path();
+        if p.extension().and_then(|x| x.to_str()) != Some("json") {
+            continue;
+        }
+        let raw = std::fs::read_to_string(&p)?;
+        let v: serde_json::Value = serde_json::from_str(&raw)?;
+        if let Some(name) = v.get("name").and_then(|n| n.as_str()) {
+            catalog.insert(name.to_string(), v);
+        }
+    }
+    Ok(catalog)
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -167,4 +193,19 @@ mod tests {
         let names = list(dir.path());
         assert_eq!(names, vec!["button"]);
     }
+
+    // ── load_all ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn load_all_keys_by_name_and_rejects_malformed() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("button.json"), r#"{"name":"button"}"#).unwrap();
+        fs::write(dir.path().join("noname.json"), r#"{"title":"x"}"#).unwrap();
+        let catalog = load_all(dir.path()).unwrap();
+        assert_eq!(catalog.keys().collect::<Vec<_>>(), vec!["button"]);
+
+        fs::write(dir.path().join("broken.json"), "{ bad json }").unwrap();
+        assert!(load_all(dir.path()).is_err());
+        assert!(load_all(&dir.path().join("missing")).unwrap().is_empty());
+    }
 }
@@ -16,9 +16,10 @@
 //! 1. **Explicit CLI flags / positional args** — passed via [`CliPathOverrides`].
 //!    `Some` values are used directly; `None` falls through to the next tier.
 //! 2. **`.design-data.toml` config file** — discovered by walking up from `cwd`.
-//!    `path` and `github` sources fetch/resolve data; `npm`/`git` still
-//!    return [`DataSourceError::NotYetImplemented`] until `#1050` lands. The
-//!    `github` source pins by `tag`, `branch`, or `sha`. A Layer 2 platform
+//!    `path` resolves a local checkout; `github`, `npm`, and `git` are fetched
+//!    and cached (`fetch` feature). The `github` source pins by `tag`,
+//!    `branch`, or `sha`; `npm` by exact `version` (tarball `integrity`
+//!    verified); `git` by any `ref` the remote serves. A Layer 2 platform
 //!    manifest is configured via the **top-level `manifest` key** (not per
 //!    source), so it cascades over any source or the embedded/probed default.
 //! 3. **CWD-relative probing** — tries `packages/tokens/…` and
@@ -42,6 +43,8 @@ use std::path::{Path, PathBuf};
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::validate::rule_config::RuleConfig;
+
 // ---------------------------------------------------------------------------
 // Config file structs — `.design-data.toml`
 // ---------------------------------------------------------------------------
@@ -67,6 +70,10 @@ pub struct DesignDataConfig {
     /// ([`crate::graph::TokenGraph::apply_platform_manifest`]) over whatever source
     /// is configured (`path`, `github`, or the embedded/probed default).
     pub manifest: Option<PathBuf>,
+    /// Per-rule validation levels and path-scoped overrides (`[validate.rules]`,
+    /// `[[validate.overrides]]`). Source-independent like `manifest`; recorded on
+    /// [`ResolvedData::rule_config`]. See [`crate::validate::rule_config`].
+    pub validate: Option<RuleConfig>,
 }
 
 /// Describes where to obtain or locate the design data.
@@ -86,13 +93,25 @@ pub enum SourceConfig {
         /// the directory containing `.design-data.toml`).
         root: PathBuf,
     },
-    /// `@adobe/spectrum-tokens` (and matching `design-data-spec`) from the npm
-    /// registry.  **Not yet implemented — planned for `#1050`.**
+    /// A published package tarball from an npm registry. Fetched and cached by
+    /// version (see `sdk/core/src/data_source/fetch.rs`).
+    ///
+    /// The tarball is verified against the registry's `dist.integrity` (and the
+    /// optional `integrity` pin) before extraction, then placed at the package's
+    /// monorepo location (`packages/tokens/` for `@adobe/spectrum-tokens`,
+    /// `packages/design-data/` for `@adobe/spectrum-design-data`). `registry` may
+    /// be a `file://` mirror laid out like the registry (`<mirror>/<package>`
+    /// holds the packument JSON).
     Npm {
         /// Package name (default: `@adobe/spectrum-tokens`).
         package: Option<String>,
         /// Exact version to resolve.
         version: String,
+        /// Registry base URL (default: `https://registry.npmjs.org`).
+        registry: Option<String>,
+        /// Expected Subresource Integrity string (`sha512-…`), as recorded in a
+        /// lockfile. Checked in addition to the registry's own `dist.integrity`.
+        integrity: Option<String>,
     },
     /// A GitHub archive tarball. Fetched and cached (see
     /// `sdk/core/src/data_source/fetch.rs`). The archive contains the full
@@ -113,17 +132,38 @@ pub enum SourceConfig {
         /// Exact commit SHA to download.
         sha: Option<String>,
     },
-    /// A git repository (clone / fetch at a ref).
-    /// **Not yet implemented — planned for `#1050`.**
+    /// A git repository, shallow-fetched at `ref` with the `git` binary and
+    /// cached (see `sdk/core/src/data_source/fetch.rs`). Works with any host or
+    /// transport git supports, including `file://` and local bare repos.
+    ///
+    /// A full commit SHA is immutable and served from cache; branch and tag
+    /// names are refetched on every run, like a `github` branch pin.
     Git {
         /// Repository URL.
+        #[serde(deserialize_with = "git_argument")]
         url: String,
         /// Branch, tag, or commit SHA.
-        #[serde(rename = "ref")]
+        #[serde(rename = "ref", deserialize_with = "git_argument")]
         git_ref: String,
     },
 }
 
+/// A `git` source `url` / `ref`, which is passed to `git fetch` as a
+/// positional argument: reject values git would read as an option
+/// (`--upload-pack=…` runs an arbitrary command).
+fn git_argument<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    if value.starts_with('-') {
+        return Err(serde::de::Error::custom(format!(
+            "`{value}` starts with `-` and would be read as a git option"
+        )));
+    }
+    Ok(value)
+}
+
 /// Cache configuration.  Defaults to `dirs::cache_dir()/design-data`.
 #[derive(Debug, Default, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -167,7 +207,7 @@ pub enum Provenance {
         /// Absolute path to the config file that was found.
         config_path: PathBuf,
     },
-    /// Fetched from a remote source and cached on disk (`#1050`).
+    /// Fetched from a remote source (`github`, `npm`, `git`) and cached on disk.
     Cache {
         /// Directory where the cached data lives.
         cache_dir: PathBuf,
@@ -214,6 +254,9 @@ pub struct ResolvedData {
     /// overrides, extensions, and mode set restrictions. Applied via
     /// [`crate::graph::TokenGraph::apply_platform_manifest`].
     pub platform_manifest: Option<PathBuf>,
+    /// Validation rule levels from the config's `[validate]` table; empty when
+    /// no config file (or no table) was found.
+    pub rule_config: RuleConfig,
     /// How these paths were determined.
     pub provenance: Provenance,
 }
@@ -221,8 +264,11 @@ pub struct ResolvedData {
 /// Errors that can occur during data-source resolution.
 #[derive(Debug, Error)]
 pub enum DataSourceError {
-    /// A `[source]` type that requires network/fetch is not yet wired up.
-    #[error("data source type '{source_type}' is not yet supported (planned for issue #1050)")]
+    /// A `[source]` type that requires network/fetch was configured in a build
+    /// without the `fetch` feature.
+    #[error(
+        "data source type '{source_type}' requires the `fetch` feature, which is not enabled in this build"
+    )]
     NotYetImplemented {
         /// The source type string from the config, e.g. `"npm"`.
         source_type: String,
@@ -241,6 +287,14 @@ pub enum DataSourceError {
         #[source]
         source: toml::de::Error,
     },
+    /// A rule file listed in `[validate] custom-rules` is unreadable or declares
+    /// invalid rules.
+    #[error("custom rules configured in `.design-data.toml` at {path} are invalid")]
+    CustomRules {
+        path: PathBuf,
+        #[source]
+        source: Box<crate::CoreError>,
+    },
     /// `source.type = "path"` but the `root` directory does not exist.
     #[error("source.root '{root}' in `.design-data.toml` does not exist or is not a directory")]
     PathNotFound {
@@ -271,7 +325,8 @@ pub enum DataSourceError {
 /// Returns [`DataSourceError`] when:
 /// - A `.design-data.toml` exists but cannot be read or parsed.
 /// - `source.type = "path"` and `root` does not exist.
-/// - `source.type` is `npm`, `github`, or `git` (not yet implemented).
+/// - `source.type` is `npm`, `github`, or `git` and the fetch fails (or the
+///   build lacks the `fetch` feature).
 pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData, DataSourceError> {
     // Tier 1 is handled per-field inside each helper (overrides win always).
 
@@ -279,9 +334,10 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
     // cascade over any source below (path/github) or the probed/embedded default.
     // Carry it down to whichever tier actually resolves.
     let mut carried_manifest: Option<PathBuf> = None;
+    let mut carried_rules = RuleConfig::default();
 
     // Tier 2: look for `.design-data.toml` walking up from cwd.
-    if let Some((config_path, config)) = find_config(cwd)? {
+    if let Some((config_path, mut config)) = find_config(cwd)? {
         // Resolve the optional platform manifest relative to the config file's
         // directory, up front, before `config_path` is moved into a provenance record.
         let config_dir = config_path.parent().unwrap_or(cwd).to_path_buf();
@@ -293,6 +349,20 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
             }
         });
 
+        let mut rule_config = config.validate.take().unwrap_or_default();
+        for path in &mut rule_config.custom_rules {
+            if path.is_relative() {
+                *path = config_dir.join(&*path);
+            }
+        }
+        rule_config.custom =
+            crate::validate::custom::load_all(&rule_config.custom_rules).map_err(|source| {
+                DataSourceError::CustomRules {
+                    path: config_path.clone(),
+                    source: Box::new(source),
+                }
+            })?;
+
         if let Some(source) = &config.source {
             return match source {
                 SourceConfig::Path { root } => {
@@ -310,6 +380,7 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
                     let mut resolved =
                         from_root(&canonical, overrides, Provenance::Config { config_path });
                     resolved.platform_manifest = platform_manifest;
+                    resolved.rule_config = rule_config;
                     Ok(resolved)
                 }
                 SourceConfig::Npm { .. }
@@ -321,6 +392,7 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
                         overrides,
                     )?;
                     resolved.platform_manifest = platform_manifest;
+                    resolved.rule_config = rule_config;
                     Ok(resolved)
                 }
             };
@@ -328,6 +400,7 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
         // Config file present but no [source] block → fall through to probing,
         // carrying any top-level manifest so it can cascade over the local dataset.
         carried_manifest = platform_manifest;
+        carried_rules = rule_config;
     }
 
     // Tier 3: CWD-relative probing — original in-monorepo behaviour.
@@ -335,6 +408,7 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
     if is_in_repo(cwd) {
         let mut resolved = probe_cwd(cwd, overrides);
         resolved.platform_manifest = carried_manifest;
+        resolved.rule_config = carried_rules;
         return Ok(resolved);
     }
 
@@ -351,6 +425,7 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
                 },
             );
             resolved.platform_manifest = carried_manifest;
+            resolved.rule_config = carried_rules;
             return Ok(resolved);
         }
         Err(e) => {
@@ -367,7 +442,9 @@ pub fn resolve(cwd: &Path, overrides: &CliPathOverrides) -> Result<ResolvedData,
 
     // Tier 3 fallback: CWD probing (will likely find nothing outside a repo, but
     // callers handle None fields gracefully).
-    Ok(probe_cwd(cwd, overrides))
+    let mut resolved = probe_cwd(cwd, overrides);
+    resolved.rule_config = carried_rules;
+    Ok(resolved)
 }
 
 // ---------------------------------------------------------------------------
@@ -475,6 +552,7 @@ fn from_root(root: &Path, overrides: &CliPathOverrides, provenance: Provenance)
         exceptions,
         manifest,
         platform_manifest: None,
+        rule_config: RuleConfig::default(),
         provenance,
     }
 }
@@ -597,6 +675,7 @@ fn probe_cwd(cwd: &Path, overrides: &CliPathOverrides) -> ResolvedData {
         exceptions,
         manifest,
         platform_manifest: None,
+        rule_config: RuleConfig::default(),
         provenance: Provenance::InRepo,
     }
 }
@@ -623,7 +702,7 @@ fn resolve_schema_root(
 /// Dispatch a remote-fetch source through the fetch module (tier 2, config-driven).
 ///
 /// When the `fetch` feature is disabled this always returns `NotYetImplemented`
-/// so default builds still compile cleanly.
+/// (naming the configured source type) so default builds still compile cleanly.
 fn fetch_source(
     source: &SourceConfig,
     cache_dir_override: Option<&Path>,
@@ -642,9 +721,15 @@ fn fetch_source(
     }
     #[cfg(not(feature = "fetch"))]
     {
-        let _ = (source, cache_dir_override, overrides);
+        let _ = (cache_dir_override, overrides);
+        let source_type = match source {
+            SourceConfig::Npm { .. } => "npm",
+            SourceConfig::Git { .. } => "git",
+            SourceConfig::Github { .. } => "github",
+            SourceConfig::Path { .. } => "path",
+        };
         Err(DataSourceError::NotYetImplemented {
-            source_type: "fetch feature not enabled in this build".into(),
+            source_type: source_type.into(),
         })
     }
 }
@@ -766,7 +851,7 @@ mod tests {
         fs::create_dir_all(&project).unwrap();
         fs::write(
             project.join(".design-data.toml"),
-            "[source]\ntype = \"path\"\nroot = \"../spectrum-repo\"\n".to_string(),
+            "[source]\ntype = \"path\"\nroot = \"../spectrum-repo\"\n",
         )
         .unwrap();
 
@@ -838,27 +923,31 @@ mod tests {
     }
 
     #[test]
-    fn config_npm_source_returns_not_yet_supported() {
-        let _guard = env_lock();
+    fn config_npm_source_surfaces_registry_errors() {
         let tmp = TempDir::new().unwrap();
-        // Set DESIGN_DATA_CACHE_DIR so the fetch engine doesn't touch the real OS cache.
-        std::env::set_var("DESIGN_DATA_CACHE_DIR", tmp.path());
+        // An empty `file://` mirror and a `[cache].dir` so nothing touches the
+        // network or the real OS cache.
+        let mirror = tmp.path().join("mirror");
+        fs::create_dir_all(&mirror).unwrap();
         fs::write(
             tmp.path().join(".design-data.toml"),
-            "[source]\ntype = \"npm\"\npackage = \"@adobe/spectrum-tokens\"\nversion = \"14.0.0\"\n",
+            format!(
+                "[source]\ntype = \"npm\"\nversion = \"15.1.0\"\nregistry = \"file://{}\"\n\
+                 [cache]\ndir = \"{}\"\n",
+                mirror.display(),
+                tmp.path().join("cache").display()
+            ),
         )
         .unwrap();
 
         let err = resolve(tmp.path(), &CliPathOverrides::default()).unwrap_err();
-        std::env::remove_var("DESIGN_DATA_CACHE_DIR");
 
-        // With the `fetch` feature enabled, npm returns DataSourceError::Fetch wrapping
-        // FetchError::NotYetSupported; without the feature, NotYetImplemented.
-        // Either way the resolve fails — verify via the Display message.
+        // With the `fetch` feature the packument read fails (there is none in the
+        // mirror); without it the source type is reported as unsupported.
         let msg = err.to_string();
         assert!(
-            msg.contains("not yet") || msg.contains("npm"),
-            "expected an unsupported-source error message, got: {msg}"
+            msg.contains("spectrum-tokens") || msg.contains("'npm'"),
+            "expected an npm fetch error message, got: {msg}"
         );
     }
 
@@ -898,6 +987,23 @@ mod tests {
         assert_eq!(resolved.provenance, Provenance::InRepo);
     }
 
+    #[test]
+    fn git_source_rejects_option_like_url_and_ref() {
+        for source in [
+            "url = \"--upload-pack=touch pwned\"\nref = \"main\"",
+            "url = \"https://example.com/repo.git\"\nref = \"-oProxyCommand=x\"",
+        ] {
+            let tmp = TempDir::new().unwrap();
+            fs::write(
+                tmp.path().join(".design-data.toml"),
+                format!("[source]\ntype = \"git\"\n{source}\n"),
+            )
+            .unwrap();
+            let err = resolve(tmp.path(), &CliPathOverrides::default()).unwrap_err();
+            assert!(matches!(err, DataSourceError::ConfigParse { .. }), "{err}");
+        }
+    }
+
     #[test]
     fn config_invalid_toml_errors() {
         let tmp = TempDir::new().unwrap();
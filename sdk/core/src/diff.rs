@@ -13,14 +13,26 @@
 //!
 //! Implements the diff specification in `spec/diff.md`: six mutually exclusive
 //! categories (renamed, deprecated, reverted, added, deleted, updated) with
-//! property-level change tracking.
+//! property-level change tracking. Updated color values carry their CIEDE2000
+//! difference and are flagged when the change is visually identical.
+//!
+//! [`resolved_diff`] compares effective values instead: every token variable
+//! is resolved through the cascade and its alias chain in each mode
+//! combination, in both graphs, and only terminal values that differ are
+//! reported.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::cascade::{
+    self, alias_hops, collapse_when, mode_axes, mode_combinations, variable_key, ModeAxis,
+    ModeCombination, ResolutionContext,
+};
+use crate::color::Color;
 use crate::graph::{TokenGraph, TokenRecord};
+use crate::query::{self, TokenFilter};
 
 // ── Public types ────────────────────────────────────────────────────────────
 
@@ -42,6 +54,13 @@ pub struct PropertyChange {
     pub new_value: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub original_value: Option<Value>,
+    /// CIEDE2000 difference, when both sides of an update are color literals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_e: Option<f64>,
+    /// An updated color that is perceptually indistinguishable from the
+    /// original (see [`Color::is_visually_identical`]).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub visually_identical: bool,
 }
 
 /// A token that was renamed (same identity, different name).
@@ -112,6 +131,67 @@ impl DiffReport {
     }
 }
 
+/// One way a variable's effective value changed, and the mode combinations
+/// in which it did.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedValueChange {
+    /// Mode patterns covered, each a mode set → mode map. A mode set absent
+    /// from a pattern applies in every one of its modes; an empty pattern
+    /// covers the whole matrix.
+    pub when: Vec<BTreeMap<String, String>>,
+    /// Number of matrix combinations covered.
+    pub combinations: usize,
+    /// Terminal value before; absent when the variable did not resolve.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_value: Option<Value>,
+    /// Terminal value after; absent when the variable no longer resolves.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<Value>,
+    /// The variable followed by its alias hops, by legacy key, before.
+    pub original_chain: Vec<String>,
+    /// The variable followed by its alias hops, by legacy key, after.
+    pub new_chain: Vec<String>,
+    /// The variable whose edit caused the change: the last hop the two chains
+    /// share. That is the re-pointed alias when the chains diverge, and the
+    /// terminal token when only its value changed.
+    pub cause: String,
+    /// CIEDE2000 difference, when both values are color literals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_e: Option<f64>,
+    /// Both values are colors and perceptually indistinguishable.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub visually_identical: bool,
+}
+
+/// A variable whose effective value changed in at least one combination.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedTokenChange {
+    /// Legacy key.
+    pub name: String,
+    /// Distinct changes, in matrix order (the all-base combination's first).
+    pub changes: Vec<ResolvedValueChange>,
+}
+
+/// Result of [`resolved_diff`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResolvedDiffReport {
+    /// The mode axes enumerated (from the new graph).
+    pub axes: Vec<ModeAxis>,
+    /// Number of mode combinations resolved per variable.
+    pub combinations: usize,
+    /// Number of variables compared.
+    pub compared: usize,
+    /// Variables with a changed effective value, sorted by name.
+    pub changed: Vec<ResolvedTokenChange>,
+}
+
+impl ResolvedDiffReport {
+    /// Returns `true` if no effective value changed.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+}
+
 // ── Internal types ──────────────────────────────────────────────────────────
 
 /// A paired token across old and new graphs.
@@ -229,6 +309,165 @@ pub fn semantic_diff(old: &TokenGraph, new: &TokenGraph) -> DiffReport {
     }
 }
 
+// ── Resolved diff ───────────────────────────────────────────────────────────
+
+/// The cascade outcome of one variable in one mode combination.
+struct Resolution {
+    /// The variable followed by its alias hops, by legacy key.
+    chain: Vec<String>,
+    /// Terminal value; `None` when the chain dangles.
+    value: Option<Value>,
+}
+
+/// Compare the effective values of two token graphs.
+///
+/// Tokens are grouped into variables by legacy key, as the generators do.
+/// Each variable is resolved in every combination of the new graph's mode
+/// sets, in both graphs, and followed through its alias chain with
+/// context-aware set resolution. A change is reported wherever the terminal
+/// values differ, so re-pointing an alias at an equal value is silent while a
+/// palette edit shows up on every variable that resolves through it.
+/// Combinations with the same values and chains are collapsed into mode
+/// patterns, as in [`cascade::resolve_matrix`].
+///
+/// With a `filter`, only variables that have a token matching it in either
+/// graph are reported; aliases still resolve against the full graphs.
+pub fn resolved_diff(
+    old: &TokenGraph,
+    new: &TokenGraph,
+    filter: Option<&TokenFilter>,
+) -> ResolvedDiffReport {
+    let axes = mode_axes(new, &HashMap::new());
+    let combinations = mode_combinations(&axes);
+    let before = resolve_variables(old, &combinations);
+    let after = resolve_variables(new, &combinations);
+
+    let scope: Option<HashSet<String>> = filter.map(|f| {
+        query::filter(old, f)
+            .into_iter()
+            .chain(query::filter(new, f))
+            .map(variable_key)
+            .collect()
+    });
+    let names: BTreeSet<&String> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|name| scope.as_ref().is_none_or(|s| s.contains(*name)))
+        .collect();
+
+    let mut changed = Vec::new();
+    for name in &names {
+        let mut groups: Vec<(ResolvedValueChange, Vec<Vec<Option<String>>>)> = Vec::new();
+        for (i, combo) in combinations.iter().enumerate() {
+            let old_res = before.get(*name).and_then(|v| v[i].as_ref());
+            let new_res = after.get(*name).and_then(|v| v[i].as_ref());
+            let original_value = old_res.and_then(|r| r.value.clone());
+            let new_value = new_res.and_then(|r| r.value.clone());
+            if original_value == new_value {
+                continue;
+            }
+            let original_chain = old_res.map(|r| r.chain.clone()).unwrap_or_default();
+            let new_chain = new_res.map(|r| r.chain.clone()).unwrap_or_default();
+            let cell = combo.modes.iter().map(|(_, m)| Some(m.clone())).collect();
+            match groups.iter_mut().find(|(c, _)| {
+                c.original_value == original_value
+                    && c.new_value == new_value
+                    && c.original_chain == original_chain
+                    && c.new_chain == new_chain
+            }) {
+                Some((change, cells)) => {
+                    change.combinations += 1;
+                    cells.push(cell);
+                }
+                None => {
+                    let (delta_e, visually_identical) = match (&original_value, &new_value) {
+                        (Some(a), Some(b)) => color_delta(a, b),
+                        _ => (None, false),
+                    };
+                    groups.push((
+                        ResolvedValueChange {
+                            when: Vec::new(),
+                            combinations: 1,
+                            cause: change_cause(&original_chain, &new_chain),
+                            original_value,
+                            new_value,
+                            original_chain,
+                            new_chain,
+                            delta_e,
+                            visually_identical,
+                        },
+                        vec![cell],
+                    ));
+                }
+            }
+        }
+        if groups.is_empty() {
+            continue;
+        }
+        changed.push(ResolvedTokenChange {
+            name: (*name).clone(),
+            changes: groups
+                .into_iter()
+                .map(|(mut change, cells)| {
+                    change.when = collapse_when(&axes, cells);
+                    change
+                })
+                .collect(),
+        });
+    }
+
+    ResolvedDiffReport {
+        combinations: combinations.len(),
+        axes,
+        compared: names.len(),
+        changed,
+    }
+}
+
+/// Resolve every variable of `graph` in each of `combinations` and follow the
+/// winners' alias chains; `None` where no token of the variable matches.
+fn resolve_variables(
+    graph: &TokenGraph,
+    combinations: &[ModeCombination],
+) -> HashMap<String, Vec<Option<Resolution>>> {
+    let contexts: Vec<ResolutionContext> =
+        combinations.iter().map(ModeCombination::context).collect();
+    cascade::resolve_variables(graph, &contexts)
+        .into_iter()
+        .map(|(key, winners)| {
+            let resolutions = winners
+                .into_iter()
+                .zip(&contexts)
+                .map(|(winner, ctx)| {
+                    let winner = winner?;
+                    let hops = alias_hops(graph, winner, &ctx.mode_sets);
+                    let terminal = hops.last().copied().unwrap_or(winner);
+                    Some(Resolution {
+                        chain: std::iter::once(key.clone())
+                            .chain(hops.iter().map(|h| variable_key(h)))
+                            .collect(),
+                        value: terminal.raw.get("value").cloned(),
+                    })
+                })
+                .collect();
+            (key, resolutions)
+        })
+        .collect()
+}
+
+/// The last variable both chains share, else the first of either.
+fn change_cause(old: &[String], new: &[String]) -> String {
+    old.iter()
+        .zip(new)
+        .take_while(|(a, b)| a == b)
+        .last()
+        .map(|(a, _)| a)
+        .or(new.first())
+        .or(old.first())
+        .cloned()
+        .unwrap_or_default()
+}
+
 // ── Token pairing ───────────────────────────────────────────────────────────
 
 /// Pair tokens across old and new graphs.
@@ -509,16 +748,32 @@ fn diff_recursive(old: &Value, new: &Value, prefix: String, out: &mut Vec<Proper
         }
         _ => {
             // Leaf-level change (different types or different values).
+            let (delta_e, visually_identical) = color_delta(old, new);
             out.push(PropertyChange {
                 path: prefix,
                 change_type: ChangeType::Updated,
                 new_value: Some(new.clone()),
                 original_value: Some(old.clone()),
+                delta_e,
+                visually_identical,
             });
         }
     }
 }
 
+/// CIEDE2000 difference (rounded to two decimals) and visual identity, when
+/// both values are color literals.
+fn color_delta(old: &Value, new: &Value) -> (Option<f64>, bool) {
+    let colors = old
+        .as_str()
+        .and_then(Color::parse)
+        .zip(new.as_str().and_then(Color::parse));
+    (
+        colors.map(|(a, b)| (a.delta_e(b) * 100.0).round() / 100.0),
+        colors.is_some_and(|(a, b)| a.is_visually_identical(b)),
+    )
+}
+
 /// Record a wholly-new subtree as leaf-level Added changes (per spec/diff.md's
 /// recurse-to-leaf-level rule), rather than one Added change for the whole value.
 fn record_added(value: &Value, prefix: String, out: &mut Vec<PropertyChange>) {
@@ -537,6 +792,8 @@ fn record_added(value: &Value, prefix: String, out: &mut Vec<PropertyChange>) {
             change_type: ChangeType::Added,
             new_value: Some(value.clone()),
             original_value: None,
+            delta_e: None,
+            visually_identical: false,
         });
     }
 }
@@ -559,6 +816,8 @@ fn record_deleted(value: &Value, prefix: String, out: &mut Vec<PropertyChange>)
             change_type: ChangeType::Deleted,
             new_value: None,
             original_value: Some(value.clone()),
+            delta_e: None,
+            visually_identical: false,
         });
     }
 }
@@ -868,6 +1127,25 @@ mod tests {
             .any(|c| c.path == "c" && c.change_type == ChangeType::Added));
     }
 
+    #[test]
+    fn color_updates_carry_perceptual_difference() {
+        let changes = diff_properties(
+            &json!({"value": "rgb(110, 110, 110)", "a": "#fff", "b": "12px"}),
+            &json!({"value": "#6e6e6e", "a": "#000", "b": "14px"}),
+        );
+        let by_path = |p: &str| changes.iter().find(|c| c.path == p).unwrap();
+        // Same color, different spelling.
+        assert_eq!(by_path("value").delta_e, Some(0.0));
+        assert!(by_path("value").visually_identical);
+        assert_eq!(by_path("a").delta_e, Some(100.0));
+        assert!(!by_path("a").visually_identical);
+        assert_eq!(by_path("b").delta_e, None);
+
+        let json = serde_json::to_value(by_path("b")).unwrap();
+        assert!(json.get("delta_e").is_none());
+        assert!(json.get("visually_identical").is_none());
+    }
+
     // ── Full pipeline ───────────────────────────────────────────────────
 
     #[test]
@@ -970,4 +1248,93 @@ mod tests {
             "identical graphs should produce empty diff"
         );
     }
+
+    // ── Resolved diff ───────────────────────────────────────────────────
+
+    /// A light/dark `blue-900` and `indigo-900` palette pair, with
+    /// `accent-color-900` aliasing the set `accent_target` names and
+    /// `accent-background-color` aliasing `accent-color-900`.
+    fn cascade_graph(blue_dark: &str, indigo_dark: &str, accent_target: &str) -> TokenGraph {
+        let palette = |family: &str, scheme: &str, value: &str| {
+            json!({"name": {"property": format!("{family}-900"), "colorScheme": scheme},
+                   "uuid": format!("{family}-{scheme}"), "set_uuid": family, "value": value})
+        };
+        make_graph(vec![
+            ("blue-light", palette("blue", "light", "#3b63fb")),
+            ("blue-dark", palette("blue", "dark", blue_dark)),
+            ("indigo-light", palette("indigo", "light", "#3b63fb")),
+            ("indigo-dark", palette("indigo", "dark", indigo_dark)),
+            (
+                "accent",
+                json!({"name": {"property": "accent-color-900"}, "uuid": "accent",
+                       "$ref": accent_target}),
+            ),
+            (
+                "accent-bg",
+                json!({"name": {"property": "accent-background-color"}, "uuid": "accent-bg",
+                       "$ref": "accent"}),
+            ),
+        ])
+        .with_mode_sets(vec![crate::graph::ModeSetRecord {
+            file: PathBuf::from("color-scheme.json"),
+            name: "colorScheme".to_string(),
+            modes: vec!["light".to_string(), "dark".to_string()],
+            default_mode: "light".to_string(),
+        }])
+    }
+
+    #[test]
+    fn resolved_diff_propagates_palette_edits_through_aliases() {
+        let old = cascade_graph("#5c7bfa", "#5c7bfa", "blue");
+        let new = cascade_graph("#2f55e0", "#5c7bfa", "blue");
+        let report = resolved_diff(&old, &new, None);
+        assert_eq!(report.combinations, 2);
+        assert_eq!(report.compared, 4);
+
+        let names: Vec<&str> = report.changed.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["accent-background-color", "accent-color-900", "blue-900"]
+        );
+        let change = &report.changed[0].changes[0];
+        let dark: BTreeMap<String, String> =
+            [("colorScheme".to_string(), "dark".to_string())].into();
+        assert_eq!(change.when, vec![dark]);
+        assert_eq!(change.original_value, Some(json!("#5c7bfa")));
+        assert_eq!(change.new_value, Some(json!("#2f55e0")));
+        assert_eq!(
+            change.new_chain,
+            vec!["accent-background-color", "accent-color-900", "blue-900"]
+        );
+        assert_eq!(change.cause, "blue-900");
+        assert!(change.delta_e.is_some());
+
+        let filter = query::parse("property=blue-900").unwrap();
+        let scoped = resolved_diff(&old, &new, Some(&filter));
+        assert_eq!(scoped.changed.len(), 1);
+        assert_eq!(scoped.changed[0].name, "blue-900");
+    }
+
+    #[test]
+    fn resolved_diff_ignores_repoints_to_equal_values() {
+        let old = cascade_graph("#5c7bfa", "#5c7bfa", "blue");
+        let equal = cascade_graph("#5c7bfa", "#5c7bfa", "indigo");
+        assert!(resolved_diff(&old, &equal, None).is_empty());
+        // The raw diff still sees the re-pointed alias.
+        assert_eq!(semantic_diff(&old, &equal).updated.len(), 1);
+
+        let different = cascade_graph("#5c7bfa", "#4046ca", "indigo");
+        let report = resolved_diff(&old, &different, None);
+        let names: Vec<&str> = report.changed.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["accent-background-color", "accent-color-900", "indigo-900"]
+        );
+        let change = &report.changed[0].changes[0];
+        assert_eq!(change.cause, "accent-color-900");
+        assert_eq!(
+            change.original_chain,
+            vec!["accent-background-color", "accent-color-900", "blue-900"]
+        );
+    }
 }
@@ -0,0 +1,1022 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! W3C Design Tokens Community Group (DTCG) import and export.
+//!
+//! # Export ([`to_dtcg`])
+//!
+//! One top-level group per source file (`color-palette.tokens.json` →
+//! `"color-palette"`), one DTCG token per variable (legacy key). The record
+//! matching every mode set's default supplies `$value`; the other mode records
+//! ride along as `variants` in `$extensions`, so nothing is lost:
+//!
+//! ```json
+//! {
+//!   "color-palette": {
+//!     "blue-100": {
+//!       "$type": "color",
+//!       "$value": "rgb(245, 249, 255)",
+//!       "$extensions": {
+//!         "com.adobe.design-data": {
+//!           "uuid": "aaa",
+//!           "name": { "property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "light" },
+//!           "$schema": ".../color.json",
+//!           "modes": { "colorScheme": "light" },
+//!           "variants": [
+//!             { "modes": { "colorScheme": "dark" }, "uuid": "bbb", "$value": "rgb(14, 23, 63)" }
+//!           ]
+//!         }
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! `$ref` aliases become `{group.token}` references (the original UUID is kept in
+//! the extension, since a per-mode `$ref` may point at a specific mode record),
+//! and inline `{legacy-key}` references inside composite values are rewritten to
+//! full DTCG paths. Values are passed through as strings, the form Style
+//! Dictionary v4 and Tokens Studio consume.
+//!
+//! # Import ([`from_dtcg`])
+//!
+//! Tokens carrying the extension are rebuilt verbatim. Plain DTCG tokens from
+//! other tools get a fresh UUID, a name object decomposed from their path, a
+//! `$schema` derived from `$type` (inherited from parent groups), and `$ref`
+//! aliases resolved against the imported set. The top-level group becomes the
+//! output file.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::cascade::variable_key;
+use crate::color::Color;
+use crate::generate::schema_type;
+use crate::graph::{TokenGraph, TokenRecord};
+use crate::legacy::VerifyDifference;
+use crate::naming;
+use crate::CoreError;
+
+/// `$extensions` key for design-data round-trip metadata.
+pub const EXTENSION_KEY: &str = "com.adobe.design-data";
+
+const TOKEN_TYPES_BASE: &str =
+    "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/";
+
+/// Record fields with a DTCG-native home (or rebuilt from one) that are not
+/// copied into the extension.
+const NATIVE_FIELDS: &[&str] = &["value", "$ref", "description"];
+
+// ── Summary ───────────────────────────────────────────────────────────────────
+
+/// Summary statistics from a DTCG import.
+#[derive(Debug, Default)]
+pub struct DtcgSummary {
+    /// DTCG tokens read.
+    pub tokens: usize,
+    /// Cascade records produced (one per token plus one per mode variant).
+    pub records: usize,
+    /// Records rebuilt from `$extensions` round-trip metadata.
+    pub from_extensions: usize,
+    /// Records created from plain DTCG tokens (fresh UUIDs).
+    pub generated_uuids: usize,
+    /// Alias references whose target was not in the imported set. The raw
+    /// reference is written to `$ref` so SPEC-014 reports it downstream.
+    pub dangling_aliases: usize,
+    /// Output files written by [`convert_file`].
+    pub files_written: usize,
+}
+
+// ── Type mapping ──────────────────────────────────────────────────────────────
+
+/// DTCG `$type` for a token-type schema stem. Types with no DTCG equivalent
+/// (alignment, font-style, text-transform) have none.
+fn dtcg_type(schema_type: &str) -> Option<&'static str> {
+    Some(match schema_type {
+        "color" => "color",
+        "dimension" | "font-size" => "dimension",
+        "font-family" => "fontFamily",
+        "font-weight" => "fontWeight",
+        "opacity" | "multiplier" | "gradient-stop" | "angle" => "number",
+        "drop-shadow" => "shadow",
+        "typography" => "typography",
+        "duration" => "duration",
+        "easing" => "cubicBezier",
+        "motion" => "transition",
+        _ => return None,
+    })
+}
+
+/// Token-type `$schema` URL for a DTCG `$type` (plain imports only).
+fn schema_for_type(dtcg_type: Option<&str>) -> String {
+    let stem = match dtcg_type {
+        Some("color") => "color",
+        Some("dimension") => "dimension",
+        Some("fontFamily") => "font-family",
+        Some("fontWeight") => "font-weight",
+        Some("number") => "multiplier",
+        Some("shadow") => "drop-shadow",
+        Some("typography") => "typography",
+        Some("duration") => "duration",
+        Some("cubicBezier") => "easing",
+        Some("transition") => "motion",
+        _ => "token",
+    };
+    format!("{TOKEN_TYPES_BASE}{stem}.json")
+}
+
+// ── Export ────────────────────────────────────────────────────────────────────
+
+/// Output group for a record: its source file name without `.tokens.json`/`.json`.
+fn group_name(rec: &TokenRecord) -> String {
+    let file = rec
+        .file
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("tokens");
+    file.strip_suffix(".tokens.json")
+        .or_else(|| file.strip_suffix(".json"))
+        .unwrap_or(file)
+        .to_string()
+}
+
+/// DTCG token name for a variable key. `.` is the DTCG path separator and
+/// `{`/`}` delimit references, so they cannot appear in a name.
+fn token_name(key: &str) -> String {
+    key.replace(['.', '{', '}'], "_")
+}
+
+/// Rewrite `{ref}` occurrences inside string values using `map`; unknown
+/// references are left untouched.
+fn rewrite_refs(value: &Value, map: &dyn Fn(&str) -> Option<String>) -> Value {
+    match value {
+        Value::String(s) => Value::String(naming::replace_inline_refs(s, |inner| {
+            map(inner).map(|to| format!("{{{to}}}"))
+        })),
+        Value::Array(items) => Value::Array(items.iter().map(|v| rewrite_refs(v, map)).collect()),
+        Value::Object(obj) => Value::Object(
+            obj.iter()
+                .map(|(k, v)| (k.clone(), rewrite_refs(v, map)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Records in file order. The name breaks ties, so graphs built without
+/// per-file indices (`TokenGraph::from_pairs`) still export deterministically.
+fn records_in_file_order(graph: &TokenGraph) -> Vec<&TokenRecord> {
+    let mut records: Vec<&TokenRecord> = graph.tokens.values().collect();
+    records.sort_by(|a, b| {
+        a.file
+            .cmp(&b.file)
+            .then(a.index.cmp(&b.index))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    records
+}
+
+/// Convert `graph` into a DTCG document (see the module docs for the shape).
+///
+/// Groups, tokens, and variants follow source file and array order, so the
+/// output is deterministic for a given dataset.
+pub fn to_dtcg(graph: &TokenGraph) -> Value {
+    let records = records_in_file_order(graph);
+
+    let mode_defaults: HashMap<&str, &str> = graph
+        .mode_sets
+        .iter()
+        .map(|ms| (ms.name.as_str(), ms.default_mode.as_str()))
+        .collect();
+
+    // (group, key) → records, in first-seen order.
+    let mut order: Vec<(String, String)> = Vec::new();
+    let mut variables: HashMap<(String, String), Vec<&TokenRecord>> = HashMap::new();
+    for rec in &records {
+        let id = (group_name(rec), token_name(&variable_key(rec)));
+        variables
+            .entry(id.clone())
+            .or_insert_with(|| {
+                order.push(id);
+                Vec::new()
+            })
+            .push(rec);
+    }
+
+    // uuid/set_uuid → DTCG path, and legacy key → path, for reference rewriting.
+    let mut uuid_paths: HashMap<&str, String> = HashMap::new();
+    let mut key_paths: HashMap<String, String> = HashMap::new();
+    for (group, key) in &order {
+        let path = format!("{group}.{key}");
+        for rec in &variables[&(group.clone(), key.clone())] {
+            for field in ["uuid", "set_uuid"] {
+                if let Some(id) = rec.raw.get(field).and_then(Value::as_str) {
+                    uuid_paths.insert(id, path.clone());
+                }
+            }
+            key_paths
+                .entry(variable_key(rec))
+                .or_insert_with(|| path.clone());
+        }
+    }
+    let inline_map = |inner: &str| key_paths.get(inner).cloned();
+
+    let terminal_type = |rec: &TokenRecord| -> Option<&'static str> {
+        let mut current = rec;
+        let mut seen = HashSet::new();
+        while let Some(target) = current.raw.get("$ref").and_then(Value::as_str) {
+            if !seen.insert(target) {
+                return None;
+            }
+            // A set_uuid target resolves to its first member; any member
+            // carries the type.
+            current = graph.resolve_alias_key(target)?;
+        }
+        dtcg_type(schema_type(current.schema_url.as_deref()?)?)
+    };
+
+    let dtcg_value = |rec: &TokenRecord| -> Value {
+        if let Some(target) = rec.raw.get("$ref").and_then(Value::as_str) {
+            let path = uuid_paths
+                .get(target)
+                .cloned()
+                .unwrap_or_else(|| target.to_string());
+            return Value::String(format!("{{{path}}}"));
+        }
+        rec.raw
+            .get("value")
+            .map(|v| rewrite_refs(v, &inline_map))
+            .unwrap_or(Value::Null)
+    };
+
+    let mut doc = Map::new();
+    for (group, key) in &order {
+        let members = &variables[&(group.clone(), key.clone())];
+        let modes_of = |rec: &TokenRecord| -> Map<String, Value> {
+            rec.raw
+                .get("name")
+                .and_then(Value::as_object)
+                .map(|name| {
+                    name.iter()
+                        .filter(|(k, _)| mode_defaults.contains_key(k.as_str()))
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let base = members
+            .iter()
+            .find(|rec| {
+                modes_of(rec)
+                    .iter()
+                    .all(|(k, v)| v.as_str() == mode_defaults.get(k.as_str()).copied())
+            })
+            .unwrap_or(&members[0]);
+        let base_obj = base.raw.as_object().cloned().unwrap_or_default();
+        let base_modes = modes_of(base);
+
+        let mut ext = Map::new();
+        for (field, v) in &base_obj {
+            if !NATIVE_FIELDS.contains(&field.as_str()) {
+                ext.insert(field.clone(), v.clone());
+            }
+        }
+        if let Some(target) = base_obj.get("$ref") {
+            ext.insert("$ref".into(), target.clone());
+        }
+        if !base_modes.is_empty() {
+            ext.insert("modes".into(), Value::Object(base_modes.clone()));
+        }
+
+        let variants: Vec<Value> = members
+            .iter()
+            .filter(|rec| !std::ptr::eq(**rec, *base))
+            .map(|rec| {
+                let obj = rec.raw.as_object().cloned().unwrap_or_default();
+                let modes = modes_of(rec);
+                let mut entry = Map::new();
+                entry.insert("modes".into(), Value::Object(modes.clone()));
+                for (field, v) in &obj {
+                    if matches!(field.as_str(), "value" | "name") {
+                        continue;
+                    }
+                    if base_obj.get(field) != Some(v) || field == "$ref" || field == "uuid" {
+                        entry.insert(field.clone(), v.clone());
+                    }
+                }
+                for field in base_obj.keys() {
+                    if !obj.contains_key(field) && !matches!(field.as_str(), "value" | "$ref") {
+                        entry.insert(field.clone(), Value::Null);
+                    }
+                }
+                let expected = variant_name(base_obj.get("name"), &base_modes, &modes);
+                if obj.get("name") != expected.as_ref() {
+                    entry.insert(
+                        "name".into(),
+                        obj.get("name").cloned().unwrap_or(Value::Null),
+                    );
+                }
+                entry.insert("$value".into(), dtcg_value(rec));
+                Value::Object(entry)
+            })
+            .collect();
+        if !variants.is_empty() {
+            ext.insert("variants".into(), Value::Array(variants));
+        }
+
+        let mut token = Map::new();
+        if let Some(t) = terminal_type(base) {
+            token.insert("$type".into(), t.into());
+        }
+        token.insert("$value".into(), dtcg_value(base));
+        if let Some(desc) = base_obj.get("description") {
+            token.insert("$description".into(), desc.clone());
+        }
+        if let Some(lifecycle) = base_obj.get("lifecycle").and_then(Value::as_object) {
+            if lifecycle.contains_key("deprecatedIn") {
+                let deprecated = lifecycle
+                    .get("deprecatedComment")
+                    .cloned()
+                    .unwrap_or(Value::Bool(true));
+                token.insert("$deprecated".into(), deprecated);
+            }
+        }
+        let mut extensions = Map::new();
+        extensions.insert(EXTENSION_KEY.into(), Value::Object(ext));
+        token.insert("$extensions".into(), Value::Object(extensions));
+
+        doc.entry(group.clone())
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .expect("group is an object")
+            .insert(key.clone(), Value::Object(token));
+    }
+    Value::Object(doc)
+}
+
+/// The name a variant would have if it differed from the base only in its
+/// mode-set fields. Mode fields the base carries are replaced in place.
+fn variant_name(
+    base_name: Option<&Value>,
+    base_modes: &Map<String, Value>,
+    modes: &Map<String, Value>,
+) -> Option<Value> {
+    let base = base_name?.as_object()?;
+    let mut name = Map::new();
+    for (k, v) in base {
+        if base_modes.contains_key(k) {
+            if let Some(mv) = modes.get(k) {
+                name.insert(k.clone(), mv.clone());
+            }
+        } else {
+            name.insert(k.clone(), v.clone());
+        }
+    }
+    for (k, v) in modes {
+        name.entry(k.clone()).or_insert_with(|| v.clone());
+    }
+    Some(Value::Object(name))
+}
+
+// ── Import ────────────────────────────────────────────────────────────────────
+
+/// One DTCG token found while walking a document.
+struct FoundToken<'a> {
+    path: Vec<String>,
+    token: &'a Map<String, Value>,
+    inherited_type: Option<String>,
+}
+
+fn collect_tokens<'a>(
+    group: &'a Map<String, Value>,
+    path: &mut Vec<String>,
+    inherited_type: Option<String>,
+    out: &mut Vec<FoundToken<'a>>,
+) {
+    let group_type = group
+        .get("$type")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .or(inherited_type);
+    for (key, child) in group {
+        if key.starts_with('$') {
+            continue;
+        }
+        let Some(obj) = child.as_object() else {
+            continue;
+        };
+        path.push(key.clone());
+        if obj.contains_key("$value") {
+            out.push(FoundToken {
+                path: path.clone(),
+                token: obj,
+                inherited_type: group_type.clone(),
+            });
+        } else {
+            collect_tokens(obj, path, group_type.clone(), out);
+        }
+        path.pop();
+    }
+}
+
+/// The target path of a whole-value alias (`"{a.b}"`), if `value` is one.
+fn alias_path(value: &Value) -> Option<&str> {
+    let s = value.as_str()?;
+    let inner = s.strip_prefix('{')?.strip_suffix('}')?;
+    (!inner.contains(['{', '}'])).then_some(inner)
+}
+
+/// Normalize a DTCG 2025 structured value (`{colorSpace, components}`,
+/// `{value, unit}`) to the string form cascade tokens use. Other values pass
+/// through.
+fn cascade_value(value: &Value) -> Value {
+    let Some(obj) = value.as_object() else {
+        return value.clone();
+    };
+    if let (Some(n), Some(unit)) = (obj.get("value"), obj.get("unit").and_then(Value::as_str)) {
+        if obj.len() == 2 && n.is_number() {
+            return Value::String(format!("{n}{unit}"));
+        }
+    }
+    if obj.contains_key("colorSpace") {
+        if let Some(hex) = obj.get("hex").and_then(Value::as_str) {
+            let alpha = obj.get("alpha").and_then(Value::as_f64).unwrap_or(1.0);
+            if alpha >= 1.0 {
+                return Value::String(hex.to_string());
+            }
+        }
+        if obj.get("colorSpace").and_then(Value::as_str) == Some("srgb") {
+            let comps: Option<Vec<f64>> = obj
+                .get("components")
+                .and_then(Value::as_array)
+                .map(|c| c.iter().filter_map(Value::as_f64).collect());
+            if let Some([r, g, b]) = comps.as_deref() {
+                let byte = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+                let alpha = obj.get("alpha").and_then(Value::as_f64).unwrap_or(1.0);
+                return Value::String(if alpha >= 1.0 {
+                    format!("rgb({}, {}, {})", byte(*r), byte(*g), byte(*b))
+                } else {
+                    format!("rgba({}, {}, {}, {alpha})", byte(*r), byte(*g), byte(*b))
+                });
+            }
+        }
+        // Wide-gamut and perceptual spaces normalise through the color model.
+        let space = obj.get("colorSpace").and_then(Value::as_str);
+        let comps: Option<Vec<f64>> = obj
+            .get("components")
+            .and_then(Value::as_array)
+            .map(|c| c.iter().filter_map(Value::as_f64).collect());
+        if let (Some(space), Some([c0, c1, c2])) = (space, comps.as_deref()) {
+            let alpha = obj.get("alpha").and_then(Value::as_f64).unwrap_or(1.0);
+            if let Some(color) = Color::from_components(space, [*c0, *c1, *c2], alpha) {
+                return Value::String(color.to_css());
+            }
+        }
+    }
+    Value::Object(
+        obj.iter()
+            .map(|(k, v)| (k.clone(), cascade_value(v)))
+            .collect(),
+    )
+}
+
+/// Parse a DTCG document into cascade records, grouped by output file stem
+/// (the top-level group; `"tokens"` for ungrouped tokens). Files and records
+/// follow document order.
+pub fn from_dtcg(doc: &Value) -> Result<(BTreeMap<String, Vec<Value>>, DtcgSummary), CoreError> {
+    let root = doc
+        .as_object()
+        .ok_or_else(|| CoreError::ParseError("DTCG document is not a JSON object".into()))?;
+    let mut found = Vec::new();
+    collect_tokens(root, &mut Vec::new(), None, &mut found);
+
+    let mut summary = DtcgSummary {
+        tokens: found.len(),
+        ..DtcgSummary::default()
+    };
+
+    // Pass 1: identity per path — the uuid a `$ref` to it should carry, and the
+    // legacy key inline references resolve to.
+    struct Identity {
+        uuid: String,
+        legacy_key: String,
+    }
+    let mut identities: HashMap<String, Identity> = HashMap::new();
+    for t in &found {
+        let path = t.path.join(".");
+        let identity = match extension(t.token) {
+            Some(ext) => Identity {
+                uuid: ext
+                    .get("set_uuid")
+                    .or_else(|| ext.get("uuid"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                legacy_key: ext
+                    .get("name")
+                    .and_then(naming::extract_legacy_key)
+                    .unwrap_or_else(|| t.path.last().cloned().unwrap_or_default()),
+            },
+            None => Identity {
+                uuid: Uuid::new_v4().to_string(),
+                legacy_key: plain_key(&t.path),
+            },
+        };
+        identities.insert(path, identity);
+    }
+    let inline_map = |inner: &str| identities.get(inner).map(|i| i.legacy_key.clone());
+
+    // Pass 2: records.
+    let mut files: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for t in &found {
+        let file = if t.path.len() > 1 {
+            t.path[0].clone()
+        } else {
+            "tokens".to_string()
+        };
+        let out = files.entry(file).or_default();
+        match extension(t.token) {
+            Some(ext) => {
+                let base = rebuild_record(ext, t.token, &inline_map);
+                let variants: Vec<Value> = ext
+                    .get("variants")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_object)
+                    .map(|v| rebuild_variant(&base, ext, v, &inline_map))
+                    .collect();
+                summary.from_extensions += 1 + variants.len();
+                out.push(base);
+                out.extend(variants);
+            }
+            None => {
+                let identity = &identities[&t.path.join(".")];
+                let mut rec = Map::new();
+                let key = &identity.legacy_key;
+                let name_obj = naming::parse_legacy_name(key, None);
+                let name = if naming::roundtrips(key, None) {
+                    serde_json::to_value(&name_obj)?
+                } else {
+                    serde_json::json!({ "property": key })
+                };
+                rec.insert("name".into(), name);
+                let ty = t
+                    .token
+                    .get("$type")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .or_else(|| t.inherited_type.clone());
+                let value = &t.token["$value"];
+                if let Some(target) = alias_path(value) {
+                    rec.insert(
+                        "$schema".into(),
+                        format!("{TOKEN_TYPES_BASE}alias.json").into(),
+                    );
+                    let id = match identities.get(target) {
+                        Some(i) => i.uuid.clone(),
+                        None => {
+                            summary.dangling_aliases += 1;
+                            target.to_string()
+                        }
+                    };
+                    rec.insert("$ref".into(), id.into());
+                } else {
+                    rec.insert("$schema".into(), schema_for_type(ty.as_deref()).into());
+                    rec.insert(
+                        "value".into(),
+                        rewrite_refs(&cascade_value(value), &inline_map),
+                    );
+                }
+                if let Some(desc) = t.token.get("$description") {
+                    rec.insert("description".into(), desc.clone());
+                }
+                rec.insert("uuid".into(), identity.uuid.clone().into());
+                summary.generated_uuids += 1;
+                out.push(Value::Object(rec));
+            }
+        }
+    }
+    summary.records = files.values().map(Vec::len).sum();
+    Ok((files, summary))
+}
+
+fn extension(token: &Map<String, Value>) -> Option<&Map<String, Value>> {
+    token.get("$extensions")?.get(EXTENSION_KEY)?.as_object()
+}
+
+/// Legacy key for a plain DTCG token: its path below the file group, joined
+/// with `-`.
+fn plain_key(path: &[String]) -> String {
+    let below = if path.len() > 1 { &path[1..] } else { path };
+    below.join("-")
+}
+
+/// Value fields (`value` or `$ref`) for a `$value`, given the extension's
+/// original `$ref` when the token was an alias.
+fn value_fields(
+    dtcg_value: Option<&Value>,
+    original_ref: Option<&Value>,
+    inline_map: &dyn Fn(&str) -> Option<String>,
+) -> Vec<(String, Value)> {
+    match (original_ref, dtcg_value) {
+        (Some(r), _) if !r.is_null() => vec![("$ref".into(), r.clone())],
+        (_, Some(v)) => vec![("value".into(), rewrite_refs(v, inline_map))],
+        _ => Vec::new(),
+    }
+}
+
+/// Rebuild the base record of an exported token from its extension.
+fn rebuild_record(
+    ext: &Map<String, Value>,
+    token: &Map<String, Value>,
+    inline_map: &dyn Fn(&str) -> Option<String>,
+) -> Value {
+    let mut rec = Map::new();
+    for field in ["name", "$schema"] {
+        if let Some(v) = ext.get(field) {
+            rec.insert(field.into(), v.clone());
+        }
+    }
+    for (k, v) in value_fields(token.get("$value"), ext.get("$ref"), inline_map) {
+        rec.insert(k, v);
+    }
+    if let Some(desc) = token.get("$description") {
+        rec.insert("description".into(), desc.clone());
+    }
+    for (field, v) in ext {
+        if matches!(
+            field.as_str(),
+            "name" | "$schema" | "$ref" | "modes" | "variants"
+        ) {
+            continue;
+        }
+        rec.insert(field.clone(), v.clone());
+    }
+    Value::Object(rec)
+}
+
+/// Rebuild a variant record: the base record with the variant's modes and
+/// field differences applied (`null` removes a field).
+fn rebuild_variant(
+    base: &Value,
+    ext: &Map<String, Value>,
+    variant: &Map<String, Value>,
+    inline_map: &dyn Fn(&str) -> Option<String>,
+) -> Value {
+    let base = base.as_object().cloned().unwrap_or_default();
+    let empty = Map::new();
+    let base_modes = ext
+        .get("modes")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let modes = variant
+        .get("modes")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    let mut rec = Map::new();
+    for (field, v) in &base {
+        match field.as_str() {
+            "name" => {
+                let name = match variant.get("name") {
+                    Some(explicit) => explicit.clone(),
+                    None => variant_name(Some(v), base_modes, modes).unwrap_or_else(|| v.clone()),
+                };
+                rec.insert("name".into(), name);
+            }
+            "value" | "$ref" => {
+                for (k, val) in value_fields(variant.get("$value"), variant.get("$ref"), inline_map)
+                {
+                    rec.insert(k, val);
+                }
+            }
+            _ => match variant.get(field) {
+                Some(Value::Null) => {}
+                Some(other) => {
+                    rec.insert(field.clone(), other.clone());
+                }
+                None => {
+                    rec.insert(field.clone(), v.clone());
+                }
+            },
+        }
+    }
+    for (field, v) in variant {
+        if matches!(field.as_str(), "modes" | "$value" | "name" | "$ref") || v.is_null() {
+            continue;
+        }
+        rec.entry(field.clone()).or_insert_with(|| v.clone());
+    }
+    if !rec.contains_key("value") && !rec.contains_key("$ref") {
+        for (k, val) in value_fields(variant.get("$value"), variant.get("$ref"), inline_map) {
+            rec.insert(k, val);
+        }
+    }
+    Value::Object(rec)
+}
+
+/// Read a DTCG file and write one cascade `<group>.tokens.json` per top-level
+/// group into `output_dir`.
+pub fn convert_file(input: &Path, output_dir: &Path) -> Result<DtcgSummary, CoreError> {
+    let text = std::fs::read_to_string(input)?;
+    let doc: Value = serde_json::from_str(&text)
+        .map_err(|e| CoreError::ParseError(format!("{}: {e}", input.display())))?;
+    let (files, mut summary) = from_dtcg(&doc)?;
+    std::fs::create_dir_all(output_dir)?;
+    for (stem, records) in files {
+        let out_path = output_dir.join(format!("{stem}.tokens.json"));
+        let out_text = serde_json::to_string_pretty(&Value::Array(records))?;
+        std::fs::write(&out_path, out_text + "\n")?;
+        summary.files_written += 1;
+    }
+    Ok(summary)
+}
+
+// ── Roundtrip verification ────────────────────────────────────────────────────
+
+/// Export `graph` to DTCG, import it back, and compare every record by UUID
+/// against the original (field order is ignored).
+///
+/// Returns the differences; an empty `Vec` means the roundtrip is lossless.
+/// `file` is the original file's group name and `token` the record UUID.
+pub fn roundtrip_verify(graph: &TokenGraph) -> Result<Vec<VerifyDifference>, CoreError> {
+    let (files, _) = from_dtcg(&to_dtcg(graph))?;
+    let mut rebuilt: HashMap<String, (String, Value)> = HashMap::new();
+    for (stem, records) in files {
+        for rec in records {
+            if let Some(uuid) = rec.get("uuid").and_then(Value::as_str) {
+                rebuilt.insert(uuid.to_string(), (stem.clone(), rec.clone()));
+            }
+        }
+    }
+
+    let records = records_in_file_order(graph);
+    let mut diffs = Vec::new();
+    for rec in records {
+        let file = group_name(rec);
+        let Some(uuid) = rec.raw.get("uuid").and_then(Value::as_str) else {
+            diffs.push(VerifyDifference {
+                file,
+                token: rec.name.clone(),
+                detail: "record has no uuid and cannot be matched".into(),
+            });
+            continue;
+        };
+        let Some((out_file, out)) = rebuilt.remove(uuid) else {
+            diffs.push(VerifyDifference {
+                file,
+                token: uuid.to_string(),
+                detail: "record missing after roundtrip".into(),
+            });
+            continue;
+        };
+        if out_file != file {
+            diffs.push(VerifyDifference {
+                file: file.clone(),
+                token: uuid.to_string(),
+                detail: format!("record moved to {out_file}"),
+            });
+        }
+        let (Some(a), Some(b)) = (rec.raw.as_object(), out.as_object()) else {
+            continue;
+        };
+        let fields: BTreeMap<&String, ()> = a.keys().chain(b.keys()).map(|k| (k, ())).collect();
+        for field in fields.keys() {
+            if a.get(*field) != b.get(*field) {
+                diffs.push(VerifyDifference {
+                    file: file.clone(),
+                    token: uuid.to_string(),
+                    detail: format!(
+                        "{field} mismatch: {:?} vs {:?}",
+                        a.get(*field),
+                        b.get(*field)
+                    ),
+                });
+            }
+        }
+    }
+    let mut extra: Vec<(String, String)> = rebuilt
+        .into_iter()
+        .map(|(uuid, (file, _))| (file, uuid))
+        .collect();
+    extra.sort();
+    for (file, uuid) in extra {
+        diffs.push(VerifyDifference {
+            file,
+            token: uuid,
+            detail: "extra record after roundtrip".into(),
+        });
+    }
+    Ok(diffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::graph::ModeSetRecord;
+
+    const COLOR: &str =
+        "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/color.json";
+    const ALIAS: &str =
+        "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json";
+
+    fn sample_graph() -> TokenGraph {
+        let records = [
+            (
+                "palette.tokens.json",
+                json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "dark"},
+                "$schema": COLOR, "value": "rgb(14, 23, 63)", "uuid": "blue-dark", "set_uuid": "blue-set"}),
+            ),
+            (
+                "palette.tokens.json",
+                json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "light"},
+                "$schema": COLOR, "value": "rgb(245, 249, 255)", "uuid": "blue-light", "set_uuid": "blue-set",
+                "lifecycle": {"deprecatedIn": "13.0.0", "deprecatedComment": "Use accent"}}),
+            ),
+            (
+                "aliases.tokens.json",
+                json!({"name": {"property": "color", "colorRole": "accent", "legacyKey": "accent-color"},
+                "$schema": ALIAS, "$ref": "blue-set", "uuid": "accent", "description": "Accent"}),
+            ),
+            (
+                "aliases.tokens.json",
+                json!({"name": {"property": "shadow", "legacyKey": "card-shadow"},
+                "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/drop-shadow.json",
+                "value": [{"x": "0px", "y": "1px", "blur": "2px", "spread": "0px", "color": "{blue-100}"}],
+                "uuid": "shadow"}),
+            ),
+        ];
+        let pairs = records
+            .into_iter()
+            .enumerate()
+            .map(|(i, (file, raw))| (format!("{file}:{i}"), PathBuf::from(file), raw))
+            .collect();
+        TokenGraph::from_pairs(pairs).with_mode_sets(vec![ModeSetRecord {
+            file: PathBuf::from("color-scheme.json"),
+            name: "colorScheme".to_string(),
+            modes: vec!["light".to_string(), "dark".to_string()],
+            default_mode: "light".to_string(),
+        }])
+    }
+
+    #[test]
+    fn export_groups_by_file_with_default_mode_as_value() {
+        let doc = to_dtcg(&sample_graph());
+        let blue = &doc["palette"]["blue-100"];
+        assert_eq!(blue["$type"], "color");
+        assert_eq!(blue["$value"], "rgb(245, 249, 255)");
+        assert_eq!(blue["$deprecated"], "Use accent");
+        let ext = &blue["$extensions"][EXTENSION_KEY];
+        assert_eq!(ext["uuid"], "blue-light");
+        assert_eq!(ext["modes"], json!({"colorScheme": "light"}));
+        assert_eq!(
+            ext["variants"],
+            json!([{"modes": {"colorScheme": "dark"}, "uuid": "blue-dark", "lifecycle": null,
+                    "$value": "rgb(14, 23, 63)"}])
+        );
+    }
+
+    #[test]
+    fn export_rewrites_aliases_to_dtcg_paths() {
+        let doc = to_dtcg(&sample_graph());
+        let accent = &doc["aliases"]["accent-color"];
+        assert_eq!(accent["$value"], "{palette.blue-100}");
+        assert_eq!(accent["$type"], "color");
+        assert_eq!(accent["$description"], "Accent");
+        assert_eq!(accent["$extensions"][EXTENSION_KEY]["$ref"], "blue-set");
+        assert_eq!(
+            doc["aliases"]["card-shadow"]["$value"][0]["color"],
+            "{palette.blue-100}"
+        );
+    }
+
+    #[test]
+    fn roundtrip_is_lossless() {
+        let graph = sample_graph();
+        assert_eq!(roundtrip_verify(&graph).unwrap(), Vec::new());
+
+        let (files, summary) = from_dtcg(&to_dtcg(&graph)).unwrap();
+        assert_eq!(summary.records, 4);
+        assert_eq!(summary.generated_uuids, 0);
+        let shadow = &files["aliases"][1];
+        assert_eq!(shadow["value"][0]["color"], "{blue-100}");
+    }
+
+    #[test]
+    fn imports_plain_dtcg_with_inherited_types_and_aliases() {
+        let doc = json!({
+            "color": {
+                "$type": "color",
+                "blue": {"100": {"$value": {"colorSpace": "srgb", "components": [0, 0.5, 1]}}},
+                "accent": {"$value": "{color.blue.100}", "$description": "Brand"},
+                "broken": {"$value": "{color.missing}"}
+            },
+            "size": {"$value": {"value": 4, "unit": "px"}, "$type": "dimension"}
+        });
+        let (files, summary) = from_dtcg(&doc).unwrap();
+        assert_eq!(summary.tokens, 4);
+        assert_eq!(summary.generated_uuids, 4);
+        assert_eq!(summary.dangling_aliases, 1);
+
+        let color = &files["color"];
+        assert_eq!(color[0]["name"], json!({"property": "blue-100"}));
+        assert_eq!(color[0]["$schema"], COLOR);
+        assert_eq!(color[0]["value"], "rgb(0, 128, 255)");
+        assert_eq!(color[1]["$schema"], ALIAS);
+        assert_eq!(color[1]["$ref"], color[0]["uuid"]);
+        assert_eq!(color[1]["description"], "Brand");
+        assert_eq!(color[2]["$ref"], "color.missing");
+        assert_eq!(files["tokens"][0]["value"], "4px");
+    }
+
+    #[test]
+    fn imports_wide_gamut_colors_as_canonical_css() {
+        let doc = json!({
+            "color": {
+                "$type": "color",
+                "white": {"$value": {"colorSpace": "display-p3", "components": [1, 1, 1]}},
+                "red": {"$value": {"colorSpace": "display-p3", "components": [1, 0, 0]}},
+                "scrim": {"$value": {"colorSpace": "oklch", "components": [0, 0, 0], "alpha": 0.5}}
+            }
+        });
+        let (files, _) = from_dtcg(&doc).unwrap();
+        let color = &files["color"];
+        assert_eq!(color[0]["value"], "#ffffff");
+        assert!(color[1]["value"]
+            .as_str()
+            .unwrap()
+            .starts_with("color(srgb 1.0931 -0.2267"));
+        assert_eq!(color[2]["value"], "#00000080");
+    }
+
+    #[test]
+    fn motion_types_map_both_ways() {
+        let doc = json!({
+            "motion": {
+                "fast": {"$type": "duration", "$value": {"value": 130, "unit": "ms"}},
+                "ease": {"$type": "cubicBezier", "$value": [0.45, 0, 0.4, 1]},
+                "enter": {"$type": "transition", "$value": {
+                    "duration": "{motion.fast}", "delay": {"value": 0, "unit": "ms"},
+                    "timingFunction": "{motion.ease}"}}
+            }
+        });
+        let (files, _) = from_dtcg(&doc).unwrap();
+        let motion = &files["motion"];
+        assert!(motion[0]["$schema"]
+            .as_str()
+            .unwrap()
+            .ends_with("/duration.json"));
+        assert_eq!(motion[0]["value"], "130ms");
+        assert!(motion[1]["$schema"]
+            .as_str()
+            .unwrap()
+            .ends_with("/easing.json"));
+        assert_eq!(motion[1]["value"], json!([0.45, 0, 0.4, 1]));
+        assert!(motion[2]["$schema"]
+            .as_str()
+            .unwrap()
+            .ends_with("/motion.json"));
+        assert_eq!(motion[2]["value"]["delay"], "0ms");
+
+        assert_eq!(dtcg_type("duration"), Some("duration"));
+        assert_eq!(dtcg_type("easing"), Some("cubicBezier"));
+        assert_eq!(dtcg_type("motion"), Some("transition"));
+    }
+
+    #[test]
+    fn verify_reports_lossy_differences() {
+        let mut graph = sample_graph();
+        // A record without a uuid cannot be matched after the roundtrip.
+        graph
+            .tokens
+            .get_mut("aliases.tokens.json:3")
+            .unwrap()
+            .raw
+            .as_object_mut()
+            .unwrap()
+            .remove("uuid");
+        let diffs = roundtrip_verify(&graph).unwrap();
+        assert_eq!(diffs.len(), 1);
+        assert!(diffs[0].detail.contains("no uuid"));
+    }
+
+    #[test]
+    fn rejects_non_object_documents() {
+        assert!(from_dtcg(&json!([])).is_err());
+    }
+}
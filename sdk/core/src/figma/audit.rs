@@ -52,6 +52,7 @@ pub struct AuditReport {
     pub skipped_alias_unresolved: Vec<String>,
     pub skipped_unknown_schema: Vec<String>,
     pub skipped_unparseable_value: Vec<String>,
+    pub skipped_no_collection: Vec<String>,
     /// `legacyKey` → proposed Figma name override, seeded (empty) for every
     /// `generated_only` divergence — each one needs a human decision before
     /// 11k.5 can consume it.
@@ -142,6 +143,7 @@ pub fn audit_names(existing: &VariablesMeta, token_dir: &Path) -> Result<AuditRe
         skipped_alias_unresolved: summary.skipped_alias_unresolved,
         skipped_unknown_schema: summary.skipped_unknown_schema,
         skipped_unparseable_value: summary.skipped_unparseable_value,
+        skipped_no_collection: summary.skipped_no_collection,
         overrides,
     })
 }
@@ -12,98 +12,23 @@
 
 use super::types::FigmaColor;
 use super::FigmaError;
+use crate::color::Color;
 
 /// Parse a Spectrum token color value into Figma's `{ r, g, b, a }` format.
 ///
-/// Supported input formats:
-/// - `rgb(R, G, B)` where R, G, B are 0–255 integers
-/// - `rgba(R, G, B, A)` where A is a 0–1 float
-/// - `#RRGGBB`, `#RGB`, `#RRGGBBAA` hex
+/// Accepts every CSS color form [`Color::parse`] supports (hex, `rgb()`,
+/// `hsl()`, `oklch()`, `color(display-p3 …)`, …). Figma variables are sRGB,
+/// so wide-gamut values are clipped into the sRGB gamut.
 pub fn parse_color(value: &str) -> Result<FigmaColor, FigmaError> {
-    let s = value.trim();
-
-    if let Some(inner) = s.strip_prefix("rgba(").and_then(|s| s.strip_suffix(')')) {
-        let parts = parse_number_list(inner)?;
-        if parts.len() != 4 {
-            return Err(FigmaError::UnsupportedColorFormat(value.to_string()));
-        }
-        return Ok(FigmaColor {
-            r: parts[0] / 255.0,
-            g: parts[1] / 255.0,
-            b: parts[2] / 255.0,
-            a: parts[3],
-        });
-    }
-
-    if let Some(inner) = s.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')) {
-        let parts = parse_number_list(inner)?;
-        if parts.len() != 3 {
-            return Err(FigmaError::UnsupportedColorFormat(value.to_string()));
-        }
-        return Ok(FigmaColor {
-            r: parts[0] / 255.0,
-            g: parts[1] / 255.0,
-            b: parts[2] / 255.0,
-            a: 1.0,
-        });
-    }
-
-    if let Some(hex) = s.strip_prefix('#') {
-        return parse_hex(hex, value);
-    }
-
-    Err(FigmaError::UnsupportedColorFormat(value.to_string()))
-}
-
-fn parse_number_list(s: &str) -> Result<Vec<f64>, FigmaError> {
-    s.split(',')
-        .map(|p| {
-            p.trim()
-                .parse::<f64>()
-                .map_err(|_| FigmaError::UnsupportedColorFormat(s.to_string()))
-        })
-        .collect()
-}
-
-fn parse_hex(hex: &str, original: &str) -> Result<FigmaColor, FigmaError> {
-    let err = || FigmaError::UnsupportedColorFormat(original.to_string());
-    match hex.len() {
-        6 => {
-            let r = u8::from_str_radix(&hex[0..2], 16).map_err(|_| err())?;
-            let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| err())?;
-            let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| err())?;
-            Ok(FigmaColor {
-                r: r as f64 / 255.0,
-                g: g as f64 / 255.0,
-                b: b as f64 / 255.0,
-                a: 1.0,
-            })
-        }
-        3 => {
-            let r = u8::from_str_radix(&hex[0..1], 16).map_err(|_| err())?;
-            let g = u8::from_str_radix(&hex[1..2], 16).map_err(|_| err())?;
-            let b = u8::from_str_radix(&hex[2..3], 16).map_err(|_| err())?;
-            Ok(FigmaColor {
-                r: (r * 17) as f64 / 255.0,
-                g: (g * 17) as f64 / 255.0,
-                b: (b * 17) as f64 / 255.0,
-                a: 1.0,
-            })
-        }
-        8 => {
-            let r = u8::from_str_radix(&hex[0..2], 16).map_err(|_| err())?;
-            let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| err())?;
-            let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| err())?;
-            let a = u8::from_str_radix(&hex[6..8], 16).map_err(|_| err())?;
-            Ok(FigmaColor {
-                r: r as f64 / 255.0,
-                g: g as f64 / 255.0,
-                b: b as f64 / 255.0,
-                a: a as f64 / 255.0,
-            })
-        }
-        _ => Err(err()),
-    }
+    let c = Color::parse(value)
+        .ok_or_else(|| FigmaError::UnsupportedColorFormat(value.to_string()))?
+        .clamped();
+    Ok(FigmaColor {
+        r: c.r,
+        g: c.g,
+        b: c.b,
+        a: c.alpha,
+    })
 }
 
 #[cfg(test)]
@@ -167,9 +92,17 @@ mod tests {
         assert_color(&c, 10.0 / 255.0, 20.0 / 255.0, 30.0 / 255.0, 1.0);
     }
 
+    #[test]
+    fn css_color_4_forms() {
+        let c = parse_color("hsl(0, 100%, 50%)").unwrap();
+        assert_color(&c, 1.0, 0.0, 0.0, 1.0);
+        // Display P3 red is outside sRGB and clips to the nearest channel values.
+        let c = parse_color("color(display-p3 1 0 0)").unwrap();
+        assert_color(&c, 1.0, 0.0, 0.0, 1.0);
+    }
+
     #[test]
     fn unsupported_format_returns_error() {
-        assert!(parse_color("hsl(0, 100%, 50%)").is_err());
         assert!(parse_color("not-a-color").is_err());
         assert!(parse_color("#gg0000").is_err());
     }
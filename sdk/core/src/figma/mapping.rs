@@ -12,7 +12,9 @@
 //!
 //! Targets the `.Color theme` and `.Platform scale` collections, which use
 //! `{camelCasePrefix}/{kebab-case-token-name}` naming — matching legacy token
-//! names 1:1.
+//! names 1:1. Motion tokens (durations, easings and `motion-set`s keyed by
+//! `reducedMotion`) go to an optional `.Motion` collection; files without one
+//! report them as skipped.
 
 use std::collections::HashMap;
 use std::path::Path;
@@ -37,6 +39,9 @@ const FONT_SIZE: &str = "font-size.json";
 const FONT_STYLE: &str = "font-style.json";
 const FONT_WEIGHT: &str = "font-weight.json";
 const ALIAS: &str = "alias.json";
+const MOTION_SET: &str = "motion-set.json";
+const DURATION: &str = "duration.json";
+const EASING: &str = "easing.json";
 
 // Schemas we skip (composite types with no Figma Variable equivalent).
 const SKIP_SCHEMAS: &[&str] = &[
@@ -46,6 +51,7 @@ const SKIP_SCHEMAS: &[&str] = &[
     "multiplier.json",
     "alignment.json",
     "text-transform.json",
+    "motion.json",
 ];
 
 // ── Collection prefixes ──────────────────────────────────────────────────────
@@ -54,11 +60,14 @@ const COLOR_THEME_COLLECTION: &str = ".Color theme";
 const COLOR_THEME_PREFIX: &str = "colorTheme";
 const PLATFORM_SCALE_COLLECTION: &str = ".Platform scale";
 const PLATFORM_SCALE_PREFIX: &str = "platformScale";
+const MOTION_COLLECTION: &str = ".Motion";
+const MOTION_PREFIX: &str = "motion";
 
 // ── Mode name mapping ────────────────────────────────────────────────────────
 
 const COLOR_MODES: &[&str] = &["light", "dark", "wireframe"];
 const SCALE_MODES: &[&str] = &["desktop", "mobile"];
+const MOTION_MODES: &[&str] = &["no-preference", "reduce"];
 
 /// A structured summary of one Figma collection and its non-remote variables.
 #[derive(Debug)]
@@ -107,6 +116,8 @@ pub struct ExportSummary {
     pub skipped_alias_unresolved: Vec<String>,
     pub skipped_unknown_schema: Vec<String>,
     pub skipped_unparseable_value: Vec<String>,
+    /// Tokens whose target collection (e.g. `.Motion`) is not in the file.
+    pub skipped_no_collection: Vec<String>,
 }
 
 /// Build a Figma POST payload from legacy token source files.
@@ -135,8 +146,14 @@ pub fn build_export_payload(
             message: format!("collection '{PLATFORM_SCALE_COLLECTION}' not found in file"),
         })?;
 
+    // `.Motion` is optional: older files predate motion tokens.
+    let motion_col = find_collection(existing, MOTION_COLLECTION);
+
     let color_mode_ids = resolve_mode_ids(&color_col.modes, COLOR_MODES);
     let scale_mode_ids = resolve_mode_ids(&scale_col.modes, SCALE_MODES);
+    let motion_mode_ids = motion_col
+        .map(|c| resolve_mode_ids(&c.modes, MOTION_MODES))
+        .unwrap_or_default();
     let color_default_mode = &color_col.default_mode_id;
     let scale_default_mode = &scale_col.default_mode_id;
 
@@ -170,6 +187,54 @@ pub fn build_export_payload(
             continue;
         }
 
+        // Motion tokens, and aliases to them → .Motion.
+        let is_motion = is_motion_schema(schema)
+            || (schema.ends_with(ALIAS)
+                && alias_target_schema(token_entry, &all_tokens).is_some_and(is_motion_schema));
+        if is_motion {
+            let Some(motion_col) = motion_col else {
+                summary.skipped_no_collection.push(token_name.clone());
+                continue;
+            };
+            if schema.ends_with(MOTION_SET) {
+                process_scale_set_token(
+                    token_name,
+                    token_entry,
+                    &motion_col.id,
+                    MOTION_PREFIX,
+                    MOTION_MODES,
+                    &motion_mode_ids,
+                    &value_index,
+                    &existing_var_index,
+                    overrides,
+                    &mut variables,
+                    &mut mode_values,
+                    &mut summary,
+                );
+            } else {
+                // Durations become FLOAT milliseconds; easings stay STRING.
+                let figma_type = match value_index.get(token_name) {
+                    Some(v) if crate::cascade::parse_duration(v).is_some() => "FLOAT",
+                    _ => "STRING",
+                };
+                process_flat_token(
+                    token_name,
+                    token_entry,
+                    &motion_col.id,
+                    MOTION_PREFIX,
+                    figma_type,
+                    &motion_col.default_mode_id,
+                    &value_index,
+                    &existing_var_index,
+                    overrides,
+                    &mut variables,
+                    &mut mode_values,
+                    &mut summary,
+                );
+            }
+            continue;
+        }
+
         // Route to the appropriate collection.
         if schema.ends_with(COLOR_SET) {
             process_color_set_token(
@@ -191,6 +256,7 @@ pub fn build_export_payload(
                 token_entry,
                 &scale_col.id,
                 PLATFORM_SCALE_PREFIX,
+                SCALE_MODES,
                 &scale_mode_ids,
                 &value_index,
                 &existing_var_index,
@@ -279,17 +345,42 @@ fn find_collection<'a>(
     meta.variable_collections.values().find(|c| c.name == name)
 }
 
+/// Whether a token-type schema belongs in the `.Motion` collection.
+fn is_motion_schema(schema: &str) -> bool {
+    schema.ends_with(MOTION_SET) || schema.ends_with(DURATION) || schema.ends_with(EASING)
+}
+
+/// `$schema` of the token a top-level `{alias}` value points at.
+fn alias_target_schema<'a>(entry: &Value, all_tokens: &'a [(String, Value)]) -> Option<&'a str> {
+    let raw = entry.get("value")?.as_str()?;
+    let target = raw.strip_prefix('{')?.strip_suffix('}')?;
+    all_tokens
+        .iter()
+        .find(|(n, _)| n == target)?
+        .1
+        .get("$schema")?
+        .as_str()
+}
+
 /// Map mode names (e.g. "light", "dark") to their Figma mode IDs.
-/// Case-insensitive matching since Figma uses "Light"/"Dark" etc.
+/// Case-insensitive matching since Figma uses "Light"/"Dark" etc.; spaces,
+/// hyphens and underscores are ignored so "No preference" matches
+/// "no-preference".
 fn resolve_mode_ids(
     figma_modes: &[super::types::FigmaMode],
     expected: &[&str],
 ) -> HashMap<String, String> {
+    let normalize = |s: &str| -> String {
+        s.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
     let mut map = HashMap::new();
     for mode_name in expected {
         if let Some(fm) = figma_modes
             .iter()
-            .find(|m| m.name.eq_ignore_ascii_case(mode_name))
+            .find(|m| normalize(&m.name) == normalize(mode_name))
         {
             map.insert(mode_name.to_string(), fm.mode_id.clone());
         }
@@ -355,9 +446,10 @@ fn resolve_value(
     }
 
     // Try top-level value first; fall back to a set mode's value.
-    // Prefer "light" (color default) then "desktop" (scale default) so that
-    // aliases which resolve through a set token pick the canonical default-mode
-    // value rather than whichever mode happens to be listed first in the file.
+    // Prefer "light" (color default), "desktop" (scale default) then
+    // "no-preference" (reducedMotion default) so that aliases which resolve
+    // through a set token pick the canonical default-mode value rather than
+    // whichever mode happens to be listed first in the file.
     let value_str = entry.get("value").and_then(|v| v.as_str()).or_else(|| {
         entry
             .get("sets")
@@ -365,6 +457,7 @@ fn resolve_value(
             .and_then(|sets| {
                 sets.get("light")
                     .or_else(|| sets.get("desktop"))
+                    .or_else(|| sets.get("no-preference"))
                     .or_else(|| sets.values().next())
             })
             .and_then(|mode_entry| mode_entry.get("value"))
@@ -390,6 +483,7 @@ fn schema_to_figma_type(schema: &str) -> &'static str {
         || schema.ends_with(OPACITY)
         || schema.ends_with(FONT_SIZE)
         || schema.ends_with(FONT_WEIGHT)
+        || schema.ends_with(DURATION)
     {
         "FLOAT"
     } else {
@@ -405,6 +499,10 @@ fn value_to_figma(value_str: &str, figma_type: &str) -> Option<Value> {
             Some(serde_json::to_value(c).unwrap())
         }
         "FLOAT" => {
+            // Durations are exported in milliseconds.
+            if let Some(ms) = crate::cascade::parse_duration(value_str) {
+                return Some(Value::Number(serde_json::Number::from_f64(ms)?));
+            }
             // Strip common unit suffixes: px, em, rem, %
             // Note: dp (Android density-independent pixels) is intentionally not
             // stripped — dp values have no Figma equivalent and are tracked separately.
@@ -539,12 +637,15 @@ fn process_color_set_token(
     }
 }
 
+/// A set keyed by a single mode set (`scale-set`, `motion-set`): one variable
+/// with a value per mode in `modes`.
 #[allow(clippy::too_many_arguments)]
 fn process_scale_set_token(
     token_name: &str,
     entry: &Value,
     collection_id: &str,
     prefix: &str,
+    modes: &[&str],
     mode_ids: &HashMap<String, String>,
     value_index: &HashMap<String, String>,
     existing_var_index: &HashMap<&str, &str>,
@@ -577,6 +678,7 @@ fn process_scale_set_token(
         });
         match first_resolved {
             Some(v) if parse_color(v).is_ok() => "COLOR",
+            Some(v) if crate::cascade::parse_duration(v).is_some() => "FLOAT",
             Some(v)
                 if v.trim()
                     .trim_end_matches("rem")
@@ -607,7 +709,7 @@ fn process_scale_set_token(
     variables.push(va);
     summary.variables_created += 1;
 
-    for &mode_name in SCALE_MODES {
+    for &mode_name in modes {
         let Some(mode_id) = mode_ids.get(mode_name) else {
             continue;
         };
@@ -931,6 +1033,89 @@ mod tests {
         assert_eq!(val.as_f64(), Some(8.0));
     }
 
+    #[test]
+    fn motion_tokens_need_a_motion_collection() {
+        use std::io::Write;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("motion.json");
+        let mut f = std::fs::File::create(&path).unwrap();
+        write!(
+            f,
+            "{}",
+            json!({
+                "animation-duration-100": {
+                    "$schema": "https://example.com/motion-set.json",
+                    "sets": {
+                        "no-preference": { "$schema": "https://example.com/duration.json", "value": "130ms", "uuid": "m1" },
+                        "reduce": { "$schema": "https://example.com/duration.json", "value": "0ms", "uuid": "m2" }
+                    },
+                    "uuid": "m0"
+                },
+                "animation-ease-in-out": {
+                    "$schema": "https://example.com/easing.json",
+                    "value": "cubic-bezier(0.45, 0, 0.4, 1)",
+                    "uuid": "m3"
+                },
+                "animation-enter": {
+                    "$schema": "https://example.com/motion.json",
+                    "value": { "duration": "{animation-duration-100}", "timingFunction": "{animation-ease-in-out}" },
+                    "uuid": "m4"
+                }
+            })
+        )
+        .unwrap();
+
+        let mut meta = mock_meta();
+        let (body, summary) = build_export_payload(dir.path(), &meta, None).unwrap();
+        assert!(body.variables.is_empty());
+        assert_eq!(summary.skipped_composite, vec!["animation-enter"]);
+        assert_eq!(summary.skipped_no_collection.len(), 2);
+
+        meta.variable_collections.insert(
+            "col-3".into(),
+            super::super::types::FigmaVariableCollection {
+                id: "col-3".into(),
+                name: ".Motion".into(),
+                key: "k3".into(),
+                modes: vec![
+                    super::super::types::FigmaMode {
+                        mode_id: "m-full".into(),
+                        name: "No preference".into(),
+                    },
+                    super::super::types::FigmaMode {
+                        mode_id: "m-reduce".into(),
+                        name: "Reduce".into(),
+                    },
+                ],
+                default_mode_id: "m-full".into(),
+                remote: false,
+                hidden_from_publishing: false,
+                variable_ids: vec![],
+            },
+        );
+        let (body, summary) = build_export_payload(dir.path(), &meta, None).unwrap();
+        assert!(summary.skipped_no_collection.is_empty());
+        let duration = body
+            .variables
+            .iter()
+            .find(|v| v.name == "motion/animation-duration-100")
+            .unwrap();
+        assert_eq!(duration.resolved_type, "FLOAT");
+        let reduced = body
+            .variable_mode_values
+            .iter()
+            .find(|mv| mv.mode_id == "m-reduce")
+            .unwrap();
+        assert_eq!(reduced.value.as_f64(), Some(0.0));
+        let easing = body
+            .variables
+            .iter()
+            .find(|v| v.name == "motion/animation-ease-in-out")
+            .unwrap();
+        assert_eq!(easing.resolved_type, "STRING");
+        assert_eq!(summary.mode_values_set, 3);
+    }
+
     #[test]
     fn override_remaps_name_absent_override_stays_1to1() {
         use std::io::Write;
@@ -0,0 +1,573 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! CSS custom property backend.
+//!
+//! The all-base combination is written under the root selector (default `:root`).
+//! Every other combination gets a compound class selector built from its non-base
+//! modes — `.spectrum--dark`, `.spectrum--mobile`, `.spectrum--dark.spectrum--mobile`
+//! — and only carries a declaration when the blocks already emitted for its
+//! ancestor combinations would otherwise produce a different value. The
+//! effective value is the one from the most specific matching selector (ties go
+//! to the later block), mirroring how the browser applies the cascade. A mode
+//! name shared by several mode sets is qualified with its mode set
+//! (`.spectrum--contrast-high`, `.spectrum--density-high`) so the classes stay
+//! distinct.
+//!
+//! A custom property holding `var()` is resolved on the element that declares
+//! it and inherited as a value, so an alias is re-declared in every block where
+//! its resolved value changes; otherwise a nested `.spectrum--dark` subtree would
+//! inherit the light value.
+//!
+//! Aliases and inline `{legacy-key}` references become `var(--…)` references to
+//! the target variable. When the target is not part of the output the reference
+//! is flattened to its resolved value instead of emitting a dangling reference.
+//! Inline references to keys that are not in the dataset at all are kept as
+//! `var()`, for a stylesheet that defines them elsewhere.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use crate::naming;
+
+use super::{GeneratedToken, ModeAxis, ModeCombination, ResolvedTokenSet, ResolvedValue};
+
+/// Options for [`render`].
+#[derive(Debug, Clone)]
+pub struct CssOptions {
+    /// Prefix for custom property names (`--{prefix}-{key}`) and mode class
+    /// names (`.{prefix}--{mode}`, or `.{prefix}--{mode-set}-{mode}` for a mode
+    /// name shared by several mode sets).
+    pub prefix: String,
+    /// Selector for the all-base combination.
+    pub root_selector: String,
+}
+
+impl Default for CssOptions {
+    fn default() -> Self {
+        Self {
+            prefix: "spectrum".to_string(),
+            root_selector: ":root".to_string(),
+        }
+    }
+}
+
+/// Render `set` as a CSS stylesheet.
+pub fn render(set: &ResolvedTokenSet, opts: &CssOptions) -> String {
+    let names = set.output_names();
+    let flat = HashMap::new();
+    let refs = |names, combo| Refs {
+        names,
+        referenced: &set.referenced,
+        combo,
+        visiting: Vec::new(),
+    };
+    // Per token per combination: the declarations as written, and as fully
+    // flattened to values. A block inherits an ancestor's entry only when both
+    // match, so aliases are re-declared wherever their resolved value changes.
+    type Rendered = (Vec<(String, String)>, Vec<(String, String)>);
+    let rendered: Vec<Vec<Rendered>> = set
+        .tokens
+        .iter()
+        .map(|t| {
+            t.values
+                .iter()
+                .enumerate()
+                .map(|(idx, v)| {
+                    v.as_ref()
+                        .map(|v| {
+                            (
+                                declarations(t, v, &refs(&names, idx), opts),
+                                declarations(t, v, &refs(&flat, idx), opts),
+                            )
+                        })
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut out = String::from("/* Generated by design-data generate css. Do not edit. */\n");
+    // Per token: which combinations received a block entry.
+    let mut emitted: Vec<Vec<bool>> = vec![vec![false; set.combinations.len()]; set.tokens.len()];
+    for (idx, combo) in set.combinations.iter().enumerate() {
+        let ancestors = set.ancestors(idx);
+        let mut block = String::new();
+        for (t, per_token) in rendered.iter().enumerate() {
+            let (decls, _) = &per_token[idx];
+            if decls.is_empty() {
+                continue;
+            }
+            let inherited = ancestors
+                .iter()
+                .filter(|a| emitted[t][**a])
+                .max_by_key(|a| (set.combinations[**a].non_base(&set.axes).len(), **a));
+            if inherited.is_some_and(|a| per_token[*a] == per_token[idx]) {
+                continue;
+            }
+            emitted[t][idx] = true;
+            for (name, value) in decls {
+                let _ = writeln!(block, "  {name}: {value};");
+            }
+        }
+        if block.is_empty() {
+            continue;
+        }
+        let _ = write!(
+            out,
+            "\n{} {{\n{block}}}\n",
+            selector(combo, &set.axes, opts)
+        );
+    }
+    out
+}
+
+/// The selector for a mode combination.
+pub fn selector(combo: &ModeCombination, axes: &[ModeAxis], opts: &CssOptions) -> String {
+    let non_base = combo.non_base(axes);
+    if non_base.is_empty() {
+        return opts.root_selector.clone();
+    }
+    non_base
+        .iter()
+        .map(|(set, mode)| {
+            let shared = axes
+                .iter()
+                .filter(|a| a.modes.iter().any(|m| m == mode))
+                .count()
+                > 1;
+            if shared {
+                format!(".{}--{}-{mode}", opts.prefix, kebab(set))
+            } else {
+                format!(".{}--{mode}", opts.prefix)
+            }
+        })
+        .collect()
+}
+
+/// Custom property name for a variable's output name. Characters that are not
+/// valid unescaped in a custom property name (e.g. a `.` or `/` formatting
+/// delimiter) become `-`.
+pub fn property_name(name: &str, opts: &CssOptions) -> String {
+    let ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("--{}-{ident}", opts.prefix)
+}
+
+/// How inline references render in one combination.
+struct Refs<'a> {
+    /// Emitted variable key → output name; empty to flatten every reference.
+    names: &'a HashMap<&'a str, &'a str>,
+    /// [`ResolvedTokenSet::referenced`].
+    referenced: &'a BTreeMap<String, Vec<Option<Value>>>,
+    /// Index into [`ResolvedTokenSet::combinations`].
+    combo: usize,
+    /// Keys being flattened, to stop on reference cycles.
+    visiting: Vec<&'a str>,
+}
+
+/// The `(property, value)` declarations for one token in one combination.
+///
+/// Composite object values (e.g. typography) expand into one declaration per
+/// field, suffixed with the kebab-cased field name.
+fn declarations(
+    token: &GeneratedToken,
+    value: &ResolvedValue,
+    refs: &Refs,
+    opts: &CssOptions,
+) -> Vec<(String, String)> {
+    let name = property_name(token.output_name(), opts);
+    if let ResolvedValue::Alias { target, .. } = value {
+        if let Some(target) = refs.names.get(target.as_str()) {
+            return vec![(name, format!("var({})", property_name(target, opts)))];
+        }
+    }
+    let Some(literal) = value.terminal() else {
+        return Vec::new();
+    };
+    match literal {
+        Value::Object(fields) => fields
+            .iter()
+            .filter_map(|(field, v)| {
+                let css = css_value(v, token.schema_type(), refs, opts)?;
+                Some((format!("{name}-{}", kebab(field)), css))
+            })
+            .collect(),
+        v => css_value(v, token.schema_type(), refs, opts)
+            .map(|css| vec![(name, css)])
+            .unwrap_or_default(),
+    }
+}
+
+/// Render a literal token value as CSS, resolving inline `{ref}` references.
+fn css_value(
+    value: &Value,
+    schema_type: Option<&str>,
+    refs: &Refs,
+    opts: &CssOptions,
+) -> Option<String> {
+    match value {
+        Value::String(s) => Some(replace_refs(s, refs, opts)),
+        Value::Number(n) if schema_type == Some("angle") => Some(format!("{n}deg")),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        // DTCG cubicBezier easing arrays (standalone or a motion `timingFunction`).
+        Value::Array(_) if matches!(schema_type, Some("easing" | "motion")) => {
+            let [x1, y1, x2, y2] = super::parse_cubic_bezier(value)?;
+            Some(format!("cubic-bezier({x1}, {y1}, {x2}, {y2})"))
+        }
+        // Drop shadows: a list of `{x, y, blur, spread, color}` layers.
+        Value::Array(items) => {
+            let parts: Vec<String> = items
+                .iter()
+                .filter_map(|item| match item {
+                    Value::Object(layer) => {
+                        let fields: Vec<String> = ["x", "y", "blur", "spread", "color"]
+                            .iter()
+                            .filter_map(|f| css_value(layer.get(*f)?, None, refs, opts))
+                            .collect();
+                        (!fields.is_empty()).then(|| fields.join(" "))
+                    }
+                    other => css_value(other, schema_type, refs, opts),
+                })
+                .collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        }
+        Value::Object(_) | Value::Null => None,
+    }
+}
+
+/// Replace `{legacy-key}` references inside a string with `var(--prefix-name)`
+/// when the referenced variable is emitted, else with its value in this
+/// combination. Keys that resolve to nothing keep a `var()` reference.
+fn replace_refs(s: &str, refs: &Refs, opts: &CssOptions) -> String {
+    naming::replace_inline_refs(s, |key| {
+        Some(match refs.names.get(key) {
+            Some(name) => format!("var({})", property_name(name, opts)),
+            None => flatten(key, refs, opts)
+                .unwrap_or_else(|| format!("var({})", property_name(key, opts))),
+        })
+    })
+}
+
+/// The CSS value of a referenced variable that is not emitted, in the current
+/// combination.
+fn flatten(key: &str, refs: &Refs, opts: &CssOptions) -> Option<String> {
+    let (key, values) = refs.referenced.get_key_value(key)?;
+    if refs.visiting.contains(&key.as_str()) {
+        return None;
+    }
+    let value = values.get(refs.combo)?.as_ref()?;
+    let mut visiting = refs.visiting.clone();
+    visiting.push(key);
+    let inner = Refs { visiting, ..*refs };
+    css_value(value, None, &inner, opts)
+}
+
+/// `letterSpacing` → `letter-spacing`.
+fn kebab(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    for c in s.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('-');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::generate::resolve_all;
+    use crate::generate::tests::{mode_set, motion_graph, sample_graph};
+    use crate::graph::TokenGraph;
+
+    #[test]
+    fn base_values_go_under_root_selector() {
+        let css = render(
+            &resolve_all(&sample_graph(), &HashMap::new()),
+            &CssOptions::default(),
+        );
+        assert!(css.contains(":root {\n"));
+        assert!(css.contains("  --spectrum-blue-100: rgb(245, 249, 255);\n"));
+        assert!(css.contains("  --spectrum-field-gap: 8px;\n"));
+    }
+
+    #[test]
+    fn mode_overrides_go_under_mode_selectors() {
+        let css = render(
+            &resolve_all(&sample_graph(), &HashMap::new()),
+            &CssOptions::default(),
+        );
+        assert!(css.contains("  --spectrum-blue-100: rgb(14, 23, 63);\n}"));
+        assert!(css.contains(".spectrum--mobile {\n  --spectrum-field-gap: 10px;\n}"));
+        // Both overrides are inherited by the compound selector, so it is not emitted.
+        assert!(!css.contains(".spectrum--dark.spectrum--mobile"));
+    }
+
+    #[test]
+    fn aliases_are_redeclared_where_their_value_changes() {
+        let css = render(
+            &resolve_all(&sample_graph(), &HashMap::new()),
+            &CssOptions::default(),
+        );
+        let alias = "  --spectrum-accent-background-color-default: var(--spectrum-blue-100);\n";
+        assert!(css.contains(&format!(":root {{\n{alias}")));
+        // Redeclared under dark, where blue-100 resolves differently, so a nested
+        // dark subtree does not inherit the value computed at the root.
+        assert!(css.contains(&format!(
+            ".spectrum--dark {{\n{alias}  --spectrum-blue-100: rgb(14, 23, 63);\n}}"
+        )));
+        assert_eq!(
+            css.matches("--spectrum-accent-background-color-default:")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn nested_mode_blocks_redeclare_only_changed_aliases() {
+        let token = |key: &str, value: serde_json::Value| {
+            (key.to_string(), PathBuf::from("t.tokens.json"), value)
+        };
+        let graph = TokenGraph::from_pairs(vec![
+            token(
+                "blue-light",
+                json!({"name": {"property": "blue-100", "colorScheme": "light"},
+                   "value": "rgb(245, 249, 255)", "uuid": "blue-light", "set_uuid": "blue"}),
+            ),
+            token(
+                "blue-dark",
+                json!({"name": {"property": "blue-100", "colorScheme": "dark"},
+                   "value": "rgb(14, 23, 63)", "uuid": "blue-dark", "set_uuid": "blue"}),
+            ),
+            token(
+                "accent",
+                json!({"name": {"property": "accent"}, "$ref": "blue"}),
+            ),
+            token(
+                "gap-desktop",
+                json!({"name": {"property": "field-gap", "scale": "desktop"},
+                   "value": "8px", "uuid": "gap-desktop", "set_uuid": "gap"}),
+            ),
+            token(
+                "gap-mobile",
+                json!({"name": {"property": "field-gap", "scale": "mobile"},
+                   "value": "10px", "uuid": "gap-mobile", "set_uuid": "gap"}),
+            ),
+            token(
+                "row-gap",
+                json!({"name": {"property": "row-gap"}, "$ref": "gap"}),
+            ),
+        ])
+        .with_mode_sets(vec![
+            mode_set("colorScheme", &["light", "dark"], "light"),
+            mode_set("scale", &["desktop", "mobile"], "desktop"),
+        ]);
+        let css = render(
+            &resolve_all(&graph, &HashMap::new()),
+            &CssOptions::default(),
+        );
+        let block = |selector: &str| {
+            let start = css.find(&format!("\n{selector} {{\n")).unwrap();
+            css[start..].split_once("}\n").unwrap().0.to_string()
+        };
+        assert!(
+            block(".spectrum--mobile").contains("--spectrum-row-gap: var(--spectrum-field-gap);")
+        );
+        assert!(block(".spectrum--dark").contains("--spectrum-accent: var(--spectrum-blue-100);"));
+        assert!(!block(".spectrum--mobile").contains("--spectrum-accent"));
+        assert!(!block(".spectrum--dark").contains("--spectrum-row-gap"));
+        // The compound block inherits both redeclarations unchanged.
+        assert!(!css.contains(".spectrum--dark.spectrum--mobile"));
+    }
+
+    #[test]
+    fn motion_tokens_render_under_reduce_class() {
+        let css = render(
+            &resolve_all(&motion_graph(), &HashMap::new()),
+            &CssOptions::default(),
+        );
+        assert!(css.contains("  --spectrum-animation-duration-100: 130ms;\n"));
+        assert!(
+            css.contains("  --spectrum-animation-ease-in-out: cubic-bezier(0.45, 0, 0.4, 1);\n")
+        );
+        assert!(css.contains(
+            "  --spectrum-animation-enter-duration: var(--spectrum-animation-duration-100);\n"
+        ));
+        assert!(css.contains(
+            "  --spectrum-animation-enter-timing-function: cubic-bezier(0, 0, 0.4, 1);\n"
+        ));
+        assert!(css.contains(
+            ".spectrum--reduce {\n  --spectrum-animation-duration-100: 0ms;\n  \
+             --spectrum-animation-enter-duration: var(--spectrum-animation-duration-100);\n  \
+             --spectrum-animation-enter-timing-function: cubic-bezier(0, 0, 0.4, 1);\n}"
+        ));
+    }
+
+    #[test]
+    fn alias_to_missing_target_is_flattened() {
+        let mut set = resolve_all(&sample_graph(), &HashMap::new());
+        set.tokens.retain(|t| t.key != "blue-100");
+        let css = render(&set, &CssOptions::default());
+        assert!(css.contains("  --spectrum-accent-background-color-default: rgb(245, 249, 255);\n"));
+    }
+
+    #[test]
+    fn inline_reference_to_missing_variable_is_flattened() {
+        let graph = TokenGraph::from_pairs(vec![
+            (
+                "light".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "light"},
+                       "value": "rgb(245, 249, 255)"}),
+            ),
+            (
+                "dark".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "dark"},
+                       "value": "rgb(14, 23, 63)"}),
+            ),
+            (
+                "ring".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "border", "legacyKey": "focus-ring"},
+                       "value": "2px solid {blue-100}"}),
+            ),
+        ])
+        .with_mode_sets(vec![mode_set("colorScheme", &["light", "dark"], "light")]);
+        let mut set = resolve_all(&graph, &HashMap::new());
+        set.tokens.retain(|t| t.key != "blue-100");
+        let css = render(&set, &CssOptions::default());
+        assert!(css.contains(":root {\n  --spectrum-focus-ring: 2px solid rgb(245, 249, 255);\n}"));
+        assert!(css
+            .contains(".spectrum--dark {\n  --spectrum-focus-ring: 2px solid rgb(14, 23, 63);\n}"));
+        assert!(!css.contains("var("));
+    }
+
+    #[test]
+    fn restrictions_drop_disallowed_mode_blocks() {
+        let restrictions = HashMap::from([("colorScheme".to_string(), vec!["light".to_string()])]);
+        let css = render(
+            &resolve_all(&sample_graph(), &restrictions),
+            &CssOptions::default(),
+        );
+        assert!(!css.contains(".spectrum--dark"));
+        assert!(css.contains(".spectrum--mobile"));
+    }
+
+    #[test]
+    fn composite_values_render_as_css() {
+        let graph = TokenGraph::from_pairs(vec![
+            (
+                "shadow".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "drop-shadow", "legacyKey": "drop-shadow-emphasized"},
+                       "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/drop-shadow.json",
+                       "value": [{"x": "0px", "y": "4px", "blur": "12px", "spread": "0px", "color": "{drop-shadow-color}"}]}),
+            ),
+            (
+                "type".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "typography", "legacyKey": "heading"},
+                       "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/typography.json",
+                       "value": {"fontSize": "{font-size-200}", "letterSpacing": "0px"}}),
+            ),
+            (
+                "angle".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "angle", "legacyKey": "gradient-angle"},
+                       "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/angle.json",
+                       "value": -25}),
+            ),
+        ]);
+        let css = render(
+            &resolve_all(&graph, &HashMap::new()),
+            &CssOptions::default(),
+        );
+        assert!(css.contains(
+            "--spectrum-drop-shadow-emphasized: 0px 4px 12px 0px var(--spectrum-drop-shadow-color);"
+        ));
+        assert!(css.contains("--spectrum-heading-font-size: var(--spectrum-font-size-200);"));
+        assert!(css.contains("--spectrum-heading-letter-spacing: 0px;"));
+        assert!(css.contains("--spectrum-gradient-angle: -25deg;"));
+    }
+
+    #[test]
+    fn mode_names_shared_by_mode_sets_are_qualified() {
+        let graph = TokenGraph::from_pairs(vec![
+            (
+                "contrast".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "border-width", "contrast": "high"}, "value": "2px"}),
+            ),
+            (
+                "density".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "row-height", "density": "high"}, "value": "48px"}),
+            ),
+        ])
+        .with_mode_sets(vec![
+            mode_set("contrast", &["regular", "high"], "regular"),
+            mode_set("density", &["regular", "high"], "regular"),
+        ]);
+        let css = render(
+            &resolve_all(&graph, &HashMap::new()),
+            &CssOptions::default(),
+        );
+        assert!(css.contains(".spectrum--contrast-high {\n  --spectrum-border-width: 2px;\n}"));
+        assert!(css.contains(".spectrum--density-high {\n"));
+        assert!(!css.contains(".spectrum--high"));
+    }
+
+    #[test]
+    fn custom_prefix_and_root_selector() {
+        let opts = CssOptions {
+            prefix: "acme".to_string(),
+            root_selector: ".acme".to_string(),
+        };
+        let css = render(&resolve_all(&sample_graph(), &HashMap::new()), &opts);
+        assert!(css.contains(".acme {\n"));
+        assert!(css.contains(".acme--dark {\n"));
+        assert!(css.contains("--acme-field-gap: 8px;"));
+    }
+
+    #[test]
+    fn formatted_names_are_used_for_properties_and_references() {
+        let mut set = resolve_all(&sample_graph(), &HashMap::new());
+        set.apply_formatting(&crate::naming::NameFormatting {
+            casing: crate::naming::Casing::CamelCase,
+            delimiter: "/".to_string(),
+            ..Default::default()
+        });
+        let css = render(&set, &CssOptions::default());
+        assert!(css.contains("  --spectrum-color-blue-100: rgb(245, 249, 255);\n"));
+        assert!(css.contains("  --spectrum-button-color-accent: var(--spectrum-color-blue-100);\n"));
+        assert!(!css.contains("--spectrum-blue-100"));
+    }
+}
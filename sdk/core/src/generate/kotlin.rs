@@ -0,0 +1,260 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Kotlin (Jetpack Compose) backend.
+//!
+//! Mirrors the Swift backend: one nested `object` per mode combination, each
+//! complete with aliases flattened. Types follow the token-type schema —
+//! `color.json` → `Color`, `dimension.json` → `Dp`, `font-size.json` → `TextUnit`
+//! (`sp`), `font-weight.json` → `FontWeight`, `duration.json` → milliseconds
+//! (`Int`, as Compose animation specs take), `easing.json` → `CubicBezierEasing`.
+//! Values with no Compose representation are skipped.
+
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use crate::cascade::{numeric_value, parse_dimension, parse_duration};
+use crate::color::parse_rgba;
+
+use super::{
+    combination_name, format_number, native_identifier, parse_cubic_bezier, GeneratedToken,
+    ResolvedTokenSet, ResolvedValue,
+};
+
+/// Options for [`render`].
+#[derive(Debug, Clone)]
+pub struct KotlinOptions {
+    /// Name of the top-level `object`.
+    pub namespace: String,
+    /// Kotlin package declaration.
+    pub package: String,
+}
+
+impl Default for KotlinOptions {
+    fn default() -> Self {
+        Self {
+            namespace: "Spectrum".to_string(),
+            package: "com.adobe.spectrum.tokens".to_string(),
+        }
+    }
+}
+
+const RESERVED: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+const IMPORTS: &[&str] = &[
+    "androidx.compose.animation.core.CubicBezierEasing",
+    "androidx.compose.ui.graphics.Color",
+    "androidx.compose.ui.text.font.FontWeight",
+    "androidx.compose.ui.unit.dp",
+    "androidx.compose.ui.unit.em",
+    "androidx.compose.ui.unit.sp",
+];
+
+/// Render `set` as a Kotlin source file.
+pub fn render(set: &ResolvedTokenSet, opts: &KotlinOptions) -> String {
+    let mut out = String::from("// Generated by design-data generate kotlin. Do not edit.\n\n");
+    let _ = writeln!(out, "package {}\n", opts.package);
+    for import in IMPORTS {
+        let _ = writeln!(out, "import {import}");
+    }
+    let _ = writeln!(out, "\nobject {} {{", opts.namespace);
+    for (idx, combo) in set.combinations.iter().enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(out, "    object {} {{", combination_name(combo, &set.axes));
+        for token in &set.tokens {
+            let Some(value) = &token.values[idx] else {
+                continue;
+            };
+            let Some(expr) = kotlin_literal(token, value) else {
+                continue;
+            };
+            if let ResolvedValue::Alias { target, .. } = value {
+                let _ = writeln!(out, "        /** Alias of `{target}`. */");
+            }
+            let _ = writeln!(out, "        val {} = {expr}", identifier(token));
+        }
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Kotlin identifier for a variable.
+fn identifier(token: &GeneratedToken) -> String {
+    escape_reserved(native_identifier(token))
+}
+
+/// Escape a reserved word with backticks.
+fn escape_reserved(ident: String) -> String {
+    if RESERVED.contains(&ident.as_str()) {
+        format!("`{ident}`")
+    } else {
+        ident
+    }
+}
+
+/// The initializer expression for a token value, or `None` when the value has
+/// no Compose representation.
+fn kotlin_literal(token: &GeneratedToken, value: &ResolvedValue) -> Option<String> {
+    let v = value.terminal()?;
+    match token.schema_type()? {
+        "color" => {
+            let [r, g, b, a] = parse_rgba(v.as_str()?)?;
+            let byte = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+            Some(format!(
+                "Color(0x{:02X}{:02X}{:02X}{:02X})",
+                byte(a),
+                byte(r),
+                byte(g),
+                byte(b)
+            ))
+        }
+        schema @ ("dimension" | "font-size") => {
+            let (n, unit) = match v {
+                Value::String(s) => parse_dimension(s)?,
+                other => (numeric_value(other)?, ""),
+            };
+            let suffix = match (schema, unit) {
+                (_, "em") => "em",
+                ("font-size", "" | "px" | "sp") => "sp",
+                ("dimension", "" | "px" | "dp") => "dp",
+                _ => return None,
+            };
+            Some(format!("{}.{suffix}", format_number(n)))
+        }
+        "multiplier" | "opacity" | "gradient-stop" | "angle" => {
+            Some(format!("{}f", format_number(numeric_value(v)?)))
+        }
+        "font-weight" => Some(format!("FontWeight.{}", font_weight(v.as_str()?)?)),
+        "duration" => Some(format!("{}", parse_duration(v.as_str()?)?.round() as i64)),
+        "easing" => {
+            let [x1, y1, x2, y2] = parse_cubic_bezier(v)?;
+            Some(format!(
+                "CubicBezierEasing({}f, {}f, {}f, {}f)",
+                format_number(x1),
+                format_number(y1),
+                format_number(x2),
+                format_number(y2)
+            ))
+        }
+        "font-family" | "font-style" | "alignment" | "text-transform" => {
+            Some(serde_json::to_string(v.as_str()?).ok()?.replace('$', "\\$"))
+        }
+        _ => None,
+    }
+}
+
+/// Map a font-weight keyword to its `FontWeight` companion member.
+fn font_weight(keyword: &str) -> Option<&'static str> {
+    Some(match keyword {
+        "thin" => "Thin",
+        "extra-light" | "ultra-light" => "ExtraLight",
+        "light" => "Light",
+        "regular" | "normal" => "Normal",
+        "medium" => "Medium",
+        "semi-bold" | "semibold" => "SemiBold",
+        "bold" => "Bold",
+        "extra-bold" | "heavy" => "ExtraBold",
+        "black" => "Black",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::generate::resolve_all;
+    use crate::generate::tests::{motion_graph, sample_graph};
+
+    fn render_sample() -> String {
+        render(
+            &resolve_all(&sample_graph(), &HashMap::new()),
+            &KotlinOptions::default(),
+        )
+    }
+
+    #[test]
+    fn emits_package_and_nested_objects() {
+        let kt = render_sample();
+        assert!(kt.contains("package com.adobe.spectrum.tokens\n"));
+        assert!(kt.contains("import androidx.compose.ui.graphics.Color\n"));
+        assert!(kt.contains("object Spectrum {"));
+        assert!(kt.contains("    object DarkMobile {"));
+    }
+
+    #[test]
+    fn maps_schema_types_to_compose_types() {
+        let kt = render_sample();
+        assert!(kt.contains("val blue100 = Color(0xFFF5F9FF)"));
+        assert!(kt.contains("val blue100 = Color(0xFF0E173F)"));
+        assert!(kt.contains("val fieldGap = 8.dp"));
+        assert!(kt.contains("val fieldGap = 10.dp"));
+    }
+
+    #[test]
+    fn maps_motion_types() {
+        let kt = render(
+            &resolve_all(&motion_graph(), &HashMap::new()),
+            &KotlinOptions::default(),
+        );
+        assert!(kt.contains("    object Reduce {"));
+        assert!(kt.contains("val animationDuration100 = 130\n"));
+        assert!(kt.contains("val animationDuration100 = 0\n"));
+        assert!(kt.contains("val animationEaseInOut = CubicBezierEasing(0.45f, 0f, 0.4f, 1f)"));
+        assert!(!kt.contains("animationEnter"));
+    }
+
+    #[test]
+    fn aliases_are_flattened_per_combination() {
+        let kt = render_sample();
+        assert!(kt.contains("/** Alias of `blue-100`. */"));
+        assert!(kt.contains("val accentBackgroundColorDefault = Color(0xFF0E173F)"));
+    }
+
+    #[test]
+    fn font_weights_and_reserved_identifiers() {
+        assert_eq!(font_weight("semi-bold"), Some("SemiBold"));
+        assert_eq!(escape_reserved("object".into()), "`object`");
+    }
+}
@@ -0,0 +1,625 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Platform code generation from a resolved [`TokenGraph`].
+//!
+//! The shared pipeline ([`resolve_all`]) groups tokens into output variables by
+//! legacy key (the same identity `legacy_name_index` uses), enumerates every
+//! mode-set combination allowed by the platform manifest, and runs the cascade
+//! once per variable per combination ([`crate::cascade::resolve_variables`]). Each backend
+//! ([`css`], [`swift`], [`kotlin`]) then renders the resulting [`ResolvedTokenSet`]
+//! in its own syntax.
+//!
+//! Variables are named by legacy key unless the platform manifest declares
+//! `extensions.formatting`, in which case [`ResolvedTokenSet::apply_formatting`]
+//! gives each one its platform name.
+
+pub mod css;
+pub mod kotlin;
+pub mod swift;
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+
+use crate::cascade::{self, terminal_record, variable_key, ResolutionContext};
+pub use crate::cascade::{mode_axes, mode_combinations, ModeAxis, ModeCombination};
+use crate::graph::{TokenGraph, TokenRecord};
+use crate::naming::{self, camel_identifier, pascal_case, NameFormatting};
+
+// ── Resolved output model ─────────────────────────────────────────────────────
+
+/// The cascade winner for one variable in one mode combination.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedValue {
+    /// The winner carries a literal `value`.
+    Literal(Value),
+    /// The winner aliases another variable.
+    Alias {
+        /// Legacy key of the alias target variable.
+        target: String,
+        /// Terminal value of the alias chain in this combination, when reachable.
+        resolved: Option<Value>,
+    },
+}
+
+impl ResolvedValue {
+    /// The literal value, following the alias chain when necessary.
+    pub fn terminal(&self) -> Option<&Value> {
+        match self {
+            ResolvedValue::Literal(v) => Some(v),
+            ResolvedValue::Alias { resolved, .. } => resolved.as_ref(),
+        }
+    }
+}
+
+/// One output variable: every token sharing a legacy key, resolved per combination.
+#[derive(Debug, Clone)]
+pub struct GeneratedToken {
+    /// Legacy key (e.g. `accent-background-color-default`).
+    pub key: String,
+    /// Token-type `$schema` URL of the base-combination winner, following
+    /// aliases to the terminal token (so an alias to a color reports `color.json`).
+    pub schema_url: Option<String>,
+    /// `name` of the base-combination winner (object or string form).
+    pub name: Option<Value>,
+    /// Platform name from [`ResolvedTokenSet::apply_formatting`]; backends use
+    /// it in place of `key` when set.
+    pub formatted: Option<String>,
+    /// One entry per [`ResolvedTokenSet::combinations`] index; `None` when no
+    /// token matches that combination.
+    pub values: Vec<Option<ResolvedValue>>,
+}
+
+impl GeneratedToken {
+    /// Token-type schema file stem (e.g. `"color"` for `…/token-types/color.json`).
+    pub fn schema_type(&self) -> Option<&str> {
+        schema_type(self.schema_url.as_deref()?)
+    }
+
+    /// The output name: the formatted platform name, else the legacy key.
+    pub fn output_name(&self) -> &str {
+        self.formatted.as_deref().unwrap_or(&self.key)
+    }
+}
+
+/// A fully resolved dataset, ready for a backend to render.
+#[derive(Debug, Clone)]
+pub struct ResolvedTokenSet {
+    pub axes: Vec<ModeAxis>,
+    /// All mode combinations; index 0 is the all-base combination.
+    pub combinations: Vec<ModeCombination>,
+    /// Output variables sorted by key.
+    pub tokens: Vec<GeneratedToken>,
+    /// Terminal values, one per combination, of every variable referenced
+    /// inline (`{legacy-key}`) from a literal, keyed by legacy key. Backends use
+    /// these to flatten references to variables they do not emit.
+    pub referenced: BTreeMap<String, Vec<Option<Value>>>,
+}
+
+impl ResolvedTokenSet {
+    /// Indexes of the combinations that combination `idx` inherits from: every
+    /// other combination whose non-base modes are a subset of its own. Index 0
+    /// (all-base) is an ancestor of every other combination.
+    pub fn ancestors(&self, idx: usize) -> Vec<usize> {
+        let combo = &self.combinations[idx];
+        self.combinations
+            .iter()
+            .enumerate()
+            .filter(|(i, other)| {
+                *i != idx
+                    && other
+                        .modes
+                        .iter()
+                        .zip(&combo.modes)
+                        .zip(&self.axes)
+                        .all(|(((_, m), (_, own)), axis)| *m == axis.base || m == own)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Emitted variable key → output name, for alias-target lookups.
+    pub fn output_names(&self) -> HashMap<&str, &str> {
+        self.tokens
+            .iter()
+            .map(|t| (t.key.as_str(), t.output_name()))
+            .collect()
+    }
+
+    /// Name every variable by the platform's `extensions.formatting` rules.
+    ///
+    /// Variables keep their legacy `key` (alias targets still refer to it);
+    /// backends render [`GeneratedToken::output_name`]. Tokens whose name yields
+    /// no concepts keep the legacy key, as do variables whose formatted names
+    /// collide — those are returned as `(formatted name, legacy keys)` so the
+    /// caller can report them.
+    pub fn apply_formatting(&mut self, formatting: &NameFormatting) -> Vec<(String, Vec<String>)> {
+        let mut by_name: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (i, token) in self.tokens.iter().enumerate() {
+            if let Some(formatted) = token.name.as_ref().and_then(|n| formatting.format(n)) {
+                by_name.entry(formatted).or_default().push(i);
+            }
+        }
+        let mut collisions = Vec::new();
+        for (formatted, idxs) in by_name {
+            if idxs.len() > 1 {
+                collisions.push((
+                    formatted,
+                    idxs.iter().map(|i| self.tokens[*i].key.clone()).collect(),
+                ));
+                continue;
+            }
+            self.tokens[idxs[0]].formatted = Some(formatted);
+        }
+        collisions
+    }
+}
+
+/// Token-type schema file stem from a `$schema` URL.
+pub fn schema_type(schema_url: &str) -> Option<&str> {
+    schema_url.rsplit('/').next()?.strip_suffix(".json")
+}
+
+// ── Resolution pipeline ───────────────────────────────────────────────────────
+
+/// Resolve every variable in `graph` for every mode combination.
+///
+/// `restrictions` are the manifest mode-set restrictions (as returned by
+/// [`crate::manifest::apply_configured`]); they both prune the mode matrix and
+/// filter candidates during cascade resolution.
+pub fn resolve_all(
+    graph: &TokenGraph,
+    restrictions: &HashMap<String, Vec<String>>,
+) -> ResolvedTokenSet {
+    let axes = mode_axes(graph, restrictions);
+    let combinations = mode_combinations(&axes);
+
+    let contexts: Vec<ResolutionContext> = combinations
+        .iter()
+        .map(|c| cascade::apply_restrictions(c.context(), restrictions))
+        .collect();
+    let ctx_maps: Vec<HashMap<String, String>> =
+        combinations.iter().map(ModeCombination::as_map).collect();
+
+    let mut tokens: Vec<GeneratedToken> = cascade::resolve_variables(graph, &contexts)
+        .into_iter()
+        .map(|(key, winners)| {
+            let base = winners
+                .iter()
+                .zip(&ctx_maps)
+                .find_map(|(w, ctx)| w.map(|rec| (rec, ctx)));
+            let values = winners
+                .iter()
+                .zip(&ctx_maps)
+                .map(|(w, ctx)| w.and_then(|rec| resolved_value(graph, rec, ctx)))
+                .collect();
+            GeneratedToken {
+                key,
+                schema_url: base
+                    .and_then(|(rec, ctx)| terminal_record(graph, rec, ctx))
+                    .and_then(|leaf| leaf.schema_url.clone()),
+                name: base.and_then(|(rec, _)| rec.raw.get("name")).cloned(),
+                formatted: None,
+                values,
+            }
+        })
+        .collect();
+    tokens.sort_by(|a, b| a.key.cmp(&b.key));
+    let referenced = referenced_values(&tokens);
+
+    ResolvedTokenSet {
+        axes,
+        combinations,
+        tokens,
+        referenced,
+    }
+}
+
+/// Per-combination terminal values of every variable reachable through inline
+/// `{legacy-key}` references from the literals in `tokens`.
+fn referenced_values(tokens: &[GeneratedToken]) -> BTreeMap<String, Vec<Option<Value>>> {
+    let terminals: HashMap<&str, Vec<Option<Value>>> = tokens
+        .iter()
+        .map(|t| {
+            let values = t
+                .values
+                .iter()
+                .map(|v| v.as_ref().and_then(ResolvedValue::terminal).cloned())
+                .collect();
+            (t.key.as_str(), values)
+        })
+        .collect();
+    let mut pending = Vec::new();
+    for value in terminals.values().flatten().flatten() {
+        inline_refs(value, &mut pending);
+    }
+    let mut referenced = BTreeMap::new();
+    while let Some(key) = pending.pop() {
+        if referenced.contains_key(&key) {
+            continue;
+        }
+        let Some(values) = terminals.get(key.as_str()) else {
+            continue;
+        };
+        for value in values.iter().flatten() {
+            inline_refs(value, &mut pending);
+        }
+        referenced.insert(key, values.clone());
+    }
+    referenced
+}
+
+/// Collect the keys of `{legacy-key}` references inside the strings of `value`.
+fn inline_refs(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) => out.extend(naming::inline_refs(s).map(|(_, key)| key.to_string())),
+        Value::Array(items) => items.iter().for_each(|v| inline_refs(v, out)),
+        Value::Object(fields) => fields.values().for_each(|v| inline_refs(v, out)),
+        _ => {}
+    }
+}
+
+/// Build the [`ResolvedValue`] for a cascade winner in context `ctx`.
+fn resolved_value(
+    graph: &TokenGraph,
+    rec: &TokenRecord,
+    ctx: &HashMap<String, String>,
+) -> Option<ResolvedValue> {
+    let Some(alias) = rec.alias_target.as_deref() else {
+        return rec.raw.get("value").cloned().map(ResolvedValue::Literal);
+    };
+    let target = graph.resolve_alias_in_context(alias, ctx)?;
+    Some(ResolvedValue::Alias {
+        target: variable_key(target),
+        resolved: terminal_record(graph, target, ctx)
+            .and_then(|leaf| leaf.raw.get("value").cloned()),
+    })
+}
+
+// ── Native backend helpers ────────────────────────────────────────────────────
+
+/// Type name for a combination's namespace in native backends: the PascalCase
+/// concatenation of its modes (e.g. `DarkMobile`). Axes with a single allowed
+/// mode carry no information and are omitted; an empty matrix yields `Default`.
+pub fn combination_name(combo: &ModeCombination, axes: &[ModeAxis]) -> String {
+    let name: String = combo
+        .modes
+        .iter()
+        .zip(axes)
+        .filter(|(_, axis)| axis.modes.len() > 1)
+        .map(|((_, mode), _)| pascal_case(mode))
+        .collect();
+    if name.is_empty() {
+        "Default".to_string()
+    } else {
+        name
+    }
+}
+
+/// Identifier for a variable in native backends. A formatted name is used as
+/// written, with characters outside `[A-Za-z0-9_]` replaced by `_`; otherwise the
+/// legacy key is camel-cased. Identifiers starting with a digit are prefixed
+/// with `_`.
+pub fn native_identifier(token: &GeneratedToken) -> String {
+    let Some(formatted) = &token.formatted else {
+        return camel_identifier(&token.key);
+    };
+    let ident: String = formatted
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Control points `[x1, y1, x2, y2]` of an easing value: a DTCG `cubicBezier`
+/// array, a CSS `cubic-bezier(…)` string, or a CSS easing keyword.
+pub fn parse_cubic_bezier(v: &Value) -> Option<[f64; 4]> {
+    let points: Vec<f64> = match v {
+        Value::Array(items) => items.iter().map(Value::as_f64).collect::<Option<_>>()?,
+        Value::String(s) => match s.trim() {
+            "linear" => return Some([0.0, 0.0, 1.0, 1.0]),
+            "ease" => return Some([0.25, 0.1, 0.25, 1.0]),
+            "ease-in" => return Some([0.42, 0.0, 1.0, 1.0]),
+            "ease-out" => return Some([0.0, 0.0, 0.58, 1.0]),
+            "ease-in-out" => return Some([0.42, 0.0, 0.58, 1.0]),
+            other => other
+                .strip_prefix("cubic-bezier(")?
+                .strip_suffix(')')?
+                .split(',')
+                .map(|p| p.trim().parse().ok())
+                .collect::<Option<_>>()?,
+        },
+        _ => return None,
+    };
+    match points.as_slice() {
+        &[x1, y1, x2, y2] if (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2) => {
+            Some([x1, y1, x2, y2])
+        }
+        _ => None,
+    }
+}
+
+/// Format a float without a trailing `.0` for whole numbers.
+pub fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        format!("{}", n as i64)
+    } else {
+        let s = format!("{n:.4}");
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::graph::ModeSetRecord;
+
+    pub(crate) fn mode_set(name: &str, modes: &[&str], default: &str) -> ModeSetRecord {
+        ModeSetRecord {
+            file: PathBuf::from(format!("{name}.json")),
+            name: name.to_string(),
+            modes: modes.iter().map(|m| m.to_string()).collect(),
+            default_mode: default.to_string(),
+        }
+    }
+
+    /// A small cascade dataset: a two-mode palette, an alias into it, and a
+    /// scale-dependent dimension.
+    pub(crate) fn sample_graph() -> TokenGraph {
+        let tokens = vec![
+            json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "light"},
+                   "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/color.json",
+                   "value": "rgb(245, 249, 255)", "uuid": "blue-light", "set_uuid": "blue-set"}),
+            json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100, "colorScheme": "dark"},
+                   "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/color.json",
+                   "value": "rgb(14, 23, 63)", "uuid": "blue-dark", "set_uuid": "blue-set"}),
+            json!({"name": {"property": "color", "component": "button", "colorRole": "accent", "legacyKey": "accent-background-color-default"},
+                   "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+                   "$ref": "blue-set", "uuid": "accent-bg"}),
+            json!({"name": {"property": "gap", "scale": "desktop", "legacyKey": "field-gap"},
+                   "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/dimension.json",
+                   "value": "8px", "uuid": "gap-desktop"}),
+            json!({"name": {"property": "gap", "scale": "mobile", "legacyKey": "field-gap"},
+                   "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/dimension.json",
+                   "value": "10px", "uuid": "gap-mobile"}),
+        ];
+        TokenGraph::from_pairs(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, t)| {
+                    (
+                        format!("t.tokens.json:{i}"),
+                        PathBuf::from("t.tokens.json"),
+                        t,
+                    )
+                })
+                .collect(),
+        )
+        .with_mode_sets(vec![
+            mode_set("colorScheme", &["light", "dark"], "light"),
+            mode_set("scale", &["desktop", "mobile"], "desktop"),
+        ])
+    }
+
+    /// Duration, easing and motion tokens under a `reducedMotion` mode set.
+    pub(crate) fn motion_graph() -> TokenGraph {
+        const TYPES: &str = "https://opensource.adobe.com/spectrum-design-data/schemas/token-types";
+        let tokens = vec![
+            json!({"name": {"property": "animation-duration-100", "reducedMotion": "no-preference"},
+                   "$schema": format!("{TYPES}/duration.json"), "value": "130ms", "uuid": "dur-full"}),
+            json!({"name": {"property": "animation-duration-100", "reducedMotion": "reduce"},
+                   "$schema": format!("{TYPES}/duration.json"), "value": "0ms", "uuid": "dur-reduce"}),
+            json!({"name": {"property": "animation-ease-in-out"},
+                   "$schema": format!("{TYPES}/easing.json"), "value": [0.45, 0, 0.4, 1], "uuid": "ease"}),
+            json!({"name": {"property": "animation-enter"},
+                   "$schema": format!("{TYPES}/motion.json"),
+                   "value": {"duration": "{animation-duration-100}", "timingFunction": [0, 0, 0.4, 1]},
+                   "uuid": "enter"}),
+        ];
+        TokenGraph::from_pairs(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, t)| {
+                    (
+                        format!("m.tokens.json:{i}"),
+                        PathBuf::from("m.tokens.json"),
+                        t,
+                    )
+                })
+                .collect(),
+        )
+        .with_mode_sets(vec![mode_set(
+            "reducedMotion",
+            &["no-preference", "reduce"],
+            "no-preference",
+        )])
+    }
+
+    #[test]
+    fn combinations_start_with_base_and_cover_matrix() {
+        let graph = sample_graph();
+        let axes = mode_axes(&graph, &HashMap::new());
+        let combos = mode_combinations(&axes);
+        assert_eq!(combos.len(), 4);
+        assert_eq!(
+            combos[0].modes,
+            vec![
+                ("colorScheme".to_string(), "light".to_string()),
+                ("scale".to_string(), "desktop".to_string())
+            ]
+        );
+        assert_eq!(combos[3].non_base(&axes).len(), 2);
+    }
+
+    #[test]
+    fn restrictions_prune_axes_and_rebase() {
+        let graph = sample_graph();
+        let restrictions = HashMap::from([("colorScheme".to_string(), vec!["dark".to_string()])]);
+        let axes = mode_axes(&graph, &restrictions);
+        let cs = axes.iter().find(|a| a.name == "colorScheme").unwrap();
+        assert_eq!(cs.modes, vec!["dark"]);
+        assert_eq!(cs.base, "dark");
+        assert_eq!(mode_combinations(&axes).len(), 2);
+    }
+
+    #[test]
+    fn resolve_all_groups_by_legacy_key() {
+        let set = resolve_all(&sample_graph(), &HashMap::new());
+        let keys: Vec<&str> = set.tokens.iter().map(|t| t.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["accent-background-color-default", "blue-100", "field-gap"]
+        );
+
+        let gap = set.tokens.iter().find(|t| t.key == "field-gap").unwrap();
+        assert_eq!(gap.schema_type(), Some("dimension"));
+        assert_eq!(gap.values[0], Some(ResolvedValue::Literal(json!("8px"))));
+        let mobile = set
+            .combinations
+            .iter()
+            .position(|c| c.non_base(&set.axes) == vec![("scale", "mobile")])
+            .unwrap();
+        assert_eq!(
+            gap.values[mobile],
+            Some(ResolvedValue::Literal(json!("10px")))
+        );
+    }
+
+    #[test]
+    fn aliases_resolve_to_target_key_and_context_value() {
+        let set = resolve_all(&sample_graph(), &HashMap::new());
+        let accent = set
+            .tokens
+            .iter()
+            .find(|t| t.key == "accent-background-color-default")
+            .unwrap();
+        let dark = set
+            .combinations
+            .iter()
+            .position(|c| c.non_base(&set.axes) == vec![("colorScheme", "dark")])
+            .unwrap();
+        assert_eq!(
+            accent.values[dark],
+            Some(ResolvedValue::Alias {
+                target: "blue-100".to_string(),
+                resolved: Some(json!("rgb(14, 23, 63)")),
+            })
+        );
+        assert_eq!(accent.schema_type(), Some("color"));
+    }
+
+    #[test]
+    fn combination_names_skip_single_mode_axes() {
+        let graph = sample_graph();
+        let restrictions = HashMap::from([("scale".to_string(), vec!["mobile".to_string()])]);
+        let axes = mode_axes(&graph, &restrictions);
+        let names: Vec<String> = mode_combinations(&axes)
+            .iter()
+            .map(|c| combination_name(c, &axes))
+            .collect();
+        assert_eq!(names, vec!["Light", "Dark"]);
+        assert_eq!(
+            combination_name(&ModeCombination { modes: vec![] }, &[]),
+            "Default"
+        );
+    }
+
+    #[test]
+    fn formats_numbers_without_trailing_zeros() {
+        assert_eq!(format_number(14.0), "14");
+        assert_eq!(format_number(0.3333), "0.3333");
+        assert_eq!(format_number(0.5), "0.5");
+        assert_eq!(format_number(0.00001), "0");
+        assert_eq!(format_number(10.00001), "10");
+    }
+
+    #[test]
+    fn parses_easing_literals() {
+        assert_eq!(
+            parse_cubic_bezier(&json!("cubic-bezier(0.45, 0, 0.4, 1)")),
+            Some([0.45, 0.0, 0.4, 1.0])
+        );
+        assert_eq!(
+            parse_cubic_bezier(&json!("ease-out")),
+            Some([0.0, 0.0, 0.58, 1.0])
+        );
+        assert_eq!(
+            parse_cubic_bezier(&json!([0, 0, 0.4, 1])),
+            Some([0.0, 0.0, 0.4, 1.0])
+        );
+        assert_eq!(parse_cubic_bezier(&json!([1.5, 0, 0.4, 1])), None);
+        assert_eq!(parse_cubic_bezier(&json!("bounce")), None);
+    }
+
+    #[test]
+    fn ancestors_are_non_base_subsets() {
+        let set = resolve_all(&sample_graph(), &HashMap::new());
+        let last = set.combinations.len() - 1;
+        assert_eq!(set.ancestors(last), vec![0, 1, 2]);
+        assert_eq!(set.ancestors(1), vec![0]);
+        assert!(set.ancestors(0).is_empty());
+    }
+
+    #[test]
+    fn formatting_renames_outputs_but_keeps_keys() {
+        let mut set = resolve_all(&sample_graph(), &HashMap::new());
+        set.apply_formatting(&NameFormatting {
+            delimiter: ".".to_string(),
+            ..NameFormatting::default()
+        });
+        let names = set.output_names();
+        assert_eq!(names["blue-100"], "color.blue.100");
+        assert_eq!(
+            names["accent-background-color-default"],
+            "button.color.accent"
+        );
+        let token = set.tokens.iter().find(|t| t.key == "blue-100").unwrap();
+        assert_eq!(native_identifier(token), "color_blue_100");
+    }
+
+    #[test]
+    fn colliding_formatted_names_keep_legacy_keys() {
+        let graph = TokenGraph::from_pairs(vec![
+            (
+                "a".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "gap", "legacyKey": "field-gap"}, "value": "8px"}),
+            ),
+            (
+                "b".to_string(),
+                PathBuf::from("t.json"),
+                json!({"name": {"property": "gap", "legacyKey": "row-gap"}, "value": "4px"}),
+            ),
+        ]);
+        let mut set = resolve_all(&graph, &HashMap::new());
+        let collisions = set.apply_formatting(&NameFormatting::default());
+        assert_eq!(
+            collisions,
+            vec![(
+                "gap".to_string(),
+                vec!["field-gap".to_string(), "row-gap".to_string()]
+            )]
+        );
+        assert!(set.tokens.iter().all(|t| t.formatted.is_none()));
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Swift (UIKit) backend.
+//!
+//! Emits one caseless `enum` per mode combination (e.g. `Spectrum.DarkMobile`)
+//! inside a top-level namespace enum. Every namespace is complete — aliases are
+//! flattened to their resolved value in that combination, so a consumer switches
+//! themes by switching namespace. The Swift type is chosen from the token-type
+//! schema: `color.json` → `UIColor`, `dimension.json`/`font-size.json` → `CGFloat`,
+//! `font-weight.json` → `UIFont.Weight`, `duration.json` → `TimeInterval`,
+//! `easing.json` → `CAMediaTimingFunction`, and so on. Values with no native
+//! representation (composites, non-pixel units) are skipped.
+
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use crate::cascade::{numeric_value, parse_dimension, parse_duration};
+use crate::color::parse_rgba;
+
+use super::{
+    combination_name, format_number, native_identifier, parse_cubic_bezier, GeneratedToken,
+    ResolvedTokenSet, ResolvedValue,
+};
+
+/// Options for [`render`].
+#[derive(Debug, Clone)]
+pub struct SwiftOptions {
+    /// Name of the top-level namespace enum.
+    pub namespace: String,
+}
+
+impl Default for SwiftOptions {
+    fn default() -> Self {
+        Self {
+            namespace: "Spectrum".to_string(),
+        }
+    }
+}
+
+const RESERVED: &[&str] = &[
+    "as",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "false",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "is",
+    "let",
+    "nil",
+    "operator",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "super",
+    "switch",
+    "throw",
+    "true",
+    "try",
+    "var",
+    "where",
+    "while",
+];
+
+/// Render `set` as a Swift source file.
+pub fn render(set: &ResolvedTokenSet, opts: &SwiftOptions) -> String {
+    let mut out = String::from("// Generated by design-data generate swift. Do not edit.\n\n");
+    out.push_str("import UIKit\n\n");
+    let _ = writeln!(out, "public enum {} {{", opts.namespace);
+    for (idx, combo) in set.combinations.iter().enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(
+            out,
+            "    public enum {} {{",
+            combination_name(combo, &set.axes)
+        );
+        for token in &set.tokens {
+            let Some(value) = &token.values[idx] else {
+                continue;
+            };
+            let Some((ty, expr)) = swift_literal(token, value) else {
+                continue;
+            };
+            if let ResolvedValue::Alias { target, .. } = value {
+                let _ = writeln!(out, "        /// Alias of `{target}`.");
+            }
+            let _ = writeln!(
+                out,
+                "        public static let {}: {ty} = {expr}",
+                identifier(token)
+            );
+        }
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Swift identifier for a variable.
+fn identifier(token: &GeneratedToken) -> String {
+    escape_reserved(native_identifier(token))
+}
+
+/// Escape a reserved word with backticks.
+fn escape_reserved(ident: String) -> String {
+    if RESERVED.contains(&ident.as_str()) {
+        format!("`{ident}`")
+    } else {
+        ident
+    }
+}
+
+/// The `(type, expression)` pair for a token value, or `None` when the value has
+/// no Swift representation.
+fn swift_literal(token: &GeneratedToken, value: &ResolvedValue) -> Option<(&'static str, String)> {
+    let v = value.terminal()?;
+    match token.schema_type()? {
+        "color" => {
+            let [r, g, b, a] = parse_rgba(v.as_str()?)?;
+            Some((
+                "UIColor",
+                format!(
+                    "UIColor(red: {}, green: {}, blue: {}, alpha: {})",
+                    format_number(r),
+                    format_number(g),
+                    format_number(b),
+                    format_number(a)
+                ),
+            ))
+        }
+        "dimension" | "font-size" => {
+            let (n, unit) = match v {
+                Value::String(s) => parse_dimension(s)?,
+                other => (numeric_value(other)?, ""),
+            };
+            matches!(unit, "" | "px" | "dp" | "pt").then(|| ("CGFloat", format_number(n)))
+        }
+        "multiplier" | "opacity" | "gradient-stop" | "angle" => {
+            Some(("CGFloat", format_number(numeric_value(v)?)))
+        }
+        "font-weight" => Some(("UIFont.Weight", format!(".{}", font_weight(v.as_str()?)?))),
+        "duration" => Some((
+            "TimeInterval",
+            format_number(parse_duration(v.as_str()?)? / 1000.0),
+        )),
+        "easing" => {
+            let [x1, y1, x2, y2] = parse_cubic_bezier(v)?;
+            Some((
+                "CAMediaTimingFunction",
+                format!(
+                    "CAMediaTimingFunction(controlPoints: {}, {}, {}, {})",
+                    format_number(x1),
+                    format_number(y1),
+                    format_number(x2),
+                    format_number(y2)
+                ),
+            ))
+        }
+        "font-family" | "font-style" | "alignment" | "text-transform" => {
+            Some(("String", serde_json::to_string(v.as_str()?).ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// Map a font-weight keyword to its `UIFont.Weight` member.
+fn font_weight(keyword: &str) -> Option<&'static str> {
+    Some(match keyword {
+        "thin" => "thin",
+        "extra-light" | "ultra-light" => "ultraLight",
+        "light" => "light",
+        "regular" | "normal" => "regular",
+        "medium" => "medium",
+        "semi-bold" | "semibold" => "semibold",
+        "bold" => "bold",
+        "extra-bold" | "heavy" => "heavy",
+        "black" => "black",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::generate::resolve_all;
+    use crate::generate::tests::{motion_graph, sample_graph};
+
+    fn render_sample() -> String {
+        render(
+            &resolve_all(&sample_graph(), &HashMap::new()),
+            &SwiftOptions::default(),
+        )
+    }
+
+    #[test]
+    fn emits_one_namespace_per_combination() {
+        let swift = render_sample();
+        assert!(swift.starts_with("// Generated by design-data generate swift"));
+        assert!(swift.contains("public enum Spectrum {"));
+        for name in ["LightDesktop", "DarkDesktop", "LightMobile", "DarkMobile"] {
+            assert!(
+                swift.contains(&format!("    public enum {name} {{")),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn maps_schema_types_to_swift_types() {
+        let swift = render_sample();
+        assert!(swift.contains(
+            "public static let blue100: UIColor = UIColor(red: 0.9608, green: 0.9765, blue: 1, alpha: 1)"
+        ));
+        assert!(swift.contains("public static let fieldGap: CGFloat = 8"));
+        assert!(swift.contains("public static let fieldGap: CGFloat = 10"));
+    }
+
+    #[test]
+    fn maps_motion_types() {
+        let swift = render(
+            &resolve_all(&motion_graph(), &HashMap::new()),
+            &SwiftOptions::default(),
+        );
+        assert!(swift.contains("    public enum Reduce {"));
+        assert!(swift.contains("public static let animationDuration100: TimeInterval = 0.13"));
+        assert!(swift.contains("public static let animationDuration100: TimeInterval = 0\n"));
+        assert!(swift.contains(
+            "public static let animationEaseInOut: CAMediaTimingFunction = CAMediaTimingFunction(controlPoints: 0.45, 0, 0.4, 1)"
+        ));
+        // The motion composite has no single native type.
+        assert!(!swift.contains("animationEnter"));
+    }
+
+    #[test]
+    fn aliases_are_flattened_per_combination() {
+        let swift = render_sample();
+        assert!(swift.contains("/// Alias of `blue-100`."));
+        assert!(swift.contains(
+            "public static let accentBackgroundColorDefault: UIColor = UIColor(red: 0.0549, green: 0.0902, blue: 0.2471, alpha: 1)"
+        ));
+    }
+
+    #[test]
+    fn font_weights_and_reserved_identifiers() {
+        assert_eq!(font_weight("extra-bold"), Some("heavy"));
+        assert_eq!(font_weight("unknown"), None);
+        assert_eq!(escape_reserved("default".into()), "`default`");
+    }
+
+    #[test]
+    fn formatted_names_become_identifiers() {
+        let mut set = resolve_all(&sample_graph(), &HashMap::new());
+        set.apply_formatting(&crate::naming::NameFormatting {
+            casing: crate::naming::Casing::PascalCase,
+            delimiter: "_".to_string(),
+            ..Default::default()
+        });
+        let swift = render(&set, &SwiftOptions::default());
+        assert!(swift.contains("public static let Color_Blue_100: UIColor"));
+        assert!(swift.contains("public static let Gap: CGFloat = 8"));
+    }
+}
@@ -98,6 +98,29 @@ pub struct RelationshipRecord {
     pub raw: Value,
 }
 
+/// How a dependent token refers to the token it depends on, as recorded by
+/// [`TokenGraph::reverse_dependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyKind {
+    /// `$ref` or whole-value `{name}` alias. An alias to a set-level UUID
+    /// depends on every member of the set.
+    Alias,
+    /// `{name}` reference inside a composite (object or array) value.
+    Composite,
+    /// Higher-layer token carrying the same UUID (a product-context override
+    /// or a platform extension shadowing a foundation token).
+    Override,
+}
+
+/// One reverse edge in [`TokenGraph::reverse_dependencies`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Dependent {
+    /// Graph key of the dependent token.
+    pub key: String,
+    pub kind: DependencyKind,
+}
+
 /// One mode set declaration (new spec shape), when present in a JSON file.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ModeSetRecord {
@@ -121,6 +144,10 @@ pub struct TokenRecord {
     pub raw: Value,
     /// Cascade layer this token belongs to.
     pub layer: Layer,
+    /// Byte range of the token in `file` (array element, or legacy key through
+    /// value), when loaded from disk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<crate::report::Span>,
 }
 
 /// Token graph across files.
@@ -358,6 +385,7 @@ impl TokenGraph {
         for path in discover_json_files(root)? {
             let text = std::fs::read_to_string(&path)?;
             let value: Value = serde_json::from_str(&text)?;
+            let spans = crate::locate::top_level_spans(&text);
 
             // Cascade format: top-level array of token objects.
             if let Some(arr) = value.as_array() {
@@ -416,6 +444,7 @@ impl TokenGraph {
                             alias_target,
                             raw: token_val.clone(),
                             layer: Layer::Foundation,
+                            span: spans.get(&idx.to_string()).copied(),
                         },
                     );
                 }
@@ -473,6 +502,7 @@ impl TokenGraph {
                         alias_target,
                         raw,
                         layer: Layer::Foundation,
+                        span: spans.get(token_name).copied(),
                     },
                 );
             }
@@ -544,6 +574,7 @@ impl TokenGraph {
                     alias_target,
                     raw,
                     layer: Layer::Foundation,
+                    span: None,
                 },
             );
         }
@@ -665,6 +696,7 @@ impl TokenGraph {
                         alias_target: None,
                         raw: synthetic_raw,
                         layer: Layer::Product,
+                        span: None,
                     },
                 );
             }
@@ -702,6 +734,7 @@ impl TokenGraph {
                         alias_target,
                         raw: token_val.clone(),
                         layer: Layer::Product,
+                        span: None,
                     },
                 );
             }
@@ -736,6 +769,9 @@ impl TokenGraph {
     /// 4. `modeSetRestrictions` — returned so the caller can seed a
     ///    [`crate::cascade::ResolutionContext`]; restrictions are enforced at
     ///    resolution time, not by mutating the graph.
+    /// 5. `extensions.formatting` — parsed into a
+    ///    [`crate::naming::NameFormatting`] and returned; an invalid block is a
+    ///    [`CoreError::ParseError`].
     ///
     /// The caller is responsible for Layer 1 schema validation of the manifest
     /// (see [`crate::schema::validate_manifest`]); this method assumes a
@@ -827,6 +863,7 @@ impl TokenGraph {
                             alias_target,
                             raw,
                             layer: Layer::Platform,
+                            span: None,
                         },
                     );
                 }
@@ -869,6 +906,7 @@ impl TokenGraph {
                         alias_target,
                         raw: token_val.clone(),
                         layer: Layer::Platform,
+                        span: None,
                     },
                 );
             }
@@ -893,23 +931,28 @@ impl TokenGraph {
             }
         }
 
+        // 5. extensions.formatting — returned for name serialization.
+        let formatting = crate::naming::NameFormatting::from_manifest(manifest)?;
+
         Ok(PlatformManifest {
             mode_set_restrictions,
+            formatting,
         })
     }
 
     /// Resolve a manifest override `target` to the affected token name objects.
     ///
     /// Resolution order matches [`Self::apply_platform_manifest`]: query expression
-    /// (when `target` contains `=` or `!=`), then [`Self::resolve_alias_key`]'s
+    /// (when `target` contains `=` or `(`), then [`Self::resolve_alias_key`]'s
     /// uuid → graph key → legacy-name chain.
     fn resolve_override_targets(
         &self,
         target: &str,
     ) -> Result<Vec<OverrideTargetMatch>, CoreError> {
         // Query expression heuristic: UUIDs (hex+hyphens) and slugs (word chars+hyphens)
-        // never contain '=', so presence of '=' unambiguously signals a query expression.
-        if target.contains('=') {
+        // never contain '=' or '(', so either unambiguously signals a query expression
+        // (`(` covers `exists(…)` and `in (…)` without any `=`).
+        if target.contains(['=', '(']) {
             let filter = query::parse(target)?;
             return Ok(query::filter(self, &filter)
                 .into_iter()
@@ -1157,6 +1200,9 @@ impl TokenGraph {
 pub struct PlatformManifest {
     /// Mode set name → allowed mode values declared by `modeSetRestrictions`.
     pub mode_set_restrictions: HashMap<String, Vec<String>>,
+    /// `extensions.formatting`, when declared — how this platform serializes
+    /// name objects into flat token names.
+    pub formatting: Option<crate::naming::NameFormatting>,
 }
 
 /// One foundation token matched by a manifest `overrides[].target` string.
@@ -1242,6 +1288,18 @@ pub(crate) fn extract_alias_target(obj: &serde_json::Map<String, Value>) -> Opti
     None
 }
 
+/// Every whole-string `{name}` reference nested in a composite value.
+fn collect_inline_refs<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::String(s) if s.starts_with('{') && s.ends_with('}') && s.len() > 2 => {
+            out.push(&s[1..s.len() - 1]);
+        }
+        Value::Array(items) => items.iter().for_each(|v| collect_inline_refs(v, out)),
+        Value::Object(map) => map.values().for_each(|v| collect_inline_refs(v, out)),
+        _ => {}
+    }
+}
+
 fn normalize_ref_target(s: &str) -> String {
     let s = s.trim();
     let file_name = s.rsplit(['/', '\\']).next().unwrap_or(s);
@@ -1288,6 +1346,21 @@ impl TokenGraph {
         })
     }
 
+    /// Every target [`Self::relationship_target_exists`] accepts, for callers
+    /// checking many targets (it scans all relationships per call).
+    pub fn relationship_targets(&self) -> HashSet<&str> {
+        self.relationships
+            .iter()
+            .flat_map(|r| {
+                [
+                    r.uuid.as_deref(),
+                    r.raw.get("setUuid").and_then(|v| v.as_str()),
+                ]
+            })
+            .flatten()
+            .collect()
+    }
+
     /// Resolve a set-level UUID to the context-appropriate child record.
     ///
     /// Picks the child from `set_uuid_index` whose name-object fields best match
@@ -1328,6 +1401,77 @@ impl TokenGraph {
         best
     }
 
+    /// Reverse-dependency index: graph key → the tokens that depend on it
+    /// directly, sorted by key.
+    ///
+    /// Built from the forward edges the graph already stores: `alias_target`
+    /// (expanded to every member when it names a set-level UUID), `{name}`
+    /// references inside composite values, and higher-layer tokens that share
+    /// a lower-layer token's UUID. Alias targets are resolved with
+    /// [`Self::resolve_alias_key`], so dangling references contribute no edge.
+    /// Computed on demand; callers walking it repeatedly should keep the map.
+    pub fn reverse_dependencies(&self) -> HashMap<String, Vec<Dependent>> {
+        let mut by_uuid: HashMap<&str, Vec<&TokenRecord>> = HashMap::new();
+        for rec in self.tokens.values() {
+            if let Some(u) = rec.uuid.as_deref() {
+                by_uuid.entry(u).or_default().push(rec);
+            }
+        }
+
+        let mut index: HashMap<String, Vec<Dependent>> = HashMap::new();
+        let mut add = |target: &str, rec: &TokenRecord, kind: DependencyKind| {
+            if target != rec.name {
+                index
+                    .entry(target.to_string())
+                    .or_default()
+                    .push(Dependent {
+                        key: rec.name.clone(),
+                        kind,
+                    });
+            }
+        };
+        for rec in self.tokens.values() {
+            if let Some(alias) = rec.alias_target.as_deref() {
+                match self.set_members(alias) {
+                    Some(members) => {
+                        for member in members {
+                            add(member, rec, DependencyKind::Alias);
+                        }
+                    }
+                    None => {
+                        if let Some(target) = self.resolve_alias_key(alias) {
+                            add(&target.name, rec, DependencyKind::Alias);
+                        }
+                    }
+                }
+            } else if let Some(value @ (Value::Object(_) | Value::Array(_))) = rec.raw.get("value")
+            {
+                let mut refs = Vec::new();
+                collect_inline_refs(value, &mut refs);
+                for r in refs {
+                    if let Some(target) = self.resolve_alias_key(r) {
+                        add(&target.name, rec, DependencyKind::Composite);
+                    }
+                }
+            }
+            if let Some(shadowed) = rec.uuid.as_deref().and_then(|u| by_uuid.get(u)) {
+                for base in shadowed.iter().filter(|b| b.layer < rec.layer) {
+                    add(&base.name, rec, DependencyKind::Override);
+                }
+            }
+        }
+        for deps in index.values_mut() {
+            deps.sort();
+            deps.dedup();
+        }
+        index
+    }
+
+    /// Graph keys of every member of a set-level UUID, in insertion order.
+    pub fn set_members(&self, set_uuid: &str) -> Option<&[String]> {
+        self.set_uuid_index.get(set_uuid).map(Vec::as_slice)
+    }
+
     /// Context-aware alias resolution: checks `set_uuid_index` first.
     ///
     /// When `alias_target` is a set-level UUID, returns the mode-appropriate child
@@ -1424,6 +1568,32 @@ mod tests {
         assert_eq!(name["scaleIndex"], 100);
     }
 
+    #[test]
+    fn records_source_spans_for_cascade_and_legacy_tokens() {
+        let dir = tempdir().unwrap();
+        let cascade =
+            "[\n  {\"uuid\": \"a\", \"value\": 1},\n  {\"uuid\": \"b\", \"value\": 2}\n]\n";
+        let legacy = "{\n  \"gap\": {\"$ref\": \"x\"}\n}\n";
+        std::fs::write(dir.path().join("c.tokens.json"), cascade).unwrap();
+        std::fs::write(dir.path().join("l.json"), legacy).unwrap();
+
+        let g = TokenGraph::from_json_dir(dir.path()).unwrap();
+        let text_of = |t: &TokenRecord, text: &'static str| {
+            let span = t.span.expect("span recorded");
+            &text[span.offset..span.offset + span.len]
+        };
+        let second = g
+            .tokens
+            .values()
+            .find(|t| t.uuid.as_deref() == Some("b"))
+            .unwrap();
+        assert_eq!(text_of(second, cascade), "{\"uuid\": \"b\", \"value\": 2}");
+        assert_eq!(
+            text_of(&g.tokens["gap"], legacy),
+            "\"gap\": {\"$ref\": \"x\"}"
+        );
+    }
+
     #[test]
     fn names_and_catalogs_extends_inline_mode_sets_and_merges_sidecar() {
         let tokens_dir = tempdir().unwrap();
@@ -1802,6 +1972,20 @@ mod tests {
         assert!(outcome.mode_set_restrictions.is_empty());
     }
 
+    #[test]
+    fn manifest_formatting_returned() {
+        let mut g = foundation_graph();
+        let manifest = json!({
+            "specVersion": "1.0.0-draft",
+            "foundationVersion": "1.0.0",
+            "extensions": {"formatting": {"casing": "camelCase", "delimiter": "."}}
+        });
+        let outcome = g.apply_platform_manifest(&manifest).unwrap();
+        let fmt = outcome.formatting.expect("formatting");
+        assert_eq!(fmt.casing, crate::naming::Casing::CamelCase);
+        assert_eq!(fmt.delimiter, ".");
+    }
+
     // ── resolve_alias_key / resolve_leaf (UUID-first, cycle guard) ────────────
 
     /// Helper: build a cascade-format graph from an array of token objects.
@@ -1947,4 +2131,72 @@ mod tests {
             result_a.uuid
         );
     }
+
+    #[test]
+    fn reverse_dependencies_cover_aliases_sets_composites_and_overrides() {
+        let f = || PathBuf::from("t.tokens.json");
+        let mut g = TokenGraph::from_pairs(vec![
+            (
+                "blue-light".into(),
+                f(),
+                json!({"uuid": "bl", "set_uuid": "blue", "value": "#00f"}),
+            ),
+            (
+                "blue-dark".into(),
+                f(),
+                json!({"uuid": "bd", "set_uuid": "blue", "value": "#00a"}),
+            ),
+            ("accent".into(), f(), json!({"uuid": "ac", "$ref": "blue"})),
+            (
+                "focus".into(),
+                f(),
+                json!({"uuid": "fo", "value": "{accent}"}),
+            ),
+            (
+                "ring".into(),
+                f(),
+                json!({"uuid": "ri", "value": {"color": "{focus}", "width": "2px"}}),
+            ),
+            (
+                "dangling".into(),
+                f(),
+                json!({"uuid": "da", "$ref": "missing"}),
+            ),
+            (
+                "product-blue".into(),
+                f(),
+                json!({"uuid": "bl", "value": "#11f"}),
+            ),
+        ]);
+        g.tokens.get_mut("product-blue").unwrap().layer = Layer::Product;
+
+        let index = g.reverse_dependencies();
+        let deps = |key: &str| -> Vec<(String, DependencyKind)> {
+            index
+                .get(key)
+                .map(|d| d.iter().map(|d| (d.key.clone(), d.kind)).collect())
+                .unwrap_or_default()
+        };
+        assert_eq!(
+            deps("blue-light"),
+            vec![
+                ("accent".to_string(), DependencyKind::Alias),
+                ("product-blue".to_string(), DependencyKind::Override),
+            ]
+        );
+        assert_eq!(
+            deps("blue-dark"),
+            vec![("accent".to_string(), DependencyKind::Alias)]
+        );
+        assert_eq!(
+            deps("accent"),
+            vec![("focus".to_string(), DependencyKind::Alias)]
+        );
+        assert_eq!(
+            deps("focus"),
+            vec![("ring".to_string(), DependencyKind::Composite)]
+        );
+        assert!(deps("ring").is_empty());
+        assert!(!index.contains_key("missing"));
+    }
 }
@@ -0,0 +1,646 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Alias graph export as Graphviz DOT, Mermaid or node-link JSON.
+//!
+//! Nodes are tokens, components and CTR relationships. Edges point from the
+//! referring node to the referenced one:
+//!
+//! * token → token — the edges of [`TokenGraph::reverse_dependencies`]
+//!   (alias, composite member, higher-layer override);
+//! * component → token — `tokenBindings[]`, matched by legacy key;
+//! * component → relationship — the relationship's `scope.component`;
+//! * relationship → token or relationship — its `$ref`. A set-level UUID
+//!   points at every member.
+//!
+//! [`export`] seeds the traversal with the nodes matching a query filter and
+//! follows edges forward up to a depth limit; the result is the subgraph those
+//! nodes induce. Components and relationships are matched as if their name
+//! object were `{"component": name}` and their `scope`, so
+//! `component=button` selects the button component, its relationships and
+//! any button-scoped tokens.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::graph::{DependencyKind, Layer, RelationshipRecord, TokenGraph, TokenRecord};
+use crate::naming::extract_legacy_key;
+use crate::query::{self, TokenFilter};
+
+/// What a [`GraphNode`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+    Token,
+    Component,
+    Relationship,
+}
+
+/// What a [`GraphEdge`] records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgeKind {
+    Alias,
+    Composite,
+    Override,
+    /// Component `tokenBindings[]` entry.
+    Binding,
+    /// Component → relationship scoped to it.
+    Scope,
+    /// Relationship `$ref`.
+    Ref,
+}
+
+impl EdgeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            EdgeKind::Alias => "alias",
+            EdgeKind::Composite => "composite",
+            EdgeKind::Override => "override",
+            EdgeKind::Binding => "binding",
+            EdgeKind::Scope => "scope",
+            EdgeKind::Ref => "ref",
+        }
+    }
+}
+
+impl From<DependencyKind> for EdgeKind {
+    fn from(kind: DependencyKind) -> Self {
+        match kind {
+            DependencyKind::Alias => EdgeKind::Alias,
+            DependencyKind::Composite => EdgeKind::Composite,
+            DependencyKind::Override => EdgeKind::Override,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct GraphNode {
+    /// Token UUID (graph key when the UUID is missing or shared),
+    /// `component:<name>`, or `relationship:<file>#<index>`.
+    pub id: String,
+    pub kind: NodeKind,
+    /// Legacy key (with pinned modes) for tokens, the name for components,
+    /// and `component property` for relationships.
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layer: Option<Layer>,
+    /// Literal value, for tokens and relationships that carry one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    pub file: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+    pub kind: EdgeKind,
+}
+
+/// An exported graph in node-link form.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct AliasGraph {
+    pub nodes: Vec<GraphNode>,
+    /// Named `links` in JSON, as node-link readers (d3, networkx) expect.
+    #[serde(rename = "links")]
+    pub edges: Vec<GraphEdge>,
+}
+
+/// Export the alias graph, optionally limited to the nodes matching `filter`
+/// and what they reach within `depth` edges.
+///
+/// Without a filter every node is a seed, so `depth` has no effect.
+pub fn export(
+    graph: &TokenGraph,
+    filter: Option<&TokenFilter>,
+    depth: Option<usize>,
+) -> AliasGraph {
+    let full = build(graph);
+
+    let seeds: Vec<&str> = match filter {
+        None => full.nodes.keys().map(String::as_str).collect(),
+        Some(filter) => {
+            let wanted = seed_ids(graph, filter);
+            full.nodes
+                .keys()
+                .map(String::as_str)
+                .filter(|id| wanted.contains(*id))
+                .collect()
+        }
+    };
+    let mut out_edges: HashMap<&str, Vec<&GraphEdge>> = HashMap::new();
+    for edge in &full.edges {
+        out_edges.entry(&edge.source).or_default().push(edge);
+    }
+    let mut included: HashSet<&str> = seeds.iter().copied().collect();
+    let mut queue: VecDeque<(&str, usize)> = seeds.iter().map(|id| (*id, 0)).collect();
+    while let Some((id, d)) = queue.pop_front() {
+        if depth.is_some_and(|max| d >= max) {
+            continue;
+        }
+        for edge in out_edges.get(id).into_iter().flatten() {
+            if included.insert(&edge.target) {
+                queue.push_back((&edge.target, d + 1));
+            }
+        }
+    }
+
+    let included: HashSet<String> = included.into_iter().map(str::to_string).collect();
+    let mut edges: Vec<GraphEdge> = full
+        .edges
+        .iter()
+        .filter(|e| included.contains(e.source.as_str()) && included.contains(e.target.as_str()))
+        .cloned()
+        .collect();
+    edges.sort();
+    edges.dedup();
+    let mut nodes: Vec<GraphNode> = full
+        .nodes
+        .into_values()
+        .filter(|n| included.contains(n.id.as_str()))
+        .collect();
+    nodes.sort_by(|a, b| (a.kind, &a.label, &a.id).cmp(&(b.kind, &b.label, &b.id)));
+    AliasGraph { nodes, edges }
+}
+
+impl AliasGraph {
+    /// Graphviz DOT, laid out left to right. Components are drawn as
+    /// `component` shapes, relationships as `note`s; non-alias edges are
+    /// labelled with their kind.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph tokens {\n  rankdir=LR;\n  node [shape=box];\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                NodeKind::Token => "box",
+                NodeKind::Component => "component",
+                NodeKind::Relationship => "note",
+            };
+            let _ = writeln!(
+                out,
+                "  {} [label={}, shape={shape}];",
+                dot_quote(&node.id),
+                dot_quote(&node_text(node))
+            );
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Alias => String::new(),
+                EdgeKind::Override => format!(" [label={}, style=dashed]", edge.kind.as_str()),
+                kind => format!(" [label={}]", kind.as_str()),
+            };
+            let _ = writeln!(
+                out,
+                "  {} -> {}{style};",
+                dot_quote(&edge.source),
+                dot_quote(&edge.target)
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart. Node ids are positional (`n0`, `n1`, …) because
+    /// Mermaid ids cannot hold the characters graph keys use.
+    pub fn to_mermaid(&self) -> String {
+        let ids: HashMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id.as_str(), format!("n{i}")))
+            .collect();
+        let mut out = String::from("flowchart LR\n");
+        for node in &self.nodes {
+            let text = mermaid_escape(&node_text(node)).replace('\n', "<br/>");
+            let id = &ids[node.id.as_str()];
+            let _ = match node.kind {
+                NodeKind::Token => writeln!(out, "  {id}[\"{text}\"]"),
+                NodeKind::Component => writeln!(out, "  {id}[[\"{text}\"]]"),
+                NodeKind::Relationship => writeln!(out, "  {id}([\"{text}\"])"),
+            };
+        }
+        for edge in &self.edges {
+            let (source, target) = (&ids[edge.source.as_str()], &ids[edge.target.as_str()]);
+            let _ = match edge.kind {
+                EdgeKind::Alias => writeln!(out, "  {source} --> {target}"),
+                EdgeKind::Override => writeln!(out, "  {source} -.->|override| {target}"),
+                kind => writeln!(out, "  {source} -->|{}| {target}", kind.as_str()),
+            };
+        }
+        out
+    }
+
+    /// Node-link JSON (`{"directed": true, "nodes": […], "links": […]}`).
+    pub fn to_json(&self) -> Value {
+        let mut value = json!({ "directed": true });
+        if let (Some(obj), Ok(Value::Object(body))) =
+            (value.as_object_mut(), serde_json::to_value(self))
+        {
+            obj.extend(body);
+        }
+        value
+    }
+}
+
+/// Every node and edge, before filtering.
+struct FullGraph {
+    nodes: BTreeMap<String, GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+fn build(graph: &TokenGraph) -> FullGraph {
+    let mut nodes = BTreeMap::new();
+    let mut edges = Vec::new();
+
+    let ids = token_ids(graph);
+    let mut by_legacy_key: HashMap<String, Vec<&str>> = HashMap::new();
+    for rec in graph.tokens.values() {
+        let id = &ids[rec.name.as_str()];
+        if let Some(key) = rec.raw.get("name").and_then(extract_legacy_key) {
+            by_legacy_key.entry(key).or_default().push(id);
+        }
+        nodes.insert(id.clone(), token_node(graph, rec, id));
+    }
+    for (target, dependents) in graph.reverse_dependencies() {
+        for dep in dependents {
+            edges.push(GraphEdge {
+                source: ids[dep.key.as_str()].clone(),
+                target: ids[target.as_str()].clone(),
+                kind: dep.kind.into(),
+            });
+        }
+    }
+
+    for comp in &graph.components {
+        let id = component_id(&comp.name);
+        nodes.insert(
+            id.clone(),
+            GraphNode {
+                id: id.clone(),
+                kind: NodeKind::Component,
+                label: comp.name.clone(),
+                uuid: None,
+                layer: None,
+                value: None,
+                file: comp.file.clone(),
+            },
+        );
+        let bindings = comp.raw.get("tokenBindings").and_then(Value::as_array);
+        for binding in bindings.into_iter().flatten() {
+            let Some(token) = binding.get("token").and_then(Value::as_str) else {
+                continue;
+            };
+            for key in by_legacy_key.get(token).into_iter().flatten() {
+                edges.push(GraphEdge {
+                    source: id.clone(),
+                    target: key.to_string(),
+                    kind: EdgeKind::Binding,
+                });
+            }
+        }
+    }
+
+    // Relationship anchors: own uuid and `setUuid` → relationship node ids.
+    let mut anchors: HashMap<&str, Vec<String>> = HashMap::new();
+    for rel in &graph.relationships {
+        let ids = [
+            rel.uuid.as_deref(),
+            rel.raw.get("setUuid").and_then(Value::as_str),
+        ];
+        for anchor in ids.into_iter().flatten() {
+            anchors
+                .entry(anchor)
+                .or_default()
+                .push(relationship_id(rel));
+        }
+    }
+    for rel in &graph.relationships {
+        let id = relationship_id(rel);
+        let scope = rel.raw.get("scope");
+        let component = scope
+            .and_then(|s| s.get("component"))
+            .and_then(Value::as_str);
+        if let Some(component) = component {
+            let comp_id = component_id(component);
+            // Relationships may scope components the catalog does not declare.
+            nodes.entry(comp_id.clone()).or_insert_with(|| GraphNode {
+                id: comp_id.clone(),
+                kind: NodeKind::Component,
+                label: component.to_string(),
+                uuid: None,
+                layer: None,
+                value: None,
+                file: PathBuf::new(),
+            });
+            edges.push(GraphEdge {
+                source: comp_id,
+                target: id.clone(),
+                kind: EdgeKind::Scope,
+            });
+        }
+        if let Some(target) = rel.raw.get("$ref").and_then(Value::as_str) {
+            let tokens: Vec<String> = match graph.set_members(target) {
+                Some(members) => members.iter().map(|m| ids[m.as_str()].clone()).collect(),
+                None => graph
+                    .resolve_alias_key(target)
+                    .map(|t| vec![ids[t.name.as_str()].clone()])
+                    .unwrap_or_default(),
+            };
+            let related = anchors.get(target).cloned().unwrap_or_default();
+            for target in tokens.into_iter().chain(related) {
+                if target != id {
+                    edges.push(GraphEdge {
+                        source: id.clone(),
+                        target,
+                        kind: EdgeKind::Ref,
+                    });
+                }
+            }
+        }
+        nodes.insert(
+            id.clone(),
+            GraphNode {
+                label: relationship_label(rel),
+                id,
+                kind: NodeKind::Relationship,
+                uuid: rel.uuid.clone(),
+                layer: None,
+                value: rel.raw.get("value").cloned(),
+                file: rel.file.clone(),
+            },
+        );
+    }
+
+    FullGraph { nodes, edges }
+}
+
+/// Ids of the nodes `filter` matches: tokens directly, components and
+/// relationships through a proxy graph whose name objects are
+/// `{"component": name}` and the relationship `scope`.
+fn seed_ids(graph: &TokenGraph, filter: &TokenFilter) -> HashSet<String> {
+    let mut proxies = Vec::new();
+    for comp in &graph.components {
+        proxies.push((
+            component_id(&comp.name),
+            PathBuf::new(),
+            json!({"name": {"component": comp.name}}),
+        ));
+    }
+    for rel in &graph.relationships {
+        let mut raw = rel.raw.clone();
+        if let Some(obj) = raw.as_object_mut() {
+            let scope = obj.remove("scope").unwrap_or_else(|| json!({}));
+            obj.insert("name".to_string(), scope);
+        }
+        proxies.push((relationship_id(rel), PathBuf::new(), raw));
+    }
+    let proxy_graph = TokenGraph::from_pairs(proxies).with_mode_sets(graph.mode_sets.clone());
+
+    let ids = token_ids(graph);
+    query::filter(graph, filter)
+        .into_iter()
+        .map(|rec| ids[rec.name.as_str()].clone())
+        .chain(
+            query::filter(&proxy_graph, filter)
+                .into_iter()
+                .map(|rec| rec.name.clone()),
+        )
+        .collect()
+}
+
+/// Node id per token graph key: the UUID when no other token shares it (a
+/// higher-layer override does), else the graph key.
+fn token_ids(graph: &TokenGraph) -> HashMap<&str, String> {
+    let mut uuid_counts: HashMap<&str, usize> = HashMap::new();
+    for rec in graph.tokens.values() {
+        if let Some(u) = rec.uuid.as_deref() {
+            *uuid_counts.entry(u).or_default() += 1;
+        }
+    }
+    graph
+        .tokens
+        .values()
+        .map(|rec| {
+            let id = match rec.uuid.as_deref() {
+                Some(u) if uuid_counts[u] == 1 => u.to_string(),
+                _ => rec.name.clone(),
+            };
+            (rec.name.as_str(), id)
+        })
+        .collect()
+}
+
+fn token_node(graph: &TokenGraph, rec: &TokenRecord, id: &str) -> GraphNode {
+    let name = rec.raw.get("name");
+    let mut label = name
+        .and_then(extract_legacy_key)
+        .unwrap_or_else(|| rec.name.clone());
+    let modes: Vec<String> = graph
+        .mode_sets
+        .iter()
+        .filter_map(|ms| {
+            let mode = name?.get(&ms.name)?.as_str()?;
+            Some(format!("{}={mode}", ms.name))
+        })
+        .collect();
+    if !modes.is_empty() {
+        let _ = write!(label, " [{}]", modes.join(", "));
+    }
+    GraphNode {
+        id: id.to_string(),
+        kind: NodeKind::Token,
+        label,
+        uuid: rec.uuid.clone(),
+        layer: Some(rec.layer),
+        value: rec
+            .alias_target
+            .is_none()
+            .then(|| rec.raw.get("value").cloned())
+            .flatten(),
+        file: rec.file.clone(),
+    }
+}
+
+fn component_id(name: &str) -> String {
+    format!("component:{name}")
+}
+
+fn relationship_id(rel: &RelationshipRecord) -> String {
+    let file = rel
+        .file
+        .file_name()
+        .map(|f| f.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    format!("relationship:{file}#{}", rel.index)
+}
+
+fn relationship_label(rel: &RelationshipRecord) -> String {
+    let scope = rel.raw.get("scope");
+    let field = |f: &str| scope.and_then(|s| s.get(f)).and_then(Value::as_str);
+    let mut label = [field("component"), field("property")]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if let Some(context) = rel.raw.get("context").and_then(Value::as_str) {
+        let _ = write!(label, " ({context})");
+    }
+    if label.is_empty() {
+        relationship_id(rel)
+    } else {
+        label
+    }
+}
+
+/// Label, then the literal value (strings unquoted) on a second line.
+fn node_text(node: &GraphNode) -> String {
+    match &node.value {
+        Some(Value::String(s)) => format!("{}\n{s}", node.label),
+        Some(v) => format!("{}\n{v}", node.label),
+        None => node.label.clone(),
+    }
+}
+
+fn dot_quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ComponentRecord;
+
+    fn graph() -> TokenGraph {
+        let f = || PathBuf::from("t.tokens.json");
+        TokenGraph::from_pairs(vec![
+            (
+                "blue".into(),
+                f(),
+                json!({"name": {"property": "blue-900"}, "uuid": "bl", "value": "#0000ff"}),
+            ),
+            (
+                "accent".into(),
+                f(),
+                json!({"name": {"property": "accent-color"}, "uuid": "ac", "$ref": "bl"}),
+            ),
+            (
+                "button-bg".into(),
+                f(),
+                json!({"name": {"property": "background-color", "component": "button"},
+                       "uuid": "bb", "$ref": "ac"}),
+            ),
+            (
+                "gray".into(),
+                f(),
+                json!({"name": {"property": "gray-100"}, "uuid": "gr", "value": "#eeeeee"}),
+            ),
+        ])
+        .with_components(vec![ComponentRecord {
+            name: "button".into(),
+            file: PathBuf::from("components/button.json"),
+            raw: json!({"tokenBindings": [{"token": "button-background-color"}]}),
+        }])
+        .with_relationships(vec![RelationshipRecord {
+            file: PathBuf::from("relationships/button.json"),
+            index: 0,
+            uuid: None,
+            raw: json!({"scope": {"component": "button", "property": "border-color"},
+                        "$ref": "gr"}),
+        }])
+    }
+
+    fn edges(g: &AliasGraph) -> Vec<(&str, &str, EdgeKind)> {
+        g.edges
+            .iter()
+            .map(|e| (e.source.as_str(), e.target.as_str(), e.kind))
+            .collect()
+    }
+
+    #[test]
+    fn exports_every_edge_kind() {
+        let g = export(&graph(), None, None);
+        assert_eq!(g.nodes.len(), 6);
+        assert_eq!(
+            edges(&g),
+            vec![
+                ("ac", "bl", EdgeKind::Alias),
+                ("bb", "ac", EdgeKind::Alias),
+                ("component:button", "bb", EdgeKind::Binding),
+                (
+                    "component:button",
+                    "relationship:button.json#0",
+                    EdgeKind::Scope
+                ),
+                ("relationship:button.json#0", "gr", EdgeKind::Ref),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_and_depth_limit_the_traversal() {
+        let filter = query::parse("component=button").unwrap();
+        let g = export(&graph(), Some(&filter), Some(1));
+        let ids: Vec<&str> = g.nodes.iter().map(|n| n.id.as_str()).collect();
+        // Seeds: the button token, component and relationship; one hop adds
+        // the accent alias and the gray relationship target.
+        assert_eq!(
+            ids,
+            vec![
+                "ac",
+                "bb",
+                "gr",
+                "component:button",
+                "relationship:button.json#0"
+            ]
+        );
+        assert!(!edges(&g).iter().any(|(_, t, _)| *t == "bl"));
+
+        let g = export(
+            &graph(),
+            Some(&query::parse("property=accent-color").unwrap()),
+            None,
+        );
+        assert_eq!(edges(&g), vec![("ac", "bl", EdgeKind::Alias)]);
+    }
+
+    #[test]
+    fn renders_dot_mermaid_and_json() {
+        let filter = query::parse("property=accent-color").unwrap();
+        let g = export(&graph(), Some(&filter), None);
+        assert_eq!(
+            g.to_dot(),
+            "digraph tokens {\n  rankdir=LR;\n  node [shape=box];\n  \
+             \"ac\" [label=\"accent-color\", shape=box];\n  \
+             \"bl\" [label=\"blue-900\\n#0000ff\", shape=box];\n  \
+             \"ac\" -> \"bl\";\n}\n"
+        );
+        assert_eq!(
+            g.to_mermaid(),
+            "flowchart LR\n  n0[\"accent-color\"]\n  n1[\"blue-900<br/>#0000ff\"]\n  n0 --> n1\n"
+        );
+        let json = g.to_json();
+        assert_eq!(json["directed"], true);
+        assert_eq!(json["links"][0]["kind"], "alias");
+        assert_eq!(json["nodes"][1]["value"], "#0000ff");
+    }
+}
@@ -0,0 +1,568 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Impact analysis: what depends on a token, and how a proposed value would
+//! propagate.
+//!
+//! [`analyze`] matches a target by UUID, set-level UUID, graph key or legacy
+//! key, then walks [`TokenGraph::reverse_dependencies`] transitively. Component
+//! `tokenBindings` and CTR relationships bound to any affected token are
+//! collected alongside. With a proposed value, each affected token is resolved
+//! before and after the change in its own mode context: the modes its name
+//! object pins, with every other mode set at its default.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::cascade::ResolutionContext;
+use crate::graph::{DependencyKind, Layer, TokenGraph, TokenRecord};
+use crate::naming::extract_legacy_key;
+use crate::query;
+
+/// A token affected by a change to the target.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ImpactedToken {
+    /// Legacy key, else graph key.
+    pub key: String,
+    /// Mode-set fields pinned by the token's name object.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub modes: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    pub file: PathBuf,
+    pub layer: Layer,
+    /// `name.component`, for grouping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    /// Hops from the target; 0 for a matched target token.
+    pub depth: usize,
+    /// How this token depends on `via`; absent for target tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<DependencyKind>,
+    /// Key of the token this one depends on directly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+    /// Current resolved value in the token's own mode context.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// Resolved value once the proposed value is applied; only set when one
+    /// was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposed: Option<Value>,
+}
+
+impl ImpactedToken {
+    /// Whether the proposed value changes this token's resolved value.
+    pub fn changes(&self) -> bool {
+        self.proposed.is_some() && self.proposed != self.value
+    }
+}
+
+/// A component `tokenBindings[]` entry naming an affected token.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct BindingImpact {
+    pub component: String,
+    pub file: PathBuf,
+    pub token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// A CTR relationship whose `$ref` reaches an affected token, directly or
+/// through other relationships.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct RelationshipImpact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    pub file: PathBuf,
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// Key of the token or relationship the `$ref` names.
+    pub via: String,
+}
+
+/// Result of [`analyze`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ImpactReport {
+    /// The target as given.
+    pub target: String,
+    /// Proposed value, when one was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposed: Option<Value>,
+    /// Matched target tokens (depth 0) followed by their transitive
+    /// dependents, by depth then key.
+    pub tokens: Vec<ImpactedToken>,
+    pub bindings: Vec<BindingImpact>,
+    pub relationships: Vec<RelationshipImpact>,
+}
+
+impl ImpactReport {
+    /// The matched target tokens.
+    pub fn targets(&self) -> impl Iterator<Item = &ImpactedToken> {
+        self.tokens.iter().filter(|t| t.depth == 0)
+    }
+
+    /// Dependent tokens grouped by component (`None` first, for tokens without
+    /// one) and then by layer.
+    pub fn dependents_by_component(
+        &self,
+    ) -> BTreeMap<Option<&str>, BTreeMap<Layer, Vec<&ImpactedToken>>> {
+        let mut groups: BTreeMap<Option<&str>, BTreeMap<Layer, Vec<&ImpactedToken>>> =
+            BTreeMap::new();
+        for t in self.tokens.iter().filter(|t| t.depth > 0) {
+            groups
+                .entry(t.component.as_deref())
+                .or_default()
+                .entry(t.layer)
+                .or_default()
+                .push(t);
+        }
+        groups
+    }
+
+    /// Every component touched: by an affected token's name, a binding, or a
+    /// relationship scope.
+    pub fn components(&self) -> Vec<&str> {
+        let mut out: Vec<&str> = self
+            .tokens
+            .iter()
+            .filter_map(|t| t.component.as_deref())
+            .chain(self.bindings.iter().map(|b| b.component.as_str()))
+            .chain(
+                self.relationships
+                    .iter()
+                    .filter_map(|r| r.component.as_deref()),
+            )
+            .collect();
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+}
+
+/// Analyze what depends on `target`, optionally under a `proposed` value.
+///
+/// Returns `None` when no token matches `target`. A legacy key matches every
+/// mode-specific member (e.g. the light and dark entries), and a set-level
+/// UUID matches every member of the set.
+pub fn analyze(graph: &TokenGraph, target: &str, proposed: Option<&Value>) -> Option<ImpactReport> {
+    let mut matched: Vec<&TokenRecord> = graph
+        .tokens
+        .values()
+        .filter(|t| {
+            t.name == target
+                || t.uuid.as_deref() == Some(target)
+                || t.raw.get("set_uuid").and_then(Value::as_str) == Some(target)
+                || t.raw.get("name").and_then(extract_legacy_key).as_deref() == Some(target)
+        })
+        .collect();
+    if matched.is_empty() {
+        return None;
+    }
+    // A higher-layer override of a matched token is reported as its dependent,
+    // not as a target: the override masks the change.
+    let shadowed: Vec<(Option<String>, Layer)> =
+        matched.iter().map(|t| (t.uuid.clone(), t.layer)).collect();
+    matched.retain(|t| {
+        !shadowed
+            .iter()
+            .any(|(uuid, layer)| t.uuid.is_some() && *uuid == t.uuid && *layer < t.layer)
+    });
+    matched.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Breadth-first over the reverse index, so each token keeps its shortest
+    // path from the target.
+    let index = graph.reverse_dependencies();
+    let mut visited: HashSet<&str> = matched.iter().map(|t| t.name.as_str()).collect();
+    let mut queue: VecDeque<(&TokenRecord, usize)> = matched.iter().map(|t| (*t, 0)).collect();
+    let mut affected: Vec<Affected<'_>> = matched.iter().map(|t| (*t, 0, None)).collect();
+    while let Some((rec, depth)) = queue.pop_front() {
+        for dep in index.get(&rec.name).into_iter().flatten() {
+            let Some(next) = graph.tokens.get(&dep.key) else {
+                continue;
+            };
+            if visited.insert(&next.name) {
+                affected.push((next, depth + 1, Some((dep.kind, rec))));
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+
+    let simulated = proposed.map(|value| {
+        let mut g = graph.clone();
+        for t in &matched {
+            if let Some(rec) = g.tokens.get_mut(&t.name) {
+                if let Some(raw) = rec.raw.as_object_mut() {
+                    raw.remove("$ref");
+                    raw.insert("value".to_string(), value.clone());
+                }
+                rec.alias_target = None;
+            }
+        }
+        g
+    });
+
+    let mut tokens: Vec<ImpactedToken> = affected
+        .iter()
+        .map(|(rec, depth, edge)| {
+            let name = rec.raw.get("name");
+            ImpactedToken {
+                key: label(rec),
+                modes: pinned_modes(graph, rec),
+                uuid: rec.uuid.clone(),
+                file: rec.file.clone(),
+                layer: rec.layer,
+                component: name
+                    .and_then(|n| n.get("component"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                depth: *depth,
+                kind: edge.map(|(kind, _)| kind),
+                via: edge.map(|(_, from)| label(from)),
+                value: resolve_in_own_context(graph, rec),
+                proposed: simulated.as_ref().and_then(|g| {
+                    let rec = g.tokens.get(&rec.name)?;
+                    resolve_in_own_context(g, rec)
+                }),
+            }
+        })
+        .collect();
+    tokens.sort_by(|a, b| {
+        (a.depth, &a.key, &a.modes, &a.file).cmp(&(b.depth, &b.key, &b.modes, &b.file))
+    });
+
+    let keys: HashSet<String> = affected.iter().map(|(rec, ..)| label(rec)).collect();
+    let mut bindings = Vec::new();
+    for comp in &graph.components {
+        let Some(list) = comp.raw.get("tokenBindings").and_then(Value::as_array) else {
+            continue;
+        };
+        for binding in list {
+            let Some(token) = binding.get("token").and_then(Value::as_str) else {
+                continue;
+            };
+            if keys.contains(token) {
+                bindings.push(BindingImpact {
+                    component: comp.name.clone(),
+                    file: comp.file.clone(),
+                    token: token.to_string(),
+                    context: binding
+                        .get("context")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                });
+            }
+        }
+    }
+    bindings.sort_by(|a, b| (&a.component, &a.token).cmp(&(&b.component, &b.token)));
+
+    Some(ImpactReport {
+        target: target.to_string(),
+        proposed: proposed.cloned(),
+        tokens,
+        bindings,
+        relationships: relationship_impacts(graph, &affected),
+    })
+}
+
+/// An affected token, its depth, and the edge it was reached through.
+type Affected<'a> = (
+    &'a TokenRecord,
+    usize,
+    Option<(DependencyKind, &'a TokenRecord)>,
+);
+
+/// Relationships whose `$ref` names an affected token (by UUID or set UUID),
+/// then, to a fixpoint, relationships referencing those relationships.
+fn relationship_impacts(graph: &TokenGraph, affected: &[Affected<'_>]) -> Vec<RelationshipImpact> {
+    // Anchor id → key reported as `via`.
+    let mut anchors: HashMap<String, String> = HashMap::new();
+    for (rec, ..) in affected {
+        let ids = [
+            rec.uuid.as_deref(),
+            rec.raw.get("set_uuid").and_then(Value::as_str),
+        ];
+        for id in ids.into_iter().flatten() {
+            anchors.entry(id.to_string()).or_insert_with(|| label(rec));
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut reported = vec![false; graph.relationships.len()];
+    loop {
+        let mut grew = false;
+        for (i, rel) in graph.relationships.iter().enumerate() {
+            if reported[i] {
+                continue;
+            }
+            let Some(via) = rel
+                .raw
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|r| anchors.get(r))
+                .cloned()
+            else {
+                continue;
+            };
+            reported[i] = true;
+            grew = true;
+            let scope = rel.raw.get("scope");
+            let scope_field = |f: &str| {
+                scope
+                    .and_then(|s| s.get(f))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            };
+            let key = rel
+                .raw
+                .get("legacyKey")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .or_else(|| rel.uuid.clone())
+                .unwrap_or_else(|| format!("{}[{}]", rel.file.display(), rel.index));
+            let ids = [
+                rel.uuid.as_deref(),
+                rel.raw.get("setUuid").and_then(Value::as_str),
+            ];
+            for id in ids.into_iter().flatten() {
+                anchors.entry(id.to_string()).or_insert_with(|| key.clone());
+            }
+            out.push(RelationshipImpact {
+                component: scope_field("component"),
+                property: scope_field("property"),
+                file: rel.file.clone(),
+                index: rel.index,
+                uuid: rel.uuid.clone(),
+                via,
+            });
+        }
+        if !grew {
+            break;
+        }
+    }
+    out.sort_by(|a, b| (&a.file, a.index).cmp(&(&b.file, b.index)));
+    out
+}
+
+/// Legacy key, else graph key.
+fn label(rec: &TokenRecord) -> String {
+    rec.raw
+        .get("name")
+        .and_then(extract_legacy_key)
+        .unwrap_or_else(|| rec.name.clone())
+}
+
+/// The declared mode sets `rec`'s name object pins.
+pub(crate) fn pinned_modes(graph: &TokenGraph, rec: &TokenRecord) -> BTreeMap<String, String> {
+    let name = rec.raw.get("name");
+    graph
+        .mode_sets
+        .iter()
+        .filter_map(|ms| {
+            let mode = name?.get(&ms.name)?.as_str()?;
+            Some((ms.name.clone(), mode.to_string()))
+        })
+        .collect()
+}
+
+/// `rec`'s resolved value in its own mode context, with `{name}` members of a
+/// composite value replaced by their resolved values.
+fn resolve_in_own_context(graph: &TokenGraph, rec: &TokenRecord) -> Option<Value> {
+    let mut ctx = ResolutionContext::new();
+    for (set, mode) in pinned_modes(graph, rec) {
+        ctx = ctx.with(set, mode);
+    }
+    let value = query::resolved_value(graph, rec, &ctx)?;
+    Some(match value {
+        Value::Object(_) | Value::Array(_) => expand_refs(graph, value, &ctx, 0),
+        _ => value.clone(),
+    })
+}
+
+fn expand_refs(graph: &TokenGraph, value: &Value, ctx: &ResolutionContext, depth: usize) -> Value {
+    match value {
+        Value::String(s) if s.starts_with('{') && s.ends_with('}') && s.len() > 2 && depth < 8 => {
+            graph
+                .resolve_alias_in_context(&s[1..s.len() - 1], &ctx.mode_sets)
+                .and_then(|target| query::resolved_value(graph, target, ctx))
+                .map(|v| expand_refs(graph, v, ctx, depth + 1))
+                .unwrap_or_else(|| value.clone())
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|v| expand_refs(graph, v, ctx, depth))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), expand_refs(graph, v, ctx, depth)))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ComponentRecord, ModeSetRecord, RelationshipRecord};
+    use serde_json::json;
+
+    fn graph() -> TokenGraph {
+        let f = || PathBuf::from("t.tokens.json");
+        TokenGraph::from_pairs(vec![
+            (
+                "blue-light".into(),
+                f(),
+                json!({"name": {"property": "blue-900", "colorScheme": "light"},
+                       "uuid": "bl", "set_uuid": "blue", "value": "#0000ff"}),
+            ),
+            (
+                "blue-dark".into(),
+                f(),
+                json!({"name": {"property": "blue-900", "colorScheme": "dark"},
+                       "uuid": "bd", "set_uuid": "blue", "value": "#0000aa"}),
+            ),
+            (
+                "accent".into(),
+                f(),
+                json!({"name": {"property": "accent-color"}, "uuid": "ac", "$ref": "bl"}),
+            ),
+            (
+                "button-bg".into(),
+                f(),
+                json!({"name": {"property": "background-color", "component": "button"},
+                       "uuid": "bb", "$ref": "ac"}),
+            ),
+            (
+                "focus-ring".into(),
+                f(),
+                json!({"name": {"property": "focus-ring"}, "uuid": "fr",
+                       "value": {"color": "{accent}", "width": "2px"}}),
+            ),
+            (
+                "gray".into(),
+                f(),
+                json!({"name": {"property": "gray-100"}, "uuid": "gr", "value": "#eeeeee"}),
+            ),
+        ])
+        .with_mode_sets(vec![ModeSetRecord {
+            file: PathBuf::from("mode-sets/color-scheme.json"),
+            name: "colorScheme".into(),
+            modes: vec!["light".into(), "dark".into()],
+            default_mode: "light".into(),
+        }])
+        .with_components(vec![ComponentRecord {
+            name: "button".into(),
+            file: PathBuf::from("components/button.json"),
+            raw: json!({"tokenBindings": [
+                {"token": "background-color", "context": "Fill"},
+                {"token": "gray-100"}
+            ]}),
+        }])
+        .with_relationships(vec![
+            RelationshipRecord {
+                file: PathBuf::from("relationships/link.json"),
+                index: 0,
+                uuid: Some("r1".into()),
+                raw: json!({"scope": {"component": "link", "property": "color"},
+                            "$ref": "ac", "uuid": "r1"}),
+            },
+            RelationshipRecord {
+                file: PathBuf::from("relationships/link.json"),
+                index: 1,
+                uuid: None,
+                raw: json!({"scope": {"component": "link", "property": "hover-color"},
+                            "$ref": "r1"}),
+            },
+            RelationshipRecord {
+                file: PathBuf::from("relationships/link.json"),
+                index: 2,
+                uuid: None,
+                raw: json!({"scope": {"component": "link"}, "$ref": "gr"}),
+            },
+        ])
+    }
+
+    #[test]
+    fn reports_transitive_dependents_bindings_and_relationships() {
+        let report = analyze(&graph(), "blue-900", None).expect("target matches");
+        let tokens: Vec<(&str, usize, Option<&str>)> = report
+            .tokens
+            .iter()
+            .map(|t| (t.key.as_str(), t.depth, t.via.as_deref()))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("blue-900", 0, None),
+                ("blue-900", 0, None),
+                ("accent-color", 1, Some("blue-900")),
+                ("button-background-color", 2, Some("accent-color")),
+                ("focus-ring", 2, Some("accent-color")),
+            ]
+        );
+        assert_eq!(report.tokens[0].modes["colorScheme"], "dark");
+        assert_eq!(report.tokens[4].kind, Some(DependencyKind::Composite));
+        assert_eq!(
+            report.tokens[4].value,
+            Some(json!({"color": "#0000ff", "width": "2px"}))
+        );
+        assert!(report.bindings.is_empty(), "{:?}", report.bindings);
+        assert_eq!(
+            report
+                .relationships
+                .iter()
+                .map(|r| (r.index, r.via.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(0, "accent-color"), (1, "r1")]
+        );
+        assert_eq!(report.components(), vec!["button", "link"]);
+        let groups = report.dependents_by_component();
+        assert_eq!(groups[&None][&Layer::Foundation].len(), 2);
+        assert_eq!(groups[&Some("button")][&Layer::Foundation].len(), 1);
+    }
+
+    #[test]
+    fn proposed_value_propagates_through_aliases() {
+        let g = graph();
+        let report = analyze(&g, "bl", Some(&json!("#ff0000"))).expect("target matches");
+        assert_eq!(report.targets().count(), 1);
+        let changed: Vec<(&str, Option<&Value>)> = report
+            .tokens
+            .iter()
+            .filter(|t| t.changes())
+            .map(|t| (t.key.as_str(), t.proposed.as_ref()))
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                ("blue-900", Some(&json!("#ff0000"))),
+                ("accent-color", Some(&json!("#ff0000"))),
+                ("button-background-color", Some(&json!("#ff0000"))),
+                (
+                    "focus-ring",
+                    Some(&json!({"color": "#ff0000", "width": "2px"}))
+                ),
+            ]
+        );
+
+        let gray = analyze(&g, "gray-100", None).expect("target matches");
+        assert_eq!(gray.bindings.len(), 1);
+        assert_eq!(gray.relationships.len(), 1);
+        assert!(analyze(&g, "nope", None).is_none());
+    }
+}
@@ -55,10 +55,14 @@ const COLOR_SET_SCHEMA: &str =
     "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/color-set.json";
 const SCALE_SET_SCHEMA: &str =
     "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/scale-set.json";
+const MOTION_SET_SCHEMA: &str =
+    "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/motion-set.json";
 const COLOR_SCHEMA: &str =
     "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/color.json";
 const DIMENSION_SCHEMA: &str =
     "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/dimension.json";
+const DURATION_SCHEMA: &str =
+    "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/duration.json";
 const ALIAS_SCHEMA: &str =
     "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json";
 
@@ -826,10 +830,11 @@ fn hoist_flat_field_if_consistent(entry: &mut Map<String, Value>, field: &str, b
 
 /// Collect the set of recognized mode set keys present in any token in the group.
 ///
-/// Only the known set-forming mode sets are considered (`colorScheme`, `scale`).
+/// Only the known set-forming mode sets are considered (`colorScheme`, `scale`,
+/// `reducedMotion`).
 /// Returns a sorted set so error messages are deterministic.
 fn collect_mode_set_keys(tokens: &[&Map<String, Value>]) -> BTreeSet<&'static str> {
-    const SET_DIMS: &[&str] = &["colorScheme", "scale"];
+    const SET_DIMS: &[&str] = &["colorScheme", "scale", "reducedMotion"];
     let mut found = BTreeSet::new();
     for tok in tokens {
         if let Some(name_obj) = tok.get("name").and_then(|v| v.as_object()) {
@@ -843,7 +848,8 @@ fn collect_mode_set_keys(tokens: &[&Map<String, Value>]) -> BTreeSet<&'static st
     found
 }
 
-/// Build a `color-set` or `scale-set` outer entry from a group of cascade tokens.
+/// Build a `color-set`, `scale-set` or `motion-set` outer entry from a group of
+/// cascade tokens.
 fn build_set_entry(
     _property: &str,
     tokens: &[&Map<String, Value>],
@@ -853,14 +859,15 @@ fn build_set_entry(
 ) -> Value {
     // Prefer the stored set_schema (written by migrate) so we can round-trip
     // schema types that share a mode set key (e.g. typography-scale vs scale-set).
-    // Falls back to the legacy default (color-set or scale-set) for older cascade
-    // files that were produced before set_schema was stored.
+    // Falls back to the legacy default for the mode set (color-set, motion-set or
+    // scale-set) for older cascade files that were produced before set_schema was
+    // stored.
     let stored_set_schema =
         consistent_str_field(tokens, |t| t.get("set_schema").and_then(|v| v.as_str()));
-    let set_schema = stored_set_schema.unwrap_or(if dim_key == "colorScheme" {
-        COLOR_SET_SCHEMA
-    } else {
-        SCALE_SET_SCHEMA
+    let set_schema = stored_set_schema.unwrap_or(match dim_key {
+        "colorScheme" => COLOR_SET_SCHEMA,
+        "reducedMotion" => MOTION_SET_SCHEMA,
+        _ => SCALE_SET_SCHEMA,
     });
 
     let mut outer = Map::new();
@@ -953,10 +960,13 @@ fn build_mode_entry(
         .or_else(|| {
             Some(if is_alias {
                 ALIAS_SCHEMA.to_string()
-            } else if dim_key == "colorScheme" {
-                COLOR_SCHEMA.to_string()
             } else {
-                DIMENSION_SCHEMA.to_string()
+                match dim_key {
+                    "colorScheme" => COLOR_SCHEMA,
+                    "reducedMotion" => DURATION_SCHEMA,
+                    _ => DIMENSION_SCHEMA,
+                }
+                .to_string()
             })
         });
     if let Some(schema) = schema {
@@ -1256,6 +1266,24 @@ mod tests {
         assert_eq!(entry["sets"]["mobile"]["value"], "10px");
     }
 
+    #[test]
+    fn motion_set_reconstructed_from_reduced_motion_tokens() {
+        let arr = json!([
+            {"name": {"property": "animation-duration-100", "reducedMotion": "no-preference"},
+             "value": "130ms", "uuid": "ms-0001"},
+            {"name": {"property": "animation-duration-100", "reducedMotion": "reduce"},
+             "value": "0ms", "uuid": "ms-0002"}
+        ]);
+        let mut summary = LegacySummary::default();
+        let out = convert_array(arr.as_array().unwrap(), &mut summary, &HashMap::new()).unwrap();
+
+        let entry = &out["animation-duration-100"];
+        assert_eq!(entry["$schema"], MOTION_SET_SCHEMA);
+        assert_eq!(entry["sets"]["no-preference"]["$schema"], DURATION_SCHEMA);
+        assert_eq!(entry["sets"]["reduce"]["value"], "0ms");
+        assert_eq!(summary.sets_reconstructed, 1);
+    }
+
     #[test]
     fn consistent_lifecycle_field_hoisted_to_outer() {
         let arr = json!([
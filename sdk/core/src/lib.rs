@@ -14,24 +14,33 @@ pub mod authoring;
 #[cfg(feature = "cache")]
 pub mod cache;
 pub mod cascade;
+pub mod codemod;
+pub mod color;
 pub mod compat;
 pub mod component;
 pub mod data_source;
 pub mod diff;
 pub mod discovery;
+pub mod dtcg;
 #[cfg(feature = "figma")]
 pub mod figma;
+pub mod generate;
 pub mod graph;
+pub mod graph_export;
+pub mod impact;
 pub mod legacy;
+pub mod locate;
 pub mod manifest;
 pub mod migrate;
 pub mod naming;
 pub mod primer;
 pub mod query;
 pub mod registry;
+pub mod release;
 pub mod report;
 pub mod schema;
 pub mod suggest;
+pub mod unused;
 pub mod validate;
 pub mod write;
 
@@ -521,6 +530,7 @@ mod validation_conformance {
                     alias_target,
                     raw,
                     layer,
+                    span: None,
                 }
             })
             .collect();
@@ -1442,6 +1452,18 @@ mod query_conformance {
         let mut actual_uuids: Vec<String> = results.iter().filter_map(|t| t.uuid.clone()).collect();
         actual_uuids.sort();
 
+        // The index-backed evaluator must agree with the scan on every fixture.
+        let index = query::TokenIndex::build(&graph);
+        let mut indexed_uuids: Vec<String> = query::filter_with_index(&graph, &index, &filter_expr)
+            .iter()
+            .filter_map(|t| t.uuid.clone())
+            .collect();
+        indexed_uuids.sort();
+        assert_eq!(
+            indexed_uuids, actual_uuids,
+            "{case}: filter_with_index disagrees with filter"
+        );
+
         let expected_text = std::fs::read_to_string(base.join("expected.json"))
             .unwrap_or_else(|e| panic!("{case}: failed to read expected.json: {e}"));
         let expected: Value = serde_json::from_str(&expected_text)
@@ -1508,6 +1530,61 @@ mod query_conformance {
     fn and_or_precedence() {
         run_fixture("and-or-precedence");
     }
+
+    #[test]
+    fn grouping() {
+        run_fixture("grouping");
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        run_fixture("parentheses-override-precedence");
+    }
+
+    #[test]
+    fn in_list() {
+        run_fixture("in-list");
+    }
+
+    #[test]
+    fn exists() {
+        run_fixture("exists");
+    }
+
+    #[test]
+    fn not_exists() {
+        run_fixture("not-exists");
+    }
+
+    #[test]
+    fn not_group() {
+        run_fixture("not-group");
+    }
+
+    #[test]
+    fn catalog_field() {
+        run_fixture("catalog-field");
+    }
+
+    #[test]
+    fn value_color() {
+        run_fixture("value-color");
+    }
+
+    #[test]
+    fn value_color_function() {
+        run_fixture("value-color-function");
+    }
+
+    #[test]
+    fn value_approx_color_function() {
+        run_fixture("value-approx-color-function");
+    }
+
+    #[test]
+    fn value_comparison() {
+        run_fixture("value-comparison");
+    }
 }
 
 /// Generation conformance tests — fixture-driven.
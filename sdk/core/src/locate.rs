@@ -0,0 +1,489 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Map JSON pointers back to byte spans and line/column positions in the
+//! original file text.
+//!
+//! `serde_json::Value` keeps no spans, so [`pointer_span`] re-scans the source
+//! text, descending one pointer segment at a time and skipping sibling values
+//! without building them. An object member's span starts at its key (where an
+//! editor should put the cursor); an array element's at its first character.
+//! [`top_level_spans`] collects every root child's span in a single pass for
+//! the graph loader.
+//!
+//! [`diagnostic_span`] uses a [`Diagnostic`]'s own `span` when set, else picks
+//! the best pointer: its `instance_path` when that resolves from the file root,
+//! else the path re-rooted under the diagnostic's token (per-token schema
+//! errors in object-format files are token-relative), else the token itself,
+//! else the nearest resolvable ancestor.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::report::{Diagnostic, Span};
+
+/// A 1-based line and column (columns count Unicode code points).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Position of the value at RFC 6901 `pointer` in JSON `text`, or `None` when
+/// the pointer does not resolve (or the text is not valid JSON along the way).
+/// Both `""` and `"/"` address the root.
+pub fn locate(text: &str, pointer: &str) -> Option<Position> {
+    pointer_span(text, pointer).and_then(|span| position(text, span.offset))
+}
+
+/// Byte span of the value at `pointer`: from an object member's key (or an
+/// array element's first byte) through the end of the value.
+pub fn pointer_span(text: &str, pointer: &str) -> Option<Span> {
+    let mut scanner = Scanner {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+    scanner.skip_ws();
+    let mut start = scanner.pos;
+    for segment in segments(pointer) {
+        start = scanner.descend(&segment)?;
+    }
+    scanner.skip_value()?;
+    Some(Span {
+        offset: start,
+        len: scanner.pos - start,
+    })
+}
+
+/// Spans of the root container's children in one pass, keyed as pointer
+/// segments: member names for an object, indices for an array. Empty when
+/// `text` is not a JSON object or array.
+pub fn top_level_spans(text: &str) -> HashMap<String, Span> {
+    let mut out = HashMap::new();
+    let mut scanner = Scanner {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+    scanner.skip_ws();
+    let is_array = match scanner.peek() {
+        Some(b'{') => false,
+        Some(b'[') => true,
+        _ => return out,
+    };
+    scanner.pos += 1;
+    for index in 0.. {
+        scanner.skip_ws();
+        let start = scanner.pos;
+        let key = match scanner.peek() {
+            None | Some(b'}' | b']') => break,
+            Some(_) if is_array => index.to_string(),
+            Some(_) => {
+                let Some(key) = scanner.string() else { break };
+                if scanner.expect(b':').is_none() {
+                    break;
+                }
+                scanner.skip_ws();
+                key
+            }
+        };
+        if scanner.skip_value().is_none() {
+            break;
+        }
+        out.insert(
+            key,
+            Span {
+                offset: start,
+                len: scanner.pos - start,
+            },
+        );
+        scanner.skip_ws();
+        if scanner.peek() == Some(b',') {
+            scanner.pos += 1;
+        }
+    }
+    out
+}
+
+/// Best-effort position for `d` within its file's `text` (see module docs).
+pub fn locate_diagnostic(text: &str, d: &Diagnostic) -> Option<Position> {
+    diagnostic_span(text, d).and_then(|span| position(text, span.offset))
+}
+
+/// `d.span` when set and still inside `text`, else the best pointer-derived
+/// span (see module docs).
+pub fn diagnostic_span(text: &str, d: &Diagnostic) -> Option<Span> {
+    // Spans are recorded when the graph is built; the file may have changed
+    // on disk since.
+    if let Some(span) = d.span.filter(|&span| span_text(text, span).is_some()) {
+        return Some(span);
+    }
+    let token_ptr = d.token.as_deref().map(|t| token_pointer(d, t));
+    let instance = d.instance_path.as_deref();
+    let mut candidates: Vec<String> = Vec::new();
+    if let Some(ip) = instance {
+        candidates.push(ip.to_string());
+    }
+    if let Some(tp) = &token_ptr {
+        if let Some(ip) = instance.filter(|ip| !ip.is_empty() && *ip != "/") {
+            candidates.push(format!("{tp}{ip}"));
+        }
+        candidates.push(tp.clone());
+    }
+    if let Some(span) = candidates.iter().find_map(|c| pointer_span(text, c)) {
+        return Some(span);
+    }
+    // Nearest resolvable ancestor of the instance path.
+    let mut ancestor = instance?;
+    while let Some((parent, _)) = ancestor.rsplit_once('/') {
+        if parent.is_empty() {
+            break;
+        }
+        if let Some(span) = pointer_span(text, parent) {
+            return Some(span);
+        }
+        ancestor = parent;
+    }
+    None
+}
+
+/// Reads each diagnostic's file once and locates diagnostics within it.
+/// Unreadable files yield no positions.
+#[derive(Debug, Default)]
+pub struct SourceCache {
+    texts: HashMap<PathBuf, Option<String>>,
+}
+
+impl SourceCache {
+    /// The text of `file`, read on first use.
+    pub fn text(&mut self, file: &Path) -> Option<&str> {
+        self.texts
+            .entry(file.to_path_buf())
+            .or_insert_with(|| std::fs::read_to_string(file).ok())
+            .as_deref()
+    }
+
+    pub fn position(&mut self, d: &Diagnostic) -> Option<Position> {
+        locate_diagnostic(self.text(&d.file)?, d)
+    }
+
+    pub fn span(&mut self, d: &Diagnostic) -> Option<Span> {
+        diagnostic_span(self.text(&d.file)?, d)
+    }
+}
+
+/// Pointer to `d`'s token within its file: cascade graph keys are
+/// `<file>:<index>`, legacy tokens are keyed by name.
+pub fn token_pointer(d: &Diagnostic, token: &str) -> String {
+    let cascade_prefix = format!("{}:", d.file.display());
+    match token
+        .strip_prefix(&cascade_prefix)
+        .filter(|idx| idx.parse::<usize>().is_ok())
+    {
+        Some(idx) => format!("/{idx}"),
+        None => format!("/{}", token.replace('~', "~0").replace('/', "~1")),
+    }
+}
+
+fn segments(pointer: &str) -> Vec<String> {
+    if pointer.is_empty() || pointer == "/" {
+        return Vec::new();
+    }
+    pointer
+        .strip_prefix('/')
+        .unwrap_or(pointer)
+        .split('/')
+        .map(|s| s.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+/// One-character span at a 1-based `line` / `column` (as reported by
+/// `serde_json` parse errors), clamped to the end of `text`.
+pub fn line_column_span(text: &str, line: usize, column: usize) -> Option<Span> {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        text.match_indices('\n').nth(line - 2)?.0 + 1
+    };
+    let offset = (line_start + column.saturating_sub(1)).min(text.len());
+    if !text.is_char_boundary(offset) {
+        return None;
+    }
+    let len = text[offset..].chars().next().map_or(0, char::len_utf8);
+    Some(Span { offset, len })
+}
+
+/// Line and column of byte `offset` in `text`, or `None` when `offset` is
+/// past the end or inside a multi-byte character.
+pub fn position(text: &str, offset: usize) -> Option<Position> {
+    let before = text.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Some(Position {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    })
+}
+
+/// The text `span` covers, or `None` when it does not fall on character
+/// boundaries within `text`.
+pub fn span_text(text: &str, span: Span) -> Option<&str> {
+    text.get(span.offset..span.offset.checked_add(span.len)?)
+}
+
+// ── Scanner ─────────────────────────────────────────────────────────────────
+
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Option<()> {
+        self.skip_ws();
+        (self.peek() == Some(b)).then(|| self.pos += 1)
+    }
+
+    /// With `pos` at a value, move to the child named `segment` and return the
+    /// offset to report for it (member key or element start).
+    fn descend(&mut self, segment: &str) -> Option<usize> {
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                loop {
+                    self.skip_ws();
+                    if self.peek()? == b'}' {
+                        return None;
+                    }
+                    let key_at = self.pos;
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    self.skip_ws();
+                    if key == segment {
+                        return Some(key_at);
+                    }
+                    self.skip_value()?;
+                    self.skip_ws();
+                    if self.peek()? == b',' {
+                        self.pos += 1;
+                    }
+                }
+            }
+            b'[' => {
+                let index: usize = segment.parse().ok()?;
+                self.pos += 1;
+                for i in 0.. {
+                    self.skip_ws();
+                    if self.peek()? == b']' {
+                        return None;
+                    }
+                    if i == index {
+                        return Some(self.pos);
+                    }
+                    self.skip_value()?;
+                    self.skip_ws();
+                    if self.peek()? == b',' {
+                        self.pos += 1;
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a string at `pos`, decoding the escapes a key can contain.
+    fn string(&mut self) -> Option<String> {
+        if self.peek()? != b'"' {
+            return None;
+        }
+        let start = self.pos;
+        self.skip_string()?;
+        serde_json::from_slice(&self.bytes[start..self.pos]).ok()
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_value(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'"' => self.skip_string(),
+            b'{' | b'[' => {
+                let mut depth = 0usize;
+                loop {
+                    match self.peek()? {
+                        b'"' => {
+                            self.skip_string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Some(());
+                            }
+                        }
+                        _ => {}
+                    }
+                    self.pos += 1;
+                }
+            }
+            _ => {
+                while self.peek().is_some_and(|b| {
+                    !matches!(b, b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r')
+                }) {
+                    self.pos += 1;
+                }
+                Some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::report::Severity;
+
+    const TEXT: &str = "{\n  \"a/b\": {\"x\": [1, \"]}\", {\"y\": true}]},\n  \"é\": null,\n  \"tok\": {\n    \"value\": \"8px\"\n  }\n}\n";
+
+    fn pos(line: usize, column: usize) -> Option<Position> {
+        Some(Position { line, column })
+    }
+
+    #[test]
+    fn resolves_members_and_elements() {
+        assert_eq!(locate(TEXT, ""), pos(1, 1));
+        assert_eq!(locate(TEXT, "/a~1b"), pos(2, 3));
+        assert_eq!(locate(TEXT, "/a~1b/x/2/y"), pos(2, 27));
+        assert_eq!(locate(TEXT, "/tok/value"), pos(5, 5));
+        assert_eq!(locate(TEXT, "/a~1b/x/3"), None);
+        assert_eq!(locate(TEXT, "/missing"), None);
+    }
+
+    #[test]
+    fn spans_cover_key_through_value() {
+        let span = pointer_span(TEXT, "/tok").unwrap();
+        assert_eq!(
+            &TEXT[span.offset..span.offset + span.len],
+            "\"tok\": {\n    \"value\": \"8px\"\n  }"
+        );
+        let span = pointer_span(TEXT, "/a~1b/x/1").unwrap();
+        assert_eq!(&TEXT[span.offset..span.offset + span.len], "\"]}\"");
+
+        let top = top_level_spans(TEXT);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top["tok"], pointer_span(TEXT, "/tok").unwrap());
+        assert_eq!(top["é"], pointer_span(TEXT, "/é").unwrap());
+        let err = serde_json::from_str::<serde_json::Value>("{\n  \"a\": ]\n}").unwrap_err();
+        assert_eq!(
+            line_column_span("{\n  \"a\": ]\n}", err.line(), err.column()),
+            Some(Span { offset: 9, len: 1 })
+        );
+
+        let top = top_level_spans("[ {\"a\": 1} , 2 ]");
+        assert_eq!(top["0"], Span { offset: 2, len: 8 });
+        assert_eq!(top["1"], Span { offset: 13, len: 1 });
+    }
+
+    #[test]
+    fn diagnostics_fall_back_to_token_and_ancestors() {
+        let diag = |token: Option<&str>, instance: Option<&str>| Diagnostic {
+            file: PathBuf::from("t.json"),
+            token: token.map(str::to_string),
+            rule_id: None,
+            severity: Severity::Error,
+            message: String::new(),
+            instance_path: instance.map(str::to_string),
+            schema_path: None,
+            fix: None,
+            span: None,
+        };
+        // Token-relative schema error.
+        assert_eq!(
+            locate_diagnostic(TEXT, &diag(Some("tok"), Some("/value"))),
+            pos(5, 5)
+        );
+        // Relational rule: token only.
+        assert_eq!(locate_diagnostic(TEXT, &diag(Some("tok"), None)), pos(4, 3));
+        // Missing leaf: nearest ancestor.
+        assert_eq!(
+            locate_diagnostic(TEXT, &diag(None, Some("/tok/value/deep"))),
+            pos(5, 5)
+        );
+        assert_eq!(locate_diagnostic(TEXT, &diag(None, None)), None);
+        // Cascade graph key.
+        assert_eq!(
+            locate_diagnostic("[\n  {},\n  {}\n]", &diag(Some("t.json:1"), None)),
+            pos(3, 3)
+        );
+    }
+
+    #[test]
+    fn stale_spans_fall_back_instead_of_panicking() {
+        let diag = |span: Span| Diagnostic {
+            file: PathBuf::from("t.json"),
+            token: Some("tok".to_string()),
+            rule_id: None,
+            severity: Severity::Error,
+            message: String::new(),
+            instance_path: None,
+            schema_path: None,
+            fix: None,
+            span: Some(span),
+        };
+        // Past the end of a file that shrank since the span was recorded.
+        let past_end = diag(Span {
+            offset: TEXT.len() + 10,
+            len: 4,
+        });
+        assert_eq!(diagnostic_span(TEXT, &past_end), pointer_span(TEXT, "/tok"));
+        assert_eq!(locate_diagnostic(TEXT, &past_end), pos(4, 3));
+        // Inside the two-byte `é`.
+        let offset = TEXT.find('é').unwrap() + 1;
+        let mid_char = diag(Span { offset, len: 1 });
+        assert_eq!(span_text(TEXT, Span { offset, len: 1 }), None);
+        assert_eq!(locate_diagnostic(TEXT, &mid_char), pos(4, 3));
+        assert_eq!(position(TEXT, offset), None);
+        assert_eq!(position(TEXT, TEXT.len() + 1), None);
+        assert_eq!(
+            span_text(
+                TEXT,
+                Span {
+                    offset: 1,
+                    len: usize::MAX
+                }
+            ),
+            None
+        );
+    }
+}
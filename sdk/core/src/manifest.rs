@@ -19,7 +19,8 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::data_source::ResolvedData;
-use crate::graph::TokenGraph;
+use crate::graph::{PlatformManifest, TokenGraph};
+use crate::naming::NameFormatting;
 use crate::schema::SchemaRegistry;
 use crate::CoreError;
 
@@ -43,8 +44,51 @@ pub fn apply_configured(
     graph: &mut TokenGraph,
     resolved: &ResolvedData,
 ) -> Result<HashMap<String, Vec<String>>, CoreError> {
+    Ok(apply_configured_manifest(graph, resolved)?.mode_set_restrictions)
+}
+
+/// [`apply_configured`], returning the full [`PlatformManifest`] outcome
+/// (restrictions and `extensions.formatting`).
+pub fn apply_configured_manifest(
+    graph: &mut TokenGraph,
+    resolved: &ResolvedData,
+) -> Result<PlatformManifest, CoreError> {
+    match load_configured(resolved)? {
+        Some(manifest) => graph.apply_platform_manifest(&manifest),
+        None => Ok(PlatformManifest::default()),
+    }
+}
+
+/// The configured platform manifest's `extensions.formatting`, without applying
+/// the manifest to a graph. `None` when no manifest is configured or it
+/// declares no formatting.
+pub fn configured_formatting(resolved: &ResolvedData) -> Result<Option<NameFormatting>, CoreError> {
+    match load_configured(resolved)? {
+        Some(manifest) => NameFormatting::from_manifest(&manifest),
+        None => Ok(None),
+    }
+}
+
+/// The configured platform manifest's `include` query expressions, without
+/// applying the manifest to a graph. Empty when no manifest is configured.
+pub fn configured_includes(resolved: &ResolvedData) -> Result<Vec<String>, CoreError> {
+    let Some(manifest) = load_configured(resolved)? else {
+        return Ok(Vec::new());
+    };
+    Ok(manifest
+        .get("include")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.as_str().map(str::to_string))
+        .collect())
+}
+
+/// Read, parse, and (when the schema is locatable) Layer 1-validate the
+/// configured platform manifest.
+fn load_configured(resolved: &ResolvedData) -> Result<Option<serde_json::Value>, CoreError> {
     let Some(manifest_path) = resolved.platform_manifest.as_ref() else {
-        return Ok(HashMap::new());
+        return Ok(None);
     };
     let text = std::fs::read_to_string(manifest_path).map_err(|e| {
         CoreError::ParseError(format!(
@@ -69,9 +113,7 @@ pub fn apply_configured(
             )));
         }
     }
-
-    let outcome = graph.apply_platform_manifest(&manifest)?;
-    Ok(outcome.mode_set_restrictions)
+    Ok(Some(manifest))
 }
 
 #[cfg(test)]
@@ -94,6 +136,7 @@ mod tests {
             exceptions: None,
             manifest: None,
             platform_manifest: Some(manifest_path),
+            rule_config: Default::default(),
             provenance: Provenance::InRepo,
         }
     }
@@ -144,6 +187,7 @@ mod tests {
             exceptions: None,
             manifest: None,
             platform_manifest: None,
+            rule_config: Default::default(),
             provenance: Provenance::InRepo,
         };
         let restrictions = apply_configured(&mut graph, &resolved).unwrap();
@@ -168,6 +212,10 @@ mod tests {
 
         let mut graph = make_graph();
         let resolved = resolved_with_manifest(manifest_path, dir.path().to_path_buf());
+        assert_eq!(
+            configured_includes(&resolved).unwrap(),
+            vec!["component=button"]
+        );
         let restrictions = apply_configured(&mut graph, &resolved).unwrap();
         assert!(restrictions.is_empty());
         assert_eq!(graph.tokens.len(), 2);
@@ -196,4 +244,34 @@ mod tests {
         let err = apply_configured(&mut graph, &resolved).unwrap_err();
         assert!(err.to_string().contains("query parse error"));
     }
+
+    #[test]
+    fn configured_formatting_is_read_without_applying() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("manifest.json");
+        std::fs::write(
+            &manifest_path,
+            json!({
+                "specVersion": "1.0.0-draft",
+                "foundationVersion": "1.0.0",
+                "extensions": {"formatting": {"casing": "SCREAMING_SNAKE_CASE", "delimiter": "_"}}
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let resolved = resolved_with_manifest(manifest_path, dir.path().to_path_buf());
+        let fmt = configured_formatting(&resolved)
+            .unwrap()
+            .expect("formatting");
+        let name = json!({"property": "background-color", "component": "button"});
+        assert_eq!(
+            fmt.format(&name).as_deref(),
+            Some("BUTTON_BACKGROUND_COLOR")
+        );
+
+        let mut graph = make_graph();
+        let outcome = apply_configured_manifest(&mut graph, &resolved).unwrap();
+        assert_eq!(outcome.formatting, Some(fmt));
+    }
 }
@@ -58,6 +58,9 @@ const COLOR_SET_MODE_ORDER: &[&str] = &["light", "dark", "wireframe"];
 /// Stable output order for `scale-set` modes.
 const SCALE_SET_MODE_ORDER: &[&str] = &["desktop", "mobile"];
 
+/// Stable output order for `motion-set` modes.
+const MOTION_SET_MODE_ORDER: &[&str] = &["no-preference", "reduce"];
+
 /// Token fields that live at the outer set level and propagate to all child tokens.
 const OUTER_LIFECYCLE_FIELDS: &[&str] = &[
     "deprecated",
@@ -350,6 +353,16 @@ fn convert_token_with_context(
             None,
             &mut throwaway,
         )
+    } else if schema.ends_with("motion-set.json") {
+        convert_set(
+            name,
+            token_obj,
+            "reducedMotion",
+            MOTION_SET_MODE_ORDER,
+            name_to_uuid,
+            None,
+            &mut throwaway,
+        )
     } else {
         vec![build_flat(
             name,
@@ -559,6 +572,19 @@ fn convert_object_with_context(
             summary.set_entries_unwrapped += tokens.len();
             summary.tokens_produced += tokens.len();
             out.extend(tokens);
+        } else if schema.ends_with("motion-set.json") {
+            let tokens = convert_set(
+                name,
+                tok_obj,
+                "reducedMotion",
+                MOTION_SET_MODE_ORDER,
+                name_to_uuid,
+                name_ctx,
+                summary,
+            );
+            summary.set_entries_unwrapped += tokens.len();
+            summary.tokens_produced += tokens.len();
+            out.extend(tokens);
         } else {
             let token = build_flat(name, tok_obj, name_to_uuid, name_ctx, summary);
             summary.flat_tokens_converted += 1;
@@ -755,6 +781,7 @@ fn build_flat(
         if !schema.ends_with("color-set.json")
             && !schema.ends_with("scale-set.json")
             && !schema.ends_with("typography-scale.json")
+            && !schema.ends_with("motion-set.json")
         {
             out.insert("$schema".into(), Value::String(schema.to_string()));
         }
@@ -968,6 +995,28 @@ mod tests {
         assert_eq!(tokens[1]["name"]["scale"], "mobile");
     }
 
+    #[test]
+    fn motion_set_splits_by_reduced_motion() {
+        let tokens = convert_token(
+            "animation-duration-100",
+            &obj(json!({
+                "$schema": ".../motion-set.json",
+                "sets": {
+                    "reduce":        { "$schema": ".../duration.json", "value": "0ms",   "uuid": "ffff" },
+                    "no-preference": { "$schema": ".../duration.json", "value": "130ms", "uuid": "gggg" }
+                }
+            })),
+        );
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0]["name"]["reducedMotion"], "no-preference");
+        assert_eq!(tokens[1]["name"]["reducedMotion"], "reduce");
+        assert_eq!(tokens[1]["value"], "0ms");
+        assert!(tokens[0]["$schema"]
+            .as_str()
+            .unwrap()
+            .ends_with("duration.json"));
+    }
+
     #[test]
     fn outer_lifecycle_propagates_to_all_modes() {
         let tokens = convert_token(
@@ -29,8 +29,12 @@
 //! Color-palette tokens have their own path: `{variant?}-{colorFamily}-{scaleIndex?}`.
 //! `weight`/`style` (CSS font-weight/font-style values) remain excluded pending their
 //! own decomposition pass — only `script`/`family`/`emphasis` are enabled so far.
+//!
+//! Platforms that need a different flat name declare `extensions.formatting` in
+//! their manifest; [`NameFormatting`] serializes name objects under those rules.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Range;
 use std::path::Path;
 use std::sync::LazyLock;
 
@@ -533,6 +537,226 @@ pub fn extract_legacy_key(name_val: &Value) -> Option<String> {
     Some(parts.join("-"))
 }
 
+// ── Platform formatting (manifest `extensions.formatting`) ────────────────────
+
+/// Name-object fields carrying `excludeFromLegacyKey` that are still part of a
+/// token's identity: the default serialization emits them through dedicated
+/// branches in [`extract_legacy_key`] rather than the position walk, so the
+/// formatter treats them as ordinary concepts.
+const FORMATTED_IDENTITY_FIELDS: &[&str] =
+    &["colorFamily", "colorRole", "scaleIndex", "from", "to"];
+
+/// Casing style for [`NameFormatting`] (`extensions.formatting.casing`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Casing {
+    #[default]
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+}
+
+/// A platform manifest's `extensions.formatting` block: how a name object is
+/// serialized into that platform's flat token name (`spec/manifest.md`).
+///
+/// Serialization is deterministic:
+///
+/// 1. **Concepts** — one per populated name-object field. Fields listed in
+///    `conceptOrder` come first in that order; the rest follow in field-catalog
+///    `serialization.position` order (unknown fields last, alphabetically).
+///    Fields the default serialization omits (`excludeFromLegacyKey`, e.g. the
+///    mode-set fields) are dropped unless `conceptOrder` names them, and
+///    `legacyKey` is never a concept. Registry ids expand to their `tokenName`
+///    long-form, and a thin-format `property` that already carries the
+///    `component`/`icon` prefix suppresses the duplicate owner concept.
+/// 2. **Abbreviations** — a concept whose full term is a key is replaced
+///    outright; otherwise each hyphen-separated word is looked up.
+/// 3. **Casing** — applied within each concept (`background-color` →
+///    `backgroundColor`, `BackgroundColor`, `BACKGROUND_COLOR`).
+/// 4. Concepts are joined with `delimiter`.
+///
+/// A string `name` (SPEC-017 escape hatch) is a single concept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NameFormatting {
+    #[serde(default)]
+    pub concept_order: Vec<String>,
+    #[serde(default)]
+    pub casing: Casing,
+    #[serde(default = "default_delimiter")]
+    pub delimiter: String,
+    /// Full term → abbreviation. A `BTreeMap` so serialization is stable.
+    #[serde(default)]
+    pub abbreviations: BTreeMap<String, String>,
+}
+
+fn default_delimiter() -> String {
+    "-".to_string()
+}
+
+impl Default for NameFormatting {
+    fn default() -> Self {
+        Self {
+            concept_order: Vec::new(),
+            casing: Casing::default(),
+            delimiter: default_delimiter(),
+            abbreviations: BTreeMap::new(),
+        }
+    }
+}
+
+impl NameFormatting {
+    /// Read `extensions.formatting` from a manifest document.
+    ///
+    /// Returns `Ok(None)` when the block is absent. A malformed block, an empty
+    /// `delimiter`, or a `conceptOrder` entry that is not a declared field-catalog
+    /// name is a [`crate::CoreError::ParseError`].
+    pub fn from_manifest(manifest: &Value) -> Result<Option<Self>, crate::CoreError> {
+        let Some(block) = manifest.pointer("/extensions/formatting") else {
+            return Ok(None);
+        };
+        let fmt: Self = serde_json::from_value(block.clone()).map_err(|e| {
+            crate::CoreError::ParseError(format!("invalid extensions.formatting: {e}"))
+        })?;
+        if fmt.delimiter.is_empty() {
+            return Err(crate::CoreError::ParseError(
+                "extensions.formatting.delimiter must not be empty".to_string(),
+            ));
+        }
+        let catalog = crate::registry::FieldCatalog::embedded();
+        if let Some(unknown) = fmt.concept_order.iter().find(|f| catalog.get(f).is_none()) {
+            return Err(crate::CoreError::ParseError(format!(
+                "extensions.formatting.conceptOrder: `{unknown}` is not a declared field"
+            )));
+        }
+        Ok(Some(fmt))
+    }
+
+    /// Serialize a cascade `name` value (object or string form). Returns `None`
+    /// when the name yields no concepts.
+    pub fn format(&self, name_val: &Value) -> Option<String> {
+        let concepts = match name_val {
+            Value::String(s) => vec![s.clone()],
+            Value::Object(name) => self.concepts(name),
+            _ => return None,
+        };
+        if concepts.is_empty() {
+            return None;
+        }
+        Some(
+            concepts
+                .iter()
+                .map(|c| self.case_concept(&self.abbreviate(c)))
+                .collect::<Vec<_>>()
+                .join(&self.delimiter),
+        )
+    }
+
+    /// Serialize a [`NameObject`].
+    pub fn format_name_object(&self, obj: &NameObject) -> String {
+        serde_json::to_value(obj)
+            .ok()
+            .and_then(|v| self.format(&v))
+            .unwrap_or_else(|| obj.property.clone())
+    }
+
+    /// The ordered, registry-expanded concept values of a name object.
+    fn concepts(&self, name: &Map<String, Value>) -> Vec<String> {
+        let registry = crate::registry::RegistryData::embedded();
+        let catalog = crate::registry::FieldCatalog::embedded();
+
+        let explicit = |f: &str| self.concept_order.iter().any(|c| c == f);
+        let mut fields: Vec<&str> = self.concept_order.iter().map(String::as_str).collect();
+        fields.extend(
+            catalog
+                .entries_by_position()
+                .iter()
+                .filter(|e| {
+                    !e.exclude_from_legacy_key || FORMATTED_IDENTITY_FIELDS.contains(&e.name)
+                })
+                .map(|e| e.name)
+                .filter(|f| !explicit(f)),
+        );
+        let mut unknown: Vec<&str> = name
+            .keys()
+            .map(String::as_str)
+            .filter(|k| *k != "legacyKey" && catalog.get(k).is_none())
+            .collect();
+        unknown.sort_unstable();
+        fields.extend(unknown);
+
+        let property = name.get("property").and_then(Value::as_str).unwrap_or("");
+        let mut out = Vec::new();
+        for field in fields {
+            let values: Vec<String> = match name.get(field) {
+                Some(Value::String(s)) => vec![s.clone()],
+                Some(Value::Number(n)) => vec![n.to_string()],
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect(),
+                _ => continue,
+            };
+            for v in values {
+                let expanded = registry.token_name(field, &v).unwrap_or(&v).to_string();
+                let is_owner_prefix = matches!(field, "component" | "icon")
+                    && property.starts_with(expanded.as_str())
+                    && property[expanded.len()..].starts_with('-');
+                if !is_owner_prefix {
+                    out.push(expanded);
+                }
+            }
+        }
+        out
+    }
+
+    /// Apply `abbreviations` to one concept: whole-term first, then per word.
+    fn abbreviate(&self, concept: &str) -> String {
+        if let Some(short) = self.abbreviations.get(concept) {
+            return short.clone();
+        }
+        concept
+            .split('-')
+            .map(|w| self.abbreviations.get(w).map_or(w, String::as_str))
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Apply `casing` to one (abbreviated) concept.
+    fn case_concept(&self, concept: &str) -> String {
+        let words = concept.split('-').filter(|w| !w.is_empty());
+        let capitalize = |w: &str| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().collect::<String>() + chars.as_str())
+                .unwrap_or_default()
+        };
+        match self.casing {
+            Casing::KebabCase => words.collect::<Vec<_>>().join("-"),
+            Casing::CamelCase => words
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            Casing::PascalCase => words.map(capitalize).collect(),
+            Casing::ScreamingSnakeCase => {
+                words.map(str::to_uppercase).collect::<Vec<_>>().join("_")
+            }
+        }
+    }
+}
+
 /// An entry in the naming-exceptions.json allowlist.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NamingException {
@@ -577,6 +801,45 @@ pub fn roundtrips(key: &str, component_hint: Option<&str>) -> bool {
     !has_embedded_state(&obj.property)
 }
 
+/// The key `key` would have if it followed the generation rules, for a key
+/// that fails [`roundtrips`]: the hinted component is prefixed, and a state
+/// word embedded before the property moves to the end
+/// (`swatch-disabled-icon-border-color` → `swatch-icon-border-color-disabled`).
+///
+/// `None` when `key` already roundtrips or no single rename makes it.
+pub fn canonical_legacy_name(key: &str, component_hint: Option<&str>) -> Option<String> {
+    let mut obj = parse_legacy_name(key, component_hint);
+    if let Some((property, state)) = lift_embedded_state(&obj.property) {
+        obj.property = property;
+        let mut states = vec![state];
+        states.extend(obj.state.take().unwrap_or_default());
+        obj.state = Some(states);
+    }
+    let name = generate_legacy_name(&obj);
+    (name != key && roundtrips(&name, component_hint)).then_some(name)
+}
+
+/// Remove the first state word (or two-segment compound) found before the
+/// last segment of `property`, returning the remaining property and the state.
+fn lift_embedded_state(property: &str) -> Option<(String, String)> {
+    let segments: Vec<&str> = property.split('-').collect();
+    for i in 0..segments.len().saturating_sub(1) {
+        // Compounds first, as in `split_trailing_state`; keep at least one
+        // property segment.
+        for len in [2, 1] {
+            if i + len >= segments.len() {
+                continue;
+            }
+            let state = segments[i..i + len].join("-");
+            if STATE_WORDS.contains(state.as_str()) {
+                let rest = [&segments[..i], &segments[i + len..]].concat().join("-");
+                return Some((rest, state));
+            }
+        }
+    }
+    None
+}
+
 /// Returns `true` when the property string contains a known state word as a
 /// hyphen-delimited segment in a non-trailing position, indicating the
 /// legacy name encoded state before property.
@@ -604,6 +867,68 @@ fn has_embedded_state(property: &str) -> bool {
     false
 }
 
+// ── Inline references ─────────────────────────────────────────────────────────
+
+/// Each `{legacy-key}` reference inside `s`: the byte range of the braced span
+/// and the key between the braces. An unclosed `{` ends the scan.
+pub fn inline_refs(s: &str) -> impl Iterator<Item = (Range<usize>, &str)> + '_ {
+    let mut from = 0;
+    std::iter::from_fn(move || {
+        let start = from + s[from..].find('{')?;
+        let len = s[start..].find('}')?;
+        from = start + len + 1;
+        Some((start..from, &s[start + 1..start + len]))
+    })
+}
+
+/// `s` with each `{legacy-key}` reference replaced by `replace(key)`, or kept
+/// as written when that returns `None`.
+pub fn replace_inline_refs(s: &str, mut replace: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut last = 0;
+    for (span, key) in inline_refs(s) {
+        if let Some(to) = replace(key) {
+            out.push_str(&s[last..span.start]);
+            out.push_str(&to);
+            last = span.end;
+        }
+    }
+    out.push_str(&s[last..]);
+    out
+}
+
+// ── Identifiers ───────────────────────────────────────────────────────────────
+
+/// `accent-background-color-default` → `AccentBackgroundColorDefault`.
+pub(crate) fn pascal_case(s: &str) -> String {
+    s.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// A legacy key as a camelCase identifier (`accent-color-100` → `accentColor100`).
+/// Keys starting with a digit are prefixed with `_`.
+pub fn camel_identifier(key: &str) -> String {
+    let pascal = pascal_case(key);
+    let mut chars = pascal.chars();
+    let ident = chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -666,6 +991,22 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn canonical_name_repairs_roundtrip_failures() {
+        assert_eq!(
+            canonical_legacy_name("swatch-disabled-icon-border-color", Some("swatch")).as_deref(),
+            Some("swatch-icon-border-color-disabled")
+        );
+        assert_eq!(
+            canonical_legacy_name("control-size", Some("checkbox")).as_deref(),
+            Some("checkbox-control-size")
+        );
+        assert_eq!(
+            canonical_legacy_name("checkbox-control-size", Some("checkbox")),
+            None
+        );
+    }
+
     #[test]
     fn non_decomposable() {
         let obj = parse_legacy_name("white", None);
@@ -682,6 +1023,24 @@ mod tests {
         assert!(roundtrips("accent-background-color-default", None));
     }
 
+    #[test]
+    fn inline_refs_are_scanned_and_replaced() {
+        let s = "{x} solid {blue-100} {unclosed";
+        let keys: Vec<&str> = inline_refs(s).map(|(_, k)| k).collect();
+        assert_eq!(keys, vec!["x", "blue-100"]);
+        assert_eq!(
+            replace_inline_refs(s, |k| (k == "blue-100").then(|| "#00f".to_string())),
+            "{x} solid #00f {unclosed"
+        );
+    }
+
+    #[test]
+    fn identifiers_are_camel_case() {
+        assert_eq!(camel_identifier("accent-color-100"), "accentColor100");
+        assert_eq!(camel_identifier("100-percent"), "_100Percent");
+        assert_eq!(pascal_case("dark-mobile"), "DarkMobile");
+    }
+
     // ── extract_legacy_key ────────────────────────────────────────────────────
 
     use serde_json::json;
@@ -1126,4 +1485,91 @@ mod tests {
             Some("informative-color")
         );
     }
+
+    // ── NameFormatting ────────────────────────────────────────────────────────
+
+    fn formatting(block: Value) -> NameFormatting {
+        NameFormatting::from_manifest(&json!({"extensions": {"formatting": block}}))
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn formatting_defaults_follow_catalog_order() {
+        let fmt = NameFormatting::default();
+        let name = json!({"component": "button", "property": "background-color",
+                          "size": "xl", "state": ["selected", "hover"], "colorScheme": "dark"});
+        assert_eq!(
+            fmt.format(&name).as_deref(),
+            Some("button-background-color-extra-large-selected-hover")
+        );
+    }
+
+    #[test]
+    fn formatting_concept_order_casing_and_abbreviations() {
+        let fmt = formatting(json!({
+            "conceptOrder": ["state", "component"],
+            "casing": "camelCase",
+            "delimiter": ".",
+            "abbreviations": {"background": "bg", "extra-large": "xl"}
+        }));
+        let name = json!({"component": "action-button", "property": "background-color",
+                          "size": "xl", "state": ["hover"]});
+        assert_eq!(
+            fmt.format(&name).as_deref(),
+            Some("hover.actionButton.bgColor.xl")
+        );
+    }
+
+    #[test]
+    fn formatting_screaming_snake_and_pascal() {
+        let name = json!({"colorFamily": "blue", "property": "color", "scaleIndex": 100});
+        let snake = formatting(json!({"casing": "SCREAMING_SNAKE_CASE", "delimiter": "_"}));
+        assert_eq!(snake.format(&name).as_deref(), Some("COLOR_BLUE_100"));
+        let pascal = formatting(json!({"casing": "PascalCase", "delimiter": "/"}));
+        assert_eq!(pascal.format(&name).as_deref(), Some("Color/Blue/100"));
+    }
+
+    #[test]
+    fn formatting_mode_set_fields_only_when_ordered() {
+        let name = json!({"property": "gray-100", "colorScheme": "dark"});
+        assert_eq!(
+            NameFormatting::default().format(&name).as_deref(),
+            Some("gray-100")
+        );
+        let fmt = formatting(json!({"conceptOrder": ["colorScheme"]}));
+        assert_eq!(fmt.format(&name).as_deref(), Some("dark-gray-100"));
+    }
+
+    #[test]
+    fn formatting_thin_property_and_string_names() {
+        let fmt = formatting(json!({"casing": "PascalCase"}));
+        let thin = json!({"component": "checkbox", "property": "checkbox-control-size"});
+        assert_eq!(fmt.format(&thin).as_deref(), Some("CheckboxControlSize"));
+        assert_eq!(
+            fmt.format(&json!("accent-color-100")).as_deref(),
+            Some("AccentColor100")
+        );
+        let obj = NameObject {
+            property: "border-width".into(),
+            component: Some("field".into()),
+            variant: None,
+            state: None,
+        };
+        assert_eq!(fmt.format_name_object(&obj), "Field-BorderWidth");
+    }
+
+    #[test]
+    fn formatting_is_absent_or_rejected_when_invalid() {
+        assert_eq!(NameFormatting::from_manifest(&json!({})).unwrap(), None);
+        for bad in [
+            json!({"delimiter": ""}),
+            json!({"conceptOrder": ["notAField"]}),
+            json!({"casing": "Title Case"}),
+        ] {
+            assert!(
+                NameFormatting::from_manifest(&json!({"extensions": {"formatting": bad}})).is_err()
+            );
+        }
+    }
 }
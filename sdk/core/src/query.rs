@@ -11,16 +11,34 @@
 //! Query filter engine — parse and evaluate filter expressions against tokens.
 //!
 //! Implements the query notation defined in `spec/query.md`: `key=value` pairs
-//! with `,` (AND), `|` (OR), `!=` (negation), and `*` (glob wildcard).
+//! with `,` (AND), `|` (OR), `!=` (negation), and `*` (glob wildcard), plus
+//! `( … )` grouping, `!` (NOT), `key in (a, b)` set membership, and
+//! `exists(key)`. Keys are the spec's fixed set, a few token-level fields, and
+//! any name-object field registered in the [`FieldCatalog`].
+//!
+//! The `value` key matches a token's *resolved* value — its literal, or the
+//! terminal value of its alias chain — with color-aware equality, numeric
+//! comparisons (`>`, `>=`, `<`, `<=`), and approximate equality (`~=`).
+//! [`filter_in_context`] evaluates an expression against the cascade winners
+//! for a [`ResolutionContext`]; [`filter`] resolves aliases in the default modes.
+
+use std::collections::{HashMap, HashSet};
 
-use std::collections::HashMap;
+use serde_json::Value;
 
+use crate::cascade::{self, mode_axes, numeric_value, parse_dimension, ResolutionContext};
+use crate::color::parse_rgba;
 use crate::graph::{TokenGraph, TokenRecord};
+use crate::registry::FieldCatalog;
 use crate::CoreError;
 
 // ── Allowed keys (per spec) ─────────────────────────────────────────────────
 
 /// Keys that may appear in filter expressions.
+///
+/// These are also the fields [`TokenIndex`] indexes (and the cache persists);
+/// [`parse`] additionally accepts [`TOKEN_KEYS`] and every field in the
+/// embedded [`FieldCatalog`], which are evaluated by scanning.
 pub(crate) const ALLOWED_KEYS: &[&str] = &[
     "property",
     "component",
@@ -41,16 +59,24 @@ pub fn indexed_fields() -> &'static [&'static str] {
     ALLOWED_KEYS
 }
 
-/// Keys resolved from `raw["name"][key]` (name-object fields).
-const NAME_OBJECT_KEYS: &[&str] = &[
-    "property",
-    "component",
-    "variant",
-    "state",
-    "colorScheme",
-    "scale",
-    "contrast",
-];
+/// Token-level (non-name-object) keys beyond `uuid` and `$schema`.
+///
+/// `deprecated` resolves to `lifecycle.deprecatedIn` (or `"true"` for a legacy
+/// top-level `deprecated: true`), so both `exists(deprecated)` and
+/// `deprecated=13.*` work.
+const TOKEN_KEYS: &[&str] = &["$ref", "deprecated", "private"];
+
+/// The resolved-value key. Unlike every other key it is evaluated against the
+/// graph (to follow aliases), and it is the only key that accepts the ordering
+/// and `~=` operators.
+const VALUE_KEY: &str = "value";
+
+/// Tolerance for `~=` when none is given: one 8-bit channel step for colors, one
+/// unit for numbers and dimensions.
+const DEFAULT_TOLERANCE: f64 = 1.0;
+
+/// Maximum `(`/`!` nesting depth, so hostile input cannot overflow the stack.
+const MAX_DEPTH: usize = 64;
 
 // ── AST types ───────────────────────────────────────────────────────────────
 
@@ -60,48 +86,56 @@ pub struct TokenFilter {
     expr: FilterExpr,
 }
 
-impl TokenFilter {
-    /// If this filter is exactly one `key=value` equality condition (no `,`,
-    /// `|`, `!=`, or `*`), return `(key, value)`. Used to choose the index-backed
-    /// fast path in [`filter_with_index`].
-    fn single_equality(&self) -> Option<(&str, &str)> {
-        let FilterExpr::Or(alternatives) = &self.expr else {
-            return None;
-        };
-        let [group] = alternatives.as_slice() else {
-            return None;
-        };
-        let [cond] = group.as_slice() else {
-            return None;
-        };
-        if cond.op != Operator::Eq || cond.value.contains('*') {
-            return None;
-        }
-        Some((&cond.key, &cond.value))
-    }
-}
-
 #[derive(Debug, Clone)]
 enum FilterExpr {
     /// Universal match (empty expression).
     All,
-    /// `|`-separated alternatives.
-    Or(Vec<Vec<Condition>>),
+    /// `|`-separated alternatives (two or more).
+    Or(Vec<FilterExpr>),
+    /// `,`-separated conjuncts (two or more).
+    And(Vec<FilterExpr>),
+    /// `!expr`.
+    Not(Box<FilterExpr>),
+    /// `exists(key)` — the field resolves to at least one value.
+    Exists(String),
+    /// A single comparison.
+    Condition(Condition),
 }
 
 /// Comparison operator.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operator {
     Eq,
     NotEq,
+    /// `key in (a, b, …)` — equality against any listed value.
+    In,
+    /// `value>n` and friends — numeric comparison, units must agree.
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    /// `value~=literal[/tolerance]` — color or numeric equality within a tolerance.
+    Approx,
 }
 
-/// A single `key=value` or `key!=value` condition.
+impl Operator {
+    /// Operators that only apply to [`VALUE_KEY`].
+    fn is_value_only(self) -> bool {
+        matches!(
+            self,
+            Operator::Gt | Operator::Ge | Operator::Lt | Operator::Le | Operator::Approx
+        )
+    }
+}
+
+/// A single `key=value`, `key!=value`, `key in (…)`, or value comparison.
+///
+/// `values` holds exactly one entry for every operator but `In`.
 #[derive(Debug, Clone)]
 struct Condition {
     key: String,
     op: Operator,
-    value: String,
+    values: Vec<String>,
 }
 
 // ── Parser ──────────────────────────────────────────────────────────────────
@@ -110,149 +144,532 @@ struct Condition {
 ///
 /// Returns `CoreError` for syntax errors or unknown keys.
 pub fn parse(input: &str) -> Result<TokenFilter, CoreError> {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
+    if input.trim().is_empty() {
         return Ok(TokenFilter {
             expr: FilterExpr::All,
         });
     }
+    let mut parser = Parser { src: input, pos: 0 };
+    let expr = parser.or_expr(0)?;
+    parser.skip_ws();
+    if let Some(c) = parser.peek() {
+        return Err(parser.error(&format!("unexpected {c:?}")));
+    }
+    Ok(TokenFilter { expr })
+}
+
+/// Recursive-descent parser over the grammar in `spec/query.md`.
+///
+/// All delimiters are ASCII, so byte positions taken at a delimiter are always
+/// valid `str` boundaries.
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
 
-    // Split on `|` first (OR — lower precedence).
-    let or_parts: Vec<&str> = split_top_level(trimmed, '|');
-    let mut alternatives = Vec::new();
+    fn skip_ws(&mut self) {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
 
-    for or_part in or_parts {
-        let and_parts: Vec<&str> = split_top_level(or_part.trim(), ',');
-        let mut conditions = Vec::new();
-        for part in and_parts {
-            conditions.push(parse_condition(part.trim())?);
+    /// Consume `c` (after optional whitespace) if it is next.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_ws();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
         }
-        alternatives.push(conditions);
     }
 
-    Ok(TokenFilter {
-        expr: FilterExpr::Or(alternatives),
-    })
-}
+    fn expect(&mut self, c: char) -> Result<(), CoreError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {c:?}")))
+        }
+    }
 
-/// Split a string on a delimiter, respecting that we don't have nesting.
-fn split_top_level(s: &str, delim: char) -> Vec<&str> {
-    s.split(delim).collect()
-}
+    fn error(&self, msg: &str) -> CoreError {
+        CoreError::QueryParse(format!(
+            "{msg} at position {} in {:?}",
+            self.src[..self.pos].chars().count(),
+            self.src
+        ))
+    }
 
-/// Parse a single `key=value` or `key!=value` condition.
-fn parse_condition(s: &str) -> Result<Condition, CoreError> {
-    // Try `!=` first (longer operator).
-    if let Some(pos) = s.find("!=") {
-        let key = s[..pos].trim().to_string();
-        let value = s[pos + 2..].trim().to_string();
-        validate_key(&key)?;
-        return Ok(Condition {
-            key,
-            op: Operator::NotEq,
-            value,
-        });
+    /// `or-expr = and-expr { "|" and-expr }`
+    fn or_expr(&mut self, depth: usize) -> Result<FilterExpr, CoreError> {
+        let mut alternatives = vec![self.and_expr(depth)?];
+        while self.eat('|') {
+            alternatives.push(self.and_expr(depth)?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.remove(0)
+        } else {
+            FilterExpr::Or(alternatives)
+        })
+    }
+
+    /// `and-expr = unary { "," unary }`
+    fn and_expr(&mut self, depth: usize) -> Result<FilterExpr, CoreError> {
+        let mut conjuncts = vec![self.unary(depth)?];
+        while self.eat(',') {
+            conjuncts.push(self.unary(depth)?);
+        }
+        Ok(if conjuncts.len() == 1 {
+            conjuncts.remove(0)
+        } else {
+            FilterExpr::And(conjuncts)
+        })
     }
-    // Then `=`.
-    if let Some(pos) = s.find('=') {
-        let key = s[..pos].trim().to_string();
-        let value = s[pos + 1..].trim().to_string();
+
+    /// `unary = "!" unary | "(" or-expr ")" | "exists" "(" key ")" | condition`
+    fn unary(&mut self, depth: usize) -> Result<FilterExpr, CoreError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("expression nested too deeply"));
+        }
+        if self.eat('!') {
+            return Ok(FilterExpr::Not(Box::new(self.unary(depth + 1)?)));
+        }
+        if self.eat('(') {
+            let inner = self.or_expr(depth + 1)?;
+            self.expect(')')?;
+            return Ok(inner);
+        }
+
+        let key = self.key()?;
+        if key == "exists" && self.eat('(') {
+            let field = self.key()?;
+            validate_key(&field)?;
+            self.expect(')')?;
+            return Ok(FilterExpr::Exists(field));
+        }
         validate_key(&key)?;
-        return Ok(Condition {
+
+        self.skip_ws();
+        let rest = &self.src[self.pos..];
+        // Two-character operators first, so `>=` is not read as `>` then `=`.
+        const OPERATORS: &[(&str, Operator)] = &[
+            ("!=", Operator::NotEq),
+            (">=", Operator::Ge),
+            ("<=", Operator::Le),
+            ("~=", Operator::Approx),
+            (">", Operator::Gt),
+            ("<", Operator::Lt),
+            ("=", Operator::Eq),
+        ];
+        let op = if let Some((token, op)) = OPERATORS.iter().find(|(t, _)| rest.starts_with(t)) {
+            if op.is_value_only() && key != VALUE_KEY {
+                return Err(self.error(&format!(
+                    "operator `{token}` only applies to `{VALUE_KEY}`, not {key:?}"
+                )));
+            }
+            self.pos += token.len();
+            *op
+        } else if rest.starts_with("in") && rest[2..].trim_start().starts_with('(') {
+            self.pos += 2;
+            self.expect('(')?;
+            let literal = key == VALUE_KEY;
+            let mut values = vec![self.value(literal)?];
+            while self.eat(',') {
+                values.push(self.value(literal)?);
+            }
+            self.expect(')')?;
+            return Ok(FilterExpr::Condition(Condition {
+                key,
+                op: Operator::In,
+                values,
+            }));
+        } else {
+            return Err(self.error(&format!(
+                "invalid condition for key {key:?} (expected `=`, `!=`, or `in (…)`)"
+            )));
+        };
+        let start = self.pos;
+        let value = self.value(key == VALUE_KEY)?;
+        if let Err(msg) = check_value_literal(op, &value) {
+            self.pos = start;
+            return Err(self.error(&msg));
+        }
+        Ok(FilterExpr::Condition(Condition {
             key,
-            op: Operator::Eq,
-            value,
-        });
+            op,
+            values: vec![value],
+        }))
+    }
+
+    /// `key = (letter | "$") { letter | digit | "$" | "_" }`
+    fn key(&mut self) -> Result<String, CoreError> {
+        self.skip_ws();
+        let rest = &self.src[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '$' || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a key"));
+        }
+        self.pos += len;
+        Ok(rest[..len].to_string())
+    }
+
+    /// A value runs to the next `,`, `|`, `(`, or `)` and is trimmed. A `value`
+    /// literal may also hold balanced parentheses (`rgb(255, 255, 255)`), whose
+    /// contents are part of the literal.
+    fn value(&mut self, literal: bool) -> Result<String, CoreError> {
+        let rest = &self.src[self.pos..];
+        let mut open = None;
+        let mut len = rest.len();
+        for (i, c) in rest.char_indices() {
+            match (c, open) {
+                ('(', None) if literal => open = Some(i),
+                ('(', _) => {
+                    self.pos += i;
+                    return Err(self.error("unexpected '(' in value"));
+                }
+                (')', Some(_)) => open = None,
+                (',' | '|' | ')', None) => {
+                    len = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        if let Some(i) = open {
+            self.pos += i;
+            return Err(self.error("unclosed '(' in value"));
+        }
+        self.pos += len;
+        Ok(rest[..len].trim().to_string())
     }
-    Err(CoreError::QueryParse(format!(
-        "invalid condition (missing operator): {s:?}"
-    )))
 }
 
-/// Validate that a key is in the allowed set.
+/// Validate that a key is queryable: a spec key, a token-level key, or a
+/// registered name-object field.
 fn validate_key(key: &str) -> Result<(), CoreError> {
     if key.is_empty() {
         return Err(CoreError::QueryParse("empty key".to_string()));
     }
-    if ALLOWED_KEYS.contains(&key) {
+    if ALLOWED_KEYS.contains(&key)
+        || TOKEN_KEYS.contains(&key)
+        || key == VALUE_KEY
+        || FieldCatalog::embedded().get(key).is_some()
+    {
         return Ok(());
     }
     Err(CoreError::QueryParse(format!(
-        "unknown key {key:?}; allowed keys are: {}",
-        ALLOWED_KEYS.join(", ")
+        "unknown key {key:?}; allowed keys are {}, {}, {VALUE_KEY}, or any field in the \
+         fields/ catalog",
+        ALLOWED_KEYS.join(", "),
+        TOKEN_KEYS.join(", ")
     )))
 }
 
+/// Reject right-hand sides the ordering and `~=` operators cannot compare.
+fn check_value_literal(op: Operator, literal: &str) -> Result<(), String> {
+    match op {
+        Operator::Gt | Operator::Ge | Operator::Lt | Operator::Le => {
+            if parse_dimension(literal).is_none() {
+                return Err(format!("expected a number or dimension, found {literal:?}"));
+            }
+        }
+        Operator::Approx => {
+            let (target, tolerance) = split_tolerance(literal)
+                .ok_or_else(|| format!("invalid tolerance in {literal:?}"))?;
+            if parse_rgba(target).is_none() && parse_dimension(target).is_none() {
+                return Err(format!("expected a color or dimension, found {target:?}"));
+            }
+            if tolerance < 0.0 {
+                return Err(format!("tolerance must not be negative in {literal:?}"));
+            }
+        }
+        Operator::Eq | Operator::NotEq | Operator::In => {}
+    }
+    Ok(())
+}
+
+/// Split `literal[/tolerance]`, defaulting to [`DEFAULT_TOLERANCE`]. A `/`
+/// inside a color function (`rgb(0 0 0 / 50%)`) belongs to the color.
+fn split_tolerance(literal: &str) -> Option<(&str, f64)> {
+    let from = literal.rfind(')').map_or(0, |i| i + 1);
+    match literal[from..].find('/') {
+        Some(i) => {
+            let (target, tolerance) = (&literal[..from + i], &literal[from + i + 1..]);
+            Some((target.trim(), tolerance.trim().parse().ok()?))
+        }
+        None => Some((literal, DEFAULT_TOLERANCE)),
+    }
+}
+
 // ── Filter evaluation ───────────────────────────────────────────────────────
 
 /// Filter tokens in a graph that match the given expression.
 ///
+/// `value` conditions follow alias chains in the default mode of every mode set;
+/// use [`filter_in_context`] to evaluate them in other modes.
+///
 /// Returns references to matching `TokenRecord`s, sorted by name for
 /// deterministic output.
 pub fn filter<'a>(graph: &'a TokenGraph, expr: &TokenFilter) -> Vec<&'a TokenRecord> {
+    let scope = Scope::new(graph, &ResolutionContext::new());
+    let mut results: Vec<&TokenRecord> = graph
+        .tokens
+        .values()
+        .filter(|t| matches_expr(t, &expr.expr, &scope))
+        .collect();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+/// Filter the tokens that win cascade resolution in `ctx`.
+///
+/// Tokens are grouped into variables by legacy key (as in
+/// [`cascade::resolve_variables`]) and only each variable's winner is
+/// considered, so a light-mode variant never matches a dark-mode query. Mode
+/// sets absent from `ctx` resolve to their default mode, and `ctx`'s
+/// restrictions apply to both the cascade and the defaults. Tokens without a
+/// name object take no part in the cascade and are always candidates.
+///
+/// `value` conditions then match each winner's resolved value in `ctx`.
+pub fn filter_in_context<'a>(
+    graph: &'a TokenGraph,
+    expr: &TokenFilter,
+    ctx: &ResolutionContext,
+) -> Vec<&'a TokenRecord> {
+    let scope = Scope::new(graph, ctx);
+    let full_ctx = scope
+        .modes
+        .iter()
+        .fold(ResolutionContext::new(), |acc, (set, mode)| {
+            acc.with(set.clone(), mode.clone())
+        });
+    let full_ctx = cascade::apply_restrictions(full_ctx, &ctx.mode_set_restrictions);
+
+    // Tokens without a name object take no part in the cascade, so no variable
+    // has them as its winner; they are evaluated directly.
     let mut results: Vec<&TokenRecord> = graph
         .tokens
         .values()
-        .filter(|t| matches_expr(&t.raw, &expr.expr))
+        .filter(|t| !t.raw.get("name").is_some_and(Value::is_object))
+        .filter(|t| matches_expr(t, &expr.expr, &scope))
         .collect();
+    results.extend(
+        cascade::resolve_variables(graph, &[full_ctx])
+            .into_values()
+            .filter_map(|winners| winners[0])
+            .filter(|winner| matches_expr(winner, &expr.expr, &scope)),
+    );
     results.sort_by(|a, b| a.name.cmp(&b.name));
     results
 }
 
-/// Evaluate a filter expression against a token's raw JSON.
-fn matches_expr(raw: &serde_json::Value, expr: &FilterExpr) -> bool {
+/// The resolved value of `token` in `ctx`: its literal `value`, or the terminal
+/// value of its alias chain. Mode sets absent from `ctx` take their default mode.
+///
+/// This is the value `value` conditions compare against.
+pub fn resolved_value<'a>(
+    graph: &'a TokenGraph,
+    token: &'a TokenRecord,
+    ctx: &ResolutionContext,
+) -> Option<&'a Value> {
+    Scope::new(graph, ctx).value(token)
+}
+
+/// What `value` conditions resolve against: the graph, for alias chains, and a
+/// mode for every mode set.
+struct Scope<'g> {
+    graph: &'g TokenGraph,
+    modes: HashMap<String, String>,
+}
+
+impl<'g> Scope<'g> {
+    fn new(graph: &'g TokenGraph, ctx: &ResolutionContext) -> Self {
+        let mut modes: HashMap<String, String> = mode_axes(graph, &ctx.mode_set_restrictions)
+            .into_iter()
+            .map(|axis| (axis.name, axis.base))
+            .collect();
+        modes.extend(ctx.mode_sets.clone());
+        Scope { graph, modes }
+    }
+
+    fn value(&self, token: &'g TokenRecord) -> Option<&'g Value> {
+        cascade::terminal_record(self.graph, token, &self.modes)?
+            .raw
+            .get("value")
+    }
+}
+
+/// Evaluate a filter expression against a token.
+fn matches_expr<'g>(token: &'g TokenRecord, expr: &FilterExpr, scope: &Scope<'g>) -> bool {
     match expr {
         FilterExpr::All => true,
-        FilterExpr::Or(alternatives) => alternatives
-            .iter()
-            .any(|and_group| and_group.iter().all(|cond| matches_condition(raw, cond))),
+        FilterExpr::Or(alternatives) => alternatives.iter().any(|e| matches_expr(token, e, scope)),
+        FilterExpr::And(conjuncts) => conjuncts.iter().all(|e| matches_expr(token, e, scope)),
+        FilterExpr::Not(inner) => !matches_expr(token, inner, scope),
+        FilterExpr::Exists(key) if key == VALUE_KEY => scope.value(token).is_some(),
+        FilterExpr::Exists(key) => !resolve_key(&token.raw, key).is_empty(),
+        FilterExpr::Condition(cond) if cond.key == VALUE_KEY => {
+            matches_value(scope.value(token), cond)
+        }
+        FilterExpr::Condition(cond) => matches_condition(&token.raw, cond),
     }
 }
 
 /// Evaluate a single condition against a token's raw JSON.
 ///
 /// A field can resolve to more than one value (Proposal 006: `state` is an
-/// ordered array), so `Eq` matches if *any* value matches, and `NotEq` matches
-/// if *no* value matches — this is also correct for the single-value case, and
-/// for a missing field (empty list): `Eq` → false, `NotEq` → true.
-fn matches_condition(raw: &serde_json::Value, cond: &Condition) -> bool {
+/// ordered array), so `Eq`/`In` match if *any* value matches, and `NotEq`
+/// matches if *no* value matches — this is also correct for the single-value
+/// case, and for a missing field (empty list): `Eq` → false, `NotEq` → true.
+fn matches_condition(raw: &Value, cond: &Condition) -> bool {
     let values = resolve_key(raw, &cond.key);
-    let any_match = values.iter().any(|v| glob_match(&cond.value, v));
+    let any_match = values
+        .iter()
+        .any(|v| cond.values.iter().any(|pattern| glob_match(pattern, v)));
 
     match cond.op {
-        Operator::Eq => any_match,
         Operator::NotEq => !any_match,
+        _ => any_match,
     }
 }
 
 /// Resolve a query key to the field's value(s) in a token's raw JSON.
 ///
-/// Most name-object fields are a single string. `state` (Proposal 006) is an
-/// ordered array of atomic ids — handled generically here so any array-valued
-/// field resolves to each of its elements.
+/// `uuid`, `$schema`, `$ref`, and `private` are top-level fields, `deprecated`
+/// is derived from `lifecycle` (see [`TOKEN_KEYS`]), and every other key is a
+/// name-object field. Most fields are a single string; numbers and booleans
+/// (e.g. `scaleIndex`) are matched by their JSON text. `state` (Proposal 006) is
+/// an ordered array of atomic ids — handled generically here so any
+/// array-valued field resolves to each of its elements.
 pub(crate) fn resolve_key(raw: &serde_json::Value, key: &str) -> Vec<String> {
-    let field = if NAME_OBJECT_KEYS.contains(&key) {
-        raw.get("name").and_then(|n| n.get(key))
-    } else if key == "uuid" {
-        raw.get("uuid")
-    } else if key == "$schema" {
-        raw.get("$schema")
-    } else {
-        None
+    let field = match key {
+        "uuid" | "$schema" | "$ref" | "private" => raw.get(key),
+        "deprecated" => {
+            if let Some(since) = raw.pointer("/lifecycle/deprecatedIn") {
+                Some(since)
+            } else {
+                raw.get("deprecated").filter(|d| d.as_bool() == Some(true))
+            }
+        }
+        _ => raw.get("name").and_then(|n| n.get(key)),
     };
 
+    let scalar = |v: &serde_json::Value| match v {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    };
     match field {
-        Some(v) if v.is_string() => v.as_str().map(String::from).into_iter().collect(),
-        Some(v) if v.is_array() => v
-            .as_array()
-            .unwrap()
-            .iter()
-            .filter_map(|e| e.as_str().map(String::from))
-            .collect(),
-        _ => Vec::new(),
+        Some(serde_json::Value::Array(items)) => items.iter().filter_map(scalar).collect(),
+        Some(v) => scalar(v).into_iter().collect(),
+        None => Vec::new(),
     }
 }
 
+// ── Value predicates ────────────────────────────────────────────────────────
+
+/// Evaluate a `value` condition against a resolved value.
+///
+/// A token without a resolvable value matches only `!=`; composite (object or
+/// array) values never compare equal to a literal.
+fn matches_value(actual: Option<&Value>, cond: &Condition) -> bool {
+    let Some(actual) = actual else {
+        return cond.op == Operator::NotEq;
+    };
+    let literal = &cond.values[0];
+    match cond.op {
+        Operator::Eq => value_equals(actual, literal),
+        Operator::NotEq => !value_equals(actual, literal),
+        Operator::In => cond.values.iter().any(|v| value_equals(actual, v)),
+        Operator::Gt | Operator::Ge | Operator::Lt | Operator::Le => {
+            let (Some((a, unit)), Some((b, expected_unit))) =
+                (dimension_of(actual), parse_dimension(literal))
+            else {
+                return false;
+            };
+            unit == expected_unit
+                && match cond.op {
+                    Operator::Gt => a > b,
+                    Operator::Ge => a >= b,
+                    Operator::Lt => a < b,
+                    _ => a <= b,
+                }
+        }
+        Operator::Approx => {
+            let Some((target, tolerance)) = split_tolerance(literal) else {
+                return false;
+            };
+            if let Some(expected) = parse_rgba(target) {
+                return color_of(actual).is_some_and(|c| color_distance(c, expected) <= tolerance);
+            }
+            match (dimension_of(actual), parse_dimension(target)) {
+                (Some((a, unit)), Some((b, expected_unit))) => {
+                    unit == expected_unit && (a - b).abs() <= tolerance
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Equality between a resolved value and a literal.
+///
+/// Wildcard literals glob-match the value's text. Color literals match any
+/// color notation with the same 8-bit channels (`#FFF` = `rgb(255, 255, 255)`),
+/// numeric literals match the same number in the same unit (`24px` = `24.0px`),
+/// and anything else must equal the value's text exactly.
+fn value_equals(actual: &Value, literal: &str) -> bool {
+    if literal.contains('*') {
+        return value_text(actual).is_some_and(|text| glob_match(literal, &text));
+    }
+    if let Some(expected) = parse_rgba(literal) {
+        return color_of(actual).is_some_and(|c| color_distance(c, expected) < 0.5);
+    }
+    if let Some((b, expected_unit)) = parse_dimension(literal) {
+        return dimension_of(actual)
+            .is_some_and(|(a, unit)| unit == expected_unit && (a - b).abs() < 1e-9);
+    }
+    value_text(actual).is_some_and(|text| text == literal)
+}
+
+/// A scalar value as matchable text (strings verbatim, numbers and booleans as
+/// JSON text).
+fn value_text(v: &Value) -> Option<String> {
+    match v {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(_) | Value::Bool(_) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+fn color_of(v: &Value) -> Option<[f64; 4]> {
+    parse_rgba(v.as_str()?)
+}
+
+/// A number and its unit; JSON numbers and unitless strings have unit `""`.
+fn dimension_of(v: &Value) -> Option<(f64, &str)> {
+    match v {
+        Value::String(s) => parse_dimension(s),
+        _ => Some((numeric_value(v)?, "")),
+    }
+}
+
+/// Largest per-channel difference between two colors, in 8-bit channel steps
+/// (alpha included, scaled the same way).
+fn color_distance(a: [f64; 4], b: [f64; 4]) -> f64 {
+    a.iter()
+        .zip(&b)
+        .map(|(x, y)| (x - y).abs() * 255.0)
+        .fold(0.0, f64::max)
+}
+
 // ── Glob matching ───────────────────────────────────────────────────────────
 
 /// Simple glob matching: `*` matches zero or more characters.
@@ -395,9 +812,14 @@ pub fn facet_counts(
     result
 }
 
-/// Filter tokens using a prebuilt [`TokenIndex`] for the common single-field
-/// equality case, falling back to the full [`filter`] scan for everything else
-/// (wildcards, negation, `AND`/`OR` compositions).
+/// Filter tokens using a prebuilt [`TokenIndex`] to narrow the candidate set,
+/// falling back to the full [`filter`] scan when the index cannot help.
+///
+/// Equality, `in`, and wildcard conditions on indexed fields are answered from
+/// the index; `|` unions its alternatives' candidates and `,` takes the
+/// smallest candidate set among its conjuncts. The full expression is then
+/// re-evaluated on the candidates only, so negation, `exists`, and non-indexed
+/// keys compose freely with an indexed conjunct.
 ///
 /// Results are identical to [`filter`]; only the evaluation strategy differs.
 pub fn filter_with_index<'a>(
@@ -405,21 +827,54 @@ pub fn filter_with_index<'a>(
     index: &TokenIndex,
     expr: &TokenFilter,
 ) -> Vec<&'a TokenRecord> {
-    if let Some((key, value)) = expr.single_equality() {
-        if let Some(graph_keys) = index.lookup(key, value) {
-            let mut results: Vec<&TokenRecord> = graph_keys
-                .iter()
-                .filter_map(|k| graph.tokens.get(k))
-                .collect();
-            results.sort_by(|a, b| a.name.cmp(&b.name));
-            return results;
+    let Some(candidates) = index_candidates(index, &expr.expr) else {
+        return filter(graph, expr);
+    };
+    let scope = Scope::new(graph, &ResolutionContext::new());
+    let mut results: Vec<&TokenRecord> = candidates
+        .into_iter()
+        .filter_map(|k| graph.tokens.get(k))
+        .filter(|t| matches_expr(t, &expr.expr, &scope))
+        .collect();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+/// A superset of the graph keys matching `expr`, computed from the index alone,
+/// or `None` when some part of `expr` needs a full scan.
+fn index_candidates<'i>(index: &'i TokenIndex, expr: &FilterExpr) -> Option<HashSet<&'i str>> {
+    match expr {
+        FilterExpr::Condition(cond) if cond.op != Operator::NotEq => {
+            // A field absent from the index is either unindexed or carried by no
+            // token; scanning is correct for both.
+            let by_value = index.by_field.get(&cond.key)?;
+            let mut keys = HashSet::new();
+            for pattern in &cond.values {
+                if pattern.contains('*') {
+                    for (value, graph_keys) in by_value {
+                        if glob_match(pattern, value) {
+                            keys.extend(graph_keys.iter().map(String::as_str));
+                        }
+                    }
+                } else if let Some(graph_keys) = index.lookup(&cond.key, pattern) {
+                    keys.extend(graph_keys.iter().map(String::as_str));
+                }
+            }
+            Some(keys)
         }
-        // Indexed field, but no entries for this value → empty (not a fallback).
-        if index.by_field.contains_key(key) {
-            return Vec::new();
+        FilterExpr::Or(alternatives) => {
+            let mut keys = HashSet::new();
+            for alternative in alternatives {
+                keys.extend(index_candidates(index, alternative)?);
+            }
+            Some(keys)
         }
+        FilterExpr::And(conjuncts) => conjuncts
+            .iter()
+            .filter_map(|c| index_candidates(index, c))
+            .min_by_key(HashSet::len),
+        _ => None,
     }
-    filter(graph, expr)
 }
 
 // ── Fuzzy subsequence matching ───────────────────────────────────────────────
@@ -543,49 +998,49 @@ mod tests {
         assert!(matches!(f.expr, FilterExpr::All));
     }
 
+    /// The condition a filter parsed to, asserting it is a single comparison.
+    fn condition(f: &TokenFilter) -> &Condition {
+        match &f.expr {
+            FilterExpr::Condition(c) => c,
+            other => panic!("expected a single condition, got {other:?}"),
+        }
+    }
+
     #[test]
     fn parse_single_condition() {
         let f = parse("component=button").unwrap();
-        if let FilterExpr::Or(alts) = &f.expr {
-            assert_eq!(alts.len(), 1);
-            assert_eq!(alts[0].len(), 1);
-            assert_eq!(alts[0][0].key, "component");
-            assert_eq!(alts[0][0].value, "button");
-            assert_eq!(alts[0][0].op, Operator::Eq);
-        } else {
-            panic!("expected Or");
-        }
+        let c = condition(&f);
+        assert_eq!(c.key, "component");
+        assert_eq!(c.values, ["button"]);
+        assert_eq!(c.op, Operator::Eq);
     }
 
     #[test]
     fn parse_and_conditions() {
         let f = parse("component=button,state=hover").unwrap();
-        if let FilterExpr::Or(alts) = &f.expr {
-            assert_eq!(alts.len(), 1);
-            assert_eq!(alts[0].len(), 2);
-        } else {
-            panic!("expected Or");
-        }
+        assert!(matches!(&f.expr, FilterExpr::And(c) if c.len() == 2));
     }
 
     #[test]
     fn parse_or_conditions() {
         let f = parse("property=bg|property=fg").unwrap();
-        if let FilterExpr::Or(alts) = &f.expr {
-            assert_eq!(alts.len(), 2);
-        } else {
+        assert!(matches!(&f.expr, FilterExpr::Or(a) if a.len() == 2));
+    }
+
+    #[test]
+    fn parse_and_binds_tighter_than_or() {
+        let f = parse("component=x,state=y|variant=z").unwrap();
+        let FilterExpr::Or(alts) = &f.expr else {
             panic!("expected Or");
-        }
+        };
+        assert!(matches!(&alts[0], FilterExpr::And(c) if c.len() == 2));
+        assert!(matches!(&alts[1], FilterExpr::Condition(_)));
     }
 
     #[test]
     fn parse_negation() {
         let f = parse("colorScheme!=light").unwrap();
-        if let FilterExpr::Or(alts) = &f.expr {
-            assert_eq!(alts[0][0].op, Operator::NotEq);
-        } else {
-            panic!("expected Or");
-        }
+        assert_eq!(condition(&f).op, Operator::NotEq);
     }
 
     #[test]
@@ -597,24 +1052,82 @@ mod tests {
     #[test]
     fn parse_dollar_schema_key() {
         let f = parse("$schema=https://example.com/token.json").unwrap();
-        if let FilterExpr::Or(alts) = &f.expr {
-            assert_eq!(alts[0][0].key, "$schema");
-        } else {
-            panic!("expected Or");
-        }
+        assert_eq!(condition(&f).key, "$schema");
+        assert_eq!(condition(&f).values, ["https://example.com/token.json"]);
     }
 
     #[test]
     fn parse_whitespace_trimmed() {
         let f = parse("  component = button , state = hover  ").unwrap();
-        if let FilterExpr::Or(alts) = &f.expr {
-            assert_eq!(alts[0][0].key, "component");
-            assert_eq!(alts[0][0].value, "button");
-            assert_eq!(alts[0][1].key, "state");
-            assert_eq!(alts[0][1].value, "hover");
-        } else {
-            panic!("expected Or");
+        let FilterExpr::And(conjuncts) = &f.expr else {
+            panic!("expected And");
+        };
+        let [FilterExpr::Condition(a), FilterExpr::Condition(b)] = conjuncts.as_slice() else {
+            panic!("expected two conditions");
+        };
+        assert_eq!(
+            (a.key.as_str(), a.values[0].as_str()),
+            ("component", "button")
+        );
+        assert_eq!((b.key.as_str(), b.values[0].as_str()), ("state", "hover"));
+    }
+
+    #[test]
+    fn parse_grouping_in_exists_and_not() {
+        let f = parse(
+            "(component=button|component=action-button),state in (hover, down),!exists(deprecated)",
+        )
+        .unwrap();
+        let FilterExpr::And(conjuncts) = &f.expr else {
+            panic!("expected And");
+        };
+        assert!(matches!(&conjuncts[0], FilterExpr::Or(a) if a.len() == 2));
+        assert!(matches!(
+            &conjuncts[1],
+            FilterExpr::Condition(Condition { op: Operator::In, values, .. })
+                if values == &["hover", "down"]
+        ));
+        assert!(matches!(
+            &conjuncts[2],
+            FilterExpr::Not(inner) if matches!(inner.as_ref(), FilterExpr::Exists(k) if k == "deprecated")
+        ));
+    }
+
+    #[test]
+    fn parse_accepts_catalog_fields() {
+        assert_eq!(
+            condition(&parse("colorRole=accent").unwrap()).key,
+            "colorRole"
+        );
+        assert_eq!(
+            condition(&parse("object in (background, border)").unwrap()).op,
+            Operator::In
+        );
+        assert!(matches!(
+            parse("exists(scaleIndex)").unwrap().expr,
+            FilterExpr::Exists(_)
+        ));
+        assert!(parse("exists(nonsense)").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        for bad in [
+            "(component=button",
+            "component=button)",
+            "component in hover",
+            "component in (a,b",
+            "exists(component",
+            "component",
+            "!",
+            "component=a,",
+            "component=a(b)",
+        ] {
+            assert!(parse(bad).is_err(), "{bad:?} should not parse");
         }
+        // Deep nesting is an error, not a stack overflow.
+        let deep = format!("{}component=a{}", "(".repeat(500), ")".repeat(500));
+        assert!(parse(&deep).is_err());
     }
 
     // ── Glob matching ───────────────────────────────────────────────────
@@ -814,6 +1327,327 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    fn grammar_graph() -> TokenGraph {
+        make_graph(vec![
+            (
+                "btn-hover",
+                json!({"name": {"property": "bg", "component": "button", "state": ["hover"], "colorRole": "accent"}, "value": "1"}),
+            ),
+            (
+                "action-down",
+                json!({"name": {"property": "bg", "component": "action-button", "state": ["down"]}, "value": "2",
+                       "lifecycle": {"deprecatedIn": "13.0.0"}}),
+            ),
+            (
+                "btn-default",
+                json!({"name": {"property": "bg", "component": "button", "state": ["default"]}, "value": "3"}),
+            ),
+            (
+                "blue-100",
+                json!({"name": {"property": "color", "colorFamily": "blue", "scaleIndex": 100}, "value": "4"}),
+            ),
+        ])
+    }
+
+    fn names(graph: &TokenGraph, expr: &str) -> Vec<String> {
+        filter(graph, &parse(expr).unwrap())
+            .into_iter()
+            .map(|t| t.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn filter_grouping_membership_and_existence() {
+        let g = grammar_graph();
+        assert_eq!(
+            names(
+                &g,
+                "(component=button|component=action-button),state in (hover,down),!exists(deprecated)"
+            ),
+            ["btn-hover"]
+        );
+        assert_eq!(
+            names(&g, "state in (hover, down)"),
+            ["action-down", "btn-hover"]
+        );
+        assert_eq!(names(&g, "exists(deprecated)"), ["action-down"]);
+        assert_eq!(names(&g, "deprecated=13.*"), ["action-down"]);
+        assert_eq!(
+            names(&g, "!(component=button)"),
+            ["action-down", "blue-100"]
+        );
+    }
+
+    #[test]
+    fn filter_catalog_fields_including_numbers() {
+        let g = grammar_graph();
+        assert_eq!(names(&g, "colorRole=acc*"), ["btn-hover"]);
+        assert_eq!(names(&g, "scaleIndex=100"), ["blue-100"]);
+        assert_eq!(names(&g, "colorFamily in (red, blue)"), ["blue-100"]);
+        assert_eq!(
+            names(&g, "exists(colorFamily),!exists(state)"),
+            ["blue-100"]
+        );
+    }
+
+    // ── Value predicates ────────────────────────────────────────────────
+
+    fn record_names(results: Vec<&TokenRecord>) -> Vec<&str> {
+        results.into_iter().map(|t| t.name.as_str()).collect()
+    }
+
+    #[test]
+    fn parse_value_operators() {
+        for expr in [
+            "value=#fff",
+            "value>=24px",
+            "value > 1.5",
+            "value<0",
+            "value<=-2px",
+            "value~=#fafafa",
+            "value~=#fafafa/3",
+            "value~=24px/0.5",
+            "value in (#fff, #000)",
+            "exists(value)",
+        ] {
+            assert!(parse(expr).is_ok(), "expected {expr:?} to parse");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_value_operators_on_other_keys() {
+        let err = parse("scale>=2").unwrap_err().to_string();
+        assert!(err.contains("only applies to `value`"), "{err}");
+    }
+
+    #[test]
+    fn parse_rejects_uncomparable_value_literals() {
+        for expr in [
+            "value>=large",
+            "value<#fff",
+            "value~=bold",
+            "value~=#fff/x",
+            "value~=#fff/-1",
+        ] {
+            assert!(parse(expr).is_err(), "expected {expr:?} to be rejected");
+        }
+    }
+
+    #[test]
+    fn value_color_equality_ignores_notation() {
+        let g = make_graph(vec![
+            ("rgb", json!({"value": "rgb(255, 255, 255)"})),
+            ("hex", json!({"value": "#FFFFFF"})),
+            ("near", json!({"value": "#fdfdfd"})),
+            ("black", json!({"value": "#000000"})),
+        ]);
+        let f = parse("value=#fff").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["hex", "rgb"]);
+        let f = parse("value~=#fff/2").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["hex", "near", "rgb"]);
+        let f = parse("value!=#fff").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["black", "near"]);
+    }
+
+    #[test]
+    fn value_literals_accept_color_functions() {
+        let g = make_graph(vec![
+            ("rgb", json!({"value": "rgb(255, 255, 255)"})),
+            ("hex", json!({"value": "#FFFFFF"})),
+            ("half", json!({"value": "rgba(0, 0, 0, 0.5)"})),
+        ]);
+        let f = parse("value=rgb(255, 255, 255)").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["hex", "rgb"]);
+        let f = parse("value in (rgba(0, 0, 0, 0.5), #000)|value=hsl(0, 0%, 100%)").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["half", "hex", "rgb"]);
+        let f = parse("(value~=rgb(0 0 0 / 0.5)/1)").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["half"]);
+        for expr in ["value=rgb(0, 0, 0", "value=rgb((0))", "component=rgb(0)"] {
+            assert!(parse(expr).is_err(), "expected {expr:?} to be rejected");
+        }
+    }
+
+    #[test]
+    fn value_comparisons_require_matching_units() {
+        let g = make_graph(vec![
+            ("small", json!({"value": "16px"})),
+            ("medium", json!({"value": "24px"})),
+            ("large", json!({"value": "32px"})),
+            ("rem", json!({"value": "2rem"})),
+            ("ratio", json!({"value": "1.5"})),
+            ("number", json!({"value": 2})),
+            ("font", json!({"value": "Adobe Clean"})),
+        ]);
+        let f = parse("value>=24px").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["large", "medium"]);
+        let f = parse("value<24px").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["small"]);
+        let f = parse("value<2").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["ratio"]);
+        let f = parse("value=2").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["number"]);
+        let f = parse("value~=23px/1").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["medium"]);
+        let f = parse("value=Adobe*").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["font"]);
+    }
+
+    #[test]
+    fn value_follows_alias_chains() {
+        let g = make_graph(vec![
+            ("white", json!({"uuid": "u-white", "value": "#ffffff"})),
+            ("alias", json!({"uuid": "u-alias", "$ref": "u-white"})),
+            ("alias-of-alias", json!({"$ref": "u-alias"})),
+            ("dangling", json!({"$ref": "u-missing"})),
+        ]);
+        let f = parse("value=#fff").unwrap();
+        assert_eq!(
+            record_names(filter(&g, &f)),
+            vec!["alias", "alias-of-alias", "white"]
+        );
+        let f = parse("!exists(value)").unwrap();
+        assert_eq!(record_names(filter(&g, &f)), vec!["dangling"]);
+    }
+
+    #[test]
+    fn value_conjoins_with_indexed_conditions() {
+        let g = make_graph(vec![
+            (
+                "bg",
+                json!({"name": {"property": "bg"}, "value": "#ffffff"}),
+            ),
+            (
+                "fg",
+                json!({"name": {"property": "fg"}, "value": "#ffffff"}),
+            ),
+        ]);
+        assert_filter_equivalent(&g, "property=bg,value=#fff");
+        let f = parse("property=bg,value=#fff").unwrap();
+        let index = TokenIndex::build(&g);
+        assert_eq!(record_names(filter_with_index(&g, &index, &f)), vec!["bg"]);
+    }
+
+    fn color_scheme_graph() -> TokenGraph {
+        use crate::generate::tests::mode_set;
+        make_graph(vec![
+            (
+                "bg-light",
+                json!({"name": {"property": "bg", "colorScheme": "light"}, "value": "#ffffff"}),
+            ),
+            (
+                "bg-dark",
+                json!({"name": {"property": "bg", "colorScheme": "dark"}, "value": "#1d1d1d"}),
+            ),
+            (
+                "fg",
+                json!({"name": {"property": "fg"}, "value": "#ffffff"}),
+            ),
+            (
+                "border-light",
+                json!({
+                    "uuid": "u-border-light",
+                    "name": {"property": "border", "colorScheme": "light"},
+                    "value": "#000000"
+                }),
+            ),
+            (
+                "border-dark",
+                json!({
+                    "uuid": "u-border-dark",
+                    "name": {"property": "border", "colorScheme": "dark"},
+                    "value": "#ffffff"
+                }),
+            ),
+        ])
+        .with_mode_sets(vec![mode_set("colorScheme", &["light", "dark"], "light")])
+    }
+
+    #[test]
+    fn filter_in_context_considers_only_cascade_winners() {
+        let g = color_scheme_graph();
+        let dark = ResolutionContext::new().with("colorScheme", "dark");
+        let f = parse("").unwrap();
+        assert_eq!(
+            record_names(filter_in_context(&g, &f, &dark)),
+            vec!["bg-dark", "border-dark", "fg"]
+        );
+        // Unspecified mode sets resolve to their default mode.
+        assert_eq!(
+            record_names(filter_in_context(&g, &f, &ResolutionContext::new())),
+            vec!["bg-light", "border-light", "fg"]
+        );
+    }
+
+    #[test]
+    fn filter_in_context_matches_resolved_values() {
+        let g = color_scheme_graph();
+        let f = parse("value=#FFFFFF").unwrap();
+        let dark = ResolutionContext::new().with("colorScheme", "dark");
+        assert_eq!(
+            record_names(filter_in_context(&g, &f, &dark)),
+            vec!["border-dark", "fg"]
+        );
+        let light = ResolutionContext::new().with("colorScheme", "light");
+        assert_eq!(
+            record_names(filter_in_context(&g, &f, &light)),
+            vec!["bg-light", "fg"]
+        );
+    }
+
+    #[test]
+    fn filter_in_context_honours_restrictions() {
+        let g = color_scheme_graph();
+        let ctx =
+            ResolutionContext::new().with_restriction("colorScheme", vec!["dark".to_string()]);
+        let f = parse("property=bg").unwrap();
+        assert_eq!(
+            record_names(filter_in_context(&g, &f, &ctx)),
+            vec!["bg-dark"]
+        );
+    }
+
+    #[test]
+    fn resolved_value_follows_aliases_in_context() {
+        use crate::generate::tests::mode_set;
+        let g = make_graph(vec![
+            (
+                "gray-light",
+                json!({
+                    "uuid": "u-gray-light",
+                    "set_uuid": "s-gray",
+                    "name": {"property": "gray", "colorScheme": "light"},
+                    "value": "#eeeeee"
+                }),
+            ),
+            (
+                "gray-dark",
+                json!({
+                    "uuid": "u-gray-dark",
+                    "set_uuid": "s-gray",
+                    "name": {"property": "gray", "colorScheme": "dark"},
+                    "value": "#222222"
+                }),
+            ),
+            (
+                "surface",
+                json!({"name": {"property": "surface"}, "$ref": "s-gray"}),
+            ),
+        ])
+        .with_mode_sets(vec![mode_set("colorScheme", &["light", "dark"], "light")]);
+        let surface = &g.tokens["surface"];
+        let dark = ResolutionContext::new().with("colorScheme", "dark");
+        assert_eq!(resolved_value(&g, surface, &dark), Some(&json!("#222222")));
+        assert_eq!(
+            resolved_value(&g, surface, &ResolutionContext::new()),
+            Some(&json!("#eeeeee"))
+        );
+        let f = parse("property=surface,value=#222").unwrap();
+        assert_eq!(
+            record_names(filter_in_context(&g, &f, &dark)),
+            vec!["surface"]
+        );
+    }
+
     // ── filter_with_index equivalence (#783) ─────────────────────────────
 
     /// Assert `filter` and `filter_with_index` return the same names for `expr`.
@@ -879,6 +1713,50 @@ mod tests {
         assert_filter_equivalent(&g, "component=missing");
     }
 
+    #[test]
+    fn filter_with_index_matches_filter_extended_grammar() {
+        let g = grammar_graph();
+        for expr in [
+            "component in (button, action-button)",
+            "component=*button",
+            "(component=button|component=action-button),state in (hover,down),!exists(deprecated)",
+            "component=button,colorRole=accent",
+            "colorRole=accent|component=action-button",
+            "!(component=button)",
+            "exists(scaleIndex)|state=default",
+            "component in (missing)",
+        ] {
+            assert_filter_equivalent(&g, expr);
+        }
+    }
+
+    #[test]
+    fn index_candidates_narrow_indexed_conditions() {
+        let g = grammar_graph();
+        let index = TokenIndex::build(&g);
+        let candidates = |expr: &str| {
+            index_candidates(&index, &parse(expr).unwrap().expr).map(|keys| {
+                let mut keys: Vec<_> = keys.into_iter().collect();
+                keys.sort();
+                keys
+            })
+        };
+        assert_eq!(
+            candidates("component=button"),
+            Some(vec!["btn-default", "btn-hover"])
+        );
+        assert_eq!(candidates("component=action*"), Some(vec!["action-down"]));
+        // `,` keeps the smallest indexed conjunct; the rest is checked per candidate.
+        assert_eq!(
+            candidates("state=hover,!exists(deprecated)"),
+            Some(vec!["btn-hover"])
+        );
+        // Negation, existence, and catalog-only keys need a scan.
+        assert_eq!(candidates("component!=button"), None);
+        assert_eq!(candidates("exists(component)"), None);
+        assert_eq!(candidates("colorRole=accent|component=button"), None);
+    }
+
     // ── Index builder ───────────────────────────────────────────────────
 
     #[test]
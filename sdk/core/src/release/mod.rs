@@ -0,0 +1,856 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Release classification — map a semantic diff onto the semver bump required
+//! by the change classification in `spec/evolution.md`.
+//!
+//! [`classify`] consumes a [`DiffReport`] plus the component catalog changes
+//! from [`diff_components`] and returns the highest required [`Bump`] with one
+//! [`Justification`] per change. [`declared_bump`] reads the bump a changeset
+//! declares, so CI can fail a PR whose changeset is lower than required.
+//! [`notes`] turns the same diff into release notes.
+
+pub mod notes;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::diff::{ChangeType, DiffReport, PropertyChange};
+use crate::CoreError;
+
+// ── Public types ────────────────────────────────────────────────────────────
+
+/// A semantic-version bump level, ordered from "no release" to breaking.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bump {
+    #[default]
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Bump {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Bump::None => "none",
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        }
+    }
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Bump {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "none" => Ok(Bump::None),
+            "patch" => Ok(Bump::Patch),
+            "minor" => Ok(Bump::Minor),
+            "major" => Ok(Bump::Major),
+            other => Err(CoreError::ParseError(format!(
+                "unknown bump level {other:?} (expected major, minor, patch, or none)"
+            ))),
+        }
+    }
+}
+
+/// What a [`Justification`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Subject {
+    Token,
+    Component,
+}
+
+/// One change and the bump it requires.
+#[derive(Debug, Clone, Serialize)]
+pub struct Justification {
+    pub bump: Bump,
+    pub subject: Subject,
+    /// Token display name (see [`crate::diff::display_name`]) or component name.
+    pub name: String,
+    pub reason: String,
+}
+
+/// The required bump for a set of changes, with the changes that drive it.
+///
+/// `changes` is ordered most significant first; within a level it keeps the
+/// category order of the [`DiffReport`], followed by component changes.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Classification {
+    pub bump: Bump,
+    pub changes: Vec<Justification>,
+}
+
+impl Classification {
+    /// Whether a release declared at `declared` covers the required bump.
+    pub fn satisfied_by(&self, declared: Bump) -> bool {
+        declared >= self.bump
+    }
+
+    /// Number of changes requiring exactly `bump`.
+    pub fn count(&self, bump: Bump) -> usize {
+        self.changes.iter().filter(|c| c.bump == bump).count()
+    }
+
+    fn push(&mut self, bump: Bump, subject: Subject, name: String, reason: String) {
+        self.changes.push(Justification {
+            bump,
+            subject,
+            name,
+            reason,
+        });
+    }
+}
+
+/// The kind of a component catalog change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ComponentChangeKind {
+    Added,
+    Removed,
+    Deprecated,
+    Undeprecated,
+    OptionAdded,
+    OptionRemoved,
+    OptionTypeChanged,
+    OptionDefaultChanged,
+    ValueAdded,
+    ValueRemoved,
+    ValueDeprecated,
+    ConstraintTightened,
+    ConstraintLoosened,
+    SlotAdded,
+    RequiredSlotAdded,
+    SlotRemoved,
+    SlotMadeRequired,
+    SlotMadeOptional,
+    StateAdded,
+    StateRemoved,
+    /// Descriptive content changed (documentation blocks, descriptions, meta).
+    Documentation,
+}
+
+impl ComponentChangeKind {
+    /// The bump this kind of change requires.
+    pub fn bump(self) -> Bump {
+        use ComponentChangeKind::*;
+        match self {
+            Removed | OptionRemoved | OptionTypeChanged | ValueRemoved | ConstraintTightened
+            | RequiredSlotAdded | SlotRemoved | SlotMadeRequired | StateRemoved => Bump::Major,
+            Added | Deprecated | Undeprecated | OptionAdded | ValueAdded | ValueDeprecated
+            | ConstraintLoosened | SlotAdded | SlotMadeOptional | StateAdded => Bump::Minor,
+            OptionDefaultChanged | Documentation => Bump::Patch,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        use ComponentChangeKind::*;
+        match self {
+            Added => "component added",
+            Removed => "component removed; consumers that reference it break",
+            Deprecated => "component deprecated (still available)",
+            Undeprecated => "deprecation withdrawn",
+            OptionAdded => "option added",
+            OptionRemoved => "option removed",
+            OptionTypeChanged => "option type changed",
+            OptionDefaultChanged => "option default changed",
+            ValueAdded => "option value added",
+            ValueRemoved => "option value removed",
+            ValueDeprecated => "option value deprecated",
+            ConstraintTightened => "option constraint tightened",
+            ConstraintLoosened => "option constraint relaxed",
+            SlotAdded => "optional slot added",
+            RequiredSlotAdded => "required slot added",
+            SlotRemoved => "slot removed",
+            SlotMadeRequired => "slot made required",
+            SlotMadeOptional => "slot made optional",
+            StateAdded => "state added",
+            StateRemoved => "state removed",
+            Documentation => "descriptive content changed",
+        }
+    }
+}
+
+/// A single change between two component catalogs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentChange {
+    pub component: String,
+    pub kind: ComponentChangeKind,
+    /// The option, option value (`option=value`), constraint (`option.keyword`),
+    /// slot, state, or top-level field the change applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+// ── Classification ──────────────────────────────────────────────────────────
+
+/// Classify a token diff and component catalog changes into a required bump.
+pub fn classify(report: &DiffReport, components: &[ComponentChange]) -> Classification {
+    let mut out = Classification::default();
+
+    for t in &report.renamed {
+        out.push(
+            Bump::Major,
+            Subject::Token,
+            t.new_name.clone(),
+            format!(
+                "renamed from {}; references to the old name break",
+                t.old_name
+            ),
+        );
+    }
+    for t in &report.deprecated {
+        out.push(
+            Bump::Minor,
+            Subject::Token,
+            t.name.clone(),
+            "deprecated (still available)".to_string(),
+        );
+    }
+    for t in &report.reverted {
+        out.push(
+            Bump::Minor,
+            Subject::Token,
+            t.name.clone(),
+            "deprecation withdrawn".to_string(),
+        );
+    }
+    for t in &report.added {
+        out.push(
+            Bump::Minor,
+            Subject::Token,
+            t.name.clone(),
+            "token added".to_string(),
+        );
+    }
+    for t in &report.deleted {
+        out.push(
+            Bump::Major,
+            Subject::Token,
+            t.name.clone(),
+            "token removed; consumers that reference it break".to_string(),
+        );
+    }
+    for t in &report.updated {
+        for change in &t.property_changes {
+            let (bump, reason) = classify_property(change);
+            out.push(bump, Subject::Token, t.name.clone(), reason);
+        }
+    }
+
+    for c in components {
+        let reason = match &c.target {
+            Some(target) => format!("{}: {target}", c.kind.describe()),
+            None => c.kind.describe().to_string(),
+        };
+        out.push(
+            c.kind.bump(),
+            Subject::Component,
+            c.component.clone(),
+            reason,
+        );
+    }
+
+    // Stable sort keeps the category order within each level.
+    out.changes.sort_by(|a, b| b.bump.cmp(&a.bump));
+    out.bump = out.changes.first().map(|c| c.bump).unwrap_or_default();
+    out
+}
+
+/// Token fields that describe a token without affecting what consumers receive.
+const METADATA_FIELDS: &[&str] = &[
+    "description",
+    "deprecated_comment",
+    "renamed",
+    "uuid",
+    "set_uuid",
+];
+
+/// Classify one property-level change of an updated token.
+fn classify_property(change: &PropertyChange) -> (Bump, String) {
+    let path = change.path.as_str();
+    // Legacy set tokens nest per-mode fields under `sets.<mode>.`.
+    let (mode, field) = match path.strip_prefix("sets.").and_then(|r| r.split_once('.')) {
+        Some((mode, rest)) => (Some(mode), rest),
+        None => (None, path),
+    };
+    let root = field.split('.').next().unwrap_or(field);
+    let leaf = field.rsplit('.').next().unwrap_or(field);
+
+    if leaf == "deprecated" || field == "lifecycle.deprecatedIn" {
+        return match change.change_type {
+            ChangeType::Deleted => (Bump::Minor, format!("deprecation withdrawn (`{path}`)")),
+            _ => (Bump::Minor, format!("deprecated (`{path}`)")),
+        };
+    }
+    if root == "lifecycle" || METADATA_FIELDS.contains(&leaf) {
+        return (
+            Bump::Patch,
+            format!("metadata `{path}` {}", verb(&change.change_type)),
+        );
+    }
+    match (root, &change.change_type) {
+        ("$schema" | "set_schema", ChangeType::Updated) => {
+            return (
+                Bump::Major,
+                format!(
+                    "type changed: `{path}` {} → {}",
+                    short(&change.original_value),
+                    short(&change.new_value)
+                ),
+            );
+        }
+        ("private", _) => {
+            return if change.new_value.as_ref().and_then(Value::as_bool) == Some(true) {
+                (Bump::Major, "made private; no longer published".to_string())
+            } else {
+                (Bump::Minor, "made public".to_string())
+            };
+        }
+        ("value" | "$ref", ChangeType::Updated) => {
+            return (
+                Bump::Patch,
+                format!(
+                    "value changed: `{path}` {} → {}",
+                    short(&change.original_value),
+                    short(&change.new_value)
+                ),
+            );
+        }
+        ("value", ChangeType::Added) if mode.is_some() => {
+            return (
+                Bump::Minor,
+                format!("value added for mode `{}`", mode.unwrap_or_default()),
+            );
+        }
+        ("value", ChangeType::Deleted) if mode.is_some() => {
+            return (
+                Bump::Major,
+                format!("value removed for mode `{}`", mode.unwrap_or_default()),
+            );
+        }
+        _ => {}
+    }
+    match change.change_type {
+        ChangeType::Added => (Bump::Minor, format!("field `{path}` added")),
+        ChangeType::Deleted => (Bump::Major, format!("field `{path}` removed")),
+        ChangeType::Updated => (Bump::Patch, format!("field `{path}` changed")),
+    }
+}
+
+fn verb(change: &ChangeType) -> &'static str {
+    match change {
+        ChangeType::Added => "added",
+        ChangeType::Deleted => "removed",
+        ChangeType::Updated => "changed",
+    }
+}
+
+fn short(v: &Option<Value>) -> String {
+    match v {
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+// ── Component catalog changes ───────────────────────────────────────────────
+
+/// Top-level component fields compared structurally rather than as documentation.
+const STRUCTURAL_FIELDS: &[&str] = &["options", "slots", "states"];
+
+/// Lower-bound keywords: adding or raising one tightens the option.
+const LOWER_BOUNDS: &[&str] = &["minimum", "exclusiveMinimum", "minLength", "minItems"];
+/// Upper-bound keywords: adding or lowering one tightens the option.
+const UPPER_BOUNDS: &[&str] = &["maximum", "exclusiveMaximum", "maxLength", "maxItems"];
+/// Keywords whose changes cannot be ordered; any addition or change tightens.
+const OTHER_CONSTRAINTS: &[&str] = &["pattern", "multipleOf"];
+
+/// Compare two component catalogs (as loaded by [`crate::component::load_all`]).
+///
+/// Changes are ordered by component name, then by where they occur in the
+/// declaration.
+pub fn diff_components(
+    old: &BTreeMap<String, Value>,
+    new: &BTreeMap<String, Value>,
+) -> Vec<ComponentChange> {
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    let mut out = Vec::new();
+    for name in names {
+        let change = |kind, target: Option<String>| ComponentChange {
+            component: name.clone(),
+            kind,
+            target,
+        };
+        match (old.get(name), new.get(name)) {
+            (Some(_), None) => out.push(change(ComponentChangeKind::Removed, None)),
+            (None, Some(_)) => out.push(change(ComponentChangeKind::Added, None)),
+            (Some(o), Some(n)) => {
+                for (kind, target) in diff_component(o, n) {
+                    out.push(change(kind, target));
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    out
+}
+
+type Found = Vec<(ComponentChangeKind, Option<String>)>;
+
+fn diff_component(old: &Value, new: &Value) -> Found {
+    use ComponentChangeKind::*;
+    let mut out = Found::new();
+
+    let (was, is) = (is_deprecated(old), is_deprecated(new));
+    if !was && is {
+        out.push((Deprecated, None));
+    } else if was && !is {
+        out.push((Undeprecated, None));
+    }
+
+    diff_options(old.get("options"), new.get("options"), &mut out);
+    diff_named(
+        old.get("slots"),
+        new.get("slots"),
+        &mut out,
+        |name, o, n, out| match (o, n) {
+            (Some(_), None) => out.push((SlotRemoved, Some(name.to_string()))),
+            (None, Some(n)) if is_required(n) => {
+                out.push((RequiredSlotAdded, Some(name.to_string())))
+            }
+            (None, Some(_)) => out.push((SlotAdded, Some(name.to_string()))),
+            (Some(o), Some(n)) => match (is_required(o), is_required(n)) {
+                (false, true) => out.push((SlotMadeRequired, Some(name.to_string()))),
+                (true, false) => out.push((SlotMadeOptional, Some(name.to_string()))),
+                _ if o != n => out.push((Documentation, Some(format!("slots.{name}")))),
+                _ => {}
+            },
+            (None, None) => {}
+        },
+    );
+    diff_named(
+        old.get("states"),
+        new.get("states"),
+        &mut out,
+        |name, o, n, out| match (o, n) {
+            (Some(_), None) => out.push((StateRemoved, Some(name.to_string()))),
+            (None, Some(_)) => out.push((StateAdded, Some(name.to_string()))),
+            (Some(o), Some(n)) if o != n => {
+                out.push((Documentation, Some(format!("states.{name}"))))
+            }
+            _ => {}
+        },
+    );
+
+    // Everything else is descriptive; lifecycle only counts when the
+    // deprecation state itself did not change.
+    let empty = serde_json::Map::new();
+    let old_obj = old.as_object().unwrap_or(&empty);
+    let new_obj = new.as_object().unwrap_or(&empty);
+    let fields: BTreeSet<&String> = old_obj.keys().chain(new_obj.keys()).collect();
+    for field in fields {
+        if STRUCTURAL_FIELDS.contains(&field.as_str()) || (field == "lifecycle" && was != is) {
+            continue;
+        }
+        if old_obj.get(field) != new_obj.get(field) {
+            out.push((Documentation, Some(field.clone())));
+        }
+    }
+    out
+}
+
+fn diff_options(old: Option<&Value>, new: Option<&Value>, out: &mut Found) {
+    use ComponentChangeKind::*;
+    let empty = serde_json::Map::new();
+    let old = old.and_then(Value::as_object).unwrap_or(&empty);
+    let new = new.and_then(Value::as_object).unwrap_or(&empty);
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+
+    for name in names {
+        let (o, n) = match (old.get(name), new.get(name)) {
+            (Some(_), None) => {
+                out.push((OptionRemoved, Some(name.clone())));
+                continue;
+            }
+            (None, Some(_)) => {
+                out.push((OptionAdded, Some(name.clone())));
+                continue;
+            }
+            (Some(o), Some(n)) => (o, n),
+            (None, None) => continue,
+        };
+        let before = out.len();
+
+        if o.get("type") != n.get("type") || o.get("$ref") != n.get("$ref") {
+            out.push((OptionTypeChanged, Some(name.clone())));
+        }
+        if o.get("default") != n.get("default") {
+            out.push((OptionDefaultChanged, Some(name.clone())));
+        }
+
+        let (old_values, new_values) = (option_values(o), option_values(n));
+        for (value, _) in &old_values {
+            if !new_values.iter().any(|(v, _)| v == value) {
+                out.push((ValueRemoved, Some(format!("{name}={}", short_value(value)))));
+            }
+        }
+        for (value, deprecated) in &new_values {
+            match old_values.iter().find(|(v, _)| v == value) {
+                None => out.push((ValueAdded, Some(format!("{name}={}", short_value(value))))),
+                Some((_, was)) if *deprecated && !was => out.push((
+                    ValueDeprecated,
+                    Some(format!("{name}={}", short_value(value))),
+                )),
+                _ => {}
+            }
+        }
+
+        for keyword in LOWER_BOUNDS
+            .iter()
+            .chain(UPPER_BOUNDS)
+            .chain(OTHER_CONSTRAINTS)
+        {
+            if let Some(tightened) = constraint_tightened(keyword, o.get(*keyword), n.get(*keyword))
+            {
+                let kind = if tightened {
+                    ConstraintTightened
+                } else {
+                    ConstraintLoosened
+                };
+                out.push((kind, Some(format!("{name}.{keyword}"))));
+            }
+        }
+
+        if out.len() == before && o != n {
+            out.push((Documentation, Some(format!("options.{name}"))));
+        }
+    }
+}
+
+/// Whether a constraint keyword change tightens (`Some(true)`) or relaxes
+/// (`Some(false)`) the option; `None` when it did not change.
+fn constraint_tightened(keyword: &str, old: Option<&Value>, new: Option<&Value>) -> Option<bool> {
+    if old == new {
+        return None;
+    }
+    let (Some(o), Some(n)) = (old, new) else {
+        // Adding a constraint tightens; removing one relaxes.
+        return Some(new.is_some());
+    };
+    match (o.as_f64(), n.as_f64()) {
+        (Some(o), Some(n)) if LOWER_BOUNDS.contains(&keyword) => Some(n > o),
+        (Some(o), Some(n)) if UPPER_BOUNDS.contains(&keyword) => Some(n < o),
+        _ => Some(true),
+    }
+}
+
+/// Permitted values of an option: the structured `values` array, or a legacy
+/// `enum`, each paired with whether the value is deprecated.
+fn option_values(option: &Value) -> Vec<(Value, bool)> {
+    if let Some(values) = option.get("values").and_then(Value::as_array) {
+        return values
+            .iter()
+            .filter_map(|v| Some((v.get("value")?.clone(), is_deprecated(v))))
+            .collect();
+    }
+    option
+        .get("enum")
+        .and_then(Value::as_array)
+        .map(|values| values.iter().map(|v| (v.clone(), false)).collect())
+        .unwrap_or_default()
+}
+
+/// Pair the entries of two `[{ "name": … }]` arrays by name and hand each pair
+/// to `visit`.
+fn diff_named(
+    old: Option<&Value>,
+    new: Option<&Value>,
+    out: &mut Found,
+    visit: impl Fn(&str, Option<&Value>, Option<&Value>, &mut Found),
+) {
+    let by_name = |v: Option<&Value>| -> BTreeMap<String, Value> {
+        v.and_then(Value::as_array)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(|e| Some((e.get("name")?.as_str()?.to_string(), e.clone())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let (old, new) = (by_name(old), by_name(new));
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for name in names {
+        visit(name, old.get(name), new.get(name), out);
+    }
+}
+
+fn is_deprecated(v: &Value) -> bool {
+    v.get("lifecycle")
+        .and_then(|l| l.get("deprecatedIn"))
+        .is_some()
+}
+
+fn is_required(slot: &Value) -> bool {
+    slot.get("required").and_then(Value::as_bool) == Some(true)
+}
+
+fn short_value(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// ── Changesets ──────────────────────────────────────────────────────────────
+
+/// The highest bump a changeset file declares in its front matter.
+///
+/// Changesets list one `"package": level` line per package between `---`
+/// fences. With `package`, only that package's entries count. Returns `None`
+/// when the file declares nothing (for that package).
+pub fn declared_bump(changeset: &str, package: Option<&str>) -> Result<Option<Bump>, CoreError> {
+    let mut lines = changeset
+        .lines()
+        .map(str::trim)
+        .skip_while(|l| l.is_empty());
+    if lines.next() != Some("---") {
+        return Ok(None);
+    }
+    let mut declared = None;
+    for line in lines.take_while(|l| *l != "---") {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((name, level)) = line.rsplit_once(':') else {
+            return Err(CoreError::ParseError(format!(
+                "malformed changeset entry {line:?} (expected \"package\": level)"
+            )));
+        };
+        let name = name.trim().trim_matches(|c| c == '"' || c == '\'');
+        if package.is_some_and(|p| p != name) {
+            continue;
+        }
+        let bump: Bump = level
+            .trim()
+            .trim_matches(|c| c == '"' || c == '\'')
+            .parse()?;
+        declared = declared.max(Some(bump));
+    }
+    Ok(declared)
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::diff::semantic_diff;
+    use crate::graph::TokenGraph;
+
+    fn graph(tokens: Vec<(&str, Value)>) -> TokenGraph {
+        TokenGraph::from_pairs(
+            tokens
+                .into_iter()
+                .map(|(name, raw)| (name.to_string(), "tokens.json".into(), raw))
+                .collect(),
+        )
+    }
+
+    fn catalog(components: Vec<Value>) -> BTreeMap<String, Value> {
+        components
+            .into_iter()
+            .map(|c| (c["name"].as_str().unwrap().to_string(), c))
+            .collect()
+    }
+
+    #[test]
+    fn bump_orders_and_parses() {
+        assert!(Bump::Major > Bump::Minor && Bump::Minor > Bump::Patch);
+        assert!(Bump::Patch > Bump::None);
+        assert_eq!("minor".parse::<Bump>().unwrap(), Bump::Minor);
+        assert!("huge".parse::<Bump>().is_err());
+    }
+
+    #[test]
+    fn value_change_is_patch() {
+        let old = graph(vec![("gap", json!({"value": "4px", "uuid": "u1"}))]);
+        let new = graph(vec![("gap", json!({"value": "3px", "uuid": "u1"}))]);
+        let c = classify(&semantic_diff(&old, &new), &[]);
+        assert_eq!(c.bump, Bump::Patch);
+        assert_eq!(c.changes.len(), 1);
+        assert_eq!(c.changes[0].reason, "value changed: `value` 4px → 3px");
+    }
+
+    #[test]
+    fn added_and_deprecated_tokens_are_minor() {
+        let old = graph(vec![("gap", json!({"value": "4px", "uuid": "u1"}))]);
+        let new = graph(vec![
+            (
+                "gap",
+                json!({"value": "4px", "uuid": "u1", "deprecated": true}),
+            ),
+            ("gap-new", json!({"value": "4px", "uuid": "u2"})),
+        ]);
+        let c = classify(&semantic_diff(&old, &new), &[]);
+        assert_eq!(c.bump, Bump::Minor);
+        assert_eq!(c.count(Bump::Minor), 2);
+        assert!(c.changes.iter().any(|j| j.reason == "token added"));
+        assert!(c
+            .changes
+            .iter()
+            .any(|j| j.reason == "deprecated (`deprecated`)"));
+    }
+
+    #[test]
+    fn deleted_renamed_and_retyped_tokens_are_major() {
+        let old = graph(vec![
+            ("gone", json!({"value": "1px", "uuid": "u1"})),
+            ("old-name", json!({"value": "2px", "uuid": "u2"})),
+            (
+                "size",
+                json!({"$schema": "dimension.json", "value": "2px", "uuid": "u3"}),
+            ),
+        ]);
+        let new = graph(vec![
+            ("new-name", json!({"value": "2px", "uuid": "u2"})),
+            (
+                "size",
+                json!({"$schema": "color.json", "value": "#000", "uuid": "u3"}),
+            ),
+        ]);
+        let c = classify(&semantic_diff(&old, &new), &[]);
+        assert_eq!(c.bump, Bump::Major);
+        assert_eq!(c.count(Bump::Major), 3);
+        // Most significant first, category order within a level.
+        assert_eq!(c.changes[0].name, "new-name");
+        assert_eq!(c.changes[1].name, "gone");
+        assert!(c.changes[2].reason.starts_with("type changed"));
+        assert_eq!(c.changes[3].bump, Bump::Patch);
+        assert!(!c.satisfied_by(Bump::Minor));
+        assert!(c.satisfied_by(Bump::Major));
+    }
+
+    #[test]
+    fn mode_values_and_metadata() {
+        let old = graph(vec![(
+            "bg",
+            json!({"sets": {"light": {"value": "#fff", "uuid": "a"}, "dark": {"value": "#000", "uuid": "b"}}}),
+        )]);
+        let new = graph(vec![(
+            "bg",
+            json!({"sets": {"light": {"value": "#fff", "uuid": "a2"}}, "description": "Page"}),
+        )]);
+        let c = classify(&semantic_diff(&old, &new), &[]);
+        assert_eq!(c.bump, Bump::Major);
+        let reasons: Vec<&str> = c.changes.iter().map(|j| j.reason.as_str()).collect();
+        assert!(reasons.contains(&"value removed for mode `dark`"));
+        assert!(reasons.contains(&"metadata `description` added"));
+        assert!(reasons.contains(&"metadata `sets.light.uuid` changed"));
+    }
+
+    #[test]
+    fn no_changes_needs_no_release() {
+        let g = graph(vec![("gap", json!({"value": "4px"}))]);
+        let c = classify(&semantic_diff(&g, &g), &[]);
+        assert_eq!(c.bump, Bump::None);
+        assert!(c.satisfied_by(Bump::None));
+    }
+
+    #[test]
+    fn component_catalog_changes() {
+        let old = catalog(vec![
+            json!({"name": "badge", "options": {
+                "variant": {"type": "string", "values": [{"value": "neutral"}, {"value": "info"}]},
+                "size": {"type": "number", "minimum": 1, "default": 2}
+            }, "slots": [{"name": "label"}]}),
+            json!({"name": "legacy-chip"}),
+        ]);
+        let new = catalog(vec![
+            json!({"name": "badge", "options": {
+                "variant": {"type": "string", "values": [{"value": "neutral", "lifecycle": {"deprecatedIn": "2.0.0"}}, {"value": "accent"}]},
+                "size": {"type": "number", "minimum": 0, "default": 3},
+                "icon": {"type": "string"}
+            }, "slots": [{"name": "label", "required": true}], "description": "Status"}),
+            json!({"name": "tag"}),
+        ]);
+        let changes = diff_components(&old, &new);
+        let found: Vec<(&str, ComponentChangeKind, Option<&str>)> = changes
+            .iter()
+            .map(|c| (c.component.as_str(), c.kind, c.target.as_deref()))
+            .collect();
+        use ComponentChangeKind::*;
+        assert_eq!(
+            found,
+            vec![
+                ("badge", OptionAdded, Some("icon")),
+                ("badge", OptionDefaultChanged, Some("size")),
+                ("badge", ConstraintLoosened, Some("size.minimum")),
+                ("badge", ValueRemoved, Some("variant=info")),
+                ("badge", ValueDeprecated, Some("variant=neutral")),
+                ("badge", ValueAdded, Some("variant=accent")),
+                ("badge", SlotMadeRequired, Some("label")),
+                ("badge", Documentation, Some("description")),
+                ("legacy-chip", Removed, None),
+                ("tag", Added, None),
+            ]
+        );
+
+        let c = classify(&DiffReport::default(), &changes);
+        assert_eq!(c.bump, Bump::Major);
+        assert_eq!(c.changes[0].subject, Subject::Component);
+        assert_eq!(c.changes[0].reason, "option value removed: variant=info");
+    }
+
+    #[test]
+    fn component_deprecation_is_minor() {
+        let old = catalog(vec![
+            json!({"name": "chip", "lifecycle": {"introduced": "1.0.0"}}),
+        ]);
+        let new = catalog(vec![
+            json!({"name": "chip", "lifecycle": {"introduced": "1.0.0", "deprecatedIn": "2.0.0"}}),
+        ]);
+        let changes = diff_components(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ComponentChangeKind::Deprecated);
+        assert_eq!(classify(&DiffReport::default(), &changes).bump, Bump::Minor);
+    }
+
+    #[test]
+    fn declared_bump_reads_front_matter() {
+        let changeset = "---\n\"@adobe/spectrum-tokens\": minor\n\"@adobe/design-data-wasm\": patch\n---\n\nNotes.\n";
+        assert_eq!(declared_bump(changeset, None).unwrap(), Some(Bump::Minor));
+        assert_eq!(
+            declared_bump(changeset, Some("@adobe/design-data-wasm")).unwrap(),
+            Some(Bump::Patch)
+        );
+        assert_eq!(declared_bump(changeset, Some("other")).unwrap(), None);
+        assert_eq!(declared_bump("# Changesets\n", None).unwrap(), None);
+        assert!(declared_bump("---\npkg: huge\n---\n", None).is_err());
+    }
+}
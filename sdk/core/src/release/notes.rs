@@ -0,0 +1,595 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Release notes — a [`DiffReport`] regrouped for humans.
+//!
+//! [`release_notes`] groups the diff by component (`name.component`, or the
+//! legacy `component` field) and then by category, resolves deprecation
+//! successors to display names, and records when each deprecated token may be
+//! removed. The result serializes to JSON for the docs site;
+//! [`render_markdown`] produces the changelog text, optionally through a
+//! template.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::diff::{display_name, ChangeType, DiffReport, PropertyChange};
+use crate::graph::{TokenGraph, TokenRecord};
+
+/// Placeholders substituted by [`render_markdown`] templates.
+pub const PLACEHOLDERS: &[&str] = &["{{version}}", "{{summary}}", "{{notes}}"];
+
+// ── Public types ────────────────────────────────────────────────────────────
+
+/// Release notes for one dataset release.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseNotes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub summary: NotesSummary,
+    /// Tokens without a component first, then components alphabetically.
+    pub groups: Vec<NotesGroup>,
+}
+
+/// Number of changed tokens per category (as listed in the notes).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct NotesSummary {
+    pub renamed: usize,
+    pub deprecated: usize,
+    pub reverted: usize,
+    pub added: usize,
+    pub deleted: usize,
+    pub updated: usize,
+}
+
+impl NotesSummary {
+    pub fn total(&self) -> usize {
+        self.renamed + self.deprecated + self.reverted + self.added + self.deleted + self.updated
+    }
+}
+
+/// The changes to one component's tokens (or to tokens without a component).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotesGroup {
+    pub component: Option<String>,
+    /// Non-empty categories in [`Category`] order.
+    pub sections: Vec<NotesSection>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotesSection {
+    pub category: Category,
+    pub entries: Vec<NoteEntry>,
+}
+
+/// Diff category, in the partitioning order of `spec/diff.md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Renamed,
+    Deprecated,
+    Reverted,
+    Added,
+    Deleted,
+    Updated,
+}
+
+impl Category {
+    pub fn title(self) -> &'static str {
+        match self {
+            Category::Renamed => "Renamed",
+            Category::Deprecated => "Deprecated",
+            Category::Reverted => "Deprecation reverted",
+            Category::Added => "Added",
+            Category::Deleted => "Removed",
+            Category::Updated => "Updated",
+        }
+    }
+}
+
+/// One changed token.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteEntry {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// Previous display name of a renamed token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_name: Option<String>,
+    /// Display names of the successor tokens of a deprecated token.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub replaced_by: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_in: Option<String>,
+    /// `lifecycle.plannedRemoval`, when the token declares one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planned_removal: Option<String>,
+    /// First version the migration window of `spec/evolution.md` allows the
+    /// token to be removed in, when no removal is planned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_removal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<PropertyChange>,
+}
+
+// ── Builder ─────────────────────────────────────────────────────────────────
+
+/// Build release notes for `report`, the diff from `old` to `new`.
+///
+/// The graphs supply what the report leaves out: each token's component,
+/// its deprecation metadata, and the display names of `replacedBy` targets.
+pub fn release_notes(
+    report: &DiffReport,
+    old: &TokenGraph,
+    new: &TokenGraph,
+    version: Option<&str>,
+) -> ReleaseNotes {
+    let old = Lookup::new(old);
+    let new = Lookup::new(new);
+    let mut groups: BTreeMap<Option<String>, BTreeMap<Category, Vec<NoteEntry>>> = BTreeMap::new();
+    let mut add = |category, record: Option<&TokenRecord>, entry: NoteEntry| {
+        groups
+            .entry(record.and_then(component_of))
+            .or_default()
+            .entry(category)
+            .or_default()
+            .push(entry);
+    };
+
+    for t in &report.renamed {
+        let rec = new.find(&t.new_name, t.uuid.as_deref());
+        let mut entry = describe(&t.new_name, t.uuid.as_ref(), rec, &old, &new);
+        entry.old_name = Some(t.old_name.clone());
+        entry.changes = t.property_changes.clone();
+        add(Category::Renamed, rec, entry);
+    }
+    for t in &report.deprecated {
+        let rec = new.find(&t.name, t.uuid.as_deref());
+        add(
+            Category::Deprecated,
+            rec,
+            describe(&t.name, t.uuid.as_ref(), rec, &old, &new),
+        );
+    }
+    for t in &report.reverted {
+        let rec = new.find(&t.name, t.uuid.as_deref());
+        add(
+            Category::Reverted,
+            rec,
+            describe(&t.name, t.uuid.as_ref(), rec, &old, &new),
+        );
+    }
+    for t in &report.added {
+        let rec = new.find(&t.name, t.uuid.as_deref());
+        add(
+            Category::Added,
+            rec,
+            describe(&t.name, t.uuid.as_ref(), rec, &old, &new),
+        );
+    }
+    for t in &report.deleted {
+        let rec = old.find(&t.name, t.uuid.as_deref());
+        add(
+            Category::Deleted,
+            rec,
+            describe(&t.name, t.uuid.as_ref(), rec, &old, &new),
+        );
+    }
+    for t in &report.updated {
+        let rec = new.find(&t.name, t.uuid.as_deref());
+        let mut entry = describe(&t.name, t.uuid.as_ref(), rec, &old, &new);
+        entry.changes = t.property_changes.clone();
+        // Readers look for newly deprecated tokens under "Deprecated", however
+        // the diff paired them.
+        let category = if gained_deprecation(&t.property_changes) {
+            Category::Deprecated
+        } else {
+            Category::Updated
+        };
+        add(category, rec, entry);
+    }
+
+    let mut summary = NotesSummary::default();
+    for sections in groups.values() {
+        for (category, entries) in sections {
+            let count = match category {
+                Category::Renamed => &mut summary.renamed,
+                Category::Deprecated => &mut summary.deprecated,
+                Category::Reverted => &mut summary.reverted,
+                Category::Added => &mut summary.added,
+                Category::Deleted => &mut summary.deleted,
+                Category::Updated => &mut summary.updated,
+            };
+            *count += entries.len();
+        }
+    }
+
+    ReleaseNotes {
+        version: version.map(str::to_string),
+        summary,
+        groups: groups
+            .into_iter()
+            .map(|(component, sections)| NotesGroup {
+                component,
+                sections: sections
+                    .into_iter()
+                    .map(|(category, entries)| NotesSection { category, entries })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+/// Token lookup by UUID (via the graph's alias indexes) or display name.
+struct Lookup<'a> {
+    graph: &'a TokenGraph,
+    by_name: HashMap<String, &'a TokenRecord>,
+}
+
+impl<'a> Lookup<'a> {
+    fn new(graph: &'a TokenGraph) -> Self {
+        let by_name = graph
+            .tokens
+            .values()
+            .map(|t| (display_name(t), t))
+            .collect();
+        Self { graph, by_name }
+    }
+
+    fn find(&self, name: &str, uuid: Option<&str>) -> Option<&'a TokenRecord> {
+        uuid.and_then(|u| self.graph.resolve_alias_key(u))
+            .or_else(|| self.by_name.get(name).copied())
+    }
+}
+
+/// A note entry with the deprecation metadata of `record`.
+fn describe(
+    name: &str,
+    uuid: Option<&String>,
+    record: Option<&TokenRecord>,
+    old: &Lookup,
+    new: &Lookup,
+) -> NoteEntry {
+    let mut entry = NoteEntry {
+        name: name.to_string(),
+        uuid: uuid.cloned(),
+        ..NoteEntry::default()
+    };
+    let Some(raw) = record.map(|r| &r.raw) else {
+        return entry;
+    };
+    let lifecycle = raw.get("lifecycle");
+    let text = |v: Option<&Value>| v.and_then(Value::as_str).map(str::to_string);
+
+    entry.deprecated_in = text(lifecycle.and_then(|l| l.get("deprecatedIn")));
+    entry.planned_removal = text(lifecycle.and_then(|l| l.get("plannedRemoval")));
+    entry.comment = text(lifecycle.and_then(|l| l.get("deprecatedComment")))
+        .or_else(|| text(raw.get("deprecated_comment")));
+    if entry.planned_removal.is_none() {
+        entry.earliest_removal = entry.deprecated_in.as_deref().and_then(earliest_removal);
+    }
+
+    // Cascade `replacedBy` holds UUIDs; legacy `renamed` holds a token key.
+    let targets: Vec<&str> = match lifecycle.and_then(|l| l.get("replacedBy")) {
+        Some(Value::String(s)) => vec![s.as_str()],
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+        _ => raw
+            .get("renamed")
+            .and_then(Value::as_str)
+            .into_iter()
+            .collect(),
+    };
+    entry.replaced_by = targets
+        .into_iter()
+        .map(|target| {
+            new.graph
+                .resolve_alias_key(target)
+                .or_else(|| old.graph.resolve_alias_key(target))
+                .map(display_name)
+                .unwrap_or_else(|| target.to_string())
+        })
+        .collect();
+    entry
+}
+
+/// Whether a matched token picked up a `deprecated` flag or `lifecycle.deprecatedIn`.
+fn gained_deprecation(changes: &[PropertyChange]) -> bool {
+    changes.iter().any(|c| {
+        c.change_type == ChangeType::Added
+            && (c.path == "deprecated" || c.path == "lifecycle.deprecatedIn")
+    })
+}
+
+/// The component a token belongs to: `name.component`, else legacy `component`.
+fn component_of(record: &TokenRecord) -> Option<String> {
+    record
+        .raw
+        .get("name")
+        .and_then(|n| n.get("component"))
+        .or_else(|| record.raw.get("component"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Two minor versions after `deprecated_in` (see "Migration windows" in
+/// `spec/evolution.md`); `None` when the version does not parse.
+fn earliest_removal(deprecated_in: &str) -> Option<String> {
+    let v = semver::Version::parse(deprecated_in).ok()?;
+    Some(semver::Version::new(v.major, v.minor + 2, 0).to_string())
+}
+
+// ── Markdown ────────────────────────────────────────────────────────────────
+
+/// Render release notes as Markdown.
+///
+/// Without a template the output is a `## <version>` heading (when a version
+/// is set), the summary line, and the grouped notes. A template is any text
+/// containing the [`PLACEHOLDERS`]: `{{version}}`, `{{summary}}`, and
+/// `{{notes}}`; other text passes through unchanged.
+pub fn render_markdown(notes: &ReleaseNotes, template: Option<&str>) -> String {
+    let version = notes.version.clone().unwrap_or_default();
+    let summary = summary_line(&notes.summary);
+    let body = notes_markdown(notes);
+    match template {
+        Some(t) => t
+            .replace("{{version}}", &version)
+            .replace("{{summary}}", &summary)
+            .replace("{{notes}}", &body),
+        None => {
+            let mut out = String::new();
+            if !version.is_empty() {
+                out.push_str(&format!("## {version}\n\n"));
+            }
+            out.push_str(&summary);
+            out.push('\n');
+            if !body.is_empty() {
+                out.push('\n');
+                out.push_str(&body);
+            }
+            out
+        }
+    }
+}
+
+fn summary_line(s: &NotesSummary) -> String {
+    if s.total() == 0 {
+        return "No token changes.".to_string();
+    }
+    let parts: Vec<String> = [
+        (s.renamed, "renamed"),
+        (s.deprecated, "deprecated"),
+        (s.reverted, "deprecation reverted"),
+        (s.added, "added"),
+        (s.deleted, "removed"),
+        (s.updated, "updated"),
+    ]
+    .into_iter()
+    .filter(|(n, _)| *n > 0)
+    .map(|(n, label)| format!("{n} {label}"))
+    .collect();
+    format!("{} token(s) changed: {}.", s.total(), parts.join(", "))
+}
+
+fn notes_markdown(notes: &ReleaseNotes) -> String {
+    let mut out = String::new();
+    for group in &notes.groups {
+        match &group.component {
+            Some(c) => out.push_str(&format!("### {c}\n\n")),
+            None => out.push_str("### Global tokens\n\n"),
+        }
+        for section in &group.sections {
+            out.push_str(&format!("#### {}\n\n", section.category.title()));
+            for entry in &section.entries {
+                out.push_str(&entry_line(entry));
+                out.push('\n');
+                if matches!(section.category, Category::Renamed | Category::Updated) {
+                    for change in &entry.changes {
+                        out.push_str(&format!("  - {}\n", change_text(change)));
+                    }
+                }
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn entry_line(entry: &NoteEntry) -> String {
+    let mut line = match &entry.old_name {
+        Some(old) => format!("- `{old}` → `{}`", entry.name),
+        None => format!("- `{}`", entry.name),
+    };
+    let mut notes = Vec::new();
+    if !entry.replaced_by.is_empty() {
+        let targets: Vec<String> = entry.replaced_by.iter().map(|t| format!("`{t}`")).collect();
+        notes.push(format!("use {}", targets.join(" or ")));
+    }
+    if let Some(v) = &entry.deprecated_in {
+        notes.push(format!("deprecated in {v}"));
+    }
+    if let Some(v) = &entry.planned_removal {
+        notes.push(format!("removal planned in {v}"));
+    } else if let Some(v) = &entry.earliest_removal {
+        notes.push(format!("may be removed from {v}"));
+    }
+    if !notes.is_empty() {
+        line.push_str(&format!(" — {}", notes.join("; ")));
+    }
+    if let Some(comment) = &entry.comment {
+        line.push_str(&format!(". {}", comment.trim()));
+    }
+    line
+}
+
+fn change_text(change: &PropertyChange) -> String {
+    let value = |v: &Option<Value>| match v {
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => "-".to_string(),
+    };
+    match change.change_type {
+        ChangeType::Added => format!("`{}` added: {}", change.path, value(&change.new_value)),
+        ChangeType::Deleted => format!(
+            "`{}` removed (was {})",
+            change.path,
+            value(&change.original_value)
+        ),
+        ChangeType::Updated => format!(
+            "`{}`: {} → {}",
+            change.path,
+            value(&change.original_value),
+            value(&change.new_value)
+        ),
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::diff::semantic_diff;
+
+    const OLD: &str = "00000000-0000-4000-8000-000000000001";
+    const NEW: &str = "00000000-0000-4000-8000-000000000002";
+    const GLOBAL: &str = "00000000-0000-4000-8000-000000000003";
+
+    fn graph(tokens: Vec<Value>) -> TokenGraph {
+        TokenGraph::from_pairs(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, raw)| (format!("t{i}"), "tokens.json".into(), raw))
+                .collect(),
+        )
+    }
+
+    fn fixture() -> (TokenGraph, TokenGraph) {
+        let old = graph(vec![
+            json!({"name": {"property": "background-color", "component": "button"}, "value": "#fff", "uuid": OLD}),
+            json!({"name": {"property": "gap"}, "value": "4px", "uuid": GLOBAL}),
+        ]);
+        let new = graph(vec![
+            json!({"name": {"property": "background-color", "component": "button"}, "value": "#fff", "uuid": OLD,
+                   "lifecycle": {"deprecatedIn": "3.2.0", "replacedBy": NEW, "deprecatedComment": "Use the accent color."}}),
+            json!({"name": {"property": "accent-color", "component": "button"}, "value": "#00f", "uuid": NEW}),
+            json!({"name": {"property": "gap"}, "value": "3px", "uuid": GLOBAL}),
+        ]);
+        (old, new)
+    }
+
+    #[test]
+    fn groups_by_component_then_category() {
+        let (old, new) = fixture();
+        let notes = release_notes(&semantic_diff(&old, &new), &old, &new, Some("3.2.0"));
+        assert_eq!(notes.summary.total(), 3);
+        assert_eq!(notes.summary.deprecated, 1);
+
+        let components: Vec<Option<&str>> = notes
+            .groups
+            .iter()
+            .map(|g| g.component.as_deref())
+            .collect();
+        assert_eq!(components, vec![None, Some("button")]);
+        let button: Vec<Category> = notes.groups[1]
+            .sections
+            .iter()
+            .map(|s| s.category)
+            .collect();
+        assert_eq!(button, vec![Category::Deprecated, Category::Added]);
+    }
+
+    #[test]
+    fn resolves_successors_and_removal_window() {
+        let (old, new) = fixture();
+        let notes = release_notes(&semantic_diff(&old, &new), &old, &new, None);
+        let deprecated = &notes.groups[1].sections[0].entries[0];
+        assert_eq!(deprecated.name, "background-color[component=button]");
+        assert_eq!(
+            deprecated.replaced_by,
+            vec!["accent-color[component=button]"]
+        );
+        assert_eq!(deprecated.deprecated_in.as_deref(), Some("3.2.0"));
+        assert_eq!(deprecated.planned_removal, None);
+        assert_eq!(deprecated.earliest_removal.as_deref(), Some("3.4.0"));
+    }
+
+    #[test]
+    fn planned_removal_wins_over_window() {
+        let old = graph(vec![
+            json!({"name": {"property": "x"}, "value": "1px", "uuid": OLD}),
+        ]);
+        let new = graph(vec![
+            json!({"name": {"property": "x"}, "value": "1px", "uuid": OLD,
+            "lifecycle": {"deprecatedIn": "3.2.0", "plannedRemoval": "5.0.0"}}),
+        ]);
+        let notes = release_notes(&semantic_diff(&old, &new), &old, &new, None);
+        let entry = &notes.groups[0].sections[0].entries[0];
+        assert_eq!(entry.planned_removal.as_deref(), Some("5.0.0"));
+        assert_eq!(entry.earliest_removal, None);
+    }
+
+    #[test]
+    fn legacy_renamed_resolves_to_display_name() {
+        let old = graph(vec![]);
+        let new = TokenGraph::from_pairs(vec![
+            (
+                "old-gap".into(),
+                "layout.json".into(),
+                json!({"value": "4px", "deprecated": true, "renamed": "new-gap", "component": "card"}),
+            ),
+            (
+                "new-gap".into(),
+                "layout.json".into(),
+                json!({"value": "4px"}),
+            ),
+        ]);
+        let notes = release_notes(&semantic_diff(&old, &new), &old, &new, None);
+        let card = notes
+            .groups
+            .iter()
+            .find(|g| g.component.as_deref() == Some("card"))
+            .expect("card group");
+        assert_eq!(card.sections[0].category, Category::Deprecated);
+        assert_eq!(card.sections[0].entries[0].replaced_by, vec!["new-gap"]);
+    }
+
+    #[test]
+    fn markdown_default_and_template() {
+        let (old, new) = fixture();
+        let notes = release_notes(&semantic_diff(&old, &new), &old, &new, Some("3.2.0"));
+        let md = render_markdown(&notes, None);
+        assert!(
+            md.starts_with("## 3.2.0\n\n3 token(s) changed: 1 deprecated, 1 added, 1 updated.\n")
+        );
+        assert!(
+            md.contains("### Global tokens\n\n#### Updated\n\n- `gap`\n  - `value`: 4px → 3px\n")
+        );
+        assert!(md.contains(
+            "- `background-color[component=button]` — use `accent-color[component=button]`; \
+             deprecated in 3.2.0; may be removed from 3.4.0. Use the accent color.\n"
+        ));
+
+        let templated = render_markdown(&notes, Some("# Tokens {{version}}\n{{summary}}\n"));
+        assert_eq!(
+            templated,
+            "# Tokens 3.2.0\n3 token(s) changed: 1 deprecated, 1 added, 1 updated.\n"
+        );
+    }
+}
@@ -38,6 +38,48 @@ pub struct Diagnostic {
     pub instance_path: Option<String>,
     /// JSON Schema keyword path when from structural validation.
     pub schema_path: Option<String>,
+    /// Machine-applicable fix, when the rule knows one (`validate --fix`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Fix>,
+    /// Byte range in `file` the diagnostic points at (the offending token or
+    /// member), for code frames.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+/// A byte range in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, serde::Deserialize)]
+pub struct Span {
+    pub offset: usize,
+    pub len: usize,
+}
+
+/// A mechanical fix for one diagnostic: JSON edits against the diagnostic's
+/// `file` (or, per edit, another file), applied together or not at all.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Fix {
+    /// Short imperative summary (e.g. "regenerate uuid").
+    pub description: String,
+    pub edits: Vec<JsonEdit>,
+    /// The fix renames a published token key, which breaks consumers outside
+    /// the dataset. Only applied when asked for
+    /// (`validate --fix --fix-renames`).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub renames: bool,
+}
+
+/// Set (or, with `value: None`, remove) the member at an RFC 6901 JSON pointer.
+/// With `rename`, the member keeps its value and position under a new key.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonEdit {
+    pub pointer: String,
+    pub value: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename: Option<String>,
+    /// File to edit when it is not the diagnostic's own (e.g. a reference
+    /// rewritten by a rename).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
 }
 
 /// Combined validation result.
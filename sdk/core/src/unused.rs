@@ -0,0 +1,340 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Unused and orphaned token detection.
+//!
+//! A token is used when it is reachable from a root. The roots are:
+//!
+//! * tokens a component's `tokenBindings[]` names, by legacy key;
+//! * tokens a CTR relationship `$ref` names (a set-level UUID roots every
+//!   member);
+//! * tokens matching one of the platform manifest's `include` queries;
+//! * semantic tokens: no `name.component` and not `private`;
+//! * tokens on the allowlist.
+//!
+//! From the roots, [`analyze`] follows the edges of
+//! [`TokenGraph::reverse_dependencies`] forward — alias and composite targets,
+//! and the lower-layer token an override shadows. Everything else is
+//! reported. An unreachable component token whose component the catalog no
+//! longer declares is flagged as orphaned.
+//!
+//! The allowlist is a JSON file shaped like `naming-exceptions.json`; see
+//! [`UnusedExceptionsFile`].
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::graph::{Layer, TokenGraph, TokenRecord};
+use crate::impact::pinned_modes;
+use crate::naming::extract_legacy_key;
+use crate::query::{self, TokenFilter};
+
+/// An entry in the unused-token allowlist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnusedException {
+    /// Legacy key, UUID or graph key.
+    pub token: String,
+    pub reason: String,
+}
+
+/// Top-level shape of the unused-token allowlist file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnusedExceptionsFile {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub exceptions: Vec<UnusedException>,
+}
+
+impl UnusedExceptionsFile {
+    pub fn load(path: &Path) -> Result<Self, crate::CoreError> {
+        let text = std::fs::read_to_string(path)?;
+        let file: Self = serde_json::from_str(&text)?;
+        Ok(file)
+    }
+
+    pub fn token_set(&self) -> HashSet<String> {
+        self.exceptions.iter().map(|e| e.token.clone()).collect()
+    }
+}
+
+/// A token no root reaches.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnusedToken {
+    /// Legacy key, else graph key.
+    pub key: String,
+    /// Mode-set fields pinned by the token's name object.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub modes: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    pub file: PathBuf,
+    pub layer: Layer,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    /// `component` is set and the component catalog does not declare it.
+    pub orphaned: bool,
+}
+
+/// Result of [`analyze`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnusedReport {
+    /// Tokens in the graph.
+    pub total: usize,
+    /// Tokens that are roots themselves.
+    pub roots: usize,
+    /// Tokens rooted only by the allowlist.
+    pub allowlisted: usize,
+    /// Unreachable tokens, by file then position.
+    pub unused: Vec<UnusedToken>,
+    /// Allowlist entries that match no token.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stale_exceptions: Vec<String>,
+}
+
+impl UnusedReport {
+    /// The unused tokens whose component no longer exists.
+    pub fn orphaned(&self) -> impl Iterator<Item = &UnusedToken> {
+        self.unused.iter().filter(|t| t.orphaned)
+    }
+}
+
+/// Find the tokens no root reaches. `includes` are the platform manifest's
+/// `include` queries; `allowlist` holds legacy keys, UUIDs or graph keys.
+///
+/// Orphan detection needs the component catalog: when `graph.components` is
+/// empty, no token is flagged as orphaned.
+pub fn analyze(
+    graph: &TokenGraph,
+    includes: &[TokenFilter],
+    allowlist: &HashSet<String>,
+) -> UnusedReport {
+    let mut roots: HashSet<&str> = HashSet::new();
+
+    let mut by_legacy_key: HashMap<String, Vec<&str>> = HashMap::new();
+    for rec in graph.tokens.values() {
+        if let Some(key) = legacy_key(rec) {
+            by_legacy_key.entry(key).or_default().push(&rec.name);
+        }
+        if component(rec).is_none() && !is_private(rec) {
+            roots.insert(&rec.name);
+        }
+    }
+    for comp in &graph.components {
+        let bindings = comp.raw.get("tokenBindings").and_then(Value::as_array);
+        for binding in bindings.into_iter().flatten() {
+            let token = binding.get("token").and_then(Value::as_str);
+            if let Some(keys) = token.and_then(|t| by_legacy_key.get(t)) {
+                roots.extend(keys.iter().copied());
+            }
+        }
+    }
+    for rel in &graph.relationships {
+        let Some(target) = rel.raw.get("$ref").and_then(Value::as_str) else {
+            continue;
+        };
+        match graph.set_members(target) {
+            Some(members) => roots.extend(members.iter().map(String::as_str)),
+            None => roots.extend(graph.resolve_alias_key(target).map(|t| t.name.as_str())),
+        }
+    }
+    for filter in includes {
+        roots.extend(
+            query::filter(graph, filter)
+                .into_iter()
+                .map(|rec| rec.name.as_str()),
+        );
+    }
+
+    let mut matched_exceptions: HashSet<&str> = HashSet::new();
+    let mut allowlisted = 0;
+    for rec in graph.tokens.values() {
+        let hits: Vec<&str> = [legacy_key(rec), rec.uuid.clone(), Some(rec.name.clone())]
+            .into_iter()
+            .flatten()
+            .filter_map(|id| allowlist.get(&id).map(String::as_str))
+            .collect();
+        if hits.is_empty() {
+            continue;
+        }
+        matched_exceptions.extend(hits);
+        if roots.insert(&rec.name) {
+            allowlisted += 1;
+        }
+    }
+    let root_count = roots.len() - allowlisted;
+
+    // Forward edges: dependent → what it depends on.
+    let mut uses: HashMap<String, Vec<String>> = HashMap::new();
+    for (target, dependents) in graph.reverse_dependencies() {
+        for dep in dependents {
+            uses.entry(dep.key).or_default().push(target.clone());
+        }
+    }
+    let mut reached: HashSet<&str> = HashSet::new();
+    let mut stack: Vec<&str> = roots.into_iter().collect();
+    while let Some(key) = stack.pop() {
+        if !reached.insert(key) {
+            continue;
+        }
+        for next in uses.get(key).into_iter().flatten() {
+            if !reached.contains(next.as_str()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    let declared: HashSet<&str> = graph.components.iter().map(|c| c.name.as_str()).collect();
+    let mut unreached: Vec<&TokenRecord> = graph
+        .tokens
+        .values()
+        .filter(|rec| !reached.contains(rec.name.as_str()))
+        .collect();
+    unreached.sort_by(|a, b| (&a.file, a.index, &a.name).cmp(&(&b.file, b.index, &b.name)));
+    let unused = unreached
+        .into_iter()
+        .map(|rec| {
+            let component = component(rec).map(str::to_string);
+            UnusedToken {
+                key: legacy_key(rec).unwrap_or_else(|| rec.name.clone()),
+                modes: pinned_modes(graph, rec),
+                uuid: rec.uuid.clone(),
+                file: rec.file.clone(),
+                layer: rec.layer,
+                orphaned: !declared.is_empty()
+                    && component.as_deref().is_some_and(|c| !declared.contains(c)),
+                component,
+            }
+        })
+        .collect();
+
+    let mut stale_exceptions: Vec<String> = allowlist
+        .iter()
+        .filter(|e| !matched_exceptions.contains(e.as_str()))
+        .cloned()
+        .collect();
+    stale_exceptions.sort();
+
+    UnusedReport {
+        total: graph.tokens.len(),
+        roots: root_count,
+        allowlisted,
+        unused,
+        stale_exceptions,
+    }
+}
+
+fn legacy_key(rec: &TokenRecord) -> Option<String> {
+    rec.raw.get("name").and_then(extract_legacy_key)
+}
+
+fn component(rec: &TokenRecord) -> Option<&str> {
+    rec.raw
+        .get("name")
+        .and_then(|n| n.get("component"))
+        .and_then(Value::as_str)
+}
+
+fn is_private(rec: &TokenRecord) -> bool {
+    rec.raw.get("private").and_then(Value::as_bool) == Some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ComponentRecord, RelationshipRecord};
+    use serde_json::json;
+
+    fn graph() -> TokenGraph {
+        let tokens = vec![
+            json!({"name": {"property": "blue-900"}, "uuid": "bl", "value": "#00f", "private": true}),
+            json!({"name": {"property": "gray-100"}, "uuid": "gr", "value": "#eee", "private": true}),
+            json!({"name": {"property": "red-900"}, "uuid": "rd", "value": "#f00", "private": true}),
+            json!({"name": {"property": "green-900"}, "uuid": "gn", "value": "#0f0", "private": true}),
+            json!({"name": {"property": "accent-color"}, "uuid": "ac", "$ref": "bl"}),
+            json!({"name": {"property": "background-color", "component": "button"},
+                   "uuid": "bb", "value": "#fff"}),
+            json!({"name": {"property": "border-color", "component": "link"},
+                   "uuid": "lb", "$ref": "gr"}),
+            json!({"name": {"property": "border-color", "component": "badge"},
+                   "uuid": "gb", "$ref": "rd"}),
+            json!({"name": {"property": "color", "component": "tooltip"},
+                   "uuid": "tt", "value": "#000"}),
+        ];
+        let mut graph = TokenGraph::from_pairs(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, raw)| {
+                    let key = extract_legacy_key(&raw["name"]).unwrap();
+                    (key, PathBuf::from(format!("t{i}.json")), raw)
+                })
+                .collect(),
+        );
+        graph.components = ["button", "link", "badge"]
+            .into_iter()
+            .map(|name| ComponentRecord {
+                name: name.to_string(),
+                file: PathBuf::from(format!("{name}.json")),
+                raw: if name == "button" {
+                    json!({"tokenBindings": [{"token": "button-background-color"}]})
+                } else {
+                    json!({})
+                },
+            })
+            .collect();
+        graph.relationships = vec![RelationshipRecord {
+            file: PathBuf::from("link.json"),
+            index: 0,
+            uuid: None,
+            raw: json!({"scope": {"component": "link"}, "$ref": "lb"}),
+        }];
+        graph
+    }
+
+    fn keys(report: &UnusedReport) -> Vec<&str> {
+        report.unused.iter().map(|t| t.key.as_str()).collect()
+    }
+
+    #[test]
+    fn reports_tokens_no_root_reaches() {
+        let report = analyze(&graph(), &[], &HashSet::new());
+        assert_eq!(report.total, 9);
+        // accent-color (semantic), button-background-color (binding),
+        // link-border-color (relationship).
+        assert_eq!(report.roots, 3);
+        assert_eq!(
+            keys(&report),
+            vec![
+                "red-900",
+                "green-900",
+                "badge-border-color",
+                "tooltip-color"
+            ]
+        );
+        let orphaned: Vec<&str> = report.orphaned().map(|t| t.key.as_str()).collect();
+        assert_eq!(orphaned, vec!["tooltip-color"]);
+    }
+
+    #[test]
+    fn includes_and_allowlist_add_roots() {
+        let includes = vec![query::parse("component=badge").unwrap()];
+        let allowlist: HashSet<String> = ["gn".to_string(), "retired-token".to_string()].into();
+        let report = analyze(&graph(), &includes, &allowlist);
+        assert_eq!(keys(&report), vec!["tooltip-color"]);
+        assert_eq!(report.roots, 4);
+        assert_eq!(report.allowlisted, 1);
+        assert_eq!(report.stale_exceptions, vec!["retired-token"]);
+    }
+}
@@ -0,0 +1,403 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Validation baselines — accept a known backlog of diagnostics and fail only
+//! on new ones.
+//!
+//! Unlike [`crate::compat::ValidationSnapshot`], which compares a whole report
+//! for equality, a [`Baseline`] is a multiset of diagnostic fingerprints.
+//! [`compare`] suppresses every diagnostic the baseline already knows and
+//! reports the baseline entries no longer produced, so the file can shrink as
+//! the backlog is burned down.
+//!
+//! A fingerprint is the rule id, the diagnostic's *subject*, and its message
+//! template. The subject is the token's `uuid` when it has one, else its name
+//! object (cascade tokens) or legacy key, else the file name for file-level
+//! diagnostics. The template replaces the diagnostic's own file and token key
+//! with placeholders, reduces other `.json` paths to file names, and masks
+//! cascade array indices. Fingerprints therefore survive file moves and
+//! reordering, but not a reworded message.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::locate::token_pointer;
+use crate::report::{Diagnostic, ValidationReport};
+use crate::validate::catalog::diagnostic_rule_id;
+use crate::CoreError;
+
+/// Current baseline file format version.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// A set of accepted diagnostics, as written by `validate --update-baseline`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    /// Sorted, one entry per distinct fingerprint.
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// One fingerprint and how many diagnostics share it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Rule id, or `structural` for Layer 1 schema diagnostics.
+    pub rule: String,
+    /// Token uuid, name object, or legacy key; the file name for file-level diagnostics.
+    pub subject: String,
+    /// Message with file paths, the token key, and array indices masked.
+    pub message: String,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub count: usize,
+}
+
+fn one() -> usize {
+    1
+}
+
+fn is_one(n: &usize) -> bool {
+    *n == 1
+}
+
+/// Result of comparing a report against a baseline.
+#[derive(Debug, Clone, Default)]
+pub struct BaselineOutcome {
+    /// The input report with every baselined diagnostic removed.
+    pub report: ValidationReport,
+    /// Number of diagnostics suppressed by the baseline.
+    pub suppressed: usize,
+    /// Baseline entries (with their remaining counts) the report no longer produces.
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Baseline accepting every error and warning in `report`.
+    pub fn from_report(report: &ValidationReport) -> Self {
+        let mut sources = Sources::default();
+        let mut counts: BTreeMap<(String, String, String), usize> = BTreeMap::new();
+        for d in report.errors.iter().chain(&report.warnings) {
+            *counts.entry(sources.fingerprint(d)).or_default() += 1;
+        }
+        Self {
+            version: BASELINE_VERSION,
+            entries: counts
+                .into_iter()
+                .map(|((rule, subject, message), count)| BaselineEntry {
+                    rule,
+                    subject,
+                    message,
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    /// Total number of diagnostics the baseline accepts.
+    pub fn len(&self) -> usize {
+        self.entries.iter().map(|e| e.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Read a baseline file.
+    pub fn load(path: &Path) -> Result<Self, CoreError> {
+        let text = std::fs::read_to_string(path)?;
+        let baseline: Baseline = serde_json::from_str(&text)?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(CoreError::ParseError(format!(
+                "{}: unsupported baseline version {} (expected {BASELINE_VERSION})",
+                path.display(),
+                baseline.version
+            )));
+        }
+        Ok(baseline)
+    }
+
+    /// Write the baseline as pretty JSON with sorted entries.
+    pub fn write(&self, path: &Path) -> Result<(), CoreError> {
+        let mut sorted = self.clone();
+        sorted.entries.sort();
+        let mut text = serde_json::to_string_pretty(&sorted)?;
+        text.push('\n');
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+/// Split `report` into new diagnostics and those `baseline` already accepts.
+pub fn compare(report: &ValidationReport, baseline: &Baseline) -> BaselineOutcome {
+    let mut remaining: HashMap<(String, String, String), usize> = HashMap::new();
+    for e in &baseline.entries {
+        *remaining
+            .entry((e.rule.clone(), e.subject.clone(), e.message.clone()))
+            .or_default() += e.count;
+    }
+    let mut sources = Sources::default();
+    let mut suppressed = 0;
+    let mut keep = |d: &Diagnostic| match remaining.get_mut(&sources.fingerprint(d)) {
+        Some(n) if *n > 0 => {
+            *n -= 1;
+            suppressed += 1;
+            false
+        }
+        _ => true,
+    };
+    let mut out = ValidationReport {
+        valid: true,
+        errors: report.errors.iter().filter(|d| keep(d)).cloned().collect(),
+        warnings: report
+            .warnings
+            .iter()
+            .filter(|d| keep(d))
+            .cloned()
+            .collect(),
+    };
+    out.recompute_valid();
+
+    let mut fixed: Vec<BaselineEntry> = remaining
+        .into_iter()
+        .filter(|(_, n)| *n > 0)
+        .map(|((rule, subject, message), count)| BaselineEntry {
+            rule,
+            subject,
+            message,
+            count,
+        })
+        .collect();
+    fixed.sort();
+    BaselineOutcome {
+        report: out,
+        suppressed,
+        fixed,
+    }
+}
+
+// ── Fingerprints ─────────────────────────────────────────────────────────────
+
+/// Parsed source files, read once each, for looking up token uuids.
+#[derive(Default)]
+struct Sources {
+    docs: HashMap<PathBuf, Option<Value>>,
+}
+
+impl Sources {
+    fn fingerprint(&mut self, d: &Diagnostic) -> (String, String, String) {
+        (
+            diagnostic_rule_id(d).to_string(),
+            self.subject(d),
+            message_template(d),
+        )
+    }
+
+    fn subject(&mut self, d: &Diagnostic) -> String {
+        let Some(token) = d.token.as_deref() else {
+            return file_name(&d.file);
+        };
+        let pointer = token_pointer(d, token);
+        let doc = self
+            .docs
+            .entry(d.file.clone())
+            .or_insert_with(|| {
+                std::fs::read_to_string(&d.file)
+                    .ok()
+                    .and_then(|text| serde_json::from_str(&text).ok())
+            })
+            .as_ref();
+        let record = doc.and_then(|v| v.pointer(&pointer));
+        if let Some(uuid) = record.and_then(|r| r.get("uuid")).and_then(Value::as_str) {
+            return uuid.to_string();
+        }
+        match cascade_index(d, token) {
+            Some(idx) => record
+                .and_then(|r| r.get("name"))
+                .map(Value::to_string)
+                .unwrap_or_else(|| format!("{}:{idx}", file_name(&d.file))),
+            None => token.to_string(),
+        }
+    }
+}
+
+/// The array index of a cascade graph key (`<file>:<index>`) in `d`'s file.
+fn cascade_index<'a>(d: &Diagnostic, token: &'a str) -> Option<&'a str> {
+    token
+        .strip_prefix(&format!("{}:", d.file.display()))
+        .filter(|idx| idx.parse::<usize>().is_ok())
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |n| n.to_string_lossy().into_owned(),
+    )
+}
+
+/// `d.message` with its own token key and file replaced by `{token}` and
+/// `{file}`, other `.json` paths reduced to file names, and `.json:<index>`
+/// suffixes masked as `.json:#`.
+fn message_template(d: &Diagnostic) -> String {
+    let mut message = d.message.clone();
+    if let Some(token) = d.token.as_deref().filter(|t| !t.is_empty()) {
+        message = message.replace(token, "{token}");
+    }
+    let file = d.file.display().to_string();
+    if !file.is_empty() {
+        message = message.replace(&file, "{file}");
+    }
+    message
+        .split(' ')
+        .map(template_word)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn template_word(word: &str) -> String {
+    let Some(at) = word.find(".json") else {
+        return word.to_string();
+    };
+    let (head, tail) = word.split_at(at);
+    // Keep leading punctuation (quotes, brackets) but drop directories.
+    let lead = head
+        .find(|c: char| c.is_alphanumeric() || matches!(c, '/' | '.' | '_' | '-' | '~'))
+        .unwrap_or(head.len());
+    let name = head[lead..].rsplit('/').next().unwrap_or_default();
+    let rest = &tail[".json".len()..];
+    let rest = match rest.strip_prefix(':') {
+        Some(after) if after.starts_with(|c: char| c.is_ascii_digit()) => {
+            let digits = after.len() - after.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            format!(":#{}", &after[digits..])
+        }
+        _ => rest.to_string(),
+    };
+    format!("{}{name}.json{rest}", &head[..lead])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Severity;
+
+    fn diag(file: &Path, token: Option<&str>, rule: &str, message: &str) -> Diagnostic {
+        Diagnostic {
+            file: file.to_path_buf(),
+            token: token.map(str::to_string),
+            rule_id: Some(rule.into()),
+            severity: Severity::Error,
+            message: message.into(),
+            instance_path: None,
+            schema_path: None,
+            fix: None,
+            span: None,
+        }
+    }
+
+    fn report(errors: Vec<Diagnostic>) -> ValidationReport {
+        let mut r = ValidationReport::default();
+        for d in errors {
+            r.push_error(d);
+        }
+        r
+    }
+
+    #[test]
+    fn fingerprints_survive_moves_and_reordering() {
+        let dir = tempfile::tempdir().unwrap();
+        let before = dir.path().join("a/colors.tokens.json");
+        let after = dir.path().join("b/moved.tokens.json");
+        std::fs::create_dir_all(before.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(after.parent().unwrap()).unwrap();
+        std::fs::write(
+            &before,
+            r#"[{"uuid":"u-1","value":"{x}"},{"name":{"property":"gap"},"value":1}]"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &after,
+            r#"[{"name":{"property":"gap"},"value":1},{"uuid":"u-1","value":"{x}"}]"#,
+        )
+        .unwrap();
+        let key = |file: &Path, idx: usize| format!("{}:{idx}", file.display());
+
+        let old = report(vec![
+            diag(
+                &before,
+                Some(&key(&before, 0)),
+                "SPEC-001",
+                "Alias target not found for $ref: x",
+            ),
+            diag(
+                &before,
+                Some(&key(&before, 1)),
+                "SPEC-009",
+                &format!("{} duplicates other/dir/x.json:4", key(&before, 1)),
+            ),
+        ]);
+        let baseline = Baseline::from_report(&old);
+        assert_eq!(baseline.entries[0].subject, "u-1");
+        assert_eq!(baseline.entries[1].subject, r#"{"property":"gap"}"#);
+        assert_eq!(baseline.entries[1].message, "{token} duplicates x.json:#");
+
+        let new = report(vec![
+            diag(
+                &after,
+                Some(&key(&after, 1)),
+                "SPEC-001",
+                "Alias target not found for $ref: x",
+            ),
+            diag(
+                &after,
+                Some(&key(&after, 0)),
+                "SPEC-009",
+                &format!("{} duplicates elsewhere/x.json:9", key(&after, 0)),
+            ),
+            diag(&after, None, "SPEC-044", "new problem"),
+        ]);
+        let outcome = compare(&new, &baseline);
+        assert_eq!(outcome.suppressed, 2);
+        assert!(outcome.fixed.is_empty());
+        assert_eq!(outcome.report.errors.len(), 1);
+        assert_eq!(outcome.report.errors[0].message, "new problem");
+        assert!(!outcome.report.valid);
+    }
+
+    #[test]
+    fn reports_fixed_entries_and_round_trips() {
+        let file = PathBuf::from("missing/legacy.json");
+        let old = report(vec![
+            diag(&file, Some("gap"), "SPEC-007", "Token 'gap' is bad"),
+            diag(&file, Some("gap"), "SPEC-007", "Token 'gap' is bad"),
+            diag(&file, Some("pad"), "SPEC-007", "Token 'pad' is bad"),
+        ]);
+        let baseline = Baseline::from_report(&old);
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(baseline.len(), 3);
+        assert_eq!(baseline.entries[0].message, "Token '{token}' is bad");
+
+        let outcome = compare(&report(vec![old.errors[0].clone()]), &baseline);
+        assert!(outcome.report.valid);
+        assert_eq!(outcome.suppressed, 1);
+        let fixed: Vec<_> = outcome
+            .fixed
+            .iter()
+            .map(|e| (&*e.subject, e.count))
+            .collect();
+        assert_eq!(fixed, vec![("gap", 1), ("pad", 1)]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        baseline.write(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+        std::fs::write(&path, r#"{"version":99,"entries":[]}"#).unwrap();
+        assert!(Baseline::load(&path).is_err());
+    }
+}
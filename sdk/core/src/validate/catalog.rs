@@ -0,0 +1,185 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Rule metadata from the embedded `packages/design-data-spec/rules/rules.yaml`.
+//!
+//! Report exporters (SARIF, JUnit) describe each rule with its catalog
+//! `assert` text, category, and spec link. Fields the exporters do not use
+//! (`message`, `introduced_in`, …) are ignored.
+
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::report::{Diagnostic, Severity, ValidationReport};
+
+static RULES_YAML: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../packages/design-data-spec/rules/rules.yaml"
+));
+
+/// Base URL for `spec_ref` links (relative to `packages/design-data-spec/`).
+pub const SPEC_BASE_URL: &str =
+    "https://github.com/adobe/spectrum-design-data/blob/main/packages/design-data-spec/";
+
+/// One catalog entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct RuleMeta {
+    pub id: String,
+    pub name: String,
+    #[serde(deserialize_with = "severity")]
+    pub severity: Option<Severity>,
+    pub category: String,
+    /// The rule's normative `assert` sentence.
+    pub assert: String,
+    /// Spec section, relative to `packages/design-data-spec/` (e.g. `spec/token-format.md#alias-ref`).
+    pub spec_ref: String,
+}
+
+impl RuleMeta {
+    /// Absolute URL of the rule's spec section, when it has one.
+    pub fn help_uri(&self) -> Option<String> {
+        (!self.spec_ref.is_empty()).then(|| format!("{SPEC_BASE_URL}{}", self.spec_ref))
+    }
+}
+
+/// All catalog rules, in catalog order.
+pub fn rules() -> &'static [RuleMeta] {
+    static CATALOG: OnceLock<Vec<RuleMeta>> = OnceLock::new();
+    CATALOG.get_or_init(|| parse(RULES_YAML).expect("embedded rules.yaml is valid YAML"))
+}
+
+/// Catalog entry for `id`.
+pub fn rule(id: &str) -> Option<&'static RuleMeta> {
+    rules().iter().find(|r| r.id == id)
+}
+
+/// Catalog entry for `id`, only when its `name` is `name` — the catalog and
+/// the rule implementations can drift (an id reassigned on one side), and a
+/// rule must not be described with another rule's `assert` text.
+pub fn rule_named(id: &str, name: &str) -> Option<&'static RuleMeta> {
+    rule(id).filter(|r| r.name == name)
+}
+
+/// Rule id reported for Layer 1 (JSON Schema) diagnostics, which carry no `rule_id`.
+pub const STRUCTURAL_RULE_ID: &str = "structural";
+
+/// A rule that a report is scored against.
+#[derive(Debug, Clone)]
+pub struct ReportRule {
+    pub id: String,
+    pub name: String,
+    /// Catalog metadata, when the catalog entry matches the implementation.
+    pub meta: Option<&'static RuleMeta>,
+}
+
+/// The rules behind `report`: Layer 1 structural validation, every default
+/// rule, SPEC-044 (dataset structure), then any other rule id the report
+/// mentions — in that order, without duplicates.
+pub fn report_rules(report: &ValidationReport) -> Vec<ReportRule> {
+    let mut out = vec![ReportRule {
+        id: STRUCTURAL_RULE_ID.to_string(),
+        name: "json-schema".to_string(),
+        meta: None,
+    }];
+    for r in crate::validate::rules::default_rules() {
+        out.push(ReportRule {
+            id: r.id().to_string(),
+            name: r.name().to_string(),
+            meta: rule_named(r.id(), r.name()),
+        });
+    }
+    let mentioned = report
+        .errors
+        .iter()
+        .chain(&report.warnings)
+        .filter_map(|d| d.rule_id.as_deref());
+    for id in std::iter::once("SPEC-044").chain(mentioned) {
+        if out.iter().any(|r| r.id == id) {
+            continue;
+        }
+        let meta = rule(id);
+        out.push(ReportRule {
+            id: id.to_string(),
+            name: meta.map(|m| m.name.clone()).unwrap_or_default(),
+            meta,
+        });
+    }
+    out
+}
+
+/// The [`ReportRule::id`] a diagnostic is reported under.
+pub fn diagnostic_rule_id(d: &Diagnostic) -> &str {
+    d.rule_id.as_deref().unwrap_or(STRUCTURAL_RULE_ID)
+}
+
+#[derive(Deserialize)]
+struct CatalogFile {
+    #[serde(default)]
+    rules: Vec<RuleMeta>,
+}
+
+fn parse(text: &str) -> Result<Vec<RuleMeta>, serde_norway::Error> {
+    let mut rules = serde_norway::from_str::<CatalogFile>(text)?.rules;
+    rules.retain(|r| !r.id.is_empty());
+    // Folded (`>`) scalars keep their final newline.
+    for r in &mut rules {
+        r.assert.truncate(r.assert.trim_end().len());
+    }
+    Ok(rules)
+}
+
+/// A catalog severity this crate does not model reads as `None` rather than
+/// failing the whole catalog.
+fn severity<'de, D>(deserializer: D) -> Result<Option<Severity>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(match value.as_deref() {
+        Some("error") => Some(Severity::Error),
+        Some("warning") => Some(Severity::Warning),
+        Some("info") => Some(Severity::Info),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_catalog_covers_every_default_rule() {
+        for r in crate::validate::rules::default_rules() {
+            let meta = rule(r.id()).unwrap_or_else(|| panic!("{} missing from rules.yaml", r.id()));
+            assert!(!meta.assert.is_empty(), "{}", r.id());
+        }
+        let spec001 = rule("SPEC-001").unwrap();
+        assert_eq!(spec001.severity, Some(Severity::Error));
+        assert_eq!(
+            spec001.help_uri().unwrap(),
+            format!("{SPEC_BASE_URL}spec/token-format.md#alias-ref")
+        );
+    }
+
+    #[test]
+    fn folds_block_scalars_and_skips_comments() {
+        let rules = parse(
+            "rules:\n  - id: X-1\n    # note: ignored\n    name: \"quoted\"\n    severity: fatal\n    assert: >\n      first line\n      second line\n    spec_ref: spec/x.md\n  - id: X-2\n",
+        )
+        .unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].name, "quoted");
+        assert_eq!(rules[0].assert, "first line second line");
+        assert_eq!(rules[0].spec_ref, "spec/x.md");
+        assert_eq!(rules[0].severity, None);
+    }
+}
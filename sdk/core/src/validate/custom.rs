@@ -0,0 +1,576 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Declarative custom rules — house rules for design systems built on Spectrum.
+//!
+//! Rule files are YAML (`.yaml`/`.yml`) or TOML (`.toml`) and are listed in
+//! the `custom-rules` key of the `[validate]` table of `.design-data.toml`:
+//!
+//! ```yaml
+//! rules:
+//!   - id: ACME-001
+//!     name: checkout-aliases-foundation
+//!     selector: "component=checkout-*"
+//!     assert: { kind: must-alias, layers: [foundation] }
+//!   - id: ACME-002
+//!     severity: warn
+//!     selector: "exists(property)"
+//!     layers: [product]
+//!     message: "Product tokens must not hardcode hex colors"
+//!     assert: { kind: value-matches-regex, pattern: "^#", negate: true }
+//! ```
+//!
+//! A rule applies to every token matched by its `selector` (query notation,
+//! see [`crate::query`]) and, when `layers` is given, in one of those cascade
+//! layers. Each matched token must satisfy the rule's [`Assertion`]; tokens
+//! that do not get one diagnostic at the rule's `severity` (default `error`).
+//!
+//! Rules are checked when loaded: ids must be unique and must not use the
+//! catalog's `SPEC-` prefix, selectors and patterns must parse, and name
+//! fields must be registered. Loaded rules implement [`ValidationRule`] and
+//! run alongside the catalog rules, so `[validate.rules]` and overrides
+//! re-level them by id like any other rule.
+
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::graph::{Layer, TokenGraph, TokenRecord};
+use crate::query::{self, TokenFilter};
+use crate::registry::FieldCatalog;
+use crate::report::{Diagnostic, Severity};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
+use crate::validate::rule_config::RuleLevel;
+use crate::CoreError;
+
+/// Id prefix reserved for catalog rules.
+const RESERVED_PREFIX: &str = "SPEC-";
+
+// ── Rule files ──────────────────────────────────────────────────────────────
+
+/// Top level of a custom rule file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    #[serde(default)]
+    rules: Vec<RuleSpec>,
+}
+
+/// One declared rule, as written.
+#[derive(Debug, Clone, Hash, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSpec {
+    pub id: String,
+    /// Short kebab-case name; defaults to the id.
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default = "default_level")]
+    pub severity: RuleLevel,
+    /// Tokens the rule applies to, in query notation. Empty matches every token.
+    #[serde(default)]
+    pub selector: String,
+    /// Restrict the rule to tokens in these cascade layers.
+    #[serde(default)]
+    pub layers: Option<Vec<Layer>>,
+    /// Diagnostic message; defaults to a description of the failed assertion.
+    #[serde(default)]
+    pub message: Option<String>,
+    pub assert: Assertion,
+}
+
+fn default_level() -> RuleLevel {
+    RuleLevel::Error
+}
+
+/// What every selected token must satisfy.
+#[derive(Debug, Clone, Hash, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum Assertion {
+    /// The token is an alias (`$ref`). When given, its target must match the
+    /// `target` selector and be in one of `layers`.
+    MustAlias {
+        #[serde(default)]
+        target: Option<String>,
+        #[serde(default)]
+        layers: Option<Vec<Layer>>,
+    },
+    /// The token's `$schema` ends with one of `schemas` (a file name such as
+    /// `color.json`, or a full URL).
+    SchemaIn { schemas: Vec<String> },
+    /// The token is in one of `layers`.
+    LayerIn { layers: Vec<Layer> },
+    /// The token's name object sets every one of `fields`.
+    NameFieldRequired { fields: Vec<String> },
+    /// The token's literal `value` matches `pattern` (or, with `negate`, does
+    /// not). Aliases have no literal value and always pass.
+    ValueMatchesRegex {
+        pattern: String,
+        #[serde(default)]
+        negate: bool,
+    },
+}
+
+// ── Loading ─────────────────────────────────────────────────────────────────
+
+/// A loaded, checked custom rule.
+#[derive(Debug, Clone)]
+pub struct CustomRule {
+    spec: RuleSpec,
+    file: PathBuf,
+    severity: Severity,
+    selector: TokenFilter,
+    target: Option<TokenFilter>,
+    pattern: Option<Regex>,
+}
+
+impl CustomRule {
+    /// The rule as declared.
+    pub fn spec(&self) -> &RuleSpec {
+        &self.spec
+    }
+
+    /// The file the rule was declared in.
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    /// Hash of the declaration, so incremental validation re-runs a rule
+    /// whose definition changed.
+    pub fn fingerprint(&self) -> u64 {
+        let mut h = std::collections::hash_map::DefaultHasher::new();
+        self.spec.hash(&mut h);
+        h.finish()
+    }
+
+    fn compile(spec: RuleSpec, file: &Path) -> Result<Self, Vec<String>> {
+        let mut problems = Vec::new();
+        let id_ok = !spec.id.is_empty()
+            && spec
+                .id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !id_ok {
+            problems.push("id must be non-empty ASCII letters, digits, '-', '_' or '.'".into());
+        }
+        if spec.id.starts_with(RESERVED_PREFIX) {
+            problems.push(format!(
+                "ids starting with {RESERVED_PREFIX} are reserved for catalog rules"
+            ));
+        }
+        let severity = spec.severity.severity();
+        if severity.is_none() {
+            problems.push("severity cannot be off; turn the rule off in [validate.rules]".into());
+        }
+        let selector = parse_selector("selector", &spec.selector, &mut problems);
+        if spec.layers.as_ref().is_some_and(Vec::is_empty) {
+            problems.push("layers must not be empty".into());
+        }
+
+        let mut target = None;
+        let mut pattern = None;
+        match &spec.assert {
+            Assertion::MustAlias { target: t, layers } => {
+                if let Some(t) = t {
+                    target = parse_selector("target", t, &mut problems);
+                }
+                if layers.as_ref().is_some_and(Vec::is_empty) {
+                    problems.push("must-alias layers must not be empty".into());
+                }
+            }
+            Assertion::SchemaIn { schemas } => {
+                if schemas.is_empty() {
+                    problems.push("schema-in needs at least one schema".into());
+                }
+            }
+            Assertion::LayerIn { layers } => {
+                if layers.is_empty() {
+                    problems.push("layer-in needs at least one layer".into());
+                }
+            }
+            Assertion::NameFieldRequired { fields } => {
+                if fields.is_empty() {
+                    problems.push("name-field-required needs at least one field".into());
+                }
+                let catalog = FieldCatalog::embedded();
+                for f in fields.iter().filter(|f| catalog.get(f).is_none()) {
+                    problems.push(format!("unknown name field '{f}'"));
+                }
+            }
+            Assertion::ValueMatchesRegex { pattern: p, .. } => match Regex::new(p) {
+                Ok(re) => pattern = Some(re),
+                Err(e) => problems.push(format!("invalid pattern: {e}")),
+            },
+        }
+
+        match (severity, selector) {
+            (Some(severity), Some(selector)) if problems.is_empty() => Ok(Self {
+                spec,
+                file: file.to_path_buf(),
+                severity,
+                selector,
+                target,
+                pattern,
+            }),
+            _ => Err(problems),
+        }
+    }
+}
+
+fn parse_selector(field: &str, expr: &str, problems: &mut Vec<String>) -> Option<TokenFilter> {
+    query::parse(expr)
+        .map_err(|e| problems.push(format!("invalid {field} '{expr}': {e}")))
+        .ok()
+}
+
+/// Parse and check the rules in `text`. `path` picks the format by extension
+/// (`.toml`, otherwise YAML) and names the file in errors.
+pub fn parse(text: &str, path: &Path) -> Result<Vec<CustomRule>, CoreError> {
+    parse_file(text, path).map_err(|problems| CoreError::ParseError(problems.join("\n")))
+}
+
+/// Load every rule file in `paths`, in order. Fails on the first unreadable
+/// file, on any invalid rule, and on ids declared more than once.
+pub fn load_all(paths: &[PathBuf]) -> Result<Vec<CustomRule>, CoreError> {
+    let mut rules: Vec<CustomRule> = Vec::new();
+    let mut problems = Vec::new();
+    for path in paths {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| CoreError::ParseError(format!("{}: {e}", path.display())))?;
+        match parse_file(&text, path) {
+            Ok(loaded) => rules.extend(loaded),
+            Err(errs) => problems.extend(errs),
+        }
+    }
+    let mut seen = HashSet::new();
+    for r in &rules {
+        if !seen.insert(r.spec.id.as_str()) {
+            problems.push(format!(
+                "{}: {}: id is declared more than once",
+                r.file.display(),
+                r.spec.id
+            ));
+        }
+    }
+    if problems.is_empty() {
+        Ok(rules)
+    } else {
+        Err(CoreError::ParseError(problems.join("\n")))
+    }
+}
+
+/// Every problem in a rule file, one line each, prefixed with the file and rule id.
+fn parse_file(text: &str, path: &Path) -> Result<Vec<CustomRule>, Vec<String>> {
+    let file: Result<RuleFile, String> = if path.extension().is_some_and(|e| e == "toml") {
+        toml::from_str(text).map_err(|e| e.to_string())
+    } else {
+        serde_norway::from_str(text).map_err(|e| e.to_string())
+    };
+    let file = file.map_err(|e| vec![format!("{}: {}", path.display(), e.trim_end())])?;
+
+    let mut rules = Vec::new();
+    let mut problems = Vec::new();
+    for spec in file.rules {
+        let id = spec.id.clone();
+        match CustomRule::compile(spec, path) {
+            Ok(rule) => rules.push(rule),
+            Err(errs) => problems.extend(
+                errs.into_iter()
+                    .map(|e| format!("{}: {id}: {e}", path.display())),
+            ),
+        }
+    }
+    if problems.is_empty() {
+        Ok(rules)
+    } else {
+        Err(problems)
+    }
+}
+
+// ── Evaluation ──────────────────────────────────────────────────────────────
+
+impl ValidationRule for CustomRule {
+    fn id(&self) -> &str {
+        &self.spec.id
+    }
+
+    fn name(&self) -> &str {
+        self.spec.name.as_deref().unwrap_or(&self.spec.id)
+    }
+
+    /// `value` selectors follow aliases in each mode set's default mode.
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::MODE_SETS
+    }
+
+    fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
+        let graph = ctx.graph;
+        let targets: Option<HashSet<&str>> = self.target.as_ref().map(|f| {
+            query::filter(graph, f)
+                .into_iter()
+                .map(|t| t.name.as_str())
+                .collect()
+        });
+        let mut out = Vec::new();
+        for t in query::filter(graph, &self.selector) {
+            if self
+                .spec
+                .layers
+                .as_ref()
+                .is_some_and(|l| !l.contains(&t.layer))
+            {
+                continue;
+            }
+            let Some(problem) = self.check(graph, t, targets.as_ref()) else {
+                continue;
+            };
+            let message = match &self.spec.message {
+                Some(m) => format!("{m} ({problem})"),
+                None => problem,
+            };
+            out.push(Diagnostic {
+                file: t.file.clone(),
+                token: Some(t.name.clone()),
+                rule_id: Some(self.spec.id.clone()),
+                severity: self.severity,
+                message,
+                instance_path: None,
+                schema_path: None,
+                fix: None,
+                span: None,
+            });
+        }
+        out
+    }
+}
+
+impl CustomRule {
+    /// Why `t` fails the assertion, or `None` when it passes.
+    fn check(
+        &self,
+        graph: &TokenGraph,
+        t: &TokenRecord,
+        targets: Option<&HashSet<&str>>,
+    ) -> Option<String> {
+        match &self.spec.assert {
+            Assertion::MustAlias { layers, .. } => {
+                let Some(alias) = &t.alias_target else {
+                    return Some("token is not an alias".into());
+                };
+                // A dangling alias is SPEC-001's finding, not this rule's.
+                let target = graph.resolve_alias_key(alias)?;
+                if targets.is_some_and(|set| !set.contains(target.name.as_str())) {
+                    return Some(format!(
+                        "alias target {alias} does not match the target selector"
+                    ));
+                }
+                match layers {
+                    Some(layers) if !layers.contains(&target.layer) => Some(format!(
+                        "alias target {alias} is in the {} layer, expected {}",
+                        layer_name(target.layer),
+                        layer_list(layers)
+                    )),
+                    _ => None,
+                }
+            }
+            Assertion::SchemaIn { schemas } => {
+                let schema = t.schema_url.as_deref().unwrap_or_default();
+                (!schemas.iter().any(|s| schema.ends_with(s.as_str()))).then(|| {
+                    if schema.is_empty() {
+                        "token has no $schema".to_string()
+                    } else {
+                        format!("$schema {schema} is not one of {}", schemas.join(", "))
+                    }
+                })
+            }
+            Assertion::LayerIn { layers } => (!layers.contains(&t.layer)).then(|| {
+                format!(
+                    "token is in the {} layer, expected {}",
+                    layer_name(t.layer),
+                    layer_list(layers)
+                )
+            }),
+            Assertion::NameFieldRequired { fields } => {
+                let name = t.raw.get("name");
+                let missing: Vec<&str> = fields
+                    .iter()
+                    .filter(|f| {
+                        name.and_then(|n| n.get(f.as_str()))
+                            .is_none_or(|v| v.is_null() || v.as_str() == Some(""))
+                    })
+                    .map(String::as_str)
+                    .collect();
+                (!missing.is_empty())
+                    .then(|| format!("name object is missing {}", missing.join(", ")))
+            }
+            Assertion::ValueMatchesRegex { negate, .. } => {
+                let pattern = self.pattern.as_ref()?;
+                let value = match t.raw.get("value")? {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                if pattern.is_match(&value) == *negate {
+                    let verb = if *negate { "matches" } else { "does not match" };
+                    Some(format!("value {value} {verb} /{pattern}/"))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn layer_name(layer: Layer) -> &'static str {
+    match layer {
+        Layer::Foundation => "foundation",
+        Layer::Platform => "platform",
+        Layer::Product => "product",
+    }
+}
+
+fn layer_list(layers: &[Layer]) -> String {
+    layers
+        .iter()
+        .map(|l| layer_name(*l))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::validate::rules;
+
+    fn graph() -> TokenGraph {
+        TokenGraph::from_records(vec![
+            record(
+                "base",
+                json!({ "value": "#ff0000", "uuid": "u-base" }),
+                Layer::Foundation,
+            ),
+            record(
+                "checkout-bg",
+                json!({ "name": { "property": "background-color", "component": "checkout-form" }, "$ref": "u-base" }),
+                Layer::Product,
+            ),
+            record(
+                "checkout-fg",
+                json!({ "name": { "property": "color", "component": "checkout-form" }, "value": "#00ff00" }),
+                Layer::Product,
+            ),
+        ])
+    }
+
+    fn record(name: &str, raw: serde_json::Value, layer: Layer) -> TokenRecord {
+        TokenRecord {
+            name: name.into(),
+            file: PathBuf::from("tokens.json"),
+            index: 0,
+            schema_url: None,
+            uuid: raw.get("uuid").and_then(|v| v.as_str()).map(String::from),
+            alias_target: raw.get("$ref").and_then(|v| v.as_str()).map(String::from),
+            raw,
+            layer,
+            span: None,
+        }
+    }
+
+    fn run(text: &str, ext: &str) -> Vec<Diagnostic> {
+        let rules = parse(text, Path::new(&format!("house-rules.{ext}"))).unwrap();
+        let graph = graph();
+        let exceptions = HashSet::new();
+        let ctx = rules::context(&graph, &exceptions, None);
+        let mut out: Vec<Diagnostic> = rules.iter().flat_map(|r| r.validate(&ctx)).collect();
+        out.sort_by(|a, b| (&a.rule_id, &a.token).cmp(&(&b.rule_id, &b.token)));
+        out
+    }
+
+    #[test]
+    fn yaml_and_toml_rules_report_failing_tokens() {
+        let yaml = r##"
+rules:
+  - id: ACME-001
+    selector: "component=checkout-*"
+    assert: { kind: must-alias, layers: [foundation] }
+  - id: ACME-002
+    severity: warn
+    layers: [product]
+    message: No raw hex values at the product layer
+    assert: { kind: value-matches-regex, pattern: "^#", negate: true }
+"##;
+        let d = run(yaml, "yaml");
+        let found: Vec<(&str, &str, Severity)> = d
+            .iter()
+            .map(|d| {
+                (
+                    d.rule_id.as_deref().unwrap(),
+                    d.token.as_deref().unwrap(),
+                    d.severity,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("ACME-001", "checkout-fg", Severity::Error),
+                ("ACME-002", "checkout-fg", Severity::Warning),
+            ]
+        );
+        assert!(
+            d[1].message.starts_with("No raw hex values"),
+            "{}",
+            d[1].message
+        );
+
+        let toml = r#"
+[[rules]]
+id = "ACME-003"
+selector = "component=checkout-form"
+assert = { kind = "name-field-required", fields = ["variant"] }
+"#;
+        let d = run(toml, "toml");
+        assert_eq!(d.len(), 2);
+        assert!(d[0].message.contains("missing variant"), "{}", d[0].message);
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected_at_load_time() {
+        let yaml = r#"
+rules:
+  - id: SPEC-900
+    selector: "colour=red"
+    assert: { kind: name-field-required, fields: [compnent] }
+  - id: ACME-004
+    assert: { kind: value-matches-regex, pattern: "(" }
+"#;
+        let err = parse(yaml, Path::new("house.yaml"))
+            .unwrap_err()
+            .to_string();
+        for expected in [
+            "SPEC-900: ids starting with SPEC- are reserved",
+            "SPEC-900: invalid selector 'colour=red'",
+            "SPEC-900: unknown name field 'compnent'",
+            "ACME-004: invalid pattern",
+        ] {
+            assert!(err.contains(expected), "missing {expected:?} in {err}");
+        }
+        assert!(parse(
+            "rules: [{ id: X-1, assert: { kind: bogus } }]",
+            Path::new("h.yaml")
+        )
+        .is_err());
+    }
+}
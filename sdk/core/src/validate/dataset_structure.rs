@@ -105,6 +105,8 @@ fn diag(file: &Path, severity: Severity, detail: String) -> Diagnostic {
         message: format!("Dataset structure incomplete: {detail}"),
         instance_path: None,
         schema_path: None,
+        fix: None,
+        span: None,
     }
 }
 
@@ -0,0 +1,381 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! CI exports of a [`ValidationReport`]: SARIF 2.1.0 (GitHub code scanning)
+//! and JUnit XML (Jenkins and other test-report consumers).
+//!
+//! Both describe every rule the report is scored against (see
+//! [`catalog::report_rules`]) and place each diagnostic at a line/column
+//! derived from its JSON pointer (see [`crate::locate`]). File paths are made
+//! relative to `base` (normally the working directory) so results line up with
+//! repository paths.
+
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+
+use crate::locate::SourceCache;
+use crate::report::{Diagnostic, Severity, ValidationReport};
+use crate::validate::catalog::{self, ReportRule};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/adobe/spectrum-design-data";
+
+// ── SARIF ───────────────────────────────────────────────────────────────────
+
+/// The report as a SARIF 2.1.0 log with a single run of `design-data`.
+pub fn sarif(report: &ValidationReport, tool_version: &str, base: &Path) -> Value {
+    let rules = catalog::report_rules(report);
+    let descriptors: Vec<Value> = rules.iter().map(sarif_rule).collect();
+    let mut sources = SourceCache::default();
+    let results: Vec<Value> = diagnostics(report)
+        .map(|d| sarif_result(d, &rules, &mut sources, base))
+        .collect();
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "design-data",
+                    "version": tool_version,
+                    "informationUri": INFORMATION_URI,
+                    "rules": descriptors,
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }]
+    })
+}
+
+fn sarif_rule(rule: &ReportRule) -> Value {
+    let mut out = Map::new();
+    out.insert("id".into(), json!(rule.id));
+    if !rule.name.is_empty() {
+        out.insert("name".into(), json!(rule.name));
+        out.insert("shortDescription".into(), json!({ "text": rule.name }));
+    }
+    if let Some(meta) = rule.meta {
+        out.insert("fullDescription".into(), json!({ "text": meta.assert }));
+        if let Some(uri) = meta.help_uri() {
+            out.insert("helpUri".into(), json!(uri));
+        }
+        if let Some(severity) = meta.severity {
+            out.insert(
+                "defaultConfiguration".into(),
+                json!({ "level": sarif_level(severity) }),
+            );
+        }
+        out.insert("properties".into(), json!({ "category": meta.category }));
+    }
+    Value::Object(out)
+}
+
+fn sarif_result(
+    d: &Diagnostic,
+    rules: &[ReportRule],
+    sources: &mut SourceCache,
+    base: &Path,
+) -> Value {
+    let rule_id = catalog::diagnostic_rule_id(d);
+    let mut physical = Map::new();
+    let (uri, relative) = artifact_uri(&d.file, base);
+    let mut artifact = json!({ "uri": uri });
+    if relative {
+        artifact["uriBaseId"] = json!("%SRCROOT%");
+    }
+    physical.insert("artifactLocation".into(), artifact);
+    if let Some(pos) = sources.position(d) {
+        physical.insert(
+            "region".into(),
+            json!({ "startLine": pos.line, "startColumn": pos.column }),
+        );
+    }
+    let mut location = json!({ "physicalLocation": physical });
+    if let Some(token) = &d.token {
+        location["logicalLocations"] = json!([{ "name": token, "kind": "member" }]);
+    }
+    let mut result = json!({
+        "ruleId": rule_id,
+        "level": sarif_level(d.severity),
+        "message": { "text": d.message },
+        "locations": [location],
+    });
+    if let Some(index) = rules.iter().position(|r| r.id == rule_id) {
+        result["ruleIndex"] = json!(index);
+    }
+    result
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// `file` as a URI: relative to `base` (with `true`) when it lies under it,
+/// else an absolute `file://` URI.
+fn artifact_uri(file: &Path, base: &Path) -> (String, bool) {
+    let rel = relative_path(file, base);
+    if Path::new(&rel).is_absolute() {
+        (format!("file://{}", percent_encode(&rel)), false)
+    } else {
+        (percent_encode(&rel), true)
+    }
+}
+
+fn percent_encode(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for b in path.bytes() {
+        if b.is_ascii_alphanumeric() || b"/-._~".contains(&b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{b:02X}"));
+        }
+    }
+    out
+}
+
+// ── JUnit ───────────────────────────────────────────────────────────────────
+
+/// The report as JUnit XML: one `<testcase>` per rule, one `<failure>` per
+/// error (and per warning when `strict`). Non-failing diagnostics are listed
+/// in the testcase's `<system-out>`.
+pub fn junit(report: &ValidationReport, suite: &str, strict: bool, base: &Path) -> String {
+    let rules = catalog::report_rules(report);
+    let mut sources = SourceCache::default();
+    let mut cases = String::new();
+    let mut failing = 0;
+    for rule in &rules {
+        let mut failures = String::new();
+        let mut output = String::new();
+        for d in diagnostics(report).filter(|d| catalog::diagnostic_rule_id(d) == rule.id) {
+            let at = match sources.position(d) {
+                Some(pos) => format!(
+                    "{}:{}:{}",
+                    relative_path(&d.file, base),
+                    pos.line,
+                    pos.column
+                ),
+                None => relative_path(&d.file, base),
+            };
+            let token = d
+                .token
+                .as_deref()
+                .map(|t| format!(" ({t})"))
+                .unwrap_or_default();
+            let fails =
+                d.severity == Severity::Error || (strict && d.severity == Severity::Warning);
+            if fails {
+                failures.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}{}\n{}</failure>\n",
+                    severity_label(d.severity),
+                    xml_escape(&d.message),
+                    xml_escape(&at),
+                    xml_escape(&token),
+                    xml_escape(&d.message),
+                ));
+            } else {
+                output.push_str(&format!(
+                    "{}: {at}{token} {}\n",
+                    severity_label(d.severity),
+                    d.message
+                ));
+            }
+        }
+        if !failures.is_empty() {
+            failing += 1;
+        }
+        let name = if rule.name.is_empty() {
+            rule.id.clone()
+        } else {
+            format!("{} {}", rule.id, rule.name)
+        };
+        let classname = match rule.meta {
+            Some(meta) if !meta.category.is_empty() => format!("design-data.{}", meta.category),
+            _ => "design-data".to_string(),
+        };
+        cases.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"{}\"",
+            xml_escape(&name),
+            xml_escape(&classname)
+        ));
+        if failures.is_empty() && output.is_empty() {
+            cases.push_str("/>\n");
+            continue;
+        }
+        cases.push_str(">\n");
+        cases.push_str(&failures);
+        if !output.is_empty() {
+            cases.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                xml_escape(&output)
+            ));
+        }
+        cases.push_str("    </testcase>\n");
+    }
+    let suite = xml_escape(suite);
+    let tests = rules.len();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"{suite}\" tests=\"{tests}\" failures=\"{failing}\" errors=\"0\">\n  \
+         <testsuite name=\"{suite}\" tests=\"{tests}\" failures=\"{failing}\" errors=\"0\" skipped=\"0\">\n\
+         {cases}  </testsuite>\n\
+         </testsuites>\n"
+    )
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+/// Escape XML text/attribute content, dropping characters XML 1.0 forbids.
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// ── Shared ──────────────────────────────────────────────────────────────────
+
+fn diagnostics(report: &ValidationReport) -> impl Iterator<Item = &Diagnostic> {
+    report.errors.iter().chain(&report.warnings)
+}
+
+/// `file` relative to `base` with `/` separators; unchanged (but normalized)
+/// when it does not lie under `base`.
+fn relative_path(file: &Path, base: &Path) -> String {
+    let rel = file.strip_prefix(base).unwrap_or(file);
+    let s = rel.to_string_lossy().replace('\\', "/");
+    s.strip_prefix("./").map(str::to_string).unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn report(dir: &Path) -> ValidationReport {
+        let file = dir.join("tokens").join("color.json");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(
+            &file,
+            "{\n  \"accent\": {\n    \"value\": \"{missing}\"\n  }\n}\n",
+        )
+        .unwrap();
+        let diag =
+            |rule: Option<&str>, severity, instance: Option<&str>, message: &str| Diagnostic {
+                file: file.clone(),
+                token: Some("accent".into()),
+                rule_id: rule.map(str::to_string),
+                severity,
+                message: message.into(),
+                instance_path: instance.map(str::to_string),
+                schema_path: None,
+                fix: None,
+                span: None,
+            };
+        let mut r = ValidationReport::default();
+        r.push_error(diag(
+            Some("SPEC-001"),
+            Severity::Error,
+            None,
+            "Alias target not found: missing",
+        ));
+        r.push_error(diag(None, Severity::Error, Some("/value"), "\"x\" & <y>"));
+        r.push_warning(diag(
+            Some("SPEC-007"),
+            Severity::Warning,
+            None,
+            "does not roundtrip",
+        ));
+        r
+    }
+
+    #[test]
+    fn sarif_carries_rule_metadata_and_regions() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = sarif(&report(dir.path()), "1.2.3", dir.path());
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["version"], "1.2.3");
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules[0]["id"], "structural");
+        let spec001 = rules.iter().find(|r| r["id"] == "SPEC-001").unwrap();
+        assert_eq!(spec001["name"], "alias-target-exists");
+        assert_eq!(spec001["defaultConfiguration"]["level"], "error");
+        assert!(spec001["helpUri"]
+            .as_str()
+            .unwrap()
+            .ends_with("spec/token-format.md#alias-ref"));
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["ruleId"], "SPEC-001");
+        assert_eq!(
+            rules[results[0]["ruleIndex"].as_u64().unwrap() as usize]["id"],
+            "SPEC-001"
+        );
+        let loc = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(loc["artifactLocation"]["uri"], "tokens/color.json");
+        assert_eq!(loc["region"], json!({"startLine": 2, "startColumn": 3}));
+        // Token-relative structural path.
+        assert_eq!(
+            results[1]["locations"][0]["physicalLocation"]["region"],
+            json!({"startLine": 3, "startColumn": 5})
+        );
+        assert_eq!(results[2]["level"], "warning");
+    }
+
+    #[test]
+    fn junit_has_one_testcase_per_rule_and_a_failure_per_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let r = report(dir.path());
+        let xml = junit(&r, "design-data validate", false, dir.path());
+        let tests = catalog::report_rules(&r).len();
+        assert!(xml.contains(&format!(
+            "<testsuite name=\"design-data validate\" tests=\"{tests}\" failures=\"2\""
+        )));
+        assert!(xml.contains(
+            "<testcase name=\"SPEC-001 alias-target-exists\" classname=\"design-data.reference-integrity\">\n      <failure type=\"error\" message=\"Alias target not found: missing\">tokens/color.json:2:3 (accent)"
+        ));
+        assert!(xml.contains("message=\"&quot;x&quot; &amp; &lt;y&gt;\""));
+        assert!(xml.contains(
+            "<system-out>warning: tokens/color.json:2:3 (accent) does not roundtrip\n</system-out>"
+        ));
+        assert_eq!(xml.matches("<failure").count(), 2);
+
+        let strict = junit(&r, "s", true, dir.path());
+        assert_eq!(strict.matches("<failure").count(), 3);
+        assert_eq!(
+            relative_path(&PathBuf::from("./a/b.json"), Path::new("/x")),
+            "a/b.json"
+        );
+    }
+}
@@ -0,0 +1,566 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Autofix — apply the machine-applicable [`Fix`]es attached to diagnostics.
+//!
+//! Rules attach a [`Fix`] to a [`Diagnostic`] when the repair is mechanical
+//! (e.g. regenerating a duplicate uuid). [`plan`] collects those fixes from a
+//! report, grouped by file, drops exact duplicates, holds back renames unless
+//! asked for them (see [`Fix::renames`]), and sets aside any fix whose edits
+//! overlap an earlier fix's in the same file (same pointer, or one pointer
+//! inside the other). A fix may also edit other files ([`JsonEdit::file`]),
+//! as a rename does to rewrite references to the old key. [`apply`] then
+//! applies the fixes in order — a fix whose target no longer exists is skipped
+//! whole, across every file it touches — and writes each changed file back
+//! through the same pretty, order-preserving writer as [`crate::write`].
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::graph::{RelationshipRecord, TokenGraph, TokenRecord};
+use crate::naming;
+use crate::report::{Diagnostic, Fix, JsonEdit, ValidationReport};
+use crate::write::write_json_file;
+use crate::CoreError;
+
+/// One diagnostic's fix, with enough context to report it.
+#[derive(Debug, Clone)]
+pub struct PlannedFix {
+    pub rule_id: Option<String>,
+    pub token: Option<String>,
+    pub fix: Fix,
+}
+
+/// Fixes to apply, grouped by file, plus those set aside as conflicting and
+/// the renames that were not asked for.
+#[derive(Debug, Clone, Default)]
+pub struct FixPlan {
+    pub files: BTreeMap<PathBuf, Vec<PlannedFix>>,
+    pub conflicts: Vec<(PathBuf, PlannedFix)>,
+    pub held_back: Vec<(PathBuf, PlannedFix)>,
+}
+
+impl FixPlan {
+    /// Number of fixes that will be attempted.
+    pub fn len(&self) -> usize {
+        self.files.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// What [`apply`] did.
+#[derive(Debug, Clone, Default)]
+pub struct FixOutcome {
+    pub applied: Vec<(PathBuf, PlannedFix)>,
+    /// Fixes whose edits could not be applied, with the reason.
+    pub failed: Vec<(PathBuf, PlannedFix, String)>,
+    /// Files that were (or, on a dry run, would be) rewritten.
+    pub files_written: Vec<PathBuf>,
+}
+
+/// Collect the fixes attached to `report`'s errors and warnings. Fixes that
+/// rename a token key are only included with `renames`.
+pub fn plan(report: &ValidationReport, renames: bool) -> FixPlan {
+    let mut plan = FixPlan::default();
+    for d in report.errors.iter().chain(&report.warnings) {
+        let Some(fix) = &d.fix else {
+            continue;
+        };
+        let planned = planned(d, fix);
+        if fix.renames && !renames {
+            plan.held_back.push((d.file.clone(), planned));
+            continue;
+        }
+        // Two rules proposing the same edits (e.g. SPEC-012 and SPEC-013 both
+        // setting `deprecatedIn`) need it applied once.
+        if plan
+            .files
+            .get(&d.file)
+            .is_some_and(|accepted| accepted.iter().any(|a| a.fix.edits == fix.edits))
+        {
+            continue;
+        }
+        let overlaps = plan.files.iter().any(|(file, accepted)| {
+            accepted.iter().any(|a| {
+                a.fix.edits.iter().any(|e| {
+                    fix.edits.iter().any(|f| {
+                        target(file, e) == target(&d.file, f)
+                            && pointers_overlap(&e.pointer, &f.pointer)
+                    })
+                })
+            })
+        });
+        if overlaps {
+            plan.conflicts.push((d.file.clone(), planned));
+        } else {
+            plan.files.entry(d.file.clone()).or_default().push(planned);
+        }
+    }
+    plan.files.retain(|_, fixes| !fixes.is_empty());
+    plan
+}
+
+fn planned(d: &Diagnostic, fix: &Fix) -> PlannedFix {
+    PlannedFix {
+        rule_id: d.rule_id.clone(),
+        token: d.token.clone(),
+        fix: fix.clone(),
+    }
+}
+
+/// The file `edit` applies to, for a fix attached to a diagnostic in `file`.
+fn target<'a>(file: &'a Path, edit: &'a JsonEdit) -> &'a Path {
+    edit.file.as_deref().unwrap_or(file)
+}
+
+/// Apply `plan` fix by fix. With `dry_run`, edits are checked against the
+/// current file contents but nothing is written.
+pub fn apply(plan: &FixPlan, dry_run: bool) -> Result<FixOutcome, CoreError> {
+    let mut outcome = FixOutcome::default();
+    let mut docs: BTreeMap<PathBuf, Value> = BTreeMap::new();
+    let mut changed = BTreeSet::new();
+    for (file, fixes) in &plan.files {
+        for planned in fixes {
+            let mut candidates = BTreeMap::new();
+            for edit in &planned.fix.edits {
+                let path = target(file, edit);
+                if !docs.contains_key(path) {
+                    let text = std::fs::read_to_string(path)?;
+                    docs.insert(path.to_path_buf(), serde_json::from_str(&text)?);
+                }
+                candidates.entry(path).or_insert_with(|| docs[path].clone());
+            }
+            match planned.fix.edits.iter().try_for_each(|e| {
+                let doc = candidates.get_mut(target(file, e)).expect("loaded above");
+                apply_edit(doc, e)
+            }) {
+                Ok(()) => {
+                    for (path, doc) in candidates {
+                        changed.insert(path.to_path_buf());
+                        docs.insert(path.to_path_buf(), doc);
+                    }
+                    outcome.applied.push((file.clone(), planned.clone()));
+                }
+                Err(reason) => outcome.failed.push((file.clone(), planned.clone(), reason)),
+            }
+        }
+    }
+    for file in changed {
+        if !dry_run {
+            write_json_file(&file, &docs[&file])?;
+        }
+        outcome.files_written.push(file);
+    }
+    Ok(outcome)
+}
+
+/// Apply one edit. The pointer's parent must already exist; setting replaces
+/// or adds an object member (or replaces an array element), removing and
+/// renaming require the member to exist, and renaming onto an existing key
+/// fails.
+fn apply_edit(doc: &mut Value, edit: &JsonEdit) -> Result<(), String> {
+    let (parent_ptr, key) = split_pointer(&edit.pointer)
+        .ok_or_else(|| format!("cannot edit the document root ({:?})", edit.pointer))?;
+    let parent = doc
+        .pointer_mut(parent_ptr)
+        .ok_or_else(|| format!("{parent_ptr:?} not found"))?;
+    if let Some(to) = &edit.rename {
+        let Value::Object(map) = parent else {
+            return Err(format!("{parent_ptr:?} is not an object"));
+        };
+        if !map.contains_key(&key) {
+            return Err(format!("{:?} not found", edit.pointer));
+        }
+        if map.contains_key(to) {
+            return Err(format!("{to:?} already exists"));
+        }
+        *map = std::mem::take(map)
+            .into_iter()
+            .map(|(k, v)| if k == key { (to.clone(), v) } else { (k, v) })
+            .collect();
+        return Ok(());
+    }
+    match (parent, &edit.value) {
+        (Value::Object(map), Some(v)) => {
+            map.insert(key, materialize(v));
+        }
+        (Value::Object(map), None) => {
+            map.shift_remove(&key)
+                .ok_or_else(|| format!("{:?} not found", edit.pointer))?;
+        }
+        (Value::Array(items), value) => {
+            let idx: usize = key
+                .parse()
+                .ok()
+                .filter(|i| *i < items.len())
+                .ok_or_else(|| format!("{:?} not found", edit.pointer))?;
+            match value {
+                Some(v) => items[idx] = materialize(v),
+                None => {
+                    items.remove(idx);
+                }
+            }
+        }
+        _ => return Err(format!("{parent_ptr:?} is not an object or array")),
+    }
+    Ok(())
+}
+
+/// Split `/a/b~1c` into (`/a`, `b/c`); `None` for the root pointer.
+fn split_pointer(pointer: &str) -> Option<(&str, String)> {
+    let (parent, last) = pointer.rsplit_once('/')?;
+    Some((parent, last.replace("~1", "/").replace("~0", "~")))
+}
+
+/// True when `a` and `b` address the same member or one contains the other.
+fn pointers_overlap(a: &str, b: &str) -> bool {
+    let within = |outer: &str, inner: &str| {
+        inner
+            .strip_prefix(outer)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    };
+    within(a, b) || within(b, a)
+}
+
+/// Escape one reference token per RFC 6901.
+fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// JSON pointer to a token's object within its source file: `/<index>` for a
+/// cascade array element (graph key `<file>:<index>`), `/<name>` for a legacy
+/// object-format entry.
+pub fn token_pointer(t: &TokenRecord) -> String {
+    if is_cascade_key(&t.name, &t.file, t.index) {
+        format!("/{}", t.index)
+    } else {
+        format!("/{}", escape(&t.name))
+    }
+}
+
+/// JSON pointer to a relationship entry within its `relationships/*.json` array.
+pub fn relationship_pointer(r: &RelationshipRecord) -> String {
+    format!("/{}", r.index)
+}
+
+fn is_cascade_key(name: &str, file: &Path, index: usize) -> bool {
+    name == format!("{}:{}", file.display(), index)
+}
+
+/// Placeholder edit value that [`apply`] replaces with a freshly generated v4
+/// uuid. Rules propose it rather than generating the uuid themselves, so
+/// diagnostics (and `validate --format json`) stay deterministic.
+pub const NEW_UUID: &str = "$new-uuid";
+
+/// A [`Fix`] that gives the object at `pointer` a fresh `uuid` when applied.
+pub fn regenerate_uuid(pointer: &str) -> Fix {
+    set(
+        "regenerate uuid",
+        format!("{pointer}/uuid"),
+        Value::String(NEW_UUID.to_string()),
+    )
+}
+
+/// An edit's value as written: [`NEW_UUID`] becomes a fresh uuid.
+fn materialize(value: &Value) -> Value {
+    match value {
+        Value::String(s) if s == NEW_UUID => Value::String(uuid::Uuid::new_v4().to_string()),
+        _ => value.clone(),
+    }
+}
+
+/// A [`Fix`] that sets one member.
+pub fn set(description: impl Into<String>, pointer: String, value: Value) -> Fix {
+    Fix {
+        description: description.into(),
+        edits: vec![JsonEdit {
+            pointer,
+            value: Some(value),
+            rename: None,
+            file: None,
+        }],
+        renames: false,
+    }
+}
+
+/// Every string in a graph's tokens holding an inline `{key}` reference,
+/// by referenced key. Built once per run and shared by [`rename_token`].
+pub struct References<'a> {
+    by_key: HashMap<&'a str, Vec<Reference<'a>>>,
+}
+
+struct Reference<'a> {
+    token: &'a TokenRecord,
+    /// Pointer to the string within the token's file.
+    pointer: String,
+    text: &'a str,
+}
+
+impl<'a> References<'a> {
+    pub fn new(graph: &'a TokenGraph) -> Self {
+        let mut tokens: Vec<&TokenRecord> = graph.tokens.values().collect();
+        tokens.sort_by(|a, b| (&a.file, a.index).cmp(&(&b.file, b.index)));
+        let mut by_key = HashMap::new();
+        for token in tokens {
+            collect_references(token, &token.raw, token_pointer(token), &mut by_key);
+        }
+        Self { by_key }
+    }
+}
+
+fn collect_references<'a>(
+    token: &'a TokenRecord,
+    value: &'a Value,
+    pointer: String,
+    by_key: &mut HashMap<&'a str, Vec<Reference<'a>>>,
+) {
+    match value {
+        Value::String(text) => {
+            let mut keys: Vec<&str> = naming::inline_refs(text).map(|(_, key)| key).collect();
+            keys.sort_unstable();
+            keys.dedup();
+            for key in keys {
+                by_key.entry(key).or_default().push(Reference {
+                    token,
+                    pointer: pointer.clone(),
+                    text,
+                });
+            }
+        }
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .for_each(|(i, v)| collect_references(token, v, format!("{pointer}/{i}"), by_key)),
+        Value::Object(map) => map.iter().for_each(|(k, v)| {
+            collect_references(token, v, format!("{pointer}/{}", escape(k)), by_key)
+        }),
+        _ => {}
+    }
+}
+
+/// A [`Fix`] that renames legacy token `t` to the key `to` in place in its
+/// object-format file, and rewrites every `{old}` reference to it in
+/// `references`. `None` for a cascade token, whose name lives in its name
+/// object.
+pub fn rename_token(references: &References<'_>, t: &TokenRecord, to: &str) -> Option<Fix> {
+    if is_cascade_key(&t.name, &t.file, t.index) {
+        return None;
+    }
+    let mut edits = vec![JsonEdit {
+        pointer: token_pointer(t),
+        value: None,
+        rename: Some(to.to_string()),
+        file: None,
+    }];
+    let referrers = references.by_key.get(t.name.as_str()).into_iter().flatten();
+    edits.extend(
+        referrers
+            .filter(|r| r.token.name != t.name)
+            .map(|r| JsonEdit {
+                pointer: r.pointer.clone(),
+                value: Some(Value::String(naming::replace_inline_refs(r.text, |key| {
+                    (key == t.name).then(|| format!("{{{to}}}"))
+                }))),
+                rename: None,
+                file: (r.token.file != t.file).then(|| r.token.file.clone()),
+            }),
+    );
+    Some(Fix {
+        description: format!("rename to {to}"),
+        edits,
+        renames: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::report::Severity;
+
+    fn diag(file: &Path, fix: Fix) -> Diagnostic {
+        Diagnostic {
+            file: file.to_path_buf(),
+            token: None,
+            rule_id: Some("SPEC-004".into()),
+            severity: Severity::Error,
+            message: String::new(),
+            instance_path: None,
+            schema_path: None,
+            fix: Some(fix),
+            span: None,
+        }
+    }
+
+    #[test]
+    fn applies_edits_in_order_and_preserves_key_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.tokens.json");
+        std::fs::write(
+            &file,
+            r#"[{"name":{"property":"x"},"uuid":"dup","value":"1px"},{"uuid":"dup"}]"#,
+        )
+        .unwrap();
+        let mut report = ValidationReport::default();
+        report.push_error(diag(
+            &file,
+            set("regenerate uuid", "/1/uuid".into(), json!("new")),
+        ));
+        report.push_error(diag(
+            &file,
+            set(
+                "mark deprecated",
+                "/0/lifecycle".into(),
+                json!({"deprecatedIn": "1.0.0"}),
+            ),
+        ));
+
+        let plan = plan(&report, false);
+        assert_eq!(plan.len(), 2);
+        let dry = apply(&plan, true).unwrap();
+        assert_eq!(dry.applied.len(), 2);
+        assert!(std::fs::read_to_string(&file)
+            .unwrap()
+            .contains("\"dup\"}]"));
+
+        apply(&plan, false).unwrap();
+        let text = std::fs::read_to_string(&file).unwrap();
+        let v: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(v[1]["uuid"], "new");
+        assert_eq!(
+            v[0].as_object().unwrap().keys().collect::<Vec<_>>(),
+            vec!["name", "uuid", "value", "lifecycle"]
+        );
+        assert!(text.ends_with("]\n"));
+    }
+
+    #[test]
+    fn new_uuid_placeholder_is_generated_on_apply() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.tokens.json");
+        std::fs::write(&file, r#"[{"uuid":"dup"},{"uuid":"dup"}]"#).unwrap();
+        let mut report = ValidationReport::default();
+        report.push_error(diag(&file, regenerate_uuid("/1")));
+
+        apply(&plan(&report, false), false).unwrap();
+        let v: Value = serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+        let fresh = v[1]["uuid"].as_str().unwrap();
+        assert!(uuid::Uuid::parse_str(fresh).is_ok(), "{fresh}");
+        assert_eq!(v[0]["uuid"], "dup");
+    }
+
+    #[test]
+    fn overlapping_fixes_conflict_and_missing_targets_fail() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.json");
+        std::fs::write(&file, r#"{"tok":{"uuid":"u"}}"#).unwrap();
+        let mut report = ValidationReport::default();
+        report.push_error(diag(&file, set("a", "/tok/uuid".into(), json!("1"))));
+        report.push_error(diag(&file, set("b", "/tok".into(), json!({}))));
+        report.push_error(diag(&file, set("c", "/gone/uuid".into(), json!("2"))));
+
+        let plan = plan(&report, false);
+        assert_eq!(plan.conflicts.len(), 1);
+        assert_eq!(plan.conflicts[0].1.fix.description, "b");
+        let outcome = apply(&plan, false).unwrap();
+        assert_eq!(outcome.applied.len(), 1);
+        assert_eq!(outcome.failed.len(), 1);
+        assert_eq!(outcome.failed[0].1.fix.description, "c");
+    }
+
+    #[test]
+    fn renames_keep_key_order_and_rewrite_references_in_every_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.json");
+        let b = dir.path().join("b.json");
+        std::fs::write(
+            &a,
+            r#"{"first":{"$schema":"d","value":"1px"},"old-name":{"value":"2px"},"last":{"value":"{old-name}"}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &b,
+            r#"{"border":{"$schema":"d","value":"{old-name} solid {first}","sets":{"dark":{"value":"{old-name}"}}}}"#,
+        )
+        .unwrap();
+        let graph = TokenGraph::from_json_dir(dir.path()).unwrap();
+        let references = References::new(&graph);
+        let fix = rename_token(&references, &graph.tokens["old-name"], "new-name").unwrap();
+        assert_eq!(fix.edits.len(), 4);
+
+        let mut report = ValidationReport::default();
+        report.push_error(diag(&a, fix));
+        assert!(plan(&report, false).is_empty());
+        let outcome = apply(&plan(&report, true), false).unwrap();
+        assert_eq!(outcome.files_written, vec![a.clone(), b.clone()]);
+
+        let v: Value = serde_json::from_str(&std::fs::read_to_string(&a).unwrap()).unwrap();
+        assert_eq!(
+            v.as_object().unwrap().keys().collect::<Vec<_>>(),
+            vec!["first", "new-name", "last"]
+        );
+        assert_eq!(v["new-name"]["value"], "2px");
+        assert_eq!(v["last"]["value"], "{new-name}");
+        let v: Value = serde_json::from_str(&std::fs::read_to_string(&b).unwrap()).unwrap();
+        assert_eq!(v["border"]["value"], "{new-name} solid {first}");
+        assert_eq!(v["border"]["sets"]["dark"]["value"], "{new-name}");
+    }
+
+    #[test]
+    fn a_fix_failing_in_one_file_leaves_every_file_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.json");
+        let b = dir.path().join("b.json");
+        std::fs::write(&a, r#"{"tok":{"value":"1px"}}"#).unwrap();
+        std::fs::write(&b, r#"{"other":{"value":"{tok}"}}"#).unwrap();
+        let mut fix = set("a", "/tok/value".into(), json!("2px"));
+        fix.edits.push(JsonEdit {
+            pointer: "/gone/value".into(),
+            value: Some(json!("{tok}")),
+            rename: None,
+            file: Some(b.clone()),
+        });
+        let mut report = ValidationReport::default();
+        report.push_error(diag(&a, fix));
+
+        let outcome = apply(&plan(&report, false), false).unwrap();
+        assert_eq!(outcome.failed.len(), 1);
+        assert!(outcome.files_written.is_empty());
+        assert!(std::fs::read_to_string(&a).unwrap().contains("1px"));
+    }
+
+    #[test]
+    fn pointers_escape_legacy_names() {
+        let t = TokenRecord {
+            name: "a/b~c".into(),
+            file: PathBuf::from("t.json"),
+            index: 0,
+            schema_url: None,
+            uuid: None,
+            alias_target: None,
+            raw: json!({}),
+            layer: crate::graph::Layer::Foundation,
+            span: None,
+        };
+        assert_eq!(token_pointer(&t), "/a~1b~0c");
+        let cascade = TokenRecord {
+            name: "t.json:3".into(),
+            index: 3,
+            ..t
+        };
+        assert_eq!(token_pointer(&cascade), "/3");
+    }
+}
@@ -0,0 +1,486 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Incremental validation for long-lived callers (the TUI, editor integrations).
+//!
+//! An [`IncrementalValidator`] produces the same report as
+//! [`super::validate_all_with_rule_config`], but keeps its work between runs:
+//!
+//! - **Layer 1** results per token file, reused while the file's size and
+//!   mtime are unchanged (the same freshness fingerprint [`crate::cache`] uses).
+//! - **The token graph**, rebuilt only when some input file changed.
+//! - **Each rule's raw diagnostics**, keyed by content hashes of the
+//!   [`RuleInputs`] the rule declares. Editing a component file re-runs the
+//!   component rules but not the token-only ones; touching a file without
+//!   changing its content re-runs nothing.
+//!
+//! Changed files and stale rules run in parallel. `[validate]` levels and
+//! token spans are applied after the cache, so a rule configuration change
+//! never forces a rule to re-run.
+//!
+//! A validator assumes one [`SchemaRegistry`]; create a new one (or call
+//! [`IncrementalValidator::invalidate`]) when the schemas change.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::discovery::discover_json_files;
+use crate::graph::TokenGraph;
+use crate::report::{Diagnostic, ValidationReport};
+use crate::schema::SchemaRegistry;
+use crate::validate::relational::relational_report;
+use crate::validate::rule::RuleInputs;
+use crate::validate::rule_config::RuleConfig;
+use crate::validate::{load_graph, par_map, rules, structural};
+use crate::CoreError;
+
+/// The paths one validator covers; the same inputs as
+/// [`super::validate_all_with_rule_config`].
+#[derive(Debug, Clone, Default)]
+pub struct ValidationInputs {
+    pub data_path: PathBuf,
+    pub mode_sets_path: Option<PathBuf>,
+    pub components_path: Option<PathBuf>,
+    pub names_dir: Option<PathBuf>,
+    pub guidelines_path: Option<PathBuf>,
+    pub relationships_path: Option<PathBuf>,
+}
+
+impl ValidationInputs {
+    pub fn new(data_path: impl Into<PathBuf>) -> Self {
+        Self {
+            data_path: data_path.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Catalog and sidecar directories that feed the graph.
+    fn catalog_dirs(&self) -> impl Iterator<Item = &Path> {
+        [
+            &self.mode_sets_path,
+            &self.components_path,
+            &self.names_dir,
+            &self.guidelines_path,
+            &self.relationships_path,
+        ]
+        .into_iter()
+        .flatten()
+        .map(PathBuf::as_path)
+    }
+}
+
+/// What the last [`IncrementalValidator::validate`] call recomputed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IncrementalStats {
+    /// Token files schema-checked.
+    pub files_checked: usize,
+    /// Token files whose Layer 1 results were reused.
+    pub files_reused: usize,
+    pub graph_rebuilt: bool,
+    pub rules_run: usize,
+    pub rules_reused: usize,
+}
+
+/// Size and mtime (nanoseconds since the epoch) of a file.
+type FileStamp = (u64, u128);
+
+struct CachedFile {
+    stamp: FileStamp,
+    report: ValidationReport,
+}
+
+struct CachedGraph {
+    /// Hash of every input file's stamp.
+    stamp: u64,
+    graph: TokenGraph,
+    manifest: Option<serde_json::Value>,
+    /// Content hash per [`RuleInputs::EACH`] entry, except naming exceptions.
+    sections: HashMap<RuleInputs, u64>,
+}
+
+struct CachedRule {
+    /// Combined hash of the rule's declared inputs.
+    key: u64,
+    raw: Vec<Diagnostic>,
+}
+
+/// Validation state kept between runs. See the module docs.
+pub struct IncrementalValidator {
+    inputs: ValidationInputs,
+    files: HashMap<PathBuf, CachedFile>,
+    graph: Option<CachedGraph>,
+    rules: HashMap<String, CachedRule>,
+    stats: IncrementalStats,
+}
+
+impl IncrementalValidator {
+    pub fn new(inputs: ValidationInputs) -> Self {
+        Self {
+            inputs,
+            files: HashMap::new(),
+            graph: None,
+            rules: HashMap::new(),
+            stats: IncrementalStats::default(),
+        }
+    }
+
+    pub fn inputs(&self) -> &ValidationInputs {
+        &self.inputs
+    }
+
+    /// What the last run recomputed.
+    pub fn stats(&self) -> IncrementalStats {
+        self.stats
+    }
+
+    /// The graph built by the last run.
+    pub fn graph(&self) -> Option<&TokenGraph> {
+        self.graph.as_ref().map(|g| &g.graph)
+    }
+
+    /// Drop every cached result; the next run re-validates from scratch.
+    pub fn invalidate(&mut self) {
+        self.files.clear();
+        self.graph = None;
+        self.rules.clear();
+    }
+
+    /// Validate, reusing whatever is still fresh from the previous run.
+    pub fn validate(
+        &mut self,
+        registry: &SchemaRegistry,
+        naming_exceptions: &HashSet<String>,
+        config: &RuleConfig,
+    ) -> Result<ValidationReport, CoreError> {
+        self.stats = IncrementalStats::default();
+        let token_files = stamped(&self.inputs.data_path)?;
+        let mut report = self.structural(&token_files, registry)?;
+
+        let mut stamp = DefaultHasher::new();
+        token_files.hash(&mut stamp);
+        for dir in self.inputs.catalog_dirs().filter(|d| d.is_dir()) {
+            stamped(dir)?.hash(&mut stamp);
+        }
+        let stamp = stamp.finish();
+        if self.graph.as_ref().is_none_or(|g| g.stamp != stamp) {
+            self.graph = Some(self.load(stamp)?);
+            self.stats.graph_rebuilt = true;
+        }
+        let Some(cached) = &self.graph else {
+            unreachable!("graph loaded above");
+        };
+
+        let mut sections = cached.sections.clone();
+        let mut exceptions: Vec<&String> = naming_exceptions.iter().collect();
+        exceptions.sort();
+        sections.insert(RuleInputs::NAMING_EXCEPTIONS, hash_json(&exceptions));
+
+        // A custom rule's key also covers its declaration, so editing the
+        // rule file re-runs it.
+        let definitions: HashMap<&str, u64> = config
+            .custom
+            .iter()
+            .map(|r| (r.spec().id.as_str(), r.fingerprint()))
+            .collect();
+        let builtins = rules::default_rules();
+        let active: Vec<_> = rules::active_rules(&builtins, config)
+            .into_iter()
+            .map(|r| {
+                let mut key = DefaultHasher::new();
+                rule_key(r.inputs(), &sections).hash(&mut key);
+                definitions.get(r.id()).hash(&mut key);
+                (r, key.finish())
+            })
+            .collect();
+        let stale: Vec<_> = active
+            .iter()
+            .filter(|(r, key)| self.rules.get(r.id()).is_none_or(|c| c.key != *key))
+            .collect();
+        let ctx = rules::context(&cached.graph, naming_exceptions, cached.manifest.as_ref());
+        let fresh = par_map(&stale, |(r, _)| r.validate(&ctx));
+        self.stats.rules_run = stale.len();
+        self.stats.rules_reused = active.len() - stale.len();
+        for ((r, key), raw) in stale.iter().zip(fresh) {
+            self.rules
+                .insert(r.id().to_string(), CachedRule { key: *key, raw });
+        }
+
+        let relational = relational_report(active.iter().flat_map(|(r, _)| {
+            self.rules[r.id()]
+                .raw
+                .iter()
+                .cloned()
+                .filter_map(|d| rules::finish_diagnostic(&cached.graph, config, d))
+        }));
+        report.merge(relational);
+        Ok(report)
+    }
+
+    /// Layer 1 over `files`, re-checking only those whose stamp changed.
+    fn structural(
+        &mut self,
+        files: &[(PathBuf, FileStamp)],
+        registry: &SchemaRegistry,
+    ) -> Result<ValidationReport, CoreError> {
+        let changed: Vec<&(PathBuf, FileStamp)> = files
+            .iter()
+            .filter(|(path, stamp)| self.files.get(path).is_none_or(|c| c.stamp != *stamp))
+            .collect();
+        let checked = par_map(&changed, |(path, _)| {
+            structural::validate_token_file(path, registry)
+        });
+        self.stats.files_checked = changed.len();
+        self.stats.files_reused = files.len() - changed.len();
+        for ((path, stamp), file_report) in changed.into_iter().zip(checked) {
+            self.files.insert(
+                path.clone(),
+                CachedFile {
+                    stamp: *stamp,
+                    report: file_report?,
+                },
+            );
+        }
+        let live: HashSet<&PathBuf> = files.iter().map(|(p, _)| p).collect();
+        self.files.retain(|p, _| live.contains(p));
+
+        let mut report = ValidationReport {
+            valid: true,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        for (path, _) in files {
+            report.merge(self.files[path].report.clone());
+        }
+        Ok(report)
+    }
+
+    fn load(&self, stamp: u64) -> Result<CachedGraph, CoreError> {
+        let i = &self.inputs;
+        let (graph, manifest) = load_graph(
+            &i.data_path,
+            i.mode_sets_path.as_deref(),
+            i.components_path.as_deref(),
+            i.names_dir.as_deref(),
+            i.guidelines_path.as_deref(),
+            i.relationships_path.as_deref(),
+        )?;
+        // Spans and the derived indexes follow from the fields hashed here.
+        let mut keys: Vec<&String> = graph.tokens.keys().collect();
+        keys.sort();
+        let tokens: Vec<_> = keys
+            .into_iter()
+            .map(|k| {
+                let t = &graph.tokens[k];
+                (k, &t.file, t.index, &t.layer, &t.raw)
+            })
+            .collect();
+        let sections = HashMap::from([
+            (RuleInputs::TOKENS, hash_json(&tokens)),
+            (RuleInputs::MODE_SETS, hash_json(&graph.mode_sets)),
+            (RuleInputs::COMPONENTS, hash_json(&graph.components)),
+            (RuleInputs::GUIDELINES, hash_json(&graph.guidelines)),
+            (RuleInputs::FIELDS, hash_json(&graph.fields)),
+            (RuleInputs::RELATIONSHIPS, hash_json(&graph.relationships)),
+            (
+                RuleInputs::MANIFEST,
+                hash_json(&(&manifest, &graph.manifest)),
+            ),
+        ]);
+        Ok(CachedGraph {
+            stamp,
+            graph,
+            manifest,
+            sections,
+        })
+    }
+}
+
+/// `*.json` files under `root` with their stamps, sorted by path.
+fn stamped(root: &Path) -> Result<Vec<(PathBuf, FileStamp)>, CoreError> {
+    discover_json_files(root)?
+        .into_iter()
+        .map(|path| {
+            let meta = std::fs::metadata(&path)?;
+            let mtime = meta
+                .modified()
+                .ok()
+                .and_then(|m| m.duration_since(std::time::UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_nanos());
+            Ok((path, (meta.len(), mtime)))
+        })
+        .collect()
+}
+
+fn rule_key(inputs: RuleInputs, sections: &HashMap<RuleInputs, u64>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for input in RuleInputs::EACH {
+        if inputs.contains(input) {
+            sections.get(&input).hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// Hash of `value`'s JSON serialization, streamed into the hasher.
+fn hash_json<T: Serialize + ?Sized>(value: &T) -> u64 {
+    struct HashWriter(DefaultHasher);
+
+    impl std::io::Write for HashWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.write(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = HashWriter(DefaultHasher::new());
+    // Serializing graph records into a hasher cannot fail.
+    let _ = serde_json::to_writer(&mut writer, value);
+    writer.0.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn registry() -> SchemaRegistry {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../packages/tokens/schemas");
+        SchemaRegistry::load_legacy_token_schemas(&root).unwrap()
+    }
+
+    fn write(path: &Path, value: serde_json::Value) {
+        std::fs::write(path, serde_json::to_string_pretty(&value).unwrap()).unwrap();
+    }
+
+    fn alias(target: &str, uuid: &str) -> serde_json::Value {
+        json!({
+            "$schema": "https://opensource.adobe.com/spectrum-design-data/schemas/token-types/alias.json",
+            "value": format!("{{{target}}}"),
+            "uuid": uuid
+        })
+    }
+
+    /// Rules iterate the graph's hash maps, so diagnostic order varies
+    /// between graph loads; compare as sorted lists.
+    fn as_json(report: &ValidationReport) -> (bool, Vec<String>, Vec<String>) {
+        let sorted = |ds: &[Diagnostic]| {
+            let mut out: Vec<String> = ds
+                .iter()
+                .map(|d| serde_json::to_string(d).unwrap())
+                .collect();
+            out.sort();
+            out
+        };
+        (
+            report.valid,
+            sorted(&report.errors),
+            sorted(&report.warnings),
+        )
+    }
+
+    #[test]
+    fn reuses_unchanged_files_and_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let tokens = dir.path().join("tokens");
+        let components = dir.path().join("components");
+        std::fs::create_dir_all(&tokens).unwrap();
+        std::fs::create_dir_all(&components).unwrap();
+        write(
+            &tokens.join("a.json"),
+            json!({"gap": alias("missing", "00000000-0000-4000-8000-000000000001")}),
+        );
+        write(
+            &tokens.join("b.json"),
+            json!({"pad": alias("gap", "00000000-0000-4000-8000-000000000002")}),
+        );
+        write(
+            &components.join("button.json"),
+            json!({"name": "button", "meta": {"category": "actions"}}),
+        );
+        let registry = registry();
+        let exceptions = HashSet::new();
+        let config = RuleConfig::default();
+        let full = || {
+            crate::validate::validate_all_with_rule_config(
+                &tokens,
+                &registry,
+                &exceptions,
+                None,
+                Some(&components),
+                None,
+                None,
+                None,
+                &config,
+            )
+            .unwrap()
+        };
+        let mut v = IncrementalValidator::new(ValidationInputs {
+            components_path: Some(components.clone()),
+            ..ValidationInputs::new(&tokens)
+        });
+
+        let first = v.validate(&registry, &exceptions, &config).unwrap();
+        assert_eq!(as_json(&first), as_json(&full()));
+        let total_rules = v.stats().rules_run;
+        assert_eq!(v.stats().files_checked, 2);
+        assert!(v.stats().graph_rebuilt);
+
+        // Nothing changed: everything is reused.
+        let again = v.validate(&registry, &exceptions, &config).unwrap();
+        assert_eq!(as_json(&again), as_json(&first));
+        assert_eq!(
+            v.stats(),
+            IncrementalStats {
+                files_checked: 0,
+                files_reused: 2,
+                graph_rebuilt: false,
+                rules_run: 0,
+                rules_reused: total_rules,
+            }
+        );
+
+        // A component edit re-runs only rules that read components.
+        write(
+            &components.join("button.json"),
+            json!({"name": "button", "meta": {"category": "inputs"}}),
+        );
+        let after_component = v.validate(&registry, &exceptions, &config).unwrap();
+        assert_eq!(as_json(&after_component), as_json(&full()));
+        let component_rules = rules::default_rules()
+            .iter()
+            .filter(|r| r.inputs().contains(RuleInputs::COMPONENTS))
+            .count();
+        assert_eq!(v.stats().files_checked, 0);
+        assert_eq!(v.stats().rules_run, component_rules);
+
+        // A token fix re-checks that file and clears its diagnostic.
+        write(
+            &tokens.join("a.json"),
+            json!({"gap": alias("pad", "00000000-0000-4000-8000-000000000001")}),
+        );
+        let after_fix = v.validate(&registry, &exceptions, &config).unwrap();
+        assert_eq!(as_json(&after_fix), as_json(&full()));
+        assert_eq!(v.stats().files_checked, 1);
+        assert_eq!(v.stats().files_reused, 1);
+        assert!(!after_fix
+            .errors
+            .iter()
+            .any(|d| d.message.contains("missing")));
+    }
+}
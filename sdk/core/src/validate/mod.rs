@@ -10,9 +10,16 @@
 
 //! Structural (Layer 1) and relational (Layer 2) validation.
 
+pub mod baseline;
+pub mod catalog;
+pub mod custom;
 pub mod dataset_structure;
+pub mod export;
+pub mod fix;
+pub mod incremental;
 pub mod relational;
 pub mod rule;
+pub mod rule_config;
 pub mod rules;
 pub mod structural;
 
@@ -22,6 +29,7 @@ use std::path::Path;
 use crate::graph::TokenGraph;
 use crate::report::{Severity, ValidationReport};
 use crate::schema::SchemaRegistry;
+use crate::validate::rule_config::RuleConfig;
 use crate::CoreError;
 
 /// Core validation pipeline is available (schemas + engine compile).
@@ -29,6 +37,47 @@ pub fn engine_ready() -> bool {
     true
 }
 
+/// Map `f` over `items` on scoped worker threads, returning results in input
+/// order. Workers claim items one at a time, so a few slow items (e.g. rules
+/// over the whole graph) do not serialize behind each other. Runs inline on
+/// `wasm32`, which has no threads, and for fewer than two items.
+pub(crate) fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let workers = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(items.len());
+        if workers > 1 {
+            let next = AtomicUsize::new(0);
+            let mut indexed: Vec<(usize, R)> = std::thread::scope(|scope| {
+                let handles: Vec<_> = (0..workers)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            let mut done = Vec::new();
+                            loop {
+                                let i = next.fetch_add(1, Ordering::Relaxed);
+                                let Some(item) = items.get(i) else {
+                                    return done;
+                                };
+                                done.push((i, f(item)));
+                            }
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                    .collect()
+            });
+            indexed.sort_by_key(|(i, _)| *i);
+            return indexed.into_iter().map(|(_, r)| r).collect();
+        }
+    }
+    items.iter().map(f).collect()
+}
+
 /// Run structural validation then relational rules on legacy token JSON under `data_path`.
 pub fn validate_all(
     data_path: &Path,
@@ -119,8 +168,64 @@ pub fn validate_all_with_full_options(
     names_dir: Option<&Path>,
     guidelines_path: Option<&Path>,
     relationships_path: Option<&Path>,
+) -> Result<ValidationReport, CoreError> {
+    validate_all_with_rule_config(
+        data_path,
+        schema_registry,
+        naming_exceptions,
+        mode_sets_path,
+        components_path,
+        names_dir,
+        guidelines_path,
+        relationships_path,
+        &RuleConfig::default(),
+    )
+}
+
+/// [`validate_all_with_full_options`] with per-rule levels and path-scoped
+/// overrides from the `[validate]` table of `.design-data.toml`, applied to
+/// the relational rules (see [`relational::validate_relational_with_config`]).
+#[allow(clippy::too_many_arguments)]
+pub fn validate_all_with_rule_config(
+    data_path: &Path,
+    schema_registry: &SchemaRegistry,
+    naming_exceptions: &HashSet<String>,
+    mode_sets_path: Option<&Path>,
+    components_path: Option<&Path>,
+    names_dir: Option<&Path>,
+    guidelines_path: Option<&Path>,
+    relationships_path: Option<&Path>,
+    rule_config: &RuleConfig,
 ) -> Result<ValidationReport, CoreError> {
     let mut report = structural::validate_structural(data_path, schema_registry)?;
+    let (graph, manifest) = load_graph(
+        data_path,
+        mode_sets_path,
+        components_path,
+        names_dir,
+        guidelines_path,
+        relationships_path,
+    )?;
+    let rel = relational::validate_relational_with_config(
+        &graph,
+        naming_exceptions,
+        manifest.as_ref(),
+        rule_config,
+    );
+    report.merge(rel);
+    Ok(report)
+}
+
+/// The token graph (with catalogs attached) and `manifest.json` document that
+/// relational rules run over.
+pub(crate) fn load_graph(
+    data_path: &Path,
+    mode_sets_path: Option<&Path>,
+    components_path: Option<&Path>,
+    names_dir: Option<&Path>,
+    guidelines_path: Option<&Path>,
+    relationships_path: Option<&Path>,
+) -> Result<(TokenGraph, Option<serde_json::Value>), CoreError> {
     let mut graph = TokenGraph::from_json_dir_with_names_and_catalogs(
         data_path,
         names_dir,
@@ -150,9 +255,7 @@ pub fn validate_all_with_full_options(
     } else {
         None
     };
-    let rel = relational::validate_relational(&graph, naming_exceptions, manifest.as_ref());
-    report.merge(rel);
-    Ok(report)
+    Ok((graph, manifest))
 }
 
 /// Validate a whole **dataset** rooted at `dataset_root` (or its `tokens/` dir).
@@ -172,13 +275,37 @@ pub fn validate_dataset(
     mode_sets_path: Option<&Path>,
     components_path: Option<&Path>,
     names_dir: Option<&Path>,
+) -> Result<ValidationReport, CoreError> {
+    validate_dataset_with_config(
+        dataset_root,
+        schema_registry,
+        naming_exceptions,
+        mode_sets_path,
+        components_path,
+        names_dir,
+        &RuleConfig::default(),
+    )
+}
+
+/// [`validate_dataset`] under a `[validate]` rule configuration. The levels
+/// apply to the SPEC-044 structure diagnostics as well as the relational rules;
+/// a missing `tokens/` directory still ends validation early even when SPEC-044
+/// is turned `off`, since there is no graph to build.
+pub fn validate_dataset_with_config(
+    dataset_root: &Path,
+    schema_registry: &SchemaRegistry,
+    naming_exceptions: &HashSet<String>,
+    mode_sets_path: Option<&Path>,
+    components_path: Option<&Path>,
+    names_dir: Option<&Path>,
+    rule_config: &RuleConfig,
 ) -> Result<ValidationReport, CoreError> {
     let root = dataset_structure::resolve_dataset_root(dataset_root);
 
     let mut report = ValidationReport::default();
     let structure = dataset_structure::check_dataset_structure(&root);
     let tokens_missing = structure.iter().any(|d| d.severity == Severity::Error);
-    for d in structure {
+    for d in structure.into_iter().filter_map(|d| rule_config.apply(d)) {
         match d.severity {
             Severity::Error => report.push_error(d),
             _ => report.push_warning(d),
@@ -195,7 +322,7 @@ pub fn validate_dataset(
     let tokens_dir = root.join("tokens");
     let guidelines_dir = root.join("guidelines");
     let relationships_dir = root.join("relationships");
-    let rest = validate_all_with_full_options(
+    let rest = validate_all_with_rule_config(
         &tokens_dir,
         schema_registry,
         naming_exceptions,
@@ -204,6 +331,7 @@ pub fn validate_dataset(
         names_dir,
         Some(&guidelines_dir),
         Some(&relationships_dir),
+        rule_config,
     )?;
     report.merge(rest);
     Ok(report)
@@ -246,6 +374,8 @@ pub fn validate_catalog_dir(
                     message: format!("invalid JSON: {e}"),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 continue;
             }
@@ -260,6 +390,8 @@ pub fn validate_catalog_dir(
                 message: format!("{dir_name} schema validation: {err}"),
                 instance_path: None,
                 schema_path: None,
+                fix: None,
+                span: None,
             });
         }
     }
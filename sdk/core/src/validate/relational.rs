@@ -14,6 +14,7 @@ use std::collections::HashSet;
 
 use crate::graph::TokenGraph;
 use crate::report::{Diagnostic, Severity, ValidationReport};
+use crate::validate::rule_config::RuleConfig;
 use crate::validate::rules;
 
 /// Run all relational rules; merges errors and warnings by severity on each diagnostic.
@@ -24,6 +25,31 @@ pub fn validate_relational(
     graph: &TokenGraph,
     naming_exceptions: &HashSet<String>,
     manifest: Option<&serde_json::Value>,
+) -> ValidationReport {
+    validate_relational_with_config(graph, naming_exceptions, manifest, &RuleConfig::default())
+}
+
+/// [`validate_relational`] with per-rule levels from a `[validate]` table
+/// (see [`crate::validate::rule_config`]). Levels are applied as each rule's
+/// diagnostics are produced, so a rule re-levelled to `error` fails the report
+/// and one turned `off` never reaches it.
+pub fn validate_relational_with_config(
+    graph: &TokenGraph,
+    naming_exceptions: &HashSet<String>,
+    manifest: Option<&serde_json::Value>,
+    config: &RuleConfig,
+) -> ValidationReport {
+    relational_report(rules::run_rules_with_config(
+        graph,
+        naming_exceptions,
+        manifest,
+        config,
+    ))
+}
+
+/// Sort rule diagnostics into a report by severity.
+pub(crate) fn relational_report(
+    diagnostics: impl IntoIterator<Item = Diagnostic>,
 ) -> ValidationReport {
     let mut report = ValidationReport {
         valid: true,
@@ -31,7 +57,7 @@ pub fn validate_relational(
         warnings: Vec::new(),
     };
 
-    for d in rules::run_rules(graph, naming_exceptions, manifest) {
+    for d in diagnostics {
         match d.severity {
             Severity::Error => report.push_error(d),
             Severity::Warning => report.push_warning(d),
@@ -8,7 +8,7 @@
 // OF ANY KIND, either express or implied. See the License for the specific language
 // governing permissions and limitations under the License.
 
-//! Layer 2 rule trait (catalog ids).
+//! Layer 2 rule trait (catalog ids and declarative custom rules).
 
 use std::collections::HashSet;
 
@@ -30,9 +30,59 @@ pub struct ValidationContext<'a> {
     pub manifest: Option<&'a serde_json::Value>,
 }
 
+/// The parts of a [`ValidationContext`] a rule reads. Incremental validation
+/// ([`crate::validate::incremental`]) re-runs a rule only when one of these
+/// changed since its last run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleInputs(u8);
+
+impl RuleInputs {
+    pub const TOKENS: Self = Self(1);
+    /// Inline and catalog mode sets (`graph.mode_sets`).
+    pub const MODE_SETS: Self = Self(1 << 1);
+    pub const COMPONENTS: Self = Self(1 << 2);
+    pub const GUIDELINES: Self = Self(1 << 3);
+    pub const FIELDS: Self = Self(1 << 4);
+    pub const RELATIONSHIPS: Self = Self(1 << 5);
+    /// `ctx.manifest` and `graph.manifest`.
+    pub const MANIFEST: Self = Self(1 << 6);
+    pub const NAMING_EXCEPTIONS: Self = Self(1 << 7);
+    pub const ALL: Self = Self(u8::MAX);
+
+    /// Every single input, in bit order.
+    pub const EACH: [Self; 8] = [
+        Self::TOKENS,
+        Self::MODE_SETS,
+        Self::COMPONENTS,
+        Self::GUIDELINES,
+        Self::FIELDS,
+        Self::RELATIONSHIPS,
+        Self::MANIFEST,
+        Self::NAMING_EXCEPTIONS,
+    ];
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for RuleInputs {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 /// Catalog-backed validation rule.
 pub trait ValidationRule: Send + Sync {
-    fn id(&self) -> &'static str;
-    fn name(&self) -> &'static str;
+    fn id(&self) -> &str;
+    fn name(&self) -> &str;
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic>;
+
+    /// What [`Self::validate`] reads. Defaults to everything, which is always
+    /// correct; narrower sets let incremental validation skip the rule.
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::ALL
+    }
 }
@@ -0,0 +1,289 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! Per-rule severity configuration — the `[validate]` table of `.design-data.toml`.
+//!
+//! ```toml
+//! [validate]
+//! custom-rules = ["house-rules.yaml"]
+//!
+//! [validate.rules]
+//! SPEC-031 = "off"
+//! SPEC-040 = "error"
+//!
+//! [[validate.overrides]]
+//! paths = ["components/legacy-*.json", "tokens/deprecated/**"]
+//! rules = { SPEC-058 = "warn" }
+//! ```
+//!
+//! `rules` sets a rule's level everywhere; each `overrides` entry
+//! re-levels rules for diagnostics whose file matches one of its `paths`
+//! globs. Later overrides win over earlier ones, and any override wins over
+//! `rules`. Rules absent from both keep their catalog severity.
+//!
+//! Globs match the **end** of a diagnostic's file path, component by
+//! component, so `components/button.json` matches wherever the dataset lives.
+//! `*` and `?` match within one path component; `**` matches any number of
+//! components.
+//!
+//! `custom-rules` lists files of declarative rules ([`crate::validate::custom`]),
+//! which are re-levelled by their own ids like catalog rules.
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::report::{Diagnostic, Severity};
+use crate::validate::custom::CustomRule;
+
+/// Configured level for one rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Error,
+    #[serde(alias = "warning")]
+    Warn,
+    Info,
+    Off,
+}
+
+impl RuleLevel {
+    /// The diagnostic severity for this level, `None` for [`RuleLevel::Off`].
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            RuleLevel::Error => Some(Severity::Error),
+            RuleLevel::Warn => Some(Severity::Warning),
+            RuleLevel::Info => Some(Severity::Info),
+            RuleLevel::Off => None,
+        }
+    }
+}
+
+/// A path-scoped block of rule levels.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleOverride {
+    /// Globs matched against the tail of each diagnostic's file path.
+    pub paths: Vec<String>,
+    /// Rule id → level for diagnostics in matching files.
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleLevel>,
+}
+
+/// The `[validate]` table: global rule levels plus path-scoped overrides.
+///
+/// The default (empty) configuration leaves every rule at its catalog severity.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleConfig {
+    /// Rule id → level, applied everywhere.
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleLevel>,
+    /// Path-scoped overrides, in precedence order (last match wins).
+    #[serde(default)]
+    pub overrides: Vec<RuleOverride>,
+    /// Declarative custom rule files (see [`crate::validate::custom`]). The
+    /// resolver makes them relative to `.design-data.toml` and loads them into
+    /// [`Self::custom`].
+    #[serde(default, rename = "custom-rules")]
+    pub custom_rules: Vec<PathBuf>,
+    /// Rules loaded from [`Self::custom_rules`]; they run after the catalog rules.
+    #[serde(skip)]
+    pub custom: Vec<CustomRule>,
+}
+
+impl RuleConfig {
+    /// True when no rule is re-levelled.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.overrides.iter().all(|o| o.rules.is_empty())
+    }
+
+    /// The configured level for `rule_id` on a diagnostic in `file`, or `None`
+    /// when the rule keeps its catalog severity there.
+    pub fn level_for(&self, rule_id: &str, file: &Path) -> Option<RuleLevel> {
+        self.overrides
+            .iter()
+            .rev()
+            .filter(|o| o.paths.iter().any(|p| path_glob_match(p, file)))
+            .find_map(|o| o.rules.get(rule_id).copied())
+            .or_else(|| self.rules.get(rule_id).copied())
+    }
+
+    /// True when `rule_id` is `off` globally and no override turns it back on,
+    /// so the rule need not run at all.
+    pub fn disabled_everywhere(&self, rule_id: &str) -> bool {
+        self.rules.get(rule_id) == Some(&RuleLevel::Off)
+            && self
+                .overrides
+                .iter()
+                .all(|o| o.rules.get(rule_id).is_none_or(|l| *l == RuleLevel::Off))
+    }
+
+    /// Re-level one diagnostic. Returns `None` when its rule is `off` for its
+    /// file. Diagnostics without a `rule_id` (Layer 1) pass through unchanged.
+    pub fn apply(&self, mut d: Diagnostic) -> Option<Diagnostic> {
+        let Some(rule_id) = d.rule_id.as_deref() else {
+            return Some(d);
+        };
+        if let Some(level) = self.level_for(rule_id, &d.file) {
+            d.severity = level.severity()?;
+        }
+        Some(d)
+    }
+
+    /// Configured rule ids that are not in `known`, sorted and deduplicated —
+    /// most likely typos, which would otherwise be silently ignored.
+    pub fn unknown_rules(&self, known: &[&str]) -> Vec<String> {
+        let mut unknown: Vec<String> = self
+            .rules
+            .keys()
+            .chain(self.overrides.iter().flat_map(|o| o.rules.keys()))
+            .filter(|id| !known.contains(&id.as_str()))
+            .cloned()
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+        unknown
+    }
+}
+
+// ── Path globs ──────────────────────────────────────────────────────────────
+
+/// Match `pattern` against any trailing run of `file`'s path components.
+fn path_glob_match(pattern: &str, file: &Path) -> bool {
+    let segments: Vec<&str> = file
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(s) => s.to_str(),
+            _ => None,
+        })
+        .collect();
+    let pattern: Vec<&str> = pattern
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    (0..segments.len()).any(|start| match_segments(&pattern, &segments[start..]))
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((seg, rest)) => path
+            .split_first()
+            .is_some_and(|(head, tail)| match_component(seg, head) && match_segments(rest, tail)),
+    }
+}
+
+/// `*` (any run) and `?` (any one char) within a single path component.
+fn match_component(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            backtrack = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star, matched)) = backtrack {
+            pi = star + 1;
+            ti = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn config(text: &str) -> RuleConfig {
+        toml::from_str(text).expect("valid [validate] table")
+    }
+
+    fn diag(rule: &str, file: &str) -> Diagnostic {
+        Diagnostic {
+            file: PathBuf::from(file),
+            token: None,
+            rule_id: Some(rule.into()),
+            severity: Severity::Error,
+            message: String::new(),
+            instance_path: None,
+            schema_path: None,
+            fix: None,
+            span: None,
+        }
+    }
+
+    #[test]
+    fn globs_match_path_tails() {
+        let f = Path::new("/repo/packages/design-data/components/button.json");
+        assert!(path_glob_match("components/button.json", f));
+        assert!(path_glob_match("components/*.json", f));
+        assert!(path_glob_match("design-data/**/button.json", f));
+        assert!(path_glob_match("b?tton.json", f));
+        assert!(!path_glob_match("tokens/*.json", f));
+        assert!(!path_glob_match("components/but", f));
+    }
+
+    #[test]
+    fn global_levels_and_last_matching_override_win() {
+        let c = config(
+            r#"
+            [rules]
+            SPEC-031 = "off"
+            SPEC-058 = "warn"
+
+            [[overrides]]
+            paths = ["components/*.json"]
+            rules = { SPEC-058 = "info" }
+
+            [[overrides]]
+            paths = ["components/button.json"]
+            rules = { SPEC-058 = "error", SPEC-031 = "warning" }
+            "#,
+        );
+        let sev = |rule: &str, file: &str| c.apply(diag(rule, file)).map(|d| d.severity);
+        assert_eq!(
+            sev("SPEC-058", "tokens/color.json"),
+            Some(Severity::Warning)
+        );
+        assert_eq!(sev("SPEC-058", "components/tag.json"), Some(Severity::Info));
+        assert_eq!(
+            sev("SPEC-058", "components/button.json"),
+            Some(Severity::Error)
+        );
+        assert_eq!(sev("SPEC-031", "components/tag.json"), None);
+        assert_eq!(
+            sev("SPEC-031", "components/button.json"),
+            Some(Severity::Warning)
+        );
+        assert_eq!(sev("SPEC-001", "tokens/color.json"), Some(Severity::Error));
+
+        assert!(!c.disabled_everywhere("SPEC-031"));
+        assert!(config("[rules]\nSPEC-031 = \"off\"").disabled_everywhere("SPEC-031"));
+        assert_eq!(c.unknown_rules(&["SPEC-031"]), vec!["SPEC-058"]);
+    }
+
+    #[test]
+    fn rejects_unknown_levels_and_keys() {
+        assert!(toml::from_str::<RuleConfig>("[rules]\nSPEC-001 = \"fatal\"").is_err());
+        assert!(toml::from_str::<RuleConfig>("severity = \"error\"").is_err());
+    }
+}
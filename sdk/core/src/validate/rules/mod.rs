@@ -68,6 +68,8 @@ mod spec054;
 mod spec055;
 mod spec056;
 mod spec057;
+mod spec058;
+mod spec059;
 
 use std::collections::HashSet;
 
@@ -148,10 +150,49 @@ pub(crate) fn component_option_values<'a>(
     )
 }
 
+/// Version to record as `lifecycle.deprecatedIn` when `--fix` marks a token
+/// deprecated: the later of its `lastModified` and `lifecycle.introduced`, so
+/// the fix never dates a deprecation before the token existed. Shared by
+/// SPEC-012 (`replacedBy`) and SPEC-013 (`plannedRemoval`), which both require
+/// `deprecatedIn` alongside those lifecycle fields.
+pub(crate) fn deprecation_version(t: &crate::graph::TokenRecord) -> Option<&str> {
+    let last_modified = t.raw.get("lastModified").and_then(|v| v.as_str());
+    let introduced = t
+        .raw
+        .get("lifecycle")
+        .and_then(|l| l.get("introduced"))
+        .and_then(|v| v.as_str());
+    match (last_modified, introduced) {
+        (Some(lm), Some(intro)) => {
+            match (semver::Version::parse(lm), semver::Version::parse(intro)) {
+                (Ok(a), Ok(b)) if b > a => Some(intro),
+                _ => Some(lm),
+            }
+        }
+        (lm, intro) => lm.or(intro),
+    }
+}
+
+/// The `--fix` that sets `lifecycle.deprecatedIn` to `version` on token `t`.
+pub(crate) fn mark_deprecated_fix(
+    t: &crate::graph::TokenRecord,
+    version: &str,
+) -> crate::report::Fix {
+    crate::validate::fix::set(
+        format!("set lifecycle.deprecatedIn to {version}"),
+        format!(
+            "{}/lifecycle/deprecatedIn",
+            crate::validate::fix::token_pointer(t)
+        ),
+        serde_json::Value::String(version.to_string()),
+    )
+}
+
 use crate::graph::TokenGraph;
 use crate::registry::RegistryData;
 use crate::report::Diagnostic;
 use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule_config::RuleConfig;
 
 /// Lazily initialized embedded registry data (parsed once, reused).
 fn embedded_registry() -> &'static RegistryData {
@@ -225,6 +266,8 @@ pub fn default_rules() -> Vec<Box<dyn ValidationRule>> {
         Box::new(spec055::Rule),
         Box::new(spec056::Rule),
         Box::new(spec057::Rule),
+        Box::new(spec058::Rule),
+        Box::new(spec059::Rule),
     ]
 }
 
@@ -237,16 +280,77 @@ pub fn run_rules(
     naming_exceptions: &HashSet<String>,
     manifest: Option<&serde_json::Value>,
 ) -> Vec<Diagnostic> {
-    let registry = embedded_registry();
-    let ctx = ValidationContext {
+    run_rules_with_config(graph, naming_exceptions, manifest, &RuleConfig::default())
+}
+
+/// [`run_rules`] under a `[validate]` rule configuration: its custom rules run
+/// after the catalog rules, rules that are `off` everywhere are not run, and
+/// every other diagnostic is re-levelled (or
+/// dropped) per its rule id and file before it is returned.
+pub fn run_rules_with_config(
+    graph: &TokenGraph,
+    naming_exceptions: &HashSet<String>,
+    manifest: Option<&serde_json::Value>,
+    config: &RuleConfig,
+) -> Vec<Diagnostic> {
+    let ctx = context(graph, naming_exceptions, manifest);
+    let builtins = default_rules();
+    let rules = active_rules(&builtins, config);
+    crate::validate::par_map(&rules, |r| r.validate(&ctx))
+        .into_iter()
+        .flatten()
+        .filter_map(|d| finish_diagnostic(graph, config, d))
+        .collect()
+}
+
+/// `builtins` followed by `config`'s custom rules, without the rules that are
+/// `off` everywhere.
+pub(crate) fn active_rules<'a>(
+    builtins: &'a [Box<dyn ValidationRule>],
+    config: &'a RuleConfig,
+) -> Vec<&'a dyn ValidationRule> {
+    builtins
+        .iter()
+        .map(|r| r.as_ref())
+        .chain(config.custom.iter().map(|r| r as &dyn ValidationRule))
+        .filter(|r| !config.disabled_everywhere(r.id()))
+        .collect()
+}
+
+/// Context for running rules over `graph` with the embedded registry.
+pub(crate) fn context<'a>(
+    graph: &'a TokenGraph,
+    naming_exceptions: &'a HashSet<String>,
+    manifest: Option<&'a serde_json::Value>,
+) -> ValidationContext<'a> {
+    ValidationContext {
         graph,
         naming_exceptions,
-        registry,
+        registry: embedded_registry(),
         manifest,
-    };
-    let mut out = Vec::new();
-    for r in default_rules() {
-        out.extend(r.validate(&ctx));
     }
-    out
+}
+
+/// Apply `config`'s level for a raw rule diagnostic (`None` when turned off
+/// for its file) and attach its token's source span.
+pub(crate) fn finish_diagnostic(
+    graph: &TokenGraph,
+    config: &RuleConfig,
+    d: Diagnostic,
+) -> Option<Diagnostic> {
+    config.apply(d).map(|d| with_token_span(graph, d))
+}
+
+/// Point a relational diagnostic at its token's source span when the rule set
+/// none (and the token lives in the diagnostic's file).
+fn with_token_span(graph: &TokenGraph, mut d: Diagnostic) -> Diagnostic {
+    if d.span.is_none() {
+        d.span = d
+            .token
+            .as_deref()
+            .and_then(|name| graph.tokens.get(name))
+            .filter(|t| t.file == d.file)
+            .and_then(|t| t.span);
+    }
+    d
 }
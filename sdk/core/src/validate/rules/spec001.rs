@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,14 +22,19 @@ impl ValidationRule for Rule {
         "alias-target-exists"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
+        let relationship_targets = ctx.graph.relationship_targets();
         for t in ctx.graph.tokens.values() {
             let Some(target) = &t.alias_target else {
                 continue;
             };
             if ctx.graph.resolve_alias_key(target).is_none()
-                && !ctx.graph.relationship_target_exists(target)
+                && !relationship_targets.contains(target.as_str())
             {
                 out.push(Diagnostic {
                     file: t.file.clone(),
@@ -39,6 +44,8 @@ impl ValidationRule for Rule {
                     message: format!("Alias target not found for $ref: {target}"),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
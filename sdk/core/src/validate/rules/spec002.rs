@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,6 +22,10 @@ impl ValidationRule for Rule {
         "alias-type-compatibility"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for t in ctx.graph.tokens.values() {
@@ -60,6 +64,8 @@ fn diagnostic(id: &str, t: &crate::graph::TokenRecord, message: String) -> Diagn
         message,
         instance_path: None,
         schema_path: None,
+        fix: None,
+        span: None,
     }
 }
 
@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,6 +22,10 @@ impl ValidationRule for Rule {
         "no-circular-aliases"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for start in ctx.graph.tokens.values() {
@@ -40,6 +44,8 @@ impl ValidationRule for Rule {
                         message: format!("Circular alias chain detected involving {}", start.name),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                     break;
                 }
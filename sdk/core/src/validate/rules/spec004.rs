@@ -12,7 +12,8 @@ use std::collections::HashMap;
 
 use crate::graph::Layer;
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::fix;
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -25,6 +26,10 @@ impl ValidationRule for Rule {
         "uuid-global-uniqueness"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         // Group by (uuid, layer): duplicate UUIDs across layers are intentional
         // (product overrides reference the Foundation token by its UUID), so only
@@ -43,7 +48,12 @@ impl ValidationRule for Rule {
             if group.len() < 2 {
                 continue;
             }
-            for t in group {
+            // Under `--fix` the first token in file order keeps the uuid and the
+            // rest get fresh ones.
+            let mut group = group;
+            group.sort_by(|a, b| (&a.file, a.index, &a.name).cmp(&(&b.file, b.index, &b.name)));
+            for (i, t) in group.into_iter().enumerate() {
+                let fix = (i > 0).then(|| fix::regenerate_uuid(&fix::token_pointer(t)));
                 out.push(Diagnostic {
                     file: t.file.clone(),
                     token: Some(t.name.clone()),
@@ -52,6 +62,8 @@ impl ValidationRule for Rule {
                     message: format!("Duplicate uuid {uuid}"),
                     instance_path: None,
                     schema_path: None,
+                    fix,
+                    span: None,
                 });
             }
         }
@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,6 +22,10 @@ impl ValidationRule for Rule {
         "cascade-coverage"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::MODE_SETS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for d in &ctx.graph.mode_sets {
@@ -37,6 +41,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -11,7 +11,7 @@
 use std::collections::HashMap;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -24,6 +24,10 @@ impl ValidationRule for Rule {
         "specificity-correctness"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut by_name_key: HashMap<String, Vec<&crate::graph::TokenRecord>> = HashMap::new();
         for t in ctx.graph.tokens.values() {
@@ -55,6 +59,8 @@ impl ValidationRule for Rule {
                 ),
                 instance_path: None,
                 schema_path: None,
+                fix: None,
+                span: None,
             });
         }
         out
@@ -8,9 +8,12 @@
 // OF ANY KIND, either express or implied. See the License for the specific language
 // governing permissions and limitations under the License.
 
+use std::cell::OnceCell;
+
 use crate::naming;
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::fix;
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -23,8 +26,13 @@ impl ValidationRule for Rule {
         "name-roundtrip"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::NAMING_EXCEPTIONS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
+        let references = OnceCell::new();
         for t in ctx.graph.tokens.values() {
             let component_hint = t
                 .raw
@@ -48,8 +56,18 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             } else {
+                // Renaming a published key is breaking, so the fix is opt-in
+                // (`Fix::renames`); it is never offered onto an existing key.
+                let fix = naming::canonical_legacy_name(&t.name, component_hint)
+                    .filter(|to| !ctx.graph.tokens.contains_key(to))
+                    .and_then(|to| {
+                        let references = references.get_or_init(|| fix::References::new(ctx.graph));
+                        fix::rename_token(references, t, &to)
+                    });
                 out.push(Diagnostic {
                     file: t.file.clone(),
                     token: Some(t.name.clone()),
@@ -61,6 +79,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix,
+                    span: None,
                 });
             }
         }
@@ -22,7 +22,7 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -35,6 +35,10 @@ impl ValidationRule for Rule {
         "cascade-completeness"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::MODE_SETS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         if ctx.graph.mode_sets.is_empty() {
             // No mode set declarations loaded — cannot evaluate coverage.
@@ -129,6 +133,8 @@ impl ValidationRule for Rule {
                 ),
                 instance_path: None,
                 schema_path: None,
+                fix: None,
+                span: None,
             });
         }
 
@@ -25,7 +25,7 @@
 //! against the registry independently.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -38,6 +38,10 @@ impl ValidationRule for Rule {
         "name-field-enum-sync"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
 
@@ -83,6 +87,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some(format!("/name/{field}")),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
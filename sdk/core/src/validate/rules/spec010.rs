@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,6 +22,10 @@ impl ValidationRule for Rule {
         "replaced-by-target-exists"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for t in ctx.graph.tokens.values() {
@@ -55,6 +59,8 @@ impl ValidationRule for Rule {
                         message: format!("lifecycle.replacedBy target UUID not found: {uuid}"),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
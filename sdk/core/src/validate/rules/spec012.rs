@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,6 +22,10 @@ impl ValidationRule for Rule {
         "replaced-by-requires-deprecated"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for t in ctx.graph.tokens.values() {
@@ -44,6 +48,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: super::deprecation_version(t).map(|v| super::mark_deprecated_fix(t, v)),
+                    span: None,
                 });
             }
         }
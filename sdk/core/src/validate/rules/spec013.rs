@@ -9,7 +9,7 @@
 // governing permissions and limitations under the License.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -22,6 +22,10 @@ impl ValidationRule for Rule {
         "planned-removal-requires-deprecated"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for t in ctx.graph.tokens.values() {
@@ -45,6 +49,12 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    // Skip the fix when the chosen version would itself trip
+                    // the ordering check below.
+                    fix: super::deprecation_version(t)
+                        .filter(|dep| !semver_precedes(planned, dep))
+                        .map(|dep| super::mark_deprecated_fix(t, dep)),
+                    span: None,
                 });
                 continue;
             }
@@ -64,6 +74,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -9,9 +9,11 @@
 // governing permissions and limitations under the License.
 
 use semver::Version;
+use serde_json::Value;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::fix;
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -24,6 +26,10 @@ impl ValidationRule for Rule {
         "last-modified-not-before-introduced"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
         for t in ctx.graph.tokens.values() {
@@ -58,6 +64,12 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: Some(fix::set(
+                        format!("set lastModified to {introduced}"),
+                        format!("{}/lastModified", fix::token_pointer(t)),
+                        Value::String(introduced.to_string()),
+                    )),
+                    span: None,
                 });
             }
         }
@@ -21,7 +21,7 @@ use std::sync::LazyLock;
 use serde_json::Value;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 // Embed composite value-type schemas to extract x-valueType metadata.
 static TYPOGRAPHY_SCHEMA: &str = include_str!(concat!(
@@ -36,6 +36,10 @@ static DROP_SHADOW_SCHEMA: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/../../packages/design-data-spec/schemas/value-types/drop-shadow.schema.json"
 ));
+static MOTION_SCHEMA: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../packages/design-data-spec/schemas/value-types/motion.schema.json"
+));
 
 /// Per-schema descriptor: whether the composite value is an array of objects
 /// (true) or a flat object (false), plus the sub-key → acceptable scalar types map.
@@ -66,6 +70,7 @@ static DESCRIPTORS: LazyLock<HashMap<&'static str, CompositeDescriptor>> = LazyL
             DROP_SHADOW_SCHEMA,
             true,
         ),
+        ("value-types/motion.schema.json", MOTION_SCHEMA, false),
     ];
 
     let mut map: HashMap<&'static str, CompositeDescriptor> = HashMap::new();
@@ -171,6 +176,10 @@ impl ValidationRule for Rule {
         "composite-inline-alias-type-compatible"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -225,6 +234,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -261,6 +272,7 @@ mod tests {
                     alias_target,
                     raw,
                     layer: crate::graph::Layer::Foundation,
+                    span: None,
                 },
             );
         }
@@ -276,7 +288,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -20,7 +20,7 @@ use jsonschema::Validator;
 use serde_json::Value;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 // Embed all value-type schemas from the spec at compile time.
 static COLOR_SCHEMA: &str = include_str!(concat!(
@@ -39,6 +39,18 @@ static TYPOGRAPHY_SCALE_SCHEMA: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/../../packages/design-data-spec/schemas/value-types/typography-scale.schema.json"
 ));
+static DURATION_SCHEMA: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../packages/design-data-spec/schemas/value-types/duration.schema.json"
+));
+static EASING_SCHEMA: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../packages/design-data-spec/schemas/value-types/easing.schema.json"
+));
+static MOTION_SCHEMA: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../packages/design-data-spec/schemas/value-types/motion.schema.json"
+));
 
 /// Map of `$valueType` relative path → compiled validator, initialized once.
 static VALIDATORS: LazyLock<HashMap<&'static str, Validator>> = LazyLock::new(|| {
@@ -50,6 +62,9 @@ static VALIDATORS: LazyLock<HashMap<&'static str, Validator>> = LazyLock::new(||
             "value-types/typography-scale.schema.json",
             TYPOGRAPHY_SCALE_SCHEMA,
         ),
+        ("value-types/duration.schema.json", DURATION_SCHEMA),
+        ("value-types/easing.schema.json", EASING_SCHEMA),
+        ("value-types/motion.schema.json", MOTION_SCHEMA),
     ];
     let mut map = HashMap::new();
     for (key, src) in schemas {
@@ -71,6 +86,10 @@ impl ValidationRule for Rule {
         "value-type-match"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -99,6 +118,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -132,6 +153,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw,
+                span: None,
             },
         );
         g
@@ -144,7 +166,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -184,6 +206,44 @@ mod tests {
         assert!(diags[0].message.contains("$valueType schema"));
     }
 
+    #[test]
+    fn motion_value_types() {
+        let valid = [
+            ("duration", json!("150ms")),
+            ("duration", json!("0.2s")),
+            ("easing", json!("ease-in-out")),
+            ("easing", json!("cubic-bezier(0.45, 0, 0.4, 1)")),
+            ("easing", json!([0.45, 0, 0.4, 1])),
+            (
+                "motion",
+                json!({"duration": "{fast}", "timingFunction": [0, 0, 0.4, 1]}),
+            ),
+        ];
+        for (vt, value) in valid {
+            let diags = run(json!({
+                "name": {"property": "motion"},
+                "$valueType": format!("value-types/{vt}.schema.json"),
+                "value": value,
+            }));
+            assert!(diags.is_empty(), "{vt} {value} should be valid");
+        }
+
+        let invalid = [
+            ("duration", json!("150")),
+            ("easing", json!("bounce")),
+            ("easing", json!([1.5, 0, 0.4, 1])),
+            ("motion", json!({"duration": "150ms"})),
+        ];
+        for (vt, value) in invalid {
+            let diags = run(json!({
+                "name": {"property": "motion"},
+                "$valueType": format!("value-types/{vt}.schema.json"),
+                "value": value,
+            }));
+            assert_eq!(diags.len(), 1, "{vt} {value} should be invalid");
+        }
+    }
+
     #[test]
     fn unknown_value_type_path_skipped() {
         let diags = run(json!({
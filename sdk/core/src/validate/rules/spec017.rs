@@ -15,7 +15,7 @@
 //! via the taxonomy, but it is tracked as tech debt (severity: warning).
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -28,6 +28,10 @@ impl ValidationRule for Rule {
         "string-name-tech-debt"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
 
@@ -44,6 +48,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some("/name".into()),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -13,7 +13,7 @@
 //! Token name-object `component` field MUST reference a declared component.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -26,6 +26,10 @@ impl ValidationRule for Rule {
         "component-name-declared"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         // Skip when no component catalog has been loaded — the rule cannot validate
         // component-name references against a catalog that was never provided.
@@ -61,6 +65,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -98,6 +104,7 @@ mod tests {
                     alias_target: None,
                     layer: crate::graph::Layer::Foundation,
                     raw,
+                    span: None,
                 },
             );
         }
@@ -126,7 +133,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
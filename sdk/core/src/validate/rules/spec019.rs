@@ -14,7 +14,7 @@
 //! `options.variant.values` when that values list is declared.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -27,6 +27,10 @@ impl ValidationRule for Rule {
         "component-variant-valid"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -63,6 +67,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -96,6 +102,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw: token_raw,
+                span: None,
             },
         );
         let comp_name = comp_raw
@@ -121,7 +128,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
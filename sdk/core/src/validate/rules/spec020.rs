@@ -14,7 +14,7 @@
 //! referenced component (when anatomy parts are declared).
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -27,6 +27,10 @@ impl ValidationRule for Rule {
         "component-anatomy-valid"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -74,6 +78,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -107,6 +113,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw: token_raw,
+                span: None,
             },
         );
         let comp_name = comp_raw
@@ -132,7 +139,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -17,7 +17,7 @@ use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 static CANONICAL_ANATOMY_PARTS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     [
@@ -51,6 +51,10 @@ impl ValidationRule for Rule {
         "anatomy-custom-part-documented"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -82,6 +86,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -125,7 +131,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -19,7 +19,7 @@
 //! single owning component and are explicitly allowed to stand alone.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -32,6 +32,10 @@ impl ValidationRule for Rule {
         "anatomy-requires-component"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
 
@@ -67,6 +71,8 @@ impl ValidationRule for Rule {
                 ),
                 instance_path: Some("/name/anatomy".into()),
                 schema_path: None,
+                fix: None,
+                span: None,
             });
         }
 
@@ -19,7 +19,7 @@ use std::path::Path;
 use serde_json::Value;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -42,6 +42,8 @@ fn check_blocks(blocks: &[Value], entity_label: &str, file: &Path, out: &mut Vec
                 ),
                 instance_path: None,
                 schema_path: None,
+                fix: None,
+                span: None,
             });
         }
     }
@@ -56,6 +58,10 @@ impl ValidationRule for Rule {
         "document-block-agents-equals-content"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -111,6 +117,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw,
+                span: None,
             },
         );
         g
@@ -137,7 +144,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -150,7 +157,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
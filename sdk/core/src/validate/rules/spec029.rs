@@ -18,7 +18,7 @@ use std::path::Path;
 use serde_json::Value;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -40,6 +40,8 @@ fn check_purpose(blocks: &[Value], entity_label: &str, file: &Path, out: &mut Ve
             ),
             instance_path: None,
             schema_path: None,
+            fix: None,
+            span: None,
         });
     }
 }
@@ -53,6 +55,10 @@ impl ValidationRule for Rule {
         "document-block-missing-purpose"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -108,6 +114,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw,
+                span: None,
             },
         );
         g
@@ -134,7 +141,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -147,7 +154,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -16,7 +16,7 @@
 //! populated or removed.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 const KNOWN_FIELDS: &[&str] = &["role", "intents", "focusable", "keyboardIntents", "wcag"];
 
@@ -31,6 +31,10 @@ impl ValidationRule for Rule {
         "accessibility-empty"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -54,6 +58,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -90,7 +96,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
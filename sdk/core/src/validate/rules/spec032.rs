@@ -20,7 +20,7 @@ use serde_json::Value;
 
 use crate::graph::Layer;
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -33,6 +33,10 @@ impl ValidationRule for Rule {
         "product-layer-override-type-compat"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -81,6 +85,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -123,7 +129,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -139,6 +145,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "p"}, "uuid": uuid, "value": value}),
             layer: Layer::Foundation,
+            span: None,
         }
     }
 
@@ -152,6 +159,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "p"}, "uuid": uuid, "value": value}),
             layer: Layer::Product,
+            span: None,
         }
     }
 
@@ -199,6 +207,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "q"}, "uuid": "uuid-new", "value": "#123"}),
             layer: Layer::Product,
+            span: None,
         };
         let diags = run(vec![net_new]);
         assert!(diags.is_empty());
@@ -222,6 +231,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "spacing"}, "uuid": "uuid-6", "value": 8}),
             layer: Layer::Platform,
+            span: None,
         };
         let diags = run(vec![foundation("uuid-6", json!("8px")), platform]);
         assert_eq!(diags.len(), 1);
@@ -242,6 +252,7 @@ mod tests {
             alias_target: None,
             raw: json!({"name": {"property": "spacing"}, "uuid": "uuid-7", "value": "12px"}),
             layer: Layer::Platform,
+            span: None,
         };
         let diags = run(vec![foundation("uuid-7", json!("8px")), platform]);
         assert!(diags.is_empty());
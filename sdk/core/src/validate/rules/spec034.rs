@@ -18,7 +18,7 @@
 //! applies to the `meta.category` field on component declarations instead.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -31,6 +31,10 @@ impl ValidationRule for Rule {
         "component-category-registry-sync"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
         let categories = ctx.registry.categories();
@@ -59,6 +63,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some("/meta/category".into()),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -20,7 +20,7 @@
 //! a description is present, pointing authors directly at the registry vocabulary.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -33,6 +33,10 @@ impl ValidationRule for Rule {
         "anatomy-part-name-registry-sync"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
         let Some(anatomy_vocab) = ctx.registry.for_field("anatomy") else {
@@ -60,6 +64,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some(format!("/anatomy/{idx}/name")),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -14,7 +14,7 @@
 //! unless the token is itself deprecated.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -27,6 +27,10 @@ impl ValidationRule for Rule {
         "component-deprecation-cascade"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -78,6 +82,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some("/name/component".to_string()),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -111,6 +117,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw: token_raw,
+                span: None,
             },
         );
         let comp_name = comp_raw
@@ -136,7 +143,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -14,7 +14,7 @@
 //! or deprecated option-enum value via `name.*` unless the token is itself deprecated.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -27,6 +27,10 @@ impl ValidationRule for Rule {
         "sub-entity-deprecation-cascade"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -87,6 +91,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some("/name/anatomy".to_string()),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -123,6 +129,8 @@ impl ValidationRule for Rule {
                             ),
                             instance_path: Some("/name/state".to_string()),
                             schema_path: None,
+                            fix: None,
+                            span: None,
                         });
                     }
                 }
@@ -170,6 +178,8 @@ impl ValidationRule for Rule {
                             ),
                             instance_path: Some(format!("/name/{option_key}")),
                             schema_path: None,
+                            fix: None,
+                            span: None,
                         });
                     }
                 }
@@ -205,6 +215,7 @@ mod tests {
                 alias_target: None,
                 layer: crate::graph::Layer::Foundation,
                 raw: token_raw,
+                span: None,
             },
         );
         let comp_name = comp_raw
@@ -230,7 +241,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
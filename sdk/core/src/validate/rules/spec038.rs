@@ -16,7 +16,7 @@
 //! is silently accepted at Layer 1 — this advisory rule closes that gap at Layer 2.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -29,6 +29,10 @@ impl ValidationRule for Rule {
         "option-enum-obsolete"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -52,6 +56,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some(format!("/options/{option_key}/enum")),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -95,7 +101,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -18,7 +18,7 @@
 
 use crate::query;
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -31,6 +31,10 @@ impl ValidationRule for Rule {
         "manifest-query-parseable"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::MANIFEST
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let Some(manifest) = ctx.manifest else {
             return Vec::new();
@@ -57,6 +61,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some(format!("/{array_name}/{idx}")),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -83,7 +89,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: manifest.as_ref(),
         };
         Rule.validate(&ctx)
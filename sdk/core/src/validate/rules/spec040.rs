@@ -20,7 +20,7 @@
 //! promotion to Error is deferred until the option catalog stabilises.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 /// Name-object keys owned by other rules or the cascade machinery.
 /// SPEC-040 skips these to avoid double-reporting.
@@ -54,6 +54,10 @@ impl ValidationRule for Rule {
         "component-option-field-valid"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -109,6 +113,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -148,6 +154,7 @@ mod tests {
                 uuid: None,
                 alias_target: None,
                 layer: Layer::Foundation,
+                span: None,
             },
         );
         g.components.push(ComponentRecord {
@@ -164,7 +171,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -299,6 +306,7 @@ mod tests {
                 uuid: None,
                 alias_target: None,
                 layer: Layer::Foundation,
+                span: None,
             },
         );
         assert!(run(&g).is_empty());
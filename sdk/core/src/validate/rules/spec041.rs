@@ -29,7 +29,7 @@ use std::collections::HashMap;
 use serde_json::Map;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -42,6 +42,10 @@ impl ValidationRule for Rule {
         "mode-set-restriction-coverage"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::MODE_SETS | RuleInputs::MANIFEST
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let Some(manifest) = ctx.manifest else {
             return Vec::new();
@@ -87,6 +91,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some(format!("modeSetRestrictions/{ms_name}")),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 continue;
             };
@@ -104,6 +110,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some(format!("modeSetRestrictions/{ms_name}/allowed")),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 // Still register the restriction for coverage scanning so that gap errors
                 // are also surfaced — the two problems are independent.
@@ -164,6 +172,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -236,7 +246,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &graph,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         assert!(Rule.validate(&ctx).is_empty());
@@ -248,7 +258,7 @@ mod tests {
         let registry = RegistryData::embedded();
         let exceptions = HashSet::new();
         let manifest = json!({"specVersion": "1.0.0-draft", "foundationVersion": "1.0.0"});
-        let ctx = make_ctx(&graph, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&graph, &manifest, registry, &exceptions);
         assert!(Rule.validate(&ctx).is_empty());
     }
 
@@ -271,7 +281,7 @@ mod tests {
                 "colorScheme": { "allowed": ["light"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         assert!(
             Rule.validate(&ctx).is_empty(),
             "wildcard token covers the restriction"
@@ -303,7 +313,7 @@ mod tests {
                 "colorScheme": { "allowed": ["light"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         // t-light covers the group — no coverage gap even though t-dark is restricted.
         assert!(Rule.validate(&ctx).is_empty());
     }
@@ -327,7 +337,7 @@ mod tests {
                 "colorScheme": { "allowed": ["light"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         let diags = Rule.validate(&ctx);
         assert_eq!(diags.len(), 1);
         assert_eq!(diags[0].severity, Severity::Error);
@@ -367,7 +377,7 @@ mod tests {
                 "scale": { "allowed": ["desktop"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         let diags = Rule.validate(&ctx);
         // Must detect the gap even though each restriction individually had a survivor.
         assert_eq!(
@@ -399,7 +409,7 @@ mod tests {
                 "scale": { "allowed": ["desktop"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         assert!(Rule.validate(&ctx).is_empty());
     }
 
@@ -424,7 +434,7 @@ mod tests {
                 "colorScheme": { "allowed": ["dark"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         let diags = Rule.validate(&ctx);
         // Expect both a "default not in allowed" error AND a coverage gap (no wildcard/light token).
         let default_errs: Vec<_> = diags
@@ -450,7 +460,7 @@ mod tests {
                 "typoz": { "allowed": ["a"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         let diags = Rule.validate(&ctx);
         assert_eq!(diags.len(), 1);
         assert_eq!(diags[0].severity, Severity::Warning);
@@ -476,7 +486,7 @@ mod tests {
                 "typoz": { "allowed": ["a"] }
             }
         });
-        let ctx = make_ctx(&g, &manifest, &registry, &exceptions);
+        let ctx = make_ctx(&g, &manifest, registry, &exceptions);
         let diags = Rule.validate(&ctx);
         // Only one warning, no coverage-gap errors.
         assert_eq!(diags.len(), 1);
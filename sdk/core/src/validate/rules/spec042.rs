@@ -27,7 +27,7 @@
 //! SPEC-001 owns the broken-alias diagnostic.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -51,6 +51,10 @@ impl ValidationRule for Rule {
         "field-scope-violation"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -116,6 +120,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some(format!("/name/{field}")),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -35,7 +35,7 @@
 //! Tokens without a `$schema` are skipped — domain cannot be determined.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -68,6 +68,10 @@ impl ValidationRule for Rule {
         "domain-required-fields"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -99,6 +103,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some("/name".to_string()),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -17,7 +17,7 @@
 //! Mirrors SPEC-029 (document-block-missing-purpose) scoped to the guideline entity.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -30,6 +30,10 @@ impl ValidationRule for Rule {
         "guideline-missing-purpose"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::GUIDELINES
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -59,6 +63,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -105,7 +111,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -37,7 +37,7 @@
 use std::collections::HashSet;
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 /// True if `endpoint` matches a position, a generic anatomy term, a declared
 /// anatomy part, or — failing that — is a registered position glued to one of
@@ -83,6 +83,10 @@ impl ValidationRule for Rule {
         "space-between-endpoint-valid"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -118,6 +122,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
                 continue;
@@ -135,6 +141,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 continue;
             }
@@ -179,6 +187,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: Some(format!("/name/{field}")),
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -16,7 +16,7 @@
 //! (anatomy-format.md#contains) — so this fires a warning, not an error.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -29,6 +29,10 @@ impl ValidationRule for Rule {
         "anatomy-contains-resolves"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -66,6 +70,8 @@ impl ValidationRule for Rule {
                             ),
                             instance_path: None,
                             schema_path: None,
+                            fix: None,
+                            span: None,
                         });
                     }
                 }
@@ -105,7 +111,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -19,7 +19,7 @@
 //! either case would leave the `anatomy` value completely unvalidated.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -32,6 +32,10 @@ impl ValidationRule for Rule {
         "anatomy-in-registry"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
         let Some(anatomy_vocab) = ctx.registry.for_field("anatomy") else {
@@ -62,6 +66,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some("/name/anatomy".into()),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -29,7 +29,7 @@
 //! term (anatomy has a "text" part).
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 /// Keep in sync with tools/token-mapping-analyzer/src/decomposer.js
 /// `COMPOUND_PROPERTIES`. Matching is exact-string only, not prefix -- a new
@@ -116,6 +116,10 @@ impl ValidationRule for Rule {
         "property-decomposition-complete"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut diags = Vec::new();
 
@@ -147,6 +151,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: Some("/name/property".into()),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
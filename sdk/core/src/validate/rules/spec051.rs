@@ -15,7 +15,7 @@
 //! (component-name-declared) for the CTR scope object.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -28,6 +28,10 @@ impl ValidationRule for Rule {
         "ctr-component-exists"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS | RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         // Skip when no component catalog has been loaded — mirrors SPEC-018's guard.
         if ctx.graph.components.is_empty() {
@@ -62,6 +66,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -120,7 +126,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -21,7 +21,7 @@
 //! not an advisory warning.
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 /// `state` is validated by SPEC-054 against `component.states[].name` (an
 /// ordered array), not against `options.state.values[]`. Excluded here to
@@ -39,6 +39,10 @@ impl ValidationRule for Rule {
         "ctr-option-valid"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::COMPONENTS | RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -95,6 +99,8 @@ impl ValidationRule for Rule {
                         ),
                         instance_path: None,
                         schema_path: None,
+                        fix: None,
+                        span: None,
                     });
                 }
             }
@@ -147,7 +153,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: &g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
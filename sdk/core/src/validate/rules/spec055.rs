@@ -17,7 +17,7 @@
 //! aliasing).
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -30,8 +30,13 @@ impl ValidationRule for Rule {
         "ctr-ref-resolves"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
+        let relationship_targets = ctx.graph.relationship_targets();
 
         for rel in &ctx.graph.relationships {
             let Some(target) = rel.raw.get("$ref").and_then(|v| v.as_str()) else {
@@ -43,7 +48,7 @@ impl ValidationRule for Rule {
             // migrated to CTRs, the group id only survives as `setUuid` on
             // sibling CTRs (see graph.rs's set_uuid_index for the equivalent
             // token-side precedent).
-            let resolves_to_relationship = ctx.graph.relationship_target_exists(target);
+            let resolves_to_relationship = relationship_targets.contains(target);
             if !resolves_to_token && !resolves_to_relationship {
                 out.push(Diagnostic {
                     file: rel.file.clone(),
@@ -53,6 +58,8 @@ impl ValidationRule for Rule {
                     message: format!("Relationship alias target not found for $ref: {target}"),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -79,7 +86,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -96,6 +103,7 @@ mod tests {
             alias_target: None,
             layer: Layer::Foundation,
             raw: json!({"name": "t", "value": "#000", "uuid": "11111111-1111-1111-1111-111111111111"}),
+            span: None,
         }]);
         g.relationships.push(RelationshipRecord {
             file: PathBuf::from("relationships.json"),
@@ -23,8 +23,10 @@
 
 use std::collections::HashMap;
 
-use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::graph::RelationshipRecord;
+use crate::report::{Diagnostic, Fix, Severity};
+use crate::validate::fix;
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -37,8 +39,12 @@ impl ValidationRule for Rule {
         "ctr-uuid-unique"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
-        let mut by_uuid: HashMap<&str, Vec<&crate::graph::RelationshipRecord>> = HashMap::new();
+        let mut by_uuid: HashMap<&str, Vec<&RelationshipRecord>> = HashMap::new();
         for rel in &ctx.graph.relationships {
             let Some(u) = rel.uuid.as_deref() else {
                 continue;
@@ -56,7 +62,11 @@ impl ValidationRule for Rule {
         let mut out = Vec::new();
         for (uuid, group) in by_uuid {
             if group.len() >= 2 {
-                for rel in &group {
+                // Under `--fix` the first entry in file order keeps the uuid and
+                // the rest get fresh ones.
+                let mut group = group;
+                group.sort_by(|a, b| (&a.file, a.index).cmp(&(&b.file, b.index)));
+                for (i, rel) in group.iter().enumerate() {
                     out.push(Diagnostic {
                         file: rel.file.clone(),
                         token: None,
@@ -65,6 +75,8 @@ impl ValidationRule for Rule {
                         message: format!("Duplicate relationship uuid {uuid}"),
                         instance_path: None,
                         schema_path: None,
+                        fix: (i > 0).then(|| regenerate_uuid(rel)),
+                        span: None,
                     });
                 }
             } else if token_uuids.contains(uuid) {
@@ -78,6 +90,9 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    // The token keeps the uuid: `$ref`s to it must not move.
+                    fix: Some(regenerate_uuid(group[0])),
+                    span: None,
                 });
             }
         }
@@ -85,6 +100,10 @@ impl ValidationRule for Rule {
     }
 }
 
+fn regenerate_uuid(rel: &RelationshipRecord) -> Fix {
+    fix::regenerate_uuid(&fix::relationship_pointer(rel))
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -94,6 +113,7 @@ mod tests {
     use crate::graph::{RelationshipRecord, TokenGraph};
     use crate::registry::RegistryData;
     use crate::report::Severity;
+    use crate::validate::fix;
     use crate::validate::rule::{ValidationContext, ValidationRule};
     use crate::validate::rules::spec056::Rule;
 
@@ -103,7 +123,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -147,6 +167,14 @@ mod tests {
         assert!(diags
             .iter()
             .all(|d| d.severity == Severity::Error && d.rule_id.as_deref() == Some("SPEC-056")));
+        // Only the later occurrence is offered a fresh uuid.
+        let fixed: Vec<_> = diags.iter().filter(|d| d.fix.is_some()).collect();
+        assert_eq!(fixed.len(), 1);
+        assert_eq!(fixed[0].file, PathBuf::from("b.json"));
+        let edit = &fixed[0].fix.as_ref().unwrap().edits[0];
+        assert_eq!(edit.pointer, "/0/uuid");
+        // The uuid itself is generated when the fix is applied.
+        assert_eq!(edit.value, Some(json!(fix::NEW_UUID)));
     }
 
     #[test]
@@ -171,6 +199,7 @@ mod tests {
                 alias_target: None,
                 raw: json!({}),
                 layer: Default::default(),
+                span: None,
             },
         );
         let diags = run(&g);
@@ -19,7 +19,7 @@
 //! to round-trip through the legacy generator (`packages/tokens/src`).
 
 use crate::report::{Diagnostic, Severity};
-use crate::validate::rule::{ValidationContext, ValidationRule};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
 
 pub struct Rule;
 
@@ -32,6 +32,10 @@ impl ValidationRule for Rule {
         "ctr-legacykey-present-if-legacy"
     }
 
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::RELATIONSHIPS
+    }
+
     fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
         let mut out = Vec::new();
 
@@ -60,6 +64,8 @@ impl ValidationRule for Rule {
                     ),
                     instance_path: None,
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
             }
         }
@@ -86,7 +92,7 @@ mod tests {
         let ctx = ValidationContext {
             graph: g,
             naming_exceptions: &exceptions,
-            registry: &registry,
+            registry,
             manifest: None,
         };
         Rule.validate(&ctx)
@@ -0,0 +1,577 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! SPEC-058: color-contrast-minimum
+//!
+//! A CTR entry or component `tokenBindings[]` entry MAY declare a `contrast`
+//! object naming the color it is drawn against:
+//!
+//! ```json
+//! "contrast": { "against": "background-base-color", "minimum": 4.5, "algorithm": "wcag2" }
+//! ```
+//!
+//! The foreground is the CTR itself (its `value` or `$ref`) or the binding's
+//! `token`; `against` is a token name, token UUID, or relationship UUID /
+//! `setUuid`. Both sides are resolved through [`resolve_reference`] for every
+//! combination of the dataset's declared mode sets
+//! ([`cascade::mode_combinations`]), and every combination whose ratio falls
+//! below `minimum` is reported in a single diagnostic per pair. Failures are
+//! labelled with only the mode sets the pair's colors vary by, so a mode set
+//! that does not affect them (e.g. `scale`) does not repeat a failure.
+//!
+//! Pairs that cannot be resolved to an sRGB literal in a given combination are
+//! skipped for that combination — dangling references are SPEC-001/SPEC-055's
+//! concern, not this rule's. A translucent foreground is composited over the
+//! background first; the background's own alpha is ignored.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::cascade::{self, resolve_reference, ModeCombination};
+use crate::color::Color;
+use crate::graph::{RelationshipRecord, TokenGraph};
+use crate::naming::extract_legacy_key;
+use crate::report::{Diagnostic, Severity};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
+
+/// Maximum CTR → CTR `$ref` hops followed before giving up (cycle guard;
+/// SPEC-003 reports the cycle itself).
+const MAX_HOPS: usize = 16;
+
+pub struct Rule;
+
+impl ValidationRule for Rule {
+    fn id(&self) -> &'static str {
+        "SPEC-058"
+    }
+
+    fn name(&self) -> &'static str {
+        "color-contrast-minimum"
+    }
+
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS
+            | RuleInputs::MODE_SETS
+            | RuleInputs::COMPONENTS
+            | RuleInputs::RELATIONSHIPS
+    }
+
+    fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
+        let graph = ctx.graph;
+        let axes = cascade::mode_axes(graph, &HashMap::new());
+        let combos = cascade::mode_combinations(&axes);
+        let modes: Vec<HashMap<String, String>> =
+            combos.iter().map(ModeCombination::as_map).collect();
+        let mut out = Vec::new();
+
+        for rel in &graph.relationships {
+            let Some(req) = rel.raw.get("contrast").and_then(Requirement::parse) else {
+                continue;
+            };
+            let label = rel
+                .raw
+                .get("scope")
+                .map(|c| serde_json::to_string(c).unwrap_or_default())
+                .unwrap_or_default();
+            let pairs: Vec<Option<(Color, Color)>> = modes
+                .iter()
+                .map(|mode| {
+                    if !applies_in(rel, mode) {
+                        return None;
+                    }
+                    let fg = ctr_color(graph, rel, mode, 0)?;
+                    Some((fg, resolve_color(graph, &req.against, mode, 0)?))
+                })
+                .collect();
+            let failures = req.failures(&combos, &pairs);
+            if !failures.is_empty() {
+                out.push(self.diagnostic(
+                    rel.file.clone(),
+                    None,
+                    format!("Relationship '{label}'"),
+                    &req,
+                    &failures,
+                ));
+            }
+        }
+
+        for comp in &graph.components {
+            let Some(bindings) = comp.raw.get("tokenBindings").and_then(|v| v.as_array()) else {
+                continue;
+            };
+            for binding in bindings {
+                let Some(token) = binding.get("token").and_then(|v| v.as_str()) else {
+                    continue;
+                };
+                let Some(req) = binding.get("contrast").and_then(Requirement::parse) else {
+                    continue;
+                };
+                let pairs: Vec<Option<(Color, Color)>> = modes
+                    .iter()
+                    .map(|mode| {
+                        let fg = resolve_color(graph, token, mode, 0)?;
+                        Some((fg, resolve_color(graph, &req.against, mode, 0)?))
+                    })
+                    .collect();
+                let failures = req.failures(&combos, &pairs);
+                if !failures.is_empty() {
+                    out.push(self.diagnostic(
+                        comp.file.clone(),
+                        Some(token.to_string()),
+                        format!("Component '{}' token binding '{token}'", comp.name),
+                        &req,
+                        &failures,
+                    ));
+                }
+            }
+        }
+
+        out
+    }
+}
+
+impl Rule {
+    fn diagnostic(
+        &self,
+        file: std::path::PathBuf,
+        token: Option<String>,
+        subject: String,
+        req: &Requirement,
+        failures: &[String],
+    ) -> Diagnostic {
+        Diagnostic {
+            file,
+            token,
+            rule_id: Some(self.id().to_string()),
+            severity: Severity::Error,
+            message: format!(
+                "{subject} contrast against '{}' is below {} in {}",
+                req.against,
+                req.algorithm.threshold_label(req.minimum),
+                failures.join("; ")
+            ),
+            instance_path: None,
+            schema_path: None,
+            fix: None,
+            span: None,
+        }
+    }
+}
+
+// ── Requirement ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Algorithm {
+    Wcag2,
+    Apca,
+}
+
+impl Algorithm {
+    fn default_minimum(self) -> f64 {
+        match self {
+            Algorithm::Wcag2 => 4.5,
+            Algorithm::Apca => 60.0,
+        }
+    }
+
+    fn threshold_label(self, minimum: f64) -> String {
+        match self {
+            Algorithm::Wcag2 => format!("{minimum}:1"),
+            Algorithm::Apca => format!("APCA Lc {minimum}"),
+        }
+    }
+
+    fn score(self, fg: Color, bg: Color) -> f64 {
+        match self {
+            Algorithm::Wcag2 => fg.wcag_contrast(bg),
+            Algorithm::Apca => fg.apca_contrast(bg),
+        }
+    }
+
+    fn score_label(self, score: f64) -> String {
+        match self {
+            Algorithm::Wcag2 => format!("{score:.2}:1"),
+            Algorithm::Apca => format!("Lc {score:.1}"),
+        }
+    }
+}
+
+/// A parsed `contrast` declaration.
+struct Requirement {
+    against: String,
+    minimum: f64,
+    algorithm: Algorithm,
+}
+
+impl Requirement {
+    /// Parse a `contrast` object. Malformed declarations are Layer 1's concern
+    /// and yield `None` here.
+    fn parse(v: &Value) -> Option<Self> {
+        let against = v.get("against")?.as_str()?.to_string();
+        let algorithm = match v.get("algorithm").and_then(|a| a.as_str()) {
+            None | Some("wcag2") => Algorithm::Wcag2,
+            Some("apca") => Algorithm::Apca,
+            Some(_) => return None,
+        };
+        let minimum = match v.get("minimum") {
+            None => algorithm.default_minimum(),
+            Some(m) => m.as_f64()?,
+        };
+        Some(Self {
+            against,
+            minimum,
+            algorithm,
+        })
+    }
+
+    /// `"<modes> (<score>)"` labels for the combinations whose pair (`None`
+    /// where it does not apply or resolve) falls below the minimum. A
+    /// translucent foreground is composited over the background first.
+    fn failures(
+        &self,
+        combos: &[ModeCombination],
+        pairs: &[Option<(Color, Color)>],
+    ) -> Vec<String> {
+        let varying = varying_axes(combos, pairs);
+        let mut out: Vec<String> = Vec::new();
+        for (combo, pair) in combos.iter().zip(pairs) {
+            let Some((fg, bg)) = pair else {
+                continue;
+            };
+            let score = self.algorithm.score(fg.over(*bg), *bg);
+            // APCA polarity is signed (negative for light text on dark); compare magnitude.
+            if score.abs() >= self.minimum {
+                continue;
+            }
+            let label = format!(
+                "{} ({})",
+                mode_label(combo, &varying),
+                self.algorithm.score_label(score)
+            );
+            if !out.contains(&label) {
+                out.push(label);
+            }
+        }
+        out
+    }
+}
+
+// ── Mode combinations ────────────────────────────────────────────────────────
+
+/// For each mode set axis, whether changing only its mode changes a pair's
+/// outcome between any two combinations.
+fn varying_axes(combos: &[ModeCombination], pairs: &[Option<(Color, Color)>]) -> Vec<bool> {
+    let axis_count = combos.first().map_or(0, |c| c.modes.len());
+    (0..axis_count)
+        .map(|axis| {
+            combos.iter().zip(pairs).any(|(a, pa)| {
+                combos.iter().zip(pairs).any(|(b, pb)| {
+                    pa != pb
+                        && a.modes
+                            .iter()
+                            .zip(&b.modes)
+                            .enumerate()
+                            .all(|(i, (ma, mb))| i == axis || ma == mb)
+                })
+            })
+        })
+        .collect()
+}
+
+/// `"set=mode, …"` over the `varying` axes of `combo`.
+fn mode_label(combo: &ModeCombination, varying: &[bool]) -> String {
+    let parts: Vec<String> = combo
+        .modes
+        .iter()
+        .zip(varying)
+        .filter(|(_, v)| **v)
+        .map(|((set, mode), _)| format!("{set}={mode}"))
+        .collect();
+    if parts.is_empty() {
+        "all modes".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Whether a CTR's `scope.options` mode pins (e.g. `colorScheme: "dark"`) are
+/// compatible with `mode`. Unpinned dimensions apply in every mode.
+fn applies_in(rel: &RelationshipRecord, mode: &HashMap<String, String>) -> bool {
+    let Some(options) = rel
+        .raw
+        .get("scope")
+        .and_then(|s| s.get("options"))
+        .and_then(|o| o.as_object())
+    else {
+        return true;
+    };
+    mode.iter().all(|(dim, m)| {
+        options
+            .get(dim)
+            .and_then(|v| v.as_str())
+            .is_none_or(|pinned| pinned == m)
+    })
+}
+
+// ── Resolution ───────────────────────────────────────────────────────────────
+
+/// Resolve a CTR's own color in `mode`: its literal `value`, or its `$ref`.
+fn ctr_color(
+    graph: &TokenGraph,
+    rel: &RelationshipRecord,
+    mode: &HashMap<String, String>,
+    hops: usize,
+) -> Option<Color> {
+    if let Some(value) = rel.raw.get("value") {
+        return parse_color(value.as_str()?);
+    }
+    let target = rel.raw.get("$ref")?.as_str()?;
+    resolve_color(graph, target, mode, hops + 1)
+}
+
+/// Resolve a color reference in `mode`. `target` may be a color literal, a
+/// relationship `uuid`/`setUuid`, a token UUID, or a token name.
+fn resolve_color(
+    graph: &TokenGraph,
+    target: &str,
+    mode: &HashMap<String, String>,
+    hops: usize,
+) -> Option<Color> {
+    if hops > MAX_HOPS {
+        return None;
+    }
+    if let Some(color) = parse_color(target) {
+        return Some(color);
+    }
+    let target = target.trim().trim_start_matches('{').trim_end_matches('}');
+
+    let mut ctrs = graph
+        .relationships
+        .iter()
+        .filter(|r| {
+            r.uuid.as_deref() == Some(target)
+                || r.raw.get("setUuid").and_then(|v| v.as_str()) == Some(target)
+        })
+        .peekable();
+    if ctrs.peek().is_some() {
+        let rel = ctrs.find(|r| applies_in(r, mode))?;
+        return ctr_color(graph, rel, mode, hops);
+    }
+
+    // Token UUIDs resolve to their legacy key so the cascade picks the
+    // mode-appropriate variant; names go to `resolve_reference` directly.
+    let slug = graph
+        .resolve_alias_in_context(target, mode)
+        .and_then(|t| t.raw.get("name").and_then(extract_legacy_key))
+        .unwrap_or_else(|| target.to_string());
+    let value = resolve_reference(graph, &slug, mode)?.value?;
+    parse_color(value.as_str()?)
+}
+
+/// A color literal, clipped into the sRGB gamut.
+fn parse_color(s: &str) -> Option<Color> {
+    Color::parse(s).map(Color::clamped)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::graph::{ComponentRecord, ModeSetRecord, RelationshipRecord, TokenGraph};
+    use crate::registry::RegistryData;
+    use crate::validate::rule::{ValidationContext, ValidationRule};
+    use crate::validate::rules::spec058::Rule;
+
+    fn run(g: &TokenGraph) -> Vec<crate::report::Diagnostic> {
+        let exceptions = std::collections::HashSet::new();
+        let registry = RegistryData::embedded();
+        let ctx = ValidationContext {
+            graph: g,
+            naming_exceptions: &exceptions,
+            registry,
+            manifest: None,
+        };
+        Rule.validate(&ctx)
+    }
+
+    fn mode_set(name: &str, modes: &[&str]) -> ModeSetRecord {
+        ModeSetRecord {
+            file: PathBuf::from(format!("mode-sets/{name}.json")),
+            name: name.into(),
+            modes: modes.iter().map(|m| m.to_string()).collect(),
+            default_mode: modes[0].into(),
+        }
+    }
+
+    /// `background` and `text` tokens in light and dark, with the given dark text color.
+    fn themed_graph(dark_text: &str) -> TokenGraph {
+        let tokens = [
+            ("background", "light", "#ffffff"),
+            ("background", "dark", "#1d1d1d"),
+            ("text", "light", "#222222"),
+            ("text", "dark", dark_text),
+        ];
+        TokenGraph::from_pairs(
+            tokens
+                .iter()
+                .map(|(prop, scheme, value)| {
+                    (
+                        format!("{prop}-{scheme}"),
+                        PathBuf::from("color.tokens.json"),
+                        json!({"name": {"property": prop, "colorScheme": scheme}, "value": value}),
+                    )
+                })
+                .collect(),
+        )
+        .with_mode_sets(vec![
+            mode_set("colorScheme", &["light", "dark"]),
+            mode_set("contrast", &["regular", "high"]),
+        ])
+    }
+
+    fn ctr(raw: serde_json::Value) -> RelationshipRecord {
+        RelationshipRecord {
+            file: PathBuf::from("relationships/button.json"),
+            index: 0,
+            uuid: raw.get("uuid").and_then(|v| v.as_str()).map(String::from),
+            raw,
+        }
+    }
+
+    #[test]
+    fn ctr_pair_reports_only_failing_modes() {
+        let g = themed_graph("#444444").with_relationships(vec![ctr(json!({
+            "scope": {"component": "button", "property": "color", "options": {"object": "text"}},
+            "$ref": "text",
+            "contrast": {"against": "background"}
+        }))]);
+        let diags = run(&g);
+        assert_eq!(diags.len(), 1);
+        let msg = &diags[0].message;
+        assert!(msg.contains("below 4.5:1"), "{msg}");
+        // The colors do not vary by `contrast`, so the failure is named once.
+        assert!(msg.ends_with("in colorScheme=dark (1.73:1)"), "{msg}");
+    }
+
+    #[test]
+    fn failures_name_only_the_mode_sets_colors_vary_by() {
+        let tokens = [
+            ("background", "light", "regular", "#ffffff"),
+            ("background", "dark", "regular", "#1d1d1d"),
+            ("text", "light", "regular", "#222222"),
+            ("text", "light", "high", "#000000"),
+            ("text", "dark", "regular", "#444444"),
+            ("text", "dark", "high", "#f0f0f0"),
+        ];
+        let g = TokenGraph::from_pairs(
+            tokens
+                .iter()
+                .map(|(prop, scheme, contrast, value)| {
+                    let name = if *prop == "background" {
+                        json!({"property": prop, "colorScheme": scheme})
+                    } else {
+                        json!({"property": prop, "colorScheme": scheme, "contrast": contrast})
+                    };
+                    (
+                        format!("{prop}-{scheme}-{contrast}"),
+                        PathBuf::from("color.tokens.json"),
+                        json!({"name": name, "value": value}),
+                    )
+                })
+                .collect(),
+        )
+        .with_mode_sets(vec![
+            mode_set("colorScheme", &["light", "dark"]),
+            mode_set("contrast", &["regular", "high"]),
+            mode_set("scale", &["medium", "large"]),
+        ])
+        .with_relationships(vec![ctr(json!({
+            "scope": {"component": "button", "property": "color"},
+            "$ref": "text",
+            "contrast": {"against": "background"}
+        }))]);
+        let diags = run(&g);
+        assert_eq!(diags.len(), 1);
+        let msg = &diags[0].message;
+        assert!(
+            msg.ends_with("in colorScheme=dark, contrast=regular (1.73:1)"),
+            "{msg}"
+        );
+    }
+
+    #[test]
+    fn passing_pair_is_silent() {
+        let g = themed_graph("#f0f0f0").with_relationships(vec![ctr(json!({
+            "scope": {"component": "button", "property": "color"},
+            "$ref": "text",
+            "contrast": {"against": "background"}
+        }))]);
+        assert!(run(&g).is_empty());
+    }
+
+    #[test]
+    fn mode_pinned_ctrs_resolve_against_set_uuid() {
+        let set = "bbbbbbbb-bbbb-4bbb-8bbb-bbbbbbbbbbbb";
+        let g = themed_graph("#f0f0f0").with_relationships(vec![
+            ctr(json!({
+                "scope": {"component": "button", "property": "color", "options": {"colorScheme": "light", "object": "background"}},
+                "value": "#ffffff", "setUuid": set, "legacyKey": "button-background-color"
+            })),
+            ctr(json!({
+                "scope": {"component": "button", "property": "color", "options": {"colorScheme": "dark", "object": "background"}},
+                "value": "#000000", "setUuid": set, "legacyKey": "button-background-color"
+            })),
+            ctr(json!({
+                "scope": {"component": "button", "property": "color", "options": {"colorScheme": "dark", "object": "text"}},
+                "value": "#333333",
+                "contrast": {"against": set, "minimum": 3}
+            })),
+        ]);
+        let diags = run(&g);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("below 3:1"));
+        assert!(diags[0].message.contains("colorScheme=dark"));
+        assert!(!diags[0].message.contains("colorScheme=light"));
+    }
+
+    #[test]
+    fn token_binding_pair_with_apca() {
+        let g = themed_graph("#f0f0f0").with_components(vec![ComponentRecord {
+            name: "button".into(),
+            file: PathBuf::from("components/button.json"),
+            raw: json!({
+                "name": "button",
+                "tokenBindings": [
+                    {"token": "text", "contrast": {"against": "background", "algorithm": "apca", "minimum": 100}}
+                ]
+            }),
+        }]);
+        let diags = run(&g);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].token.as_deref(), Some("text"));
+        assert!(diags[0].message.contains("below APCA Lc 100"));
+        assert!(diags[0]
+            .message
+            .contains("Component 'button' token binding 'text'"));
+    }
+
+    #[test]
+    fn unresolvable_pairs_are_skipped() {
+        let g = themed_graph("#444444").with_relationships(vec![ctr(json!({
+            "scope": {"component": "button", "property": "color"},
+            "$ref": "missing-token",
+            "contrast": {"against": "background"}
+        }))]);
+        assert!(run(&g).is_empty());
+    }
+}
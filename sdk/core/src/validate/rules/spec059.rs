@@ -0,0 +1,225 @@
+// Copyright 2026 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License. You may obtain a copy
+// of the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under
+// the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR REPRESENTATIONS
+// OF ANY KIND, either express or implied. See the License for the specific language
+// governing permissions and limitations under the License.
+
+//! SPEC-059: color-near-duplicate
+//!
+//! Two color literals in the same mode context whose CIEDE2000 difference is
+//! below the just-noticeable threshold ([`JND_DELTA_E`]) but whose values are
+//! not the same color are almost always a palette drift — `#6e6e6e` next to
+//! `rgb(109, 110, 110)` — rather than an intentional distinction.
+//!
+//! Tokens are grouped by the modes their name object pins for every declared
+//! mode set (so a light and a dark entry never collide), and each token is
+//! reported at most once, against the first entry (by name) it duplicates.
+//! Exact duplicates, however spelled, are left alone: sharing a value is what
+//! aliases are for, and is out of scope here. Deprecated tokens and
+//! translucent colors at a different opacity are skipped.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::color::{Color, JND_DELTA_E};
+use crate::graph::TokenRecord;
+use crate::naming::extract_legacy_key;
+use crate::report::{Diagnostic, Severity};
+use crate::validate::rule::{RuleInputs, ValidationContext, ValidationRule};
+
+pub struct Rule;
+
+impl ValidationRule for Rule {
+    fn id(&self) -> &'static str {
+        "SPEC-059"
+    }
+
+    fn name(&self) -> &'static str {
+        "color-near-duplicate"
+    }
+
+    fn inputs(&self) -> RuleInputs {
+        RuleInputs::TOKENS | RuleInputs::MODE_SETS
+    }
+
+    fn validate(&self, ctx: &ValidationContext<'_>) -> Vec<Diagnostic> {
+        let dims: Vec<&str> = ctx
+            .graph
+            .mode_sets
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+
+        // mode context label → palette entries in that context.
+        let mut groups: BTreeMap<String, Vec<Entry<'_>>> = BTreeMap::new();
+        for token in ctx.graph.tokens.values() {
+            if token.raw.get("deprecated").is_some() {
+                continue;
+            }
+            let Some(color) = token
+                .raw
+                .get("value")
+                .and_then(|v| v.as_str())
+                .and_then(Color::parse)
+            else {
+                continue;
+            };
+            let name = token.raw.get("name");
+            let context: Vec<String> = dims
+                .iter()
+                .filter_map(|dim| {
+                    let mode = name?.get(*dim)?.as_str()?;
+                    Some(format!("{dim}={mode}"))
+                })
+                .collect();
+            groups.entry(context.join(", ")).or_default().push(Entry {
+                label: name
+                    .and_then(extract_legacy_key)
+                    .unwrap_or_else(|| token.name.clone()),
+                token,
+                canonical: color.to_css(),
+                color,
+            });
+        }
+
+        let mut out = Vec::new();
+        for (context, mut entries) in groups {
+            entries.sort_by(|a, b| a.label.cmp(&b.label));
+            let mut reported = HashSet::new();
+            for (i, later) in entries.iter().enumerate() {
+                let Some(earlier) = entries[..i].iter().find(|e| {
+                    !reported.contains(&e.label)
+                        && e.label != later.label
+                        && e.canonical != later.canonical
+                        && e.color.is_visually_identical(later.color)
+                }) else {
+                    continue;
+                };
+                reported.insert(later.label.clone());
+                out.push(self.diagnostic(earlier, later, &context));
+            }
+        }
+        out
+    }
+}
+
+impl Rule {
+    fn diagnostic(&self, earlier: &Entry<'_>, later: &Entry<'_>, context: &str) -> Diagnostic {
+        let value = |e: &Entry<'_>| {
+            e.token
+                .raw
+                .get("value")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let context = if context.is_empty() {
+            String::new()
+        } else {
+            format!(" in {context}")
+        };
+        Diagnostic {
+            file: later.token.file.clone(),
+            token: Some(later.label.clone()),
+            rule_id: Some(self.id().to_string()),
+            severity: Severity::Warning,
+            message: format!(
+                "Color token '{}' ({}) is visually identical to '{}' ({}){context}: ΔE {:.2} is below {JND_DELTA_E}",
+                later.label,
+                value(later),
+                earlier.label,
+                value(earlier),
+                later.color.delta_e(earlier.color),
+            ),
+            instance_path: None,
+            schema_path: None,
+            fix: None,
+            span: None,
+        }
+    }
+}
+
+/// A token with a color literal value.
+struct Entry<'a> {
+    label: String,
+    token: &'a TokenRecord,
+    color: Color,
+    /// [`Color::to_css`], so exact duplicates are recognised across spellings.
+    canonical: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::graph::{ModeSetRecord, TokenGraph};
+    use crate::registry::RegistryData;
+    use crate::validate::rule::{ValidationContext, ValidationRule};
+    use crate::validate::rules::spec059::Rule;
+
+    fn run(g: &TokenGraph) -> Vec<crate::report::Diagnostic> {
+        let exceptions = std::collections::HashSet::new();
+        let registry = RegistryData::embedded();
+        let ctx = ValidationContext {
+            graph: g,
+            naming_exceptions: &exceptions,
+            registry,
+            manifest: None,
+        };
+        Rule.validate(&ctx)
+    }
+
+    fn graph(tokens: &[(&str, &str, &str)]) -> TokenGraph {
+        TokenGraph::from_pairs(
+            tokens
+                .iter()
+                .map(|(prop, scheme, value)| {
+                    (
+                        format!("{prop}-{scheme}"),
+                        PathBuf::from("color-palette.tokens.json"),
+                        json!({"name": {"property": prop, "colorScheme": scheme}, "value": value}),
+                    )
+                })
+                .collect(),
+        )
+        .with_mode_sets(vec![ModeSetRecord {
+            file: PathBuf::from("mode-sets/color-scheme.json"),
+            name: "colorScheme".into(),
+            modes: vec!["light".into(), "dark".into()],
+            default_mode: "light".into(),
+        }])
+    }
+
+    #[test]
+    fn near_duplicate_in_same_mode_is_reported_once() {
+        let diags = run(&graph(&[
+            ("gray-500", "light", "#6e6e6e"),
+            ("gray-550", "light", "rgb(109, 110, 110)"),
+            ("gray-555", "light", "hsl(0 0% 42.8%)"),
+            ("gray-600", "light", "#8f8f8f"),
+        ]));
+        assert_eq!(diags.len(), 2, "{diags:?}");
+        assert_eq!(diags[0].token.as_deref(), Some("gray-550"));
+        assert!(diags[0]
+            .message
+            .contains("is visually identical to 'gray-500' (#6e6e6e) in colorScheme=light"));
+        assert_eq!(diags[1].token.as_deref(), Some("gray-555"));
+    }
+
+    #[test]
+    fn exact_duplicates_and_other_modes_are_ignored() {
+        let diags = run(&graph(&[
+            ("white", "light", "#ffffff"),
+            ("gray-25", "light", "rgb(255, 255, 255)"),
+            ("gray-500", "light", "#6e6e6e"),
+            ("gray-500", "dark", "#6d6e6e"),
+            ("scrim", "light", "#6e6e6e80"),
+        ]));
+        assert!(diags.is_empty(), "{diags:?}");
+    }
+}
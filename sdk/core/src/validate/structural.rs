@@ -17,6 +17,7 @@ use jsonschema::ValidationError;
 use serde_json::Value;
 
 use crate::discovery::discover_json_files;
+use crate::locate;
 use crate::report::{Diagnostic, Severity, ValidationReport};
 use crate::schema::SchemaRegistry;
 use crate::CoreError;
@@ -33,21 +34,36 @@ pub fn validate_structural(
     };
 
     let files = discover_json_files(data_path)?;
-    for file in files {
-        validate_token_file(&file, registry, &mut report)?;
+    for file_report in crate::validate::par_map(&files, |file| validate_token_file(file, registry))
+    {
+        report.merge(file_report?);
     }
 
     report.recompute_valid();
     Ok(report)
 }
 
-fn validate_token_file(
+/// Layer 1 diagnostics for one token file.
+pub(crate) fn validate_token_file(
     path: &Path,
     registry: &SchemaRegistry,
-    report: &mut ValidationReport,
-) -> Result<(), CoreError> {
+) -> Result<ValidationReport, CoreError> {
     let text = std::fs::read_to_string(path)?;
-    let root: Value = match serde_json::from_str(&text) {
+    let mut report = ValidationReport::default();
+    check_token_text(path, &text, registry, &mut report);
+    for d in &mut report.errors {
+        d.span = locate::diagnostic_span(&text, d);
+    }
+    Ok(report)
+}
+
+fn check_token_text(
+    path: &Path,
+    text: &str,
+    registry: &SchemaRegistry,
+    report: &mut ValidationReport,
+) {
+    let root: Value = match serde_json::from_str(text) {
         Ok(v) => v,
         Err(e) => {
             report.push_error(Diagnostic {
@@ -58,8 +74,10 @@ fn validate_token_file(
                 message: format!("invalid JSON: {e}"),
                 instance_path: None,
                 schema_path: None,
+                fix: None,
+                span: locate::line_column_span(text, e.line(), e.column()),
             });
-            return Ok(());
+            return;
         }
     };
 
@@ -75,6 +93,8 @@ fn validate_token_file(
                     message: "cascade token array element must be a JSON object".to_string(),
                     instance_path: Some(format!("/{idx}")),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 continue;
             };
@@ -99,6 +119,8 @@ fn validate_token_file(
                     message: format!("unknown \"$schema\" URL (no loaded schema): {schema_url}"),
                     instance_path: Some(format!("/{idx}")),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 continue;
             };
@@ -113,10 +135,12 @@ fn validate_token_file(
                     message: err.to_string(),
                     instance_path: Some(format!("/{}{}", idx, err.instance_path)),
                     schema_path: Some(err.schema_path.to_string()),
+                    fix: None,
+                    span: None,
                 });
             }
         }
-        return Ok(());
+        return;
     }
 
     let Some(obj) = root.as_object() else {
@@ -128,8 +152,10 @@ fn validate_token_file(
             message: "token file root must be a JSON object or array".to_string(),
             instance_path: Some("/".to_string()),
             schema_path: None,
+            fix: None,
+            span: None,
         });
-        return Ok(());
+        return;
     };
 
     for (token_name, token_value) in obj {
@@ -142,6 +168,8 @@ fn validate_token_file(
                 message: "token value must be a JSON object".to_string(),
                 instance_path: Some(format!("/{}", escape_json_pointer(token_name))),
                 schema_path: None,
+                fix: None,
+                span: None,
             });
             continue;
         };
@@ -157,6 +185,8 @@ fn validate_token_file(
                     message: "missing required \"$schema\" property".to_string(),
                     instance_path: Some(format!("/{}", escape_json_pointer(token_name))),
                     schema_path: None,
+                    fix: None,
+                    span: None,
                 });
                 continue;
             }
@@ -171,6 +201,8 @@ fn validate_token_file(
                 message: format!("unknown \"$schema\" URL (no loaded schema): {schema_url}"),
                 instance_path: Some(format!("/{}", escape_json_pointer(token_name))),
                 schema_path: None,
+                fix: None,
+                span: None,
             });
             continue;
         };
@@ -185,6 +217,8 @@ fn validate_token_file(
                 message: err.to_string(),
                 instance_path: Some(err.instance_path.to_string()),
                 schema_path: Some(err.schema_path.to_string()),
+                fix: None,
+                span: None,
             });
         }
     }
@@ -200,10 +234,10 @@ fn validate_token_file(
             message: err.to_string(),
             instance_path: Some(err.instance_path.to_string()),
             schema_path: Some(err.schema_path.to_string()),
+            fix: None,
+            span: None,
         });
     }
-
-    Ok(())
 }
 
 fn escape_json_pointer(key: &str) -> String {
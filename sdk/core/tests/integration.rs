@@ -130,7 +130,7 @@ mod naming {
             // deliberately breaks the general roundtrip (the thin-format path is
             // tested separately in naming.rs unit tests).
             prop_assume!(
-                component.as_ref().map_or(true, |c| !property.starts_with(c.as_str()))
+                component.as_ref().is_none_or(|c| !property.starts_with(c.as_str()))
             );
 
             let obj = NameObject {
@@ -404,3 +404,184 @@ mod suggest_calibration {
         );
     }
 }
+
+mod incremental_validation {
+    //! Incremental re-validation of the full Spectrum token set
+    //! (`validate::incremental`) against a from-scratch run.
+    //!
+    //! The benchmarks cover the legacy `packages/tokens/src` set and the
+    //! cascade `packages/design-data/tokens` set. They are ignored by default;
+    //! run them optimized:
+    //! `cargo test --release -p design-data-core --test integration -- --ignored --nocapture incremental`
+
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, Instant};
+
+    use design_data_core::report::ValidationReport;
+    use design_data_core::schema::SchemaRegistry;
+    use design_data_core::validate;
+    use design_data_core::validate::incremental::{IncrementalValidator, ValidationInputs};
+    use design_data_core::validate::rule_config::RuleConfig;
+
+    fn repo() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+    }
+
+    /// The legacy token set and the file [`edit_one_file`] changes in it.
+    const LEGACY: (&str, &str) = ("packages/tokens/src", "layout.json");
+
+    /// The cascade token set and the file [`edit_one_file`] changes in it.
+    const CASCADE: (&str, &str) = ("packages/design-data/tokens", "layout.tokens.json");
+
+    /// A writable copy of a token directory plus the design-data catalogs.
+    struct Dataset {
+        _dir: tempfile::TempDir,
+        inputs: ValidationInputs,
+    }
+
+    fn dataset(tokens_dir: &str) -> Dataset {
+        let dir = tempfile::tempdir().unwrap();
+        let tokens = dir.path().join("tokens");
+        std::fs::create_dir_all(&tokens).unwrap();
+        for entry in std::fs::read_dir(repo().join(tokens_dir)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                std::fs::copy(&path, tokens.join(path.file_name().unwrap())).unwrap();
+            }
+        }
+        let catalog = |name: &str| Some(repo().join("packages/design-data").join(name));
+        Dataset {
+            _dir: dir,
+            inputs: ValidationInputs {
+                mode_sets_path: catalog("mode-sets"),
+                components_path: catalog("components"),
+                relationships_path: catalog("relationships"),
+                guidelines_path: catalog("guidelines"),
+                ..ValidationInputs::new(tokens)
+            },
+        }
+    }
+
+    fn registry() -> SchemaRegistry {
+        SchemaRegistry::load_legacy_token_schemas(&repo().join("packages/tokens/schemas")).unwrap()
+    }
+
+    fn full(inputs: &ValidationInputs, registry: &SchemaRegistry) -> ValidationReport {
+        validate::validate_all_with_rule_config(
+            &inputs.data_path,
+            registry,
+            &HashSet::new(),
+            inputs.mode_sets_path.as_deref(),
+            inputs.components_path.as_deref(),
+            inputs.names_dir.as_deref(),
+            inputs.guidelines_path.as_deref(),
+            inputs.relationships_path.as_deref(),
+            &RuleConfig::default(),
+        )
+        .unwrap()
+    }
+
+    /// Change one token value in `file` (a legacy object or a cascade array)
+    /// so it (and the token rules) must be re-checked.
+    fn edit_one_file(tokens: &Path, file: &str) -> PathBuf {
+        let path = tokens.join(file);
+        let text = std::fs::read_to_string(&path).unwrap();
+        let mut doc: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let has_value = |t: &&mut serde_json::Value| t["value"].is_string();
+        let token = match &mut doc {
+            serde_json::Value::Object(map) => map.values_mut().find(has_value),
+            serde_json::Value::Array(items) => items.iter_mut().find(has_value),
+            _ => None,
+        }
+        .unwrap();
+        token["value"] = "123px".into();
+        std::fs::write(&path, serde_json::to_string_pretty(&doc).unwrap()).unwrap();
+        path
+    }
+
+    /// Rules iterate the graph's hash maps, so diagnostic order varies
+    /// between graph loads; compare as sorted lists, without fixes.
+    fn json(report: &ValidationReport) -> (bool, Vec<String>, Vec<String>) {
+        let sorted = |ds: &[design_data_core::report::Diagnostic]| {
+            let mut out: Vec<String> = ds
+                .iter()
+                .map(|d| {
+                    // Fixes carry freshly generated uuids.
+                    let d = design_data_core::report::Diagnostic {
+                        fix: None,
+                        ..d.clone()
+                    };
+                    serde_json::to_string(&d).unwrap()
+                })
+                .collect();
+            out.sort();
+            out
+        };
+        (
+            report.valid,
+            sorted(&report.errors),
+            sorted(&report.warnings),
+        )
+    }
+
+    #[test]
+    fn incremental_matches_full_validation_after_an_edit() {
+        let (tokens_dir, file) = LEGACY;
+        let data = dataset(tokens_dir);
+        let registry = registry();
+        let mut v = IncrementalValidator::new(data.inputs.clone());
+        let none = HashSet::new();
+        let config = RuleConfig::default();
+
+        let first = v.validate(&registry, &none, &config).unwrap();
+        assert_eq!(json(&first), json(&full(&data.inputs, &registry)));
+
+        edit_one_file(&data.inputs.data_path, file);
+        let again = v.validate(&registry, &none, &config).unwrap();
+        assert_eq!(json(&again), json(&full(&data.inputs, &registry)));
+        let stats = v.stats();
+        assert_eq!(stats.files_checked, 1);
+        assert!(stats.files_reused > 10, "{stats:?}");
+        assert!(stats.rules_reused > 0, "{stats:?}");
+    }
+
+    /// Time a from-scratch run against an incremental run after editing one
+    /// file of `dataset`.
+    fn bench_single_file_edit((tokens_dir, file): (&str, &str)) {
+        let data = dataset(tokens_dir);
+        let registry = registry();
+        let none = HashSet::new();
+        let config = RuleConfig::default();
+
+        let start = Instant::now();
+        full(&data.inputs, &registry);
+        let from_scratch = start.elapsed();
+
+        let mut v = IncrementalValidator::new(data.inputs.clone());
+        v.validate(&registry, &none, &config).unwrap();
+        edit_one_file(&data.inputs.data_path, file);
+        let start = Instant::now();
+        v.validate(&registry, &none, &config).unwrap();
+        let incremental = start.elapsed();
+
+        println!(
+            "{tokens_dir}: full validation: {from_scratch:?}; after one-file edit: {incremental:?} ({:?})",
+            v.stats()
+        );
+        assert_eq!(v.stats().files_checked, 1, "{:?}", v.stats());
+        assert!(incremental < Duration::from_secs(1));
+    }
+
+    #[test]
+    #[ignore = "benchmark; run with --release -- --ignored --nocapture"]
+    fn bench_revalidate_after_single_file_edit() {
+        bench_single_file_edit(LEGACY);
+    }
+
+    #[test]
+    #[ignore = "benchmark; run with --release -- --ignored --nocapture"]
+    fn bench_revalidate_cascade_after_single_file_edit() {
+        bench_single_file_edit(CASCADE);
+    }
+}
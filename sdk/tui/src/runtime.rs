@@ -197,6 +197,7 @@ mod tests {
                 alias_target: None,
                 raw: json!({ "value": "red", "name": { "property": name } }),
                 layer: Layer::Foundation,
+                span: None,
             })
             .collect();
         TokenGraph::from_records(records)
@@ -54,6 +54,7 @@ pub fn make_graph_with_tokens(names: &[&str]) -> TokenGraph {
                 "name": { "property": name }
             }),
             layer: Layer::Foundation,
+            span: None,
         })
         .collect();
     TokenGraph::from_records(records)
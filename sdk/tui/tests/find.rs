@@ -34,6 +34,7 @@ fn make_find_graph() -> TokenGraph {
                 "name": { "property": "background-color", "variant": "accent" }
             }),
             layer: Layer::Foundation,
+            span: None,
         },
         TokenRecord {
             name: "neutral-background-color-default".into(),
@@ -47,6 +48,7 @@ fn make_find_graph() -> TokenGraph {
                 "name": { "property": "background-color", "variant": "neutral" }
             }),
             layer: Layer::Foundation,
+            span: None,
         },
         TokenRecord {
             name: "button-accent-color".into(),
@@ -60,6 +62,7 @@ fn make_find_graph() -> TokenGraph {
                 "name": { "property": "color", "component": "button", "variant": "accent" }
             }),
             layer: Layer::Platform,
+            span: None,
         },
     ];
     TokenGraph::from_records(records)
@@ -212,7 +215,7 @@ fn open_results_view_has_correct_row_count() {
     advance_to_preview(&mut fs, &graph, &index);
     let event = fs.handle_key(key(KeyCode::Enter), &graph, &index);
     if let FindEvent::OpenResults(view) = event {
-        assert!(view.rows.len() >= 1);
+        assert!(!view.rows.is_empty());
         assert_eq!(view.expr_text, "property=background-color");
     } else {
         panic!("expected OpenResults");
@@ -573,6 +576,7 @@ fn make_facet_graph() -> TokenGraph {
                 "name": { "property": "color", "component": "button", "state": "default" }
             }),
             layer: Layer::Foundation,
+            span: None,
         },
         TokenRecord {
             name: "button-color-hover".into(),
@@ -585,6 +589,7 @@ fn make_facet_graph() -> TokenGraph {
                 "name": { "property": "color", "component": "button", "state": "hover" }
             }),
             layer: Layer::Foundation,
+            span: None,
         },
         // icon + color x1
         TokenRecord {
@@ -598,6 +603,7 @@ fn make_facet_graph() -> TokenGraph {
                 "name": { "property": "color", "component": "icon", "state": "default" }
             }),
             layer: Layer::Foundation,
+            span: None,
         },
         // button + background-color x1 (no "state")
         TokenRecord {
@@ -611,6 +617,7 @@ fn make_facet_graph() -> TokenGraph {
                 "name": { "property": "background-color", "component": "button" }
             }),
             layer: Layer::Foundation,
+            span: None,
         },
     ];
     TokenGraph::from_records(records)
@@ -251,6 +251,7 @@ fn make_resolve_graph() -> TokenGraph {
         alias_target: None,
         raw: json!({"name": {"property": "background-color"}, "value": "#fff"}),
         layer: Layer::Foundation,
+        span: None,
     }];
     TokenGraph::from_records(records).with_mode_sets(vec![ms])
 }
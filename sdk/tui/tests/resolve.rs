@@ -35,6 +35,7 @@ fn make_resolve_graph() -> TokenGraph {
             alias_target: None,
             raw: json!({"name": {"property": "background-color"}, "value": "#fff"}),
             layer: Layer::Foundation,
+            span: None,
         },
         TokenRecord {
             name: "bg-light".into(),
@@ -45,6 +46,7 @@ fn make_resolve_graph() -> TokenGraph {
             alias_target: None,
             raw: json!({"name": {"property": "background-color", "colorScheme": "light"}, "value": "#f0f0f0"}),
             layer: Layer::Foundation,
+            span: None,
         },
         TokenRecord {
             name: "bg-dark".into(),
@@ -55,6 +57,7 @@ fn make_resolve_graph() -> TokenGraph {
             alias_target: None,
             raw: json!({"name": {"property": "background-color", "colorScheme": "dark"}, "value": "#111"}),
             layer: Layer::Foundation,
+            span: None,
         },
     ];
     TokenGraph::from_records(records).with_mode_sets(vec![color_scheme_ms])
@@ -519,6 +519,7 @@ fn make_cascade_graph() -> TokenGraph {
             }
         }),
         layer: Layer::Foundation,
+        span: None,
     };
     TokenGraph::from_records(vec![record])
 }
@@ -24,7 +24,7 @@ use std::sync::OnceLock;
 #[cfg(feature = "embedded")]
 use design_data_core::cache;
 use design_data_core::cascade::{resolve_property, ResolutionContext};
-use design_data_core::diff::semantic_diff;
+use design_data_core::diff::{resolved_diff, semantic_diff};
 use design_data_core::graph::TokenGraph;
 use design_data_core::primer;
 use design_data_core::query;
@@ -34,9 +34,10 @@ use wasm_bindgen::prelude::*;
 
 use crate::error::{js_err, to_js_error};
 use crate::types::{
-    AddedToken, DeletedToken, DeprecatedToken, DiffResult, PropertyChange, RenamedToken,
-    ResolutionContext as WasmContext, ResolveResult, RevertedToken, SuggestResult,
-    SuggestResultArray, TokenResult, TokenResultArray, UpdatedToken, ValidationResult,
+    AddedToken, DeletedToken, DeprecatedToken, DiffOptions, DiffResult, PropertyChange,
+    RenamedToken, ResolutionContext as WasmContext, ResolveResult, ResolvedTokenChange,
+    ResolvedValueChange, RevertedToken, SuggestResult, SuggestResultArray, TokenResult,
+    TokenResultArray, UpdatedToken, ValidationResult,
 };
 
 // ---------------------------------------------------------------------------
@@ -211,16 +212,35 @@ impl Dataset {
     /// **Syntax:** `key=value` pairs separated by `,` (AND) or `|` (OR).
     /// Use `!=` for negation and `*` as a wildcard suffix or prefix.
     /// Valid keys: `property`, `component`, `variant`, `state`, `colorScheme`,
-    /// `scale`, `contrast`, `uuid`, `$schema`.
+    /// `scale`, `contrast`, `uuid`, `$schema`, and `value` (the resolved value,
+    /// which also accepts `>`, `>=`, `<`, `<=`, and `~=`).
+    ///
+    /// When `context` is given, only the tokens that win cascade resolution in
+    /// that context are considered, and `value` compares the values they
+    /// resolve to there.
     ///
     /// ```js
     /// const results = ds.query("property=color,colorScheme=dark");
     /// const all = ds.query("");   // returns all tokens
+    /// const white = ds.query("value=#FFFFFF", { colorScheme: "dark" });
     /// ```
     #[wasm_bindgen]
-    pub fn query(&self, filter_expr: &str) -> Result<TokenResultArray, JsValue> {
+    pub fn query(
+        &self,
+        filter_expr: &str,
+        context: Option<WasmContext>,
+    ) -> Result<TokenResultArray, JsValue> {
         let filter = query::parse(filter_expr).map_err(to_js_error)?;
-        let records = query::filter(&self.graph, &filter);
+        let records = match context {
+            Some(context) => {
+                let ctx = context
+                    .into_inner()
+                    .into_iter()
+                    .fold(ResolutionContext::new(), |acc, (k, v)| acc.with(k, v));
+                query::filter_in_context(&self.graph, &filter, &ctx)
+            }
+            None => query::filter(&self.graph, &filter),
+        };
         let results: Vec<TokenResult> = records.iter().map(|r| TokenResult::from(*r)).collect();
         Ok(TokenResultArray::new(results))
     }
@@ -369,22 +389,38 @@ impl Dataset {
     /// Returns a [`DiffResult`] with arrays of renamed, deprecated, reverted, added,
     /// deleted, and updated tokens.
     ///
+    /// With `{ resolved: true }`, `resolved` also lists every token whose
+    /// effective value changed, per mode pattern, with the alias chain that
+    /// carries the change. Tokens resolve in every combination of the other
+    /// dataset's mode sets; datasets built with `Dataset.fromTokens()` declare
+    /// none, so each token resolves once.
+    ///
     /// ```js
     /// const oldDs = Dataset.fromTokens(oldTokens);
     /// const newDs = Dataset.fromTokens(newTokens);
     /// const diff = oldDs.diff(newDs);
     /// console.log(`${diff.added.length} tokens added`);
+    ///
+    /// const { resolved } = oldDs.diff(newDs, { resolved: true });
+    /// for (const t of resolved) console.log(t.name, t.changes[0].cause);
     /// ```
     #[wasm_bindgen]
-    pub fn diff(&self, other: &Dataset) -> Result<DiffResult, JsValue> {
+    pub fn diff(
+        &self,
+        other: &Dataset,
+        options: Option<DiffOptions>,
+    ) -> Result<DiffResult, JsValue> {
         use design_data_core::diff as core_diff;
         let report = semantic_diff(&self.graph, &other.graph);
+        let options = options.unwrap_or_default();
 
         let map_property_change = |c: &core_diff::PropertyChange| PropertyChange {
             path: c.path.clone(),
             change_type: format!("{:?}", c.change_type).to_lowercase(),
             new_value: c.new_value.clone(),
             original_value: c.original_value.clone(),
+            delta_e: c.delta_e,
+            visually_identical: c.visually_identical,
         };
 
         let renamed = report
@@ -452,6 +488,31 @@ impl Dataset {
             })
             .collect();
 
+        let resolved = options.resolved.then(|| {
+            resolved_diff(&self.graph, &other.graph, None)
+                .changed
+                .into_iter()
+                .map(|t| ResolvedTokenChange {
+                    name: t.name,
+                    changes: t
+                        .changes
+                        .into_iter()
+                        .map(|c| ResolvedValueChange {
+                            when: c.when,
+                            combinations: c.combinations,
+                            original_value: c.original_value,
+                            new_value: c.new_value,
+                            original_chain: c.original_chain,
+                            new_chain: c.new_chain,
+                            cause: c.cause,
+                            delta_e: c.delta_e,
+                            visually_identical: c.visually_identical,
+                        })
+                        .collect(),
+                })
+                .collect()
+        });
+
         Ok(DiffResult {
             renamed,
             deprecated,
@@ -459,6 +520,7 @@ impl Dataset {
             added,
             deleted,
             updated,
+            resolved,
         })
     }
 
@@ -123,6 +123,12 @@ pub struct PropertyChange {
     pub new_value: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub original_value: Option<serde_json::Value>,
+    /// CIEDE2000 difference when both sides of an update are colors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_e: Option<f64>,
+    /// The updated color is perceptually indistinguishable from the original.
+    #[serde(default)]
+    pub visually_identical: bool,
 }
 
 /// A token that was renamed (same UUID, different name).
@@ -188,6 +194,47 @@ pub struct UpdatedToken {
     pub property_changes: Vec<PropertyChange>,
 }
 
+/// One way a token's effective value changed, and the mode patterns in which
+/// it did.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedValueChange {
+    /// Mode set → mode patterns; an absent mode set applies in all its modes.
+    pub when: Vec<std::collections::BTreeMap<String, String>>,
+    pub combinations: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_value: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<serde_json::Value>,
+    pub original_chain: Vec<String>,
+    pub new_chain: Vec<String>,
+    /// The token whose edit caused the change.
+    pub cause: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_e: Option<f64>,
+    #[serde(default)]
+    pub visually_identical: bool,
+}
+
+/// A token whose resolved value changed in at least one mode combination.
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedTokenChange {
+    pub name: String,
+    pub changes: Vec<ResolvedValueChange>,
+}
+
+/// Options for `Dataset.diff()`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct DiffOptions {
+    /// Also compare resolved values in every mode combination.
+    #[serde(default)]
+    pub resolved: bool,
+}
+
 /// The result of `Dataset.diff()`.
 ///
 /// Mirrors `design_data_core::diff::DiffReport` with typed arrays for each change category.
@@ -203,6 +250,9 @@ pub struct DiffResult {
     pub added: Vec<AddedToken>,
     pub deleted: Vec<DeletedToken>,
     pub updated: Vec<UpdatedToken>,
+    /// Effective value changes; only present with `{ resolved: true }`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<Vec<ResolvedTokenChange>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -323,52 +373,6 @@ impl From<design_data_core::suggest::SuggestionResult> for SuggestResult {
     }
 }
 
-#[cfg(test)]
-mod suggest_result_tests {
-    use super::*;
-    use design_data_core::graph::Layer;
-    use design_data_core::suggest::SuggestionResult;
-    use serde_json::json;
-    use std::path::PathBuf;
-
-    #[test]
-    fn conversion_uses_display_name_for_cascade_token() {
-        let result = SuggestionResult {
-            token_uuid: Some("uuid-1".to_string()),
-            token_name: "color-aliases.tokens.json:24".to_string(),
-            file: PathBuf::from("color-aliases.tokens.json"),
-            layer: Layer::Foundation,
-            confidence: 0.5,
-            name_object: Some(json!({
-                "colorRole": "accent",
-                "property": "background-color",
-                "state": "default",
-                "legacyKey": "accent-background-color-default",
-            })),
-            value: None,
-        };
-
-        let converted = SuggestResult::from(result);
-        assert_eq!(converted.token_name, "accent-background-color-default");
-    }
-
-    #[test]
-    fn conversion_falls_back_to_graph_key_without_name_object() {
-        let result = SuggestionResult {
-            token_uuid: None,
-            token_name: "some-legacy-string-key".to_string(),
-            file: PathBuf::from("legacy.tokens.json"),
-            layer: Layer::Foundation,
-            confidence: 0.5,
-            name_object: None,
-            value: None,
-        };
-
-        let converted = SuggestResult::from(result);
-        assert_eq!(converted.token_name, "some-legacy-string-key");
-    }
-}
-
 impl From<&design_data_core::graph::TokenRecord> for TokenResult {
     fn from(r: &design_data_core::graph::TokenRecord) -> Self {
         Self {
@@ -434,3 +438,49 @@ impl From<design_data_core::report::ValidationReport> for ValidationResult {
         }
     }
 }
+
+#[cfg(test)]
+mod suggest_result_tests {
+    use super::*;
+    use design_data_core::graph::Layer;
+    use design_data_core::suggest::SuggestionResult;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    #[test]
+    fn conversion_uses_display_name_for_cascade_token() {
+        let result = SuggestionResult {
+            token_uuid: Some("uuid-1".to_string()),
+            token_name: "color-aliases.tokens.json:24".to_string(),
+            file: PathBuf::from("color-aliases.tokens.json"),
+            layer: Layer::Foundation,
+            confidence: 0.5,
+            name_object: Some(json!({
+                "colorRole": "accent",
+                "property": "background-color",
+                "state": "default",
+                "legacyKey": "accent-background-color-default",
+            })),
+            value: None,
+        };
+
+        let converted = SuggestResult::from(result);
+        assert_eq!(converted.token_name, "accent-background-color-default");
+    }
+
+    #[test]
+    fn conversion_falls_back_to_graph_key_without_name_object() {
+        let result = SuggestionResult {
+            token_uuid: None,
+            token_name: "some-legacy-string-key".to_string(),
+            file: PathBuf::from("legacy.tokens.json"),
+            layer: Layer::Foundation,
+            confidence: 0.5,
+            name_object: None,
+            value: None,
+        };
+
+        let converted = SuggestResult::from(result);
+        assert_eq!(converted.token_name, "some-legacy-string-key");
+    }
+}
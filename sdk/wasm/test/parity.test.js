@@ -439,6 +439,40 @@ test("diff: property-value-update", (t) => {
   t.is(diff.deleted.length, 0);
 });
 
+test("diff: resolved compares effective values", (t) => {
+  const palette = (blue) => [
+    { name: { property: "blue-900" }, uuid: "b", value: blue },
+    { name: { property: "indigo-900" }, uuid: "i", value: "#3b63fb" },
+  ];
+  const accent = (ref) => ({
+    name: { property: "accent-color-900" },
+    uuid: "a",
+    $ref: ref,
+  });
+  const oldDs = wasm.Dataset.fromTokens([...palette("#3b63fb"), accent("b")]);
+
+  // Re-pointing to an equal value changes the raw token only.
+  const repointed = wasm.Dataset.fromTokens([
+    ...palette("#3b63fb"),
+    accent("i"),
+  ]);
+  const repoint = oldDs.diff(repointed, { resolved: true });
+  t.is(repoint.updated.length, 1);
+  t.deepEqual(repoint.resolved, []);
+  t.is(oldDs.diff(repointed).resolved, undefined);
+
+  // A palette edit reaches every alias of the edited token.
+  const edited = wasm.Dataset.fromTokens([...palette("#2f55e0"), accent("b")]);
+  const { resolved } = oldDs.diff(edited, { resolved: true });
+  t.deepEqual(resolved.map((r) => r.name), ["accent-color-900", "blue-900"]);
+  t.is(resolved[0].changes[0].cause, "blue-900");
+  t.deepEqual(resolved[0].changes[0].newChain, [
+    "accent-color-900",
+    "blue-900",
+  ]);
+  t.is(resolved[0].changes[0].newValue, "#2f55e0");
+});
+
 test("diff result has all expected fields", (t) => {
   const ds = wasm.Dataset.fromTokens([]);
   const diff = ds.diff(ds);